@@ -0,0 +1,213 @@
+//! Runtime support for `#[client]`-generated HTTP clients.
+//!
+//! Generated client methods build a URL from the same route inference the
+//! `#[http]` macro uses, send the request with `reqwest`, and hand the response
+//! to one of the `client_read_*` helpers below to decode it into the declared type.
+
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+
+/// Error returned by methods on a `#[client]`-generated client.
+///
+/// # Stability
+///
+/// This enum is `#[non_exhaustive]`: new error variants may be added in minor
+/// releases.  Match on it with a trailing wildcard arm (`_ => ...`) in
+/// downstream code so that adding a variant is not a breaking change.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ClientError {
+    /// The base URL could not be parsed or cannot carry path segments.
+    InvalidUrl(String),
+
+    /// A body parameter could not be serialized to JSON.
+    Encode(serde_json::Error),
+
+    /// The request could not be sent, or the response body could not be read.
+    Transport(reqwest::Error),
+
+    /// The server answered with a non-success status code.
+    ///
+    /// `body` holds the raw response body, which for `#[http]` services is the
+    /// JSON error object produced by the handler.
+    Status { status: u16, body: String },
+
+    /// The response body could not be decoded into the method's return type.
+    Decode(serde_json::Error),
+}
+
+impl ClientError {
+    /// The HTTP status code, if the server answered with an error status.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ClientError::Status { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::InvalidUrl(message) => write!(f, "invalid base URL: {message}"),
+            ClientError::Encode(e) => write!(f, "failed to encode request body: {e}"),
+            ClientError::Transport(e) => write!(f, "request failed: {e}"),
+            ClientError::Status { status, body } if body.is_empty() => {
+                write!(f, "server returned status {status}")
+            }
+            ClientError::Status { status, body } => {
+                write!(f, "server returned status {status}: {body}")
+            }
+            ClientError::Decode(e) => write!(f, "failed to decode response: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientError::Transport(e) => Some(e),
+            ClientError::Encode(e) | ClientError::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for ClientError {
+    fn from(e: reqwest::Error) -> Self {
+        ClientError::Transport(e)
+    }
+}
+
+/// Append path segments to `base_url`, percent-encoding each one.
+///
+/// Segments are appended after any path already present on the base URL, so
+/// `http://host/api` plus `["users", "42"]` yields `http://host/api/users/42`.
+#[doc(hidden)]
+pub fn client_url(base_url: &str, segments: &[&str]) -> Result<reqwest::Url, ClientError> {
+    let mut url =
+        reqwest::Url::parse(base_url).map_err(|e| ClientError::InvalidUrl(e.to_string()))?;
+    {
+        let mut path = url.path_segments_mut().map_err(|_| {
+            ClientError::InvalidUrl(format!("`{base_url}` cannot be used as a base URL"))
+        })?;
+        path.pop_if_empty();
+        path.extend(segments);
+    }
+    Ok(url)
+}
+
+/// Turn a non-success response into [`ClientError::Status`].
+async fn check_status(response: reqwest::Response) -> Result<reqwest::Response, ClientError> {
+    let status = response.status();
+    if status.is_success() {
+        Ok(response)
+    } else {
+        let body = response.text().await.unwrap_or_default();
+        Err(ClientError::Status {
+            status: status.as_u16(),
+            body,
+        })
+    }
+}
+
+/// Decode a JSON response body into `T`.
+#[doc(hidden)]
+pub async fn client_read_json<T: DeserializeOwned>(
+    response: reqwest::Response,
+) -> Result<T, ClientError> {
+    let bytes = check_status(response).await?.bytes().await?;
+    serde_json::from_slice(&bytes).map_err(ClientError::Decode)
+}
+
+/// Decode a JSON response body into `Some(T)`, mapping `404 Not Found` to `None`.
+#[doc(hidden)]
+pub async fn client_read_optional_json<T: DeserializeOwned>(
+    response: reqwest::Response,
+) -> Result<Option<T>, ClientError> {
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    client_read_json(response).await.map(Some)
+}
+
+/// Decode a `{"data": ...}` response body, as sent by `#[http(envelope = true)]`, into `T`.
+#[doc(hidden)]
+pub async fn client_read_data_json<T: DeserializeOwned>(
+    response: reqwest::Response,
+) -> Result<T, ClientError> {
+    #[derive(serde::Deserialize)]
+    struct Data<T> {
+        data: T,
+    }
+
+    client_read_json::<Data<T>>(response)
+        .await
+        .map(|body| body.data)
+}
+
+/// Decode a `{"data": ...}` response body into `Some(T)`, mapping `404 Not Found` to `None`.
+#[doc(hidden)]
+pub async fn client_read_optional_data_json<T: DeserializeOwned>(
+    response: reqwest::Response,
+) -> Result<Option<T>, ClientError> {
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    client_read_data_json(response).await.map(Some)
+}
+
+/// Read a raw response body, as sent for `Vec<u8>` / `Bytes` returns.
+#[doc(hidden)]
+pub async fn client_read_bytes(response: reqwest::Response) -> Result<Vec<u8>, ClientError> {
+    Ok(check_status(response).await?.bytes().await?.to_vec())
+}
+
+/// Read a raw response body into `Some`, mapping `404 Not Found` to `None`.
+#[doc(hidden)]
+pub async fn client_read_optional_bytes(
+    response: reqwest::Response,
+) -> Result<Option<Vec<u8>>, ClientError> {
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    client_read_bytes(response).await.map(Some)
+}
+
+/// Check the status of a response whose body carries no value.
+#[doc(hidden)]
+pub async fn client_read_unit(response: reqwest::Response) -> Result<(), ClientError> {
+    check_status(response).await.map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_url_appends_segments() {
+        let url = client_url("http://localhost:3000", &["users", "42"]).unwrap();
+        assert_eq!(url.as_str(), "http://localhost:3000/users/42");
+    }
+
+    #[test]
+    fn client_url_keeps_base_path() {
+        let url = client_url("http://localhost:3000/api/", &["users"]).unwrap();
+        assert_eq!(url.as_str(), "http://localhost:3000/api/users");
+    }
+
+    #[test]
+    fn client_url_encodes_segments() {
+        let url = client_url("http://localhost", &["files", "a b/c"]).unwrap();
+        assert_eq!(url.as_str(), "http://localhost/files/a%20b%2Fc");
+    }
+
+    #[test]
+    fn client_url_rejects_invalid_base() {
+        assert!(matches!(
+            client_url("not a url", &[]),
+            Err(ClientError::InvalidUrl(_))
+        ));
+    }
+}
@@ -0,0 +1,267 @@
+//! Ready-to-import request collections for `#[http(collection = "...")]`.
+//!
+//! Both formats are built from a service's OpenAPI paths, one request per
+//! operation, with every path, query and header parameter and every JSON body
+//! filled in with a sample value taken from its schema (`example`, then
+//! `default`, then the first `enum` value, then a placeholder for the type).
+//! Requests are addressed relative to a base URL variable that starts out as
+//! [`DEFAULT_BASE_URL`].
+//!
+//! ```ignore
+//! let collection = postman_collection("Users", &UserService::http_openapi_paths());
+//! std::fs::write("users.postman_collection.json", collection.to_string())?;
+//! ```
+
+use serde_json::{Map, Value, json};
+use server_less_openapi::{OpenApiParameter, OpenApiPath};
+
+/// Initial value of the collection's base URL variable.
+pub const DEFAULT_BASE_URL: &str = "http://localhost:3000";
+
+/// A Postman collection (v2.1 format, also imported by Bruno and HTTPie) with
+/// one request per operation in `paths`, addressed relative to `{{baseUrl}}`.
+pub fn postman_collection(name: &str, paths: &[OpenApiPath]) -> Value {
+    let items: Vec<Value> = paths
+        .iter()
+        .map(|path| {
+            let segments: Vec<String> = path
+                .path
+                .split('/')
+                .filter(|segment| !segment.is_empty())
+                .map(|segment| match path_param_name(segment) {
+                    Some(param) => format!(":{param}"),
+                    None => segment.to_string(),
+                })
+                .collect();
+            let variables: Vec<Value> = params_in(path, "path")
+                .map(|p| json!({ "key": p.name, "value": param_sample(p) }))
+                .collect();
+            let query: Vec<Value> = params_in(path, "query")
+                .map(|p| json!({ "key": p.name, "value": param_sample(p) }))
+                .collect();
+            let mut headers: Vec<Value> = params_in(path, "header")
+                .map(|p| json!({ "key": p.name, "value": param_sample(p) }))
+                .collect();
+
+            let query_string = params_in(path, "query")
+                .map(|p| format!("{}={}", p.name, param_sample(p)))
+                .collect::<Vec<_>>()
+                .join("&");
+            let mut raw = format!("{{{{baseUrl}}}}/{}", segments.join("/"));
+            if !query_string.is_empty() {
+                raw = format!("{raw}?{query_string}");
+            }
+
+            let mut request = Map::new();
+            request.insert("method".to_string(), json!(path.method.to_uppercase()));
+            if let Some(description) = &path.operation.description {
+                request.insert("description".to_string(), json!(description));
+            }
+            if let Some((content_type, body)) = body_sample(path) {
+                headers.push(json!({ "key": "Content-Type", "value": content_type }));
+                request.insert(
+                    "body".to_string(),
+                    json!({
+                        "mode": "raw",
+                        "raw": pretty(&body),
+                        "options": { "raw": { "language": "json" } }
+                    }),
+                );
+            }
+            request.insert("header".to_string(), Value::Array(headers));
+            request.insert(
+                "url".to_string(),
+                json!({
+                    "raw": raw,
+                    "host": ["{{baseUrl}}"],
+                    "path": segments,
+                    "variable": variables,
+                    "query": query,
+                }),
+            );
+            json!({ "name": request_name(path), "request": request })
+        })
+        .collect();
+
+    json!({
+        "info": {
+            "name": name,
+            "schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json"
+        },
+        "variable": [{ "key": "baseUrl", "value": DEFAULT_BASE_URL }],
+        "item": items,
+    })
+}
+
+/// An Insomnia export (format 4) holding a workspace named `name`, a base
+/// environment defining `base_url`, and one request per operation in `paths`.
+pub fn insomnia_collection(name: &str, paths: &[OpenApiPath]) -> Value {
+    const WORKSPACE_ID: &str = "wrk_server_less";
+    let mut resources = vec![
+        json!({ "_id": WORKSPACE_ID, "_type": "workspace", "name": name }),
+        json!({
+            "_id": "env_server_less",
+            "_type": "environment",
+            "parentId": WORKSPACE_ID,
+            "name": "Base Environment",
+            "data": { "base_url": DEFAULT_BASE_URL }
+        }),
+    ];
+    for (index, path) in paths.iter().enumerate() {
+        // Insomnia has no path variables: put the samples into the URL itself.
+        let url_path = path
+            .path
+            .split('/')
+            .map(|segment| match path_param_name(segment) {
+                Some(param) => params_in(path, "path")
+                    .find(|p| p.name == param)
+                    .map(param_sample)
+                    .unwrap_or_else(|| param.to_string()),
+                None => segment.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+        let parameters: Vec<Value> = params_in(path, "query")
+            .map(|p| json!({ "name": p.name, "value": param_sample(p) }))
+            .collect();
+        let mut headers: Vec<Value> = params_in(path, "header")
+            .map(|p| json!({ "name": p.name, "value": param_sample(p) }))
+            .collect();
+        let body = match body_sample(path) {
+            Some((content_type, body)) => {
+                headers.push(json!({ "name": "Content-Type", "value": content_type }));
+                json!({ "mimeType": content_type, "text": pretty(&body) })
+            }
+            None => json!({}),
+        };
+        resources.push(json!({
+            "_id": format!("req_server_less_{index}"),
+            "_type": "request",
+            "parentId": WORKSPACE_ID,
+            "name": request_name(path),
+            "description": path.operation.description.clone().unwrap_or_default(),
+            "method": path.method.to_uppercase(),
+            "url": format!("{{{{ _.base_url }}}}{url_path}"),
+            "parameters": parameters,
+            "headers": headers,
+            "body": body,
+        }));
+    }
+
+    json!({
+        "_type": "export",
+        "__export_format": 4,
+        "__export_source": "server-less",
+        "resources": resources,
+    })
+}
+
+/// A sample value for `schema`: its `example`, `default` or first `enum` value
+/// if it has one, otherwise a placeholder of its type, recursing into arrays
+/// and object properties.
+pub fn sample_value(schema: &Value) -> Value {
+    if let Some(value) = schema.get("example").or_else(|| schema.get("default")) {
+        return value.clone();
+    }
+    if let Some(first) = schema.get("enum").and_then(|e| e.get(0)) {
+        return first.clone();
+    }
+    match schema.get("type").and_then(Value::as_str) {
+        Some("string") => json!("string"),
+        Some("integer") | Some("number") => json!(0),
+        Some("boolean") => json!(false),
+        Some("array") => match schema.get("items") {
+            Some(items) => json!([sample_value(items)]),
+            None => json!([]),
+        },
+        Some("object") | None if schema.get("properties").is_some() => {
+            let properties = schema["properties"].as_object().into_iter().flatten();
+            Value::Object(
+                properties
+                    .map(|(name, property)| (name.clone(), sample_value(property)))
+                    .collect(),
+            )
+        }
+        Some("object") => json!({}),
+        _ => Value::Null,
+    }
+}
+
+/// Name of a `{param}` path segment.
+fn path_param_name(segment: &str) -> Option<&str> {
+    segment.strip_prefix('{')?.strip_suffix('}')
+}
+
+/// The operation's parameters in `location` (`"path"`, `"query"`, `"header"`).
+fn params_in<'a>(
+    path: &'a OpenApiPath,
+    location: &'a str,
+) -> impl Iterator<Item = &'a OpenApiParameter> {
+    path.operation
+        .parameters
+        .iter()
+        .filter(move |p| p.location == location)
+}
+
+/// A parameter's sample as the text it is sent as.
+fn param_sample(param: &OpenApiParameter) -> String {
+    match sample_value(&param.schema) {
+        Value::String(text) => text,
+        other => other.to_string(),
+    }
+}
+
+/// The media type and a sample of the JSON request body, for operations that
+/// take one (`application/json` or a `+json` vendor type).
+fn body_sample(path: &OpenApiPath) -> Option<(&str, Value)> {
+    let content = path.operation.request_body.as_ref()?.get("content")?;
+    let (content_type, media) = content.as_object()?.iter().find(|(content_type, _)| {
+        *content_type == "application/json" || content_type.ends_with("+json")
+    })?;
+    Some((content_type, sample_value(media.get("schema")?)))
+}
+
+/// Display name of a request: its summary, else its operationId, else
+/// `METHOD /path`.
+fn request_name(path: &OpenApiPath) -> String {
+    path.operation
+        .summary
+        .clone()
+        .or_else(|| path.operation.operation_id.clone())
+        .unwrap_or_else(|| format!("{} {}", path.method.to_uppercase(), path.path))
+}
+
+fn pretty(value: &Value) -> String {
+    serde_json::to_string_pretty(value).expect("BUG: a serde_json::Value always serializes")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_value_prefers_example_then_default_then_enum() {
+        assert_eq!(
+            sample_value(&json!({"type": "integer", "example": 7})),
+            json!(7)
+        );
+        assert_eq!(
+            sample_value(&json!({"type": "integer", "default": 3})),
+            json!(3)
+        );
+        assert_eq!(
+            sample_value(&json!({"type": "string", "enum": ["red", "blue"]})),
+            json!("red")
+        );
+        assert_eq!(
+            sample_value(&json!({
+                "type": "object",
+                "properties": {
+                    "name": {"type": "string"},
+                    "tags": {"type": "array", "items": {"type": "boolean"}}
+                }
+            })),
+            json!({"name": "string", "tags": [false]})
+        );
+    }
+}
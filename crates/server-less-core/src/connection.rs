@@ -0,0 +1,176 @@
+//! Relay-style cursor connections for `#[graphql]` list queries.
+//!
+//! A `#[graphql]` method returning [`Connection<T>`] is exposed as a
+//! `{T}Connection` object with `edges { cursor node }` and `pageInfo` fields.
+//! A [`ConnectionArgs`] parameter becomes the standard `first`/`after`/`last`/`before`
+//! arguments:
+//!
+//! ```ignore
+//! #[graphql]
+//! impl UserService {
+//!     fn list_users(&self, page: ConnectionArgs) -> Connection<User> {
+//!         Connection::paginate(self.users.clone(), &page)
+//!     }
+//! }
+//! ```
+
+use serde::Serialize;
+
+/// Pagination arguments of a Relay connection field.
+///
+/// Filled from the `first`, `after`, `last` and `before` GraphQL arguments;
+/// any of them may be absent.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConnectionArgs {
+    /// Return at most this many edges from the start of the window.
+    pub first: Option<usize>,
+    /// Only return edges after this cursor.
+    pub after: Option<String>,
+    /// Return at most this many edges from the end of the window.
+    pub last: Option<usize>,
+    /// Only return edges before this cursor.
+    pub before: Option<String>,
+}
+
+/// One page of a Relay connection.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Connection<T> {
+    /// The nodes on this page, each with its cursor.
+    pub edges: Vec<Edge<T>>,
+    /// Where this page sits in the full list.
+    pub page_info: PageInfo,
+}
+
+/// A node together with the cursor that points at it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Edge<T> {
+    /// Opaque position of `node`, accepted by `after`/`before`.
+    pub cursor: String,
+    /// The item itself.
+    pub node: T,
+}
+
+/// Relay `PageInfo`: whether more edges exist on either side of a page.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageInfo {
+    pub has_next_page: bool,
+    pub has_previous_page: bool,
+    pub start_cursor: Option<String>,
+    pub end_cursor: Option<String>,
+}
+
+impl<T> Connection<T> {
+    /// Build a connection from edges the caller has already paged.
+    pub fn new(edges: Vec<Edge<T>>, has_previous_page: bool, has_next_page: bool) -> Self {
+        let page_info = PageInfo {
+            has_next_page,
+            has_previous_page,
+            start_cursor: edges.first().map(|edge| edge.cursor.clone()),
+            end_cursor: edges.last().map(|edge| edge.cursor.clone()),
+        };
+        Self { edges, page_info }
+    }
+
+    /// Page through a complete, in-memory list.
+    ///
+    /// Cursors are the items' offsets in `items`. A cursor that does not parse
+    /// as an offset is ignored.
+    pub fn paginate(items: Vec<T>, args: &ConnectionArgs) -> Self {
+        let parse =
+            |cursor: &Option<String>| cursor.as_deref().and_then(|c| c.parse::<usize>().ok());
+        let len = items.len();
+
+        let mut start = parse(&args.after)
+            .map_or(0, |after| after.saturating_add(1))
+            .min(len);
+        let mut end = parse(&args.before)
+            .map_or(len, |before| before.min(len))
+            .max(start);
+        if let Some(first) = args.first {
+            end = end.min(start.saturating_add(first));
+        }
+        if let Some(last) = args.last {
+            start = start.max(end.saturating_sub(last));
+        }
+
+        let edges = items
+            .into_iter()
+            .enumerate()
+            .skip(start)
+            .take(end - start)
+            .map(|(offset, node)| Edge {
+                cursor: offset.to_string(),
+                node,
+            })
+            .collect();
+        Self::new(edges, start > 0, end < len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cursors(connection: &Connection<char>) -> Vec<&str> {
+        connection
+            .edges
+            .iter()
+            .map(|edge| edge.cursor.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn paginate_forward() {
+        let items: Vec<char> = "abcde".chars().collect();
+        let args = ConnectionArgs {
+            first: Some(2),
+            after: Some("0".to_string()),
+            ..Default::default()
+        };
+        let page = Connection::paginate(items, &args);
+        assert_eq!(cursors(&page), ["1", "2"]);
+        assert_eq!(page.edges[0].node, 'b');
+        assert_eq!(
+            page.page_info,
+            PageInfo {
+                has_next_page: true,
+                has_previous_page: true,
+                start_cursor: Some("1".to_string()),
+                end_cursor: Some("2".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn paginate_backward() {
+        let items: Vec<char> = "abcde".chars().collect();
+        let args = ConnectionArgs {
+            last: Some(2),
+            before: Some("4".to_string()),
+            ..Default::default()
+        };
+        let page = Connection::paginate(items, &args);
+        assert_eq!(cursors(&page), ["2", "3"]);
+        assert!(page.page_info.has_next_page);
+        assert!(page.page_info.has_previous_page);
+    }
+
+    #[test]
+    fn paginate_without_args_returns_everything() {
+        let page = Connection::paginate(vec!['a', 'b'], &ConnectionArgs::default());
+        assert_eq!(cursors(&page), ["0", "1"]);
+        assert!(!page.page_info.has_next_page);
+        assert!(!page.page_info.has_previous_page);
+    }
+
+    #[test]
+    fn serializes_with_relay_field_names() {
+        let page = Connection::paginate(vec![1], &ConnectionArgs::default());
+        let json = serde_json::to_value(&page).unwrap();
+        assert_eq!(json["edges"][0]["node"], 1);
+        assert_eq!(json["pageInfo"]["hasNextPage"], false);
+        assert_eq!(json["pageInfo"]["endCursor"], "0");
+    }
+}
@@ -0,0 +1,242 @@
+//! Cross-origin resource sharing for `#[serve(http, cors(...))]`.
+//!
+//! [`Cors`] answers preflight (`OPTIONS` + `Access-Control-Request-Method`)
+//! requests itself and adds `Access-Control-Allow-*` headers to the responses of
+//! allowed origins. Generated `serve()` / `router()` code seeds the allowed
+//! methods and headers from the service's own routes with [`Cors::from_paths`],
+//! so a preflight names exactly what the API accepts.
+//!
+//! ```ignore
+//! let router = Cors::new()
+//!     .origins(["https://app.example.com"])
+//!     .credentials(true)
+//!     .max_age(3600)
+//!     .from_paths(&UserService::http_openapi_paths())
+//!     .layer(UserService.http_router());
+//! ```
+
+use std::sync::Arc;
+
+use axum::extract::{Request, State};
+use axum::http::{HeaderMap, HeaderValue, Method, StatusCode, header};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use server_less_openapi::OpenApiPath;
+
+/// CORS policy: which origins may call the API, and what a preflight allows.
+#[derive(Debug, Clone, Default)]
+pub struct Cors {
+    origins: Vec<String>,
+    credentials: bool,
+    max_age: Option<u64>,
+    methods: Vec<String>,
+    headers: Vec<String>,
+}
+
+impl Cors {
+    /// A policy allowing any origin, with `content-type` as the only allowed
+    /// request header and no methods until [`methods`](Self::methods) or
+    /// [`from_paths`](Self::from_paths) add some.
+    pub fn new() -> Self {
+        Self {
+            headers: vec!["content-type".to_string()],
+            ..Self::default()
+        }
+    }
+
+    /// Only allow these exact origins (`https://app.example.com`). Without any,
+    /// every origin is allowed.
+    pub fn origins<I, S>(mut self, origins: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.origins.extend(origins.into_iter().map(Into::into));
+        self
+    }
+
+    /// Allow cookies and `Authorization` on cross-origin requests
+    /// (`Access-Control-Allow-Credentials: true`). Needs explicit origins.
+    pub fn credentials(mut self, credentials: bool) -> Self {
+        self.credentials = credentials;
+        self
+    }
+
+    /// Let browsers cache a preflight answer for `seconds`.
+    pub fn max_age(mut self, seconds: u64) -> Self {
+        self.max_age = Some(seconds);
+        self
+    }
+
+    /// Also allow these methods.
+    pub fn methods<I, S>(mut self, methods: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        for method in methods {
+            push_unique(&mut self.methods, method.into().to_uppercase());
+        }
+        self
+    }
+
+    /// Also allow these request headers.
+    pub fn headers<I, S>(mut self, headers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        for name in headers {
+            push_unique(&mut self.headers, name.into().to_lowercase());
+        }
+        self
+    }
+
+    /// Allow the methods and header parameters of these routes.
+    pub fn from_paths(self, paths: &[OpenApiPath]) -> Self {
+        let headers = paths.iter().flat_map(|path| {
+            path.operation
+                .parameters
+                .iter()
+                .filter(|param| param.location == "header")
+                .map(|param| param.name.clone())
+        });
+        self.methods(paths.iter().map(|path| path.method.clone()))
+            .headers(headers)
+    }
+
+    /// Wrap `router` so it answers preflights and marks allowed responses.
+    ///
+    /// Call it on the finished router: like any axum layer, it only covers the
+    /// routes present when it is added.
+    ///
+    /// # Panics
+    ///
+    /// If credentials are allowed without explicit origins, which browsers
+    /// reject for `Access-Control-Allow-Origin: *`.
+    pub fn layer(self, router: axum::Router) -> axum::Router {
+        assert!(
+            !self.credentials || !self.origins.is_empty(),
+            "CORS credentials need explicit origins; add them with Cors::origins"
+        );
+        router.layer(axum::middleware::from_fn_with_state(
+            Arc::new(self),
+            apply_cors,
+        ))
+    }
+
+    fn allows(&self, origin: &str) -> bool {
+        self.origins.is_empty() || self.origins.iter().any(|o| o == origin)
+    }
+
+    /// The `Access-Control-Allow-Origin` / `-Credentials` / `Vary` headers for
+    /// an allowed `origin`.
+    fn origin_headers(&self, origin: &HeaderValue, headers: &mut HeaderMap) {
+        if self.origins.is_empty() && !self.credentials {
+            headers.insert(
+                header::ACCESS_CONTROL_ALLOW_ORIGIN,
+                HeaderValue::from_static("*"),
+            );
+            return;
+        }
+        headers.insert(header::ACCESS_CONTROL_ALLOW_ORIGIN, origin.clone());
+        headers.append(header::VARY, HeaderValue::from_static("origin"));
+        if self.credentials {
+            headers.insert(
+                header::ACCESS_CONTROL_ALLOW_CREDENTIALS,
+                HeaderValue::from_static("true"),
+            );
+        }
+    }
+
+    fn preflight(&self, origin: Option<&HeaderValue>) -> Response {
+        let mut response = StatusCode::NO_CONTENT.into_response();
+        let Some(origin) = origin else {
+            return response;
+        };
+        let headers = response.headers_mut();
+        self.origin_headers(origin, headers);
+        if let Ok(methods) = HeaderValue::from_str(&self.methods.join(", ")) {
+            headers.insert(header::ACCESS_CONTROL_ALLOW_METHODS, methods);
+        }
+        if let Ok(names) = HeaderValue::from_str(&self.headers.join(", ")) {
+            headers.insert(header::ACCESS_CONTROL_ALLOW_HEADERS, names);
+        }
+        if let Some(seconds) = self.max_age {
+            headers.insert(header::ACCESS_CONTROL_MAX_AGE, HeaderValue::from(seconds));
+        }
+        response
+    }
+}
+
+fn push_unique(list: &mut Vec<String>, value: String) {
+    if !list.contains(&value) {
+        list.push(value);
+    }
+}
+
+async fn apply_cors(State(cors): State<Arc<Cors>>, request: Request, next: Next) -> Response {
+    let Some(origin) = request.headers().get(header::ORIGIN).cloned() else {
+        return next.run(request).await;
+    };
+    let allowed = origin.to_str().is_ok_and(|o| cors.allows(o));
+
+    let is_preflight = request.method() == Method::OPTIONS
+        && request
+            .headers()
+            .contains_key(header::ACCESS_CONTROL_REQUEST_METHOD);
+    if is_preflight {
+        // Disallowed origins get no CORS headers, which the browser treats as a refusal.
+        return cors.preflight(allowed.then_some(&origin));
+    }
+
+    let mut response = next.run(request).await;
+    if allowed {
+        cors.origin_headers(&origin, response.headers_mut());
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preflight_lists_methods_and_headers() {
+        let cors = Cors::new()
+            .origins(["https://a.com"])
+            .credentials(true)
+            .max_age(600)
+            .methods(["get", "POST", "GET"])
+            .headers(["X-Api-Key"]);
+        assert!(cors.allows("https://a.com"));
+        assert!(!cors.allows("https://b.com"));
+
+        let origin = HeaderValue::from_static("https://a.com");
+        let response = cors.preflight(Some(&origin));
+        let headers = response.headers();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(
+            headers[header::ACCESS_CONTROL_ALLOW_ORIGIN],
+            "https://a.com"
+        );
+        assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_CREDENTIALS], "true");
+        assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_METHODS], "GET, POST");
+        assert_eq!(
+            headers[header::ACCESS_CONTROL_ALLOW_HEADERS],
+            "content-type, x-api-key"
+        );
+        assert_eq!(headers[header::ACCESS_CONTROL_MAX_AGE], "600");
+
+        let refused = cors.preflight(None);
+        assert!(refused.headers().is_empty());
+    }
+
+    #[test]
+    fn any_origin_without_credentials_uses_wildcard() {
+        let mut headers = HeaderMap::new();
+        Cors::new().origin_headers(&HeaderValue::from_static("https://a.com"), &mut headers);
+        assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+        assert!(!headers.contains_key(header::VARY));
+    }
+}
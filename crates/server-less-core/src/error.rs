@@ -159,6 +159,11 @@ impl fmt::Display for ErrorCode {
 /// Trait for converting errors to protocol-agnostic error codes.
 ///
 /// Implement this for your error types, or use the derive macro.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `IntoErrorCode`",
+    label = "handler error type without an error code",
+    note = "derive `ServerlessError` on `{Self}`, or implement `IntoErrorCode` for it by hand"
+)]
 pub trait IntoErrorCode {
     /// Get the error code for this error
     fn error_code(&self) -> ErrorCode;
@@ -173,6 +178,179 @@ pub trait IntoErrorCode {
     fn jsonrpc_code(&self) -> i32 {
         self.error_code().jsonrpc_code()
     }
+
+    /// Get the application-specific error code for this error, if any.
+    ///
+    /// A stable machine-readable code such as `"USER_NOT_FOUND"` that clients can
+    /// switch on, more specific than the protocol-level [`ErrorCode`]. Surfaced as
+    /// `app_code` in [`ErrorResponse`]. Defaults to `None`.
+    fn app_code(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Static description of every variant this error type can produce.
+    ///
+    /// Spec generators (OpenRPC, OpenAPI) use this to document the errors a
+    /// method may return without constructing values. `#[derive(ServerlessError)]`
+    /// implements it; hand-written impls default to an empty list.
+    fn error_variants() -> Vec<ErrorVariantInfo>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+}
+
+/// Static metadata for a single error variant.
+///
+/// Returned by [`IntoErrorCode::error_variants`] so that spec generators can
+/// list possible errors (name, protocol codes, default message) at runtime
+/// without an error value in hand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorVariantInfo {
+    /// Variant name as written in Rust (e.g. `"UserNotFound"`).
+    pub name: &'static str,
+    /// Protocol-agnostic error code for this variant.
+    pub code: ErrorCode,
+    /// JSON-RPC numeric error code (honours `#[error(jsonrpc_code = ...)]`).
+    pub jsonrpc_code: i32,
+    /// Application-specific error code (`#[error(app_code = "...")]`), if any.
+    pub app_code: Option<&'static str>,
+    /// Default human-readable message; templated messages keep their `{0}` / `{field}`
+    /// placeholders.
+    pub message: &'static str,
+}
+
+impl ErrorVariantInfo {
+    /// JSON Schema of the `{"error": {...}}` body an HTTP handler sends for this
+    /// variant, with `code` (and `app_code`, if any) pinned to its values.
+    pub fn http_error_schema(&self) -> serde_json::Value {
+        let mut properties = serde_json::json!({
+            "code": {"type": "string", "enum": [self.code.to_string()]},
+            "message": {"type": "string", "example": self.message},
+            "details": {}
+        });
+        let mut required = vec!["code", "message"];
+        if let Some(app_code) = self.app_code {
+            properties["app_code"] = serde_json::json!({"type": "string", "enum": [app_code]});
+            required.push("app_code");
+        }
+        serde_json::json!({
+            "title": self.name,
+            "type": "object",
+            "properties": {
+                "error": {
+                    "type": "object",
+                    "properties": properties,
+                    "required": required
+                }
+            },
+            "required": ["error"]
+        })
+    }
+}
+
+/// OpenAPI `responses` entries for the variants of an error type, keyed by HTTP
+/// status.
+///
+/// Variants sharing a status are combined under an `anyOf` schema: their bodies can
+/// share a `code`, so one body may match several branches. Returns an empty
+/// map for an empty list, so callers can fall back to generic responses.
+pub fn openapi_error_responses(
+    variants: &[ErrorVariantInfo],
+) -> serde_json::Map<String, serde_json::Value> {
+    variants_by_status(variants)
+        .into_iter()
+        .map(|(status, variants)| {
+            let names: Vec<&str> = variants.iter().map(|v| v.name).collect();
+            let schema = match variants.as_slice() {
+                [variant] => variant.http_error_schema(),
+                _ => serde_json::json!({
+                    "anyOf": variants.iter().map(|v| v.http_error_schema()).collect::<Vec<_>>()
+                }),
+            };
+            let response = serde_json::json!({
+                "description": names.join(", "),
+                "content": {"application/json": {"schema": schema}}
+            });
+            (status.to_string(), response)
+        })
+        .collect()
+}
+
+/// Markdown response-table rows (`| Status | Description | Type |`) for the variants
+/// of an error type, one per HTTP status as in [`openapi_error_responses`].
+///
+/// An empty list gives the generic 400/500 rows.
+#[doc(hidden)]
+pub fn markdown_error_rows(variants: &[ErrorVariantInfo]) -> String {
+    if variants.is_empty() {
+        return "| 400 | Bad request | — |\n| 500 | Internal server error | — |\n".to_string();
+    }
+    variants_by_status(variants)
+        .into_iter()
+        .map(|(status, variants)| {
+            let names: Vec<&str> = variants.iter().map(|v| v.name).collect();
+            format!("| {} | {} | — |\n", status, names.join(", "))
+        })
+        .collect()
+}
+
+/// `variants` grouped by HTTP status, in ascending status order.
+fn variants_by_status(
+    variants: &[ErrorVariantInfo],
+) -> std::collections::BTreeMap<u16, Vec<&ErrorVariantInfo>> {
+    let mut by_status: std::collections::BTreeMap<u16, Vec<&ErrorVariantInfo>> =
+        std::collections::BTreeMap::new();
+    for variant in variants {
+        by_status
+            .entry(variant.code.http_status())
+            .or_default()
+            .push(variant);
+    }
+    by_status
+}
+
+/// Fallback trait used by [`ErrorVariantsHelper`] when the error type does not
+/// implement [`IntoErrorCode`].
+///
+/// Same autoref-specialization pattern as [`HttpStatusFallback`]: generated spec
+/// code brings this trait into scope with `use ... as _` so that
+/// `ErrorVariantsHelper::<E>::new().error_variants()` yields an empty list for
+/// error types without `IntoErrorCode`.
+#[doc(hidden)]
+pub trait ErrorVariantsFallback {
+    /// Returns no variants.
+    fn error_variants(&self) -> Vec<ErrorVariantInfo>;
+}
+
+/// Helper used by generated spec code to list the variants of an error type.
+///
+/// Resolves to [`IntoErrorCode::error_variants`] when `T: IntoErrorCode`, and to
+/// the empty [`ErrorVariantsFallback`] impl otherwise.
+#[doc(hidden)]
+pub struct ErrorVariantsHelper<T>(std::marker::PhantomData<T>);
+
+impl<T> ErrorVariantsHelper<T> {
+    /// Create a helper for the error type `T`.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+impl<T: IntoErrorCode> ErrorVariantsHelper<T> {
+    /// Returns the variants reported by [`IntoErrorCode::error_variants`].
+    pub fn error_variants(&self) -> Vec<ErrorVariantInfo> {
+        T::error_variants()
+    }
+}
+
+impl<T> ErrorVariantsFallback for ErrorVariantsHelper<T> {
+    /// Fallback: no variant information for types without [`IntoErrorCode`].
+    fn error_variants(&self) -> Vec<ErrorVariantInfo> {
+        Vec::new()
+    }
 }
 
 /// Fallback trait used by [`HttpStatusHelper`] when the concrete error type
@@ -222,6 +400,55 @@ impl<T> HttpStatusFallback for HttpStatusHelper<'_, T> {
     }
 }
 
+/// Fallback trait used by [`ErrorResponseHelper`] when the error type does not
+/// implement [`IntoErrorCode`].
+///
+/// Same autoref-specialization pattern as [`HttpStatusFallback`]: errors without
+/// an `IntoErrorCode` impl become `INTERNAL` with their `Debug` output as message.
+#[doc(hidden)]
+pub trait ErrorResponseFallback {
+    /// Builds an `INTERNAL` error response from the `Debug` representation.
+    fn error_response(&self) -> ErrorResponse;
+}
+
+/// Helper used by generated handler code to turn any error value into an
+/// [`ErrorResponse`].
+///
+/// # Example (generated code pattern)
+///
+/// ```ignore
+/// use ::server_less::ErrorResponseFallback as _;
+/// let body = ::server_less::ErrorResponseHelper(&err).error_response().envelope();
+/// ```
+#[doc(hidden)]
+pub struct ErrorResponseHelper<'a, T>(pub &'a T);
+
+impl<T: IntoErrorCode> ErrorResponseHelper<'_, T> {
+    /// Builds the error response from [`IntoErrorCode`].
+    pub fn error_response(&self) -> ErrorResponse {
+        ErrorResponse::from(self.0)
+    }
+}
+
+impl<T: fmt::Debug> ErrorResponseFallback for ErrorResponseHelper<'_, T> {
+    fn error_response(&self) -> ErrorResponse {
+        ErrorResponse::new(ErrorCode::Internal, format!("{:?}", self.0))
+    }
+}
+
+/// Compile-time check that `E` can be the `Err` type of a handler's `Result`.
+///
+/// Generated handler code calls this spanned on the user's error type, so a
+/// missing `Debug` impl is reported against that type rather than as an
+/// unsatisfied bound on [`ErrorResponseHelper`] deep inside macro output.
+#[doc(hidden)]
+pub fn assert_handler_error<E: fmt::Debug>() {}
+
+/// [`assert_handler_error`] for JSON-RPC handlers, which additionally take the
+/// error's JSON-RPC code from [`IntoErrorCode`].
+#[doc(hidden)]
+pub fn assert_jsonrpc_error<E: IntoErrorCode + fmt::Debug>() {}
+
 // Implement for common error types
 impl IntoErrorCode for std::io::Error {
     fn error_code(&self) -> ErrorCode {
@@ -283,16 +510,23 @@ impl IntoErrorCode for Box<dyn std::error::Error + Send + Sync> {
 /// A generic error response that can be serialized and sent over the wire.
 ///
 /// Produced by protocol macros when a handler returns an `Err(_)` value.
-/// Serializes to `{"code": "NOT_FOUND", "message": "..."}` (details omitted when absent).
+/// Serializes to `{"code": "NOT_FOUND", "message": "..."}` (details omitted when absent);
+/// HTTP and WebSocket responses wrap it as `{"error": {...}}`, see [`envelope`](Self::envelope).
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ErrorResponse {
     /// Machine-readable error code (e.g. `"NOT_FOUND"`, `"INVALID_PARAMS"`).
     pub code: String,
     /// Human-readable error message.
     pub message: String,
+    /// Application-specific error code (e.g. `"USER_NOT_FOUND"`), omitted when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_code: Option<String>,
     /// Optional structured details about the error (omitted from serialization when absent).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<serde_json::Value>,
+    /// JSON-RPC numeric code (not serialized), see [`jsonrpc_code`](Self::jsonrpc_code).
+    #[serde(skip)]
+    pub(crate) jsonrpc_code: Option<i32>,
 }
 
 impl ErrorResponse {
@@ -301,15 +535,86 @@ impl ErrorResponse {
         Self {
             code: code.to_string(),
             message: message.into(),
+            app_code: None,
             details: None,
+            jsonrpc_code: Some(code.jsonrpc_code()),
         }
     }
 
+    /// JSON-RPC numeric code reported for this error.
+    ///
+    /// Taken from the [`ErrorCode`] passed to [`new`](Self::new) unless overridden
+    /// with [`with_jsonrpc_code`](Self::with_jsonrpc_code). `None` for a deserialized
+    /// error, since the numeric code is not part of the wire format.
+    pub fn jsonrpc_code(&self) -> Option<i32> {
+        self.jsonrpc_code
+    }
+
+    /// Override the JSON-RPC numeric code reported for this error.
+    pub fn with_jsonrpc_code(mut self, code: i32) -> Self {
+        self.jsonrpc_code = Some(code);
+        self
+    }
+
+    /// Attach an application-specific error code to this error response.
+    pub fn with_app_code(mut self, app_code: impl Into<String>) -> Self {
+        self.app_code = Some(app_code.into());
+        self
+    }
+
     /// Attach structured details to this error response.
     pub fn with_details(mut self, details: serde_json::Value) -> Self {
         self.details = Some(details);
         self
     }
+
+    /// Wrap this error in the wire envelope shared by the HTTP and WebSocket
+    /// protocols: `{"error": {"code": ..., "message": ..., "details": ...}}`.
+    pub fn envelope(&self) -> serde_json::Value {
+        serde_json::json!({ "error": self })
+    }
+
+    /// Build the JSON-RPC 2.0 error object: `{"code": -32602, "message": ..., "data": ...}`.
+    ///
+    /// `app_code` and `details` go under `data` when present. Without a
+    /// [`jsonrpc_code`](Self::jsonrpc_code) the code is `-32603` (internal error).
+    pub fn jsonrpc_error(&self) -> serde_json::Value {
+        let code = self.jsonrpc_code.unwrap_or(-32603);
+
+        let mut error = serde_json::json!({ "code": code, "message": self.message });
+        let mut data = serde_json::Map::new();
+        if let Some(app_code) = &self.app_code {
+            data.insert("app_code".to_string(), app_code.clone().into());
+        }
+        if let Some(details) = &self.details {
+            data.insert("details".to_string(), details.clone());
+        }
+        if !data.is_empty() {
+            error["data"] = serde_json::Value::Object(data);
+        }
+        error
+    }
+}
+
+impl<E: IntoErrorCode + ?Sized> From<&E> for ErrorResponse {
+    fn from(err: &E) -> Self {
+        let response =
+            Self::new(err.error_code(), err.message()).with_jsonrpc_code(err.jsonrpc_code());
+        match err.app_code() {
+            Some(app_code) => response.with_app_code(app_code),
+            None => response,
+        }
+    }
+}
+
+/// Keeps string-typed dispatchers (MCP, mounted JSON-RPC) working with the
+/// shared RPC codegen, which reports errors as `ErrorResponse`.
+///
+/// Renders as `CODE: message` (the `Display` form) so the code survives.
+impl From<ErrorResponse> for String {
+    fn from(err: ErrorResponse) -> Self {
+        err.to_string()
+    }
 }
 
 impl fmt::Display for ErrorResponse {
@@ -439,6 +744,105 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_error_response_envelope() {
+        let err = std::io::Error::new(std::io::ErrorKind::NotFound, "no such user");
+        let response = ErrorResponse::from(&err).with_details(serde_json::json!({"id": 7}));
+        assert_eq!(
+            response.envelope(),
+            serde_json::json!({
+                "error": {"code": "NOT_FOUND", "message": "no such user", "details": {"id": 7}}
+            })
+        );
+
+        #[derive(Debug)]
+        struct Opaque;
+        use ErrorResponseFallback as _;
+        let response = ErrorResponseHelper(&Opaque).error_response();
+        assert_eq!(response.code, "INTERNAL");
+        assert_eq!(response.message, "Opaque");
+        assert_eq!(String::from(response), "INTERNAL: Opaque");
+    }
+
+    #[test]
+    fn test_error_response_jsonrpc_error() {
+        let response = ErrorResponse::new(ErrorCode::InvalidInput, "bad id")
+            .with_app_code("BAD_ID")
+            .with_details(serde_json::json!({"id": 7}));
+        assert_eq!(
+            response.jsonrpc_error(),
+            serde_json::json!({
+                "code": -32602,
+                "message": "bad id",
+                "data": {"app_code": "BAD_ID", "details": {"id": 7}}
+            })
+        );
+
+        let response = ErrorResponse::new(ErrorCode::NotFound, "Method not found: x");
+        assert_eq!(response.jsonrpc_code(), Some(-32002));
+        let response = response.with_jsonrpc_code(-32601);
+        assert_eq!(
+            response.jsonrpc_error(),
+            serde_json::json!({"code": -32601, "message": "Method not found: x"})
+        );
+        assert!(response.envelope()["error"].get("jsonrpc_code").is_none());
+    }
+
+    #[test]
+    fn test_openapi_error_responses_group_by_status() {
+        let variants = [
+            ErrorVariantInfo {
+                name: "UserNotFound",
+                code: ErrorCode::NotFound,
+                jsonrpc_code: -32001,
+                app_code: Some("USER_NOT_FOUND"),
+                message: "user not found",
+            },
+            ErrorVariantInfo {
+                name: "PostNotFound",
+                code: ErrorCode::NotFound,
+                jsonrpc_code: -32001,
+                app_code: None,
+                message: "post not found",
+            },
+            ErrorVariantInfo {
+                name: "Invalid",
+                code: ErrorCode::InvalidInput,
+                jsonrpc_code: -32602,
+                app_code: None,
+                message: "invalid",
+            },
+        ];
+        let responses = openapi_error_responses(&variants);
+        assert_eq!(responses.keys().collect::<Vec<_>>(), ["400", "404"]);
+
+        let not_found = &responses["404"];
+        assert_eq!(not_found["description"], "UserNotFound, PostNotFound");
+        let schema = &not_found["content"]["application/json"]["schema"];
+        assert!(schema.get("oneOf").is_none());
+        let any_of = &schema["anyOf"];
+        assert_eq!(any_of.as_array().unwrap().len(), 2);
+        let user = &any_of[0]["properties"]["error"];
+        assert_eq!(
+            user["properties"]["code"]["enum"],
+            serde_json::json!(["NOT_FOUND"])
+        );
+        assert_eq!(
+            user["properties"]["app_code"]["enum"],
+            serde_json::json!(["USER_NOT_FOUND"])
+        );
+        assert_eq!(
+            user["required"],
+            serde_json::json!(["code", "message", "app_code"])
+        );
+
+        let invalid = &responses["400"]["content"]["application/json"]["schema"];
+        assert_eq!(invalid["title"], "Invalid");
+        assert!(invalid.get("anyOf").is_none());
+
+        assert!(openapi_error_responses(&[]).is_empty());
+    }
+
     #[test]
     fn test_http_status_codes() {
         assert_eq!(ErrorCode::NotFound.http_status(), 404);
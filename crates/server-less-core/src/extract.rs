@@ -266,6 +266,258 @@ impl WsSender {
     }
 }
 
+/// Shared dependency injected into `#[http]` handlers.
+///
+/// The service struct is the router's own state, so resources that live outside
+/// it (a database pool, an HTTP client, ...) are provided on the router instead
+/// and pulled out per request:
+///
+/// ```ignore
+/// use server_less::{http, Inject};
+///
+/// #[http]
+/// impl UserService {
+///     async fn get_user(&self, db: Inject<PgPool>, id: u32) -> Option<User> {
+///         db.fetch_user(id).await
+///     }
+/// }
+///
+/// let router = Inject::layer(pool, UserService.http_router());
+/// ```
+///
+/// Like [`Context`], injected parameters never appear in the OpenAPI spec or in
+/// generated clients. A request reaching a handler whose dependency was never
+/// provided fails with a 500 `INTERNAL` error.
+#[cfg(feature = "http")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Inject<T>(pub T);
+
+#[cfg(feature = "http")]
+impl<T: Clone + Send + Sync + 'static> Inject<T> {
+    /// Provide `value` to every handler of `router` that takes an `Inject<T>`.
+    ///
+    /// Call it on the finished router: like any axum layer, it only covers the
+    /// routes present when it is added.
+    pub fn layer(value: T, router: axum::Router) -> axum::Router {
+        router.layer(axum::Extension(value))
+    }
+}
+
+#[cfg(feature = "http")]
+impl<T> std::ops::Deref for Inject<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+#[cfg(feature = "http")]
+impl<T> std::ops::DerefMut for Inject<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "http")]
+impl<T, S> axum::extract::FromRequestParts<S> for Inject<T>
+where
+    T: Clone + Send + Sync + 'static,
+    S: Send + Sync,
+{
+    type Rejection = axum::response::Response;
+
+    async fn from_request_parts(
+        parts: &mut axum::http::request::Parts,
+        _state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<T>()
+            .cloned()
+            .map(Inject)
+            .ok_or_else(|| {
+                crate::http_error_response(
+                    500,
+                    crate::ErrorResponse::new(
+                        crate::ErrorCode::Internal,
+                        format!(
+                            "no `{}` was provided for injection (add it with Inject::layer)",
+                            std::any::type_name::<T>()
+                        ),
+                    ),
+                )
+            })
+    }
+}
+
+/// The raw JSON request body, bound without deserializing it.
+///
+/// An `#[http]` method taking a `RawJson` parameter receives the whole body as
+/// is, for gateways and forwarders that pass arbitrary JSON along or inspect it
+/// lazily:
+///
+/// ```ignore
+/// use server_less::{http, RawJson};
+///
+/// #[http]
+/// impl Gateway {
+///     async fn create_event(&self, topic: String, payload: RawJson) -> u64 {
+///         self.queue.publish(&topic, payload.get()).await
+///     }
+/// }
+/// ```
+///
+/// The body only has to be valid JSON; anything else answers 400
+/// `INVALID_INPUT`. Since it is the whole body, other parameters of the method
+/// must come from the path, query or headers. OpenAPI documents it as a
+/// free-form object, and generated clients send it verbatim.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct RawJson(pub Box<serde_json::value::RawValue>);
+
+impl RawJson {
+    /// The body's JSON text.
+    pub fn get(&self) -> &str {
+        self.0.get()
+    }
+
+    /// Deserialize the body as `T`.
+    pub fn parse<'a, T: serde::Deserialize<'a>>(&'a self) -> serde_json::Result<T> {
+        serde_json::from_str(self.0.get())
+    }
+}
+
+impl std::ops::Deref for RawJson {
+    type Target = serde_json::value::RawValue;
+
+    fn deref(&self) -> &serde_json::value::RawValue {
+        &self.0
+    }
+}
+
+#[cfg(feature = "http")]
+impl<S: Send + Sync> axum::extract::FromRequest<S> for RawJson {
+    type Rejection = axum::response::Response;
+
+    async fn from_request(
+        request: axum::extract::Request,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        use axum::response::IntoResponse;
+
+        let body =
+            <axum::body::Bytes as axum::extract::FromRequest<S>>::from_request(request, state)
+                .await
+                .map_err(IntoResponse::into_response)?;
+        let text = String::from_utf8(body.to_vec()).map_err(|_| {
+            crate::http_error_response(
+                400,
+                crate::ErrorResponse::new(
+                    crate::ErrorCode::InvalidInput,
+                    "Request body is not valid UTF-8",
+                ),
+            )
+        })?;
+        serde_json::value::RawValue::from_string(text)
+            .map(RawJson)
+            .map_err(|e| {
+                crate::http_error_response(
+                    400,
+                    crate::ErrorResponse::new(
+                        crate::ErrorCode::InvalidInput,
+                        format!("Request body is not valid JSON: {e}"),
+                    ),
+                )
+            })
+    }
+}
+
+/// An axum extractor whose rejections answer as `INVALID_INPUT` errors with
+/// status `STATUS`.
+///
+/// `#[http]` wraps the `Path`, `Query` and JSON body extractors of its handlers
+/// in this, so a path segment, query string or body that fails to parse gets
+/// the same `{"error": {...}}` body and `#[http(deserialize_error_status)]`
+/// status as a missing parameter. Rejections that are not about the input's
+/// shape, such as `415 Unsupported Media Type`, pass through unchanged.
+#[cfg(feature = "http")]
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct HttpInput<E, const STATUS: u16>(pub E);
+
+#[cfg(feature = "http")]
+impl<E, const STATUS: u16> HttpInput<E, STATUS> {
+    async fn reject(response: axum::response::Response) -> axum::response::Response {
+        if !matches!(response.status().as_u16(), 400 | 422) {
+            return response;
+        }
+        let (mut parts, body) = response.into_parts();
+        // Our own extractors (`RawJson`) already answer with an error body.
+        let is_json = parts
+            .headers
+            .get(axum::http::header::CONTENT_TYPE)
+            .is_some_and(|v| v.as_bytes().starts_with(b"application/json"));
+        if is_json {
+            parts.status = axum::http::StatusCode::from_u16(STATUS)
+                .unwrap_or(axum::http::StatusCode::BAD_REQUEST);
+            return axum::response::Response::from_parts(parts, body);
+        }
+        let message = axum::body::to_bytes(body, usize::MAX)
+            .await
+            .map(|text| String::from_utf8_lossy(&text).into_owned())
+            .unwrap_or_default();
+        crate::http_error_response(
+            STATUS,
+            crate::ErrorResponse::new(crate::ErrorCode::InvalidInput, message),
+        )
+    }
+}
+
+#[cfg(feature = "http")]
+impl<E, S, const STATUS: u16> axum::extract::FromRequestParts<S> for HttpInput<E, STATUS>
+where
+    E: axum::extract::FromRequestParts<S>,
+    S: Send + Sync,
+{
+    type Rejection = axum::response::Response;
+
+    async fn from_request_parts(
+        parts: &mut axum::http::request::Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        use axum::response::IntoResponse;
+
+        let rejection = match E::from_request_parts(parts, state).await {
+            Ok(value) => return Ok(HttpInput(value)),
+            Err(rejection) => rejection.into_response(),
+        };
+        Err(Self::reject(rejection).await)
+    }
+}
+
+#[cfg(feature = "http")]
+impl<E, S, const STATUS: u16> axum::extract::FromRequest<S> for HttpInput<E, STATUS>
+where
+    E: axum::extract::FromRequest<S>,
+    S: Send + Sync,
+{
+    type Rejection = axum::response::Response;
+
+    async fn from_request(
+        request: axum::extract::Request,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        use axum::response::IntoResponse;
+
+        let rejection = match E::from_request(request, state).await {
+            Ok(value) => return Ok(HttpInput(value)),
+            Err(rejection) => rejection.into_response(),
+        };
+        Err(Self::reject(rejection).await)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,8 +4,22 @@
 
 pub mod error;
 pub mod extract;
+#[cfg(feature = "client")]
+pub mod client;
+#[cfg(feature = "http")]
+pub mod collection;
 #[cfg(feature = "config")]
 pub mod config;
+#[cfg(feature = "http")]
+pub mod cors;
+#[cfg(feature = "graphql")]
+pub mod connection;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod proto;
+pub mod response;
+#[cfg(feature = "http")]
+pub mod testing;
 
 /// Re-export of `toml` for use by `#[derive(Config)]`-generated code.
 ///
@@ -15,15 +29,55 @@ pub mod config;
 #[doc(hidden)]
 pub use toml as __toml;
 
+/// Re-export of `regex` for `#[param(pattern = "...")]` checks in generated handlers.
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub use regex as __regex;
+
 pub use error::{
-    ErrorCode, ErrorResponse, HttpStatusFallback, HttpStatusHelper, IntoErrorCode,
-    SchemaValidationError,
+    ErrorCode, ErrorResponse, ErrorResponseFallback, ErrorResponseHelper, ErrorVariantInfo,
+    ErrorVariantsFallback, ErrorVariantsHelper, HttpStatusFallback, HttpStatusHelper,
+    IntoErrorCode, SchemaValidationError, assert_handler_error, assert_jsonrpc_error,
+    markdown_error_rows, openapi_error_responses,
 };
 pub use extract::Context;
+#[cfg(feature = "http")]
+pub use extract::Inject;
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub use extract::HttpInput;
+pub use extract::RawJson;
+/// Injected into `#[http]` methods that take one; cancelled when the request ends.
+#[cfg(feature = "http")]
+pub use tokio_util::sync::CancellationToken;
+pub use proto::merge_common_proto;
+pub use response::WithHeaders;
+
+#[cfg(feature = "client")]
+pub use client::{
+    ClientError, client_read_bytes, client_read_data_json, client_read_json,
+    client_read_optional_bytes, client_read_optional_data_json, client_read_optional_json,
+    client_read_unit, client_url,
+};
 
 #[cfg(feature = "ws")]
 pub use extract::WsSender;
 
+#[cfg(feature = "graphql")]
+pub use connection::{Connection, ConnectionArgs, Edge, PageInfo};
+
+#[cfg(feature = "metrics")]
+pub use metrics::HttpMetrics;
+
+#[cfg(feature = "http")]
+pub use testing::{HttpTestClient, HttpTestResponse};
+
+#[cfg(feature = "http")]
+pub use collection::{insomnia_collection, postman_collection};
+
+#[cfg(feature = "http")]
+pub use cors::Cors;
+
 /// One node in a CLI "manual": the reference entry for a single command path.
 ///
 /// The manual is the whole-subtree aggregate emitted by the `--manual` flag.
@@ -278,14 +332,129 @@ pub trait WsMount {
         &self,
         method: &str,
         params: serde_json::Value,
-    ) -> Result<serde_json::Value, String>;
+    ) -> Result<serde_json::Value, ErrorResponse>;
 
     /// Dispatch a method call (async).
     fn ws_mount_dispatch_async(
         &self,
         method: &str,
         params: serde_json::Value,
-    ) -> impl std::future::Future<Output = Result<serde_json::Value, String>> + Send;
+    ) -> impl std::future::Future<Output = Result<serde_json::Value, ErrorResponse>> + Send;
+}
+
+/// Whether a WebSocket read failed because a frame or message went over the
+/// connection's `max_frame_size` / `max_message_size` (`#[ws(max_message)]`).
+///
+/// axum does not re-export tungstenite's error type, so this matches its message.
+#[cfg(feature = "ws")]
+#[doc(hidden)]
+pub fn ws_message_too_large(err: &axum::Error) -> bool {
+    err.to_string().contains("Message too long")
+}
+
+/// Wire form of numbers JSON can't carry exactly (`i128`, `u128`, `Decimal`).
+///
+/// Generated handlers wrap such values so they serialize as decimal strings
+/// (`"340282366920938463463374607431768211455"`) and deserialize from either a
+/// string or a plain JSON number.
+#[doc(hidden)]
+pub struct DecimalString<T>(pub T);
+
+impl<T: std::fmt::Display> serde::Serialize for DecimalString<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+impl<'de, T> serde::Deserialize<'de> for DecimalString<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor<T>(std::marker::PhantomData<T>);
+
+        impl<T> Visitor<T>
+        where
+            T: std::str::FromStr,
+            T::Err: std::fmt::Display,
+        {
+            fn parse<E: serde::de::Error>(text: &str) -> Result<DecimalString<T>, E> {
+                text.parse().map(DecimalString).map_err(E::custom)
+            }
+        }
+
+        impl<T> serde::de::Visitor<'_> for Visitor<T>
+        where
+            T: std::str::FromStr,
+            T::Err: std::fmt::Display,
+        {
+            type Value = DecimalString<T>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a number or a decimal string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Self::parse(v)
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                Self::parse(&v.to_string())
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Self::parse(&v.to_string())
+            }
+
+            fn visit_i128<E: serde::de::Error>(self, v: i128) -> Result<Self::Value, E> {
+                Self::parse(&v.to_string())
+            }
+
+            fn visit_u128<E: serde::de::Error>(self, v: u128) -> Result<Self::Value, E> {
+                Self::parse(&v.to_string())
+            }
+
+            fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<Self::Value, E> {
+                Self::parse(&v.to_string())
+            }
+        }
+
+        deserializer.deserialize_any(Visitor(std::marker::PhantomData))
+    }
+}
+
+/// Look up an RPC parameter in `params`, which may be named (an object,
+/// looked up by `name`) or positional (an array, indexed by `position`).
+///
+/// Used by code generated for `#[jsonrpc]`, `#[ws]` and `#[mcp]`; `position`
+/// counts only the parameters that are read from the request.
+#[cfg(any(feature = "mcp", feature = "jsonrpc", feature = "ws"))]
+#[doc(hidden)]
+pub fn rpc_param<'a>(
+    params: &'a serde_json::Value,
+    name: &str,
+    position: usize,
+) -> Option<&'a serde_json::Value> {
+    match params {
+        serde_json::Value::Array(items) => items.get(position),
+        _ => params.get(name),
+    }
+}
+
+/// Drop the first `count` positional parameters before a slug mount delegates
+/// to its child, so the child sees its own parameters from position 0.
+/// Named (object) params are returned unchanged.
+#[cfg(any(feature = "mcp", feature = "jsonrpc", feature = "ws"))]
+#[doc(hidden)]
+pub fn rpc_skip_positional(params: serde_json::Value, count: usize) -> serde_json::Value {
+    match params {
+        serde_json::Value::Array(mut items) => {
+            items.drain(..count.min(items.len()));
+            serde_json::Value::Array(items)
+        }
+        other => other,
+    }
 }
 
 /// Trait for types that can be mounted as HTTP route groups.
@@ -305,6 +474,630 @@ pub trait HttpMount: Send + Sync + 'static {
         Self: Sized;
 }
 
+/// OpenAPI paths of the mounted service `T`, prefixed with `prefix`.
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub fn http_mount_openapi_paths_of<T: HttpMount>(
+    prefix: &str,
+) -> Vec<server_less_openapi::OpenApiPath> {
+    let mut paths = T::http_mount_openapi_paths();
+    for path in &mut paths {
+        path.path = format!("{prefix}{}", path.path);
+    }
+    paths
+}
+
+/// Route table served by `#[serve(http, discovery)]`: one
+/// `{"method": "GET", "path": "/items/{id}", "operationId": "get_item"}` object
+/// per operation, in the order given.
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub fn http_route_table(paths: &[server_less_openapi::OpenApiPath]) -> serde_json::Value {
+    paths
+        .iter()
+        .map(|path| {
+            serde_json::json!({
+                "method": path.method.to_uppercase(),
+                "path": path.path,
+                "operationId": path.operation.operation_id,
+            })
+        })
+        .collect()
+}
+
+/// Result of a readiness check wired up by `#[serve(readiness)]`.
+///
+/// Implemented for `bool` and for any `Result<T, E>` (`Ok` means ready), so a
+/// `health_check` method can return whichever is more natural.
+#[cfg(feature = "http")]
+pub trait HealthStatus {
+    /// Whether the service is ready to receive traffic.
+    fn is_healthy(&self) -> bool;
+}
+
+#[cfg(feature = "http")]
+impl HealthStatus for bool {
+    fn is_healthy(&self) -> bool {
+        *self
+    }
+}
+
+#[cfg(feature = "http")]
+impl<T, E> HealthStatus for Result<T, E> {
+    fn is_healthy(&self) -> bool {
+        self.is_ok()
+    }
+}
+
+/// Render an [`ErrorResponse`] as an HTTP response with the shared
+/// `{"error": {...}}` envelope as its JSON body.
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub fn http_error_response(status: u16, error: ErrorResponse) -> axum::response::Response {
+    use axum::response::IntoResponse;
+    let status = axum::http::StatusCode::from_u16(status)
+        .unwrap_or(axum::http::StatusCode::INTERNAL_SERVER_ERROR);
+    (status, axum::Json(error.envelope())).into_response()
+}
+
+/// Render a successful value as a JSON body wrapped in `{"data": ...}`, the
+/// counterpart of [`http_error_response`] for `#[http(envelope = true)]`.
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub fn http_data_response<T: serde::Serialize>(value: T) -> axum::response::Response {
+    use axum::response::IntoResponse;
+
+    #[derive(serde::Serialize)]
+    struct Data<T> {
+        data: T,
+    }
+
+    axum::Json(Data { data: value }).into_response()
+}
+
+/// Render a successful value as a JSON body formatted per
+/// `#[http(pretty = true)]` (indented output) and `#[http(skip_nulls = true)]`
+/// (object fields that are `null` are left out, at any depth), optionally
+/// wrapped in `{"data": ...}` for `#[http(envelope = true)]`.
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub fn http_json_response<T: serde::Serialize>(
+    value: T,
+    envelope: bool,
+    pretty: bool,
+    skip_nulls: bool,
+) -> axum::response::Response {
+    use axum::response::IntoResponse;
+
+    fn strip_nulls(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                map.retain(|_, v| !v.is_null());
+                map.values_mut().for_each(strip_nulls);
+            }
+            serde_json::Value::Array(items) => items.iter_mut().for_each(strip_nulls),
+            _ => {}
+        }
+    }
+
+    let body = serde_json::to_value(value).and_then(|mut value| {
+        if skip_nulls {
+            strip_nulls(&mut value);
+        }
+        if envelope {
+            value = serde_json::json!({ "data": value });
+        }
+        if pretty {
+            serde_json::to_vec_pretty(&value)
+        } else {
+            serde_json::to_vec(&value)
+        }
+    });
+    match body {
+        Ok(body) => (
+            [(
+                axum::http::header::CONTENT_TYPE,
+                axum::http::HeaderValue::from_static("application/json"),
+            )],
+            body,
+        )
+            .into_response(),
+        Err(err) => http_error_response(
+            500,
+            ErrorResponse::new(
+                ErrorCode::Internal,
+                format!("Failed to serialize response: {err}"),
+            ),
+        ),
+    }
+}
+
+/// Render raw bytes as an `application/octet-stream` body, for methods
+/// returning `Vec<u8>` or `Bytes`.
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub fn http_bytes_response(bytes: impl Into<axum::body::Body>) -> axum::response::Response {
+    use axum::response::IntoResponse;
+    (
+        [(
+            axum::http::header::CONTENT_TYPE,
+            axum::http::HeaderValue::from_static("application/octet-stream"),
+        )],
+        bytes.into(),
+    )
+        .into_response()
+}
+
+/// The `Location` of a resource created under `collection` (the path the
+/// request was posted to): the collection path followed by the `id` field
+/// `value` serializes with, when that is a string or a number. The id is
+/// percent-encoded as one path segment.
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub fn http_created_location<T: serde::Serialize>(
+    collection: &str,
+    value: &T,
+) -> Option<axum::http::HeaderValue> {
+    let id = match serde_json::to_value(value).ok()?.get("id")? {
+        serde_json::Value::String(id) => id.clone(),
+        serde_json::Value::Number(id) => id.to_string(),
+        _ => return None,
+    };
+    let mut location = collection.trim_end_matches('/').to_string();
+    location.push('/');
+    for byte in id.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            location.push(byte as char);
+        } else {
+            location.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    axum::http::HeaderValue::try_from(location).ok()
+}
+
+/// The part of a body selected by a `Range` request header.
+#[cfg(feature = "http")]
+#[derive(Debug, PartialEq)]
+enum ByteRange {
+    /// No usable range: serve the whole body.
+    Full,
+    /// Inclusive `start..=end` byte positions.
+    Partial(usize, usize),
+    /// The range starts past the end of the body.
+    Unsatisfiable,
+}
+
+/// Resolve a `Range` header against a body of `len` bytes.
+///
+/// Only a single `bytes=` range is honoured; anything else (other units,
+/// multiple ranges, malformed values) is ignored, as RFC 9110 allows, and the
+/// full body is served.
+#[cfg(feature = "http")]
+fn byte_range(header: Option<&axum::http::HeaderValue>, len: usize) -> ByteRange {
+    let Some(spec) = header
+        .and_then(|h| h.to_str().ok())
+        .and_then(|h| h.trim().strip_prefix("bytes="))
+    else {
+        return ByteRange::Full;
+    };
+    let Some((first, last)) = spec.split_once('-').filter(|_| !spec.contains(',')) else {
+        return ByteRange::Full;
+    };
+    let (first, last) = (first.trim(), last.trim());
+    if first.is_empty() {
+        // Suffix range: the last `n` bytes.
+        return match last.parse::<usize>() {
+            Ok(0) => ByteRange::Unsatisfiable,
+            Ok(_) if len == 0 => ByteRange::Unsatisfiable,
+            Ok(n) => ByteRange::Partial(len.saturating_sub(n), len - 1),
+            Err(_) => ByteRange::Full,
+        };
+    }
+    let Ok(start) = first.parse::<usize>() else {
+        return ByteRange::Full;
+    };
+    let end = if last.is_empty() {
+        usize::MAX
+    } else {
+        match last.parse::<usize>() {
+            Ok(end) if end >= start => end,
+            _ => return ByteRange::Full,
+        }
+    };
+    if start >= len {
+        ByteRange::Unsatisfiable
+    } else {
+        ByteRange::Partial(start, end.min(len - 1))
+    }
+}
+
+/// Render raw bytes honouring a `Range: bytes=...` request header, for
+/// `#[route(ranges)]` methods: `206 Partial Content` with `Content-Range` for
+/// a satisfiable range, `416` for one past the end, the full body otherwise.
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub fn http_range_response(
+    range: Option<&axum::http::HeaderValue>,
+    bytes: impl Into<axum::body::Bytes>,
+) -> axum::response::Response {
+    use axum::http::{HeaderValue, StatusCode, header};
+
+    let bytes = bytes.into();
+    let len = bytes.len();
+    let mut response = match byte_range(range, len) {
+        ByteRange::Full => http_bytes_response(bytes),
+        ByteRange::Partial(start, end) => {
+            let mut response = http_bytes_response(bytes.slice(start..=end));
+            *response.status_mut() = StatusCode::PARTIAL_CONTENT;
+            response.headers_mut().insert(
+                header::CONTENT_RANGE,
+                HeaderValue::from_str(&format!("bytes {start}-{end}/{len}"))
+                    .expect("BUG: Content-Range is ASCII"),
+            );
+            response
+        }
+        ByteRange::Unsatisfiable => {
+            let error = ErrorResponse::new(ErrorCode::InvalidInput, "Range not satisfiable");
+            let mut response = http_error_response(416, error);
+            response.headers_mut().insert(
+                header::CONTENT_RANGE,
+                HeaderValue::from_str(&format!("bytes */{len}"))
+                    .expect("BUG: Content-Range is ASCII"),
+            );
+            response
+        }
+    };
+    response
+        .headers_mut()
+        .insert(header::ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+    response
+}
+
+/// Serve a stream as Server-Sent Events through a buffer of `capacity` events,
+/// for `#[route(sse_buffer = N)]` methods.
+///
+/// A spawned task drives the stream at its own pace, so a slow client neither
+/// stalls the producer nor makes the server buffer without bound: once
+/// `capacity` events are waiting, the oldest are dropped. The task ends with the
+/// stream, or is aborted once the response is dropped because the client
+/// disconnected, even if the stream is idle.
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub fn http_sse_buffered<S>(
+    stream: S,
+    capacity: usize,
+) -> axum::response::sse::Sse<
+    impl futures::Stream<Item = Result<axum::response::sse::Event, std::convert::Infallible>>,
+>
+where
+    S: futures::Stream + Send + 'static,
+    S::Item: serde::Serialize,
+{
+    use futures::StreamExt;
+    use tokio::sync::broadcast::{self, error::RecvError};
+
+    /// Aborts the producer task along with the response body.
+    struct AbortOnDrop(tokio::task::AbortHandle);
+    impl Drop for AbortOnDrop {
+        fn drop(&mut self) {
+            self.0.abort();
+        }
+    }
+
+    let (sender, receiver) = broadcast::channel(capacity);
+    let producer = tokio::spawn(async move {
+        let mut stream = std::pin::pin!(stream);
+        while let Some(item) = stream.next().await {
+            let event = axum::response::sse::Event::default()
+                .json_data(item)
+                .expect("BUG: failed to serialize SSE event as JSON — the Stream item type must implement serde::Serialize");
+            // Only fails once the receiver, i.e. the client, is gone.
+            if sender.send(event).is_err() {
+                break;
+            }
+        }
+    });
+    let state = (receiver, AbortOnDrop(producer.abort_handle()));
+    let events = futures::stream::unfold(state, |(mut receiver, producer)| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => return Some((Ok(event), (receiver, producer))),
+                // The oldest events were overwritten; carry on from the oldest kept.
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    });
+    axum::response::sse::Sse::new(events)
+}
+
+/// Serve a stream as newline-delimited JSON (`application/x-ndjson`), for
+/// `#[route(stream = "ndjson")]` methods.
+///
+/// Each item is written as one line of JSON as soon as the stream yields it.
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub fn http_ndjson_response<S>(stream: S) -> axum::response::Response
+where
+    S: futures::Stream + Send + 'static,
+    S::Item: serde::Serialize,
+{
+    use axum::http::{HeaderValue, header};
+    use futures::StreamExt;
+
+    let lines = stream.map(|item| {
+        let mut line = serde_json::to_vec(&item)
+            .expect("BUG: failed to serialize NDJSON line — the Stream item type must implement serde::Serialize");
+        line.push(b'\n');
+        Ok::<_, std::convert::Infallible>(line)
+    });
+    let mut response = axum::response::Response::new(axum::body::Body::from_stream(lines));
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/x-ndjson"),
+    );
+    response
+}
+
+/// Await a handler's response, answering `504 Gateway Timeout` instead once
+/// `secs` seconds pass, for `#[route(timeout = N)]` methods.
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub async fn http_with_timeout<F>(secs: u64, response: F) -> axum::response::Response
+where
+    F: std::future::Future<Output = axum::response::Response>,
+{
+    match tokio::time::timeout(std::time::Duration::from_secs(secs), response).await {
+        Ok(response) => response,
+        Err(_) => http_error_response(
+            504,
+            ErrorResponse::new(
+                ErrorCode::Unavailable,
+                format!("Request timed out after {secs}s"),
+            ),
+        ),
+    }
+}
+
+/// Fallback handler answering unmatched routes with a JSON 404:
+/// `{"error": {"code": "NOT_FOUND", "message": "...", "details": {"path": "/requested/path"}}}`.
+///
+/// `#[serve]` installs it on the combined router (opt out with
+/// `#[serve(json_errors = false)]`). It is not added to `http_router()` itself,
+/// since axum refuses to merge two routers that both carry a fallback; attach it
+/// with `.fallback(server_less::json_not_found)` once the final router is built.
+#[cfg(feature = "http")]
+pub async fn json_not_found(uri: axum::http::Uri) -> axum::response::Response {
+    let error = ErrorResponse::new(ErrorCode::NotFound, format!("No route for {}", uri.path()))
+        .with_details(serde_json::json!({ "path": uri.path() }));
+    http_error_response(404, error)
+}
+
+/// Handler answering a known path requested with the wrong verb with a JSON 405:
+/// `{"error": {"code": "METHOD_NOT_ALLOWED", "message": "...", "details": {"method": "POST", "path": "/requested/path"}}}`.
+///
+/// axum adds the `Allow` header listing the path's registered verbs. `#[serve]`
+/// installs it next to [`json_not_found`]. `http_router()` leaves it out, since
+/// axum refuses to merge two routers that give the same path a fallback; attach
+/// it with `.method_not_allowed_fallback(server_less::json_method_not_allowed)`
+/// once the final router is built.
+#[cfg(feature = "http")]
+pub async fn json_method_not_allowed(
+    method: axum::http::Method,
+    uri: axum::http::Uri,
+) -> axum::response::Response {
+    let error = ErrorResponse {
+        code: "METHOD_NOT_ALLOWED".to_string(),
+        message: format!("Method {method} is not allowed for {}", uri.path()),
+        app_code: None,
+        details: Some(serde_json::json!({ "method": method.as_str(), "path": uri.path() })),
+        jsonrpc_code: None,
+    };
+    http_error_response(405, error)
+}
+
+/// Middleware redirecting unmatched `/path/` requests to `/path` with
+/// `308 Permanent Redirect`, keeping the query string.
+///
+/// `#[serve(http, redirect_slashes)]` installs it on the combined router; add it
+/// to a hand-built router with
+/// `.layer(axum::middleware::from_fn(server_less::redirect_trailing_slash))`.
+/// Requests that matched a route pass through untouched.
+#[cfg(feature = "http")]
+pub async fn redirect_trailing_slash(
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    use axum::response::IntoResponse;
+
+    let path = request.uri().path();
+    let trimmed = path.trim_end_matches('/');
+    // `//host/` would trim to a protocol-relative `//host` Location, and browsers
+    // read `/\host` the same way, so only redirect to a plain `/segment...` path.
+    let local = trimmed
+        .strip_prefix('/')
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|c| c != '/' && c != '\\');
+    if request.extensions().get::<axum::extract::MatchedPath>().is_some()
+        || trimmed.len() == path.len()
+        || !local
+    {
+        return next.run(request).await;
+    }
+    let location = match request.uri().query() {
+        Some(query) => format!("{trimmed}?{query}"),
+        None => trimmed.to_string(),
+    };
+    (
+        axum::http::StatusCode::PERMANENT_REDIRECT,
+        [(axum::http::header::LOCATION, location)],
+    )
+        .into_response()
+}
+
+/// Middleware answering a panicking handler with a JSON 500 instead of closing
+/// the connection without a response:
+/// `{"error": {"code": "INTERNAL", "message": "Internal server error"}}`.
+///
+/// The panic message goes to stderr, never to the client. `#[serve(http,
+/// catch_panic)]` installs it on the combined router; add it to a hand-built
+/// router with `.layer(axum::middleware::from_fn(server_less::catch_panic))`.
+/// Panics while a streamed body is being sent are not caught.
+#[cfg(feature = "http")]
+pub async fn catch_panic(
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    use futures::FutureExt;
+
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    match std::panic::AssertUnwindSafe(next.run(request))
+        .catch_unwind()
+        .await
+    {
+        Ok(response) => response,
+        Err(panic) => {
+            let message = panic
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("non-string panic payload");
+            eprintln!("[server-less] handler for {method} {path} panicked: {message}");
+            let error = ErrorResponse::new(ErrorCode::Internal, "Internal server error");
+            http_error_response(500, error)
+        }
+    }
+}
+
+/// Turns maintenance mode on and off for a router built by
+/// `#[serve(http, maintenance)]`. Clones share one flag, so keep a clone to flip
+/// it while the server runs; each switch only affects the routers built with it.
+#[cfg(feature = "http")]
+#[derive(Clone, Debug, Default)]
+pub struct MaintenanceSwitch(std::sync::Arc<std::sync::atomic::AtomicBool>);
+
+#[cfg(feature = "http")]
+impl MaintenanceSwitch {
+    /// A switch that starts off.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Turn maintenance mode on or off.
+    pub fn set(&self, on: bool) {
+        self.0.store(on, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    /// Whether maintenance mode is on.
+    pub fn is_on(&self) -> bool {
+        self.0.load(std::sync::atomic::Ordering::Relaxed)
+    }
+}
+
+/// Middleware behind `#[serve(http, maintenance)]`: while `switch` is on, every
+/// request except those for the `exempt` paths (the health probes) is answered
+/// with a JSON 503 carrying `Retry-After: <retry_after>`:
+/// `{"error": {"code": "UNAVAILABLE", "message": "Service under maintenance"}}`.
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub async fn http_maintenance_gate(
+    switch: MaintenanceSwitch,
+    exempt: &[&str],
+    retry_after: u32,
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    use axum::http::{HeaderValue, header};
+
+    if !switch.is_on() || exempt.contains(&request.uri().path()) {
+        return next.run(request).await;
+    }
+    let error = ErrorResponse::new(ErrorCode::Unavailable, "Service under maintenance");
+    let mut response = http_error_response(503, error);
+    response
+        .headers_mut()
+        .insert(header::RETRY_AFTER, HeaderValue::from(retry_after));
+    response
+}
+
+/// Check a `#[param(min = ..., max = ...)]` bound; the error is a 400-ready
+/// `INVALID_INPUT` response naming the parameter.
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub fn check_param_range<T: PartialOrd + std::fmt::Display>(
+    name: &str,
+    value: &T,
+    min: Option<T>,
+    max: Option<T>,
+) -> Result<(), ErrorResponse> {
+    let violation = match (min, max) {
+        (Some(min), _) if *value < min => format!("must be at least {min}"),
+        (_, Some(max)) if *value > max => format!("must be at most {max}"),
+        _ => return Ok(()),
+    };
+    Err(ErrorResponse::new(
+        ErrorCode::InvalidInput,
+        format!("Parameter '{name}' {violation} (got {value})"),
+    )
+    .with_details(serde_json::json!({ "param": name })))
+}
+
+/// Check a `#[param(pattern = "...")]` constraint (the regex is compiled once
+/// by the generated handler).
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub fn check_param_pattern(
+    name: &str,
+    value: &str,
+    pattern: &regex::Regex,
+) -> Result<(), ErrorResponse> {
+    if pattern.is_match(value) {
+        return Ok(());
+    }
+    Err(ErrorResponse::new(
+        ErrorCode::InvalidInput,
+        format!("Parameter '{name}' must match pattern {}", pattern.as_str()),
+    )
+    .with_details(serde_json::json!({ "param": name, "pattern": pattern.as_str() })))
+}
+
+/// HTML page rendering the spec at `spec_url` with Swagger UI (loaded from a CDN).
+///
+/// Served by `#[http(docs_path = "...")]` and `#[serve(docs = "...")]`.
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub fn openapi_docs_html(title: &str, spec_url: &str) -> String {
+    let title = title
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;");
+    // A JSON string literal is a valid JS literal; escaping `<` keeps `</script>` out.
+    let spec_url = serde_json::Value::from(spec_url)
+        .to_string()
+        .replace('<', "\\u003c");
+    format!(
+        r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{title}</title>
+<link rel="stylesheet" href="https://unpkg.com/swagger-ui-dist@5/swagger-ui.css">
+</head>
+<body>
+<div id="swagger-ui"></div>
+<script src="https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js" crossorigin></script>
+<script>
+window.ui = SwaggerUIBundle({{ url: {spec_url}, dom_id: "#swagger-ui" }});
+</script>
+</body>
+</html>
+"##
+    )
+}
+
 /// Format a `serde_json::Value` according to the active JSON output flag.
 ///
 /// This function is only called when at least one JSON flag is active
@@ -380,6 +1173,25 @@ pub fn cli_format_output(
     }
 }
 
+/// Ask the user to confirm a `#[cli(confirm)]` command before it runs.
+///
+/// Writes `"{prompt} [y/N] "` to stderr, so stdout stays clean for `--json`
+/// output, and reads one line from stdin. Only `y` or `yes` (any case) confirm;
+/// anything else, including end of input, declines.
+#[cfg(feature = "cli")]
+pub fn cli_confirm(prompt: &str) -> std::io::Result<bool> {
+    use std::io::Write;
+
+    let mut stderr = std::io::stderr();
+    write!(stderr, "{prompt} [y/N] ")?;
+    stderr.flush()?;
+
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    let answer = answer.trim();
+    Ok(answer.eq_ignore_ascii_case("y") || answer.eq_ignore_ascii_case("yes"))
+}
+
 /// Generate a JSON Schema for a type at runtime using schemars.
 ///
 /// Called by `--output-schema` in `#[cli]`-generated code when the `jsonschema`
@@ -490,12 +1302,188 @@ where
     }
 }
 
+/// Helper used by `#[cli]`-generated code to pick a parameter's value parser.
+///
+/// Resolves to a parser restricted to [`ServerlessEnum::VARIANTS`] when
+/// `T: ServerlessEnum`, so the variants are validated and listed in `--help`, and
+/// to [`CliValueParserFallback`] otherwise.
+#[cfg(feature = "cli")]
+#[doc(hidden)]
+pub struct CliValueParserHelper<T>(std::marker::PhantomData<T>);
+
+#[cfg(feature = "cli")]
+impl<T> CliValueParserHelper<T> {
+    /// Create a helper for the parameter type `T`.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+#[cfg(all(feature = "cli", feature = "jsonschema"))]
+impl<T> CliValueParserHelper<T>
+where
+    T: ServerlessEnum + std::str::FromStr + Clone + Send + Sync + 'static,
+    <T as std::str::FromStr>::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    /// Accepts only the enum's wire names and parses them into `T`.
+    pub fn value_parser(&self) -> ::clap::builder::ValueParser {
+        use ::clap::builder::TypedValueParser as _;
+        ::clap::builder::ValueParser::new(
+            ::clap::builder::PossibleValuesParser::new(T::VARIANTS.iter().copied())
+                .try_map(|s| s.parse::<T>()),
+        )
+    }
+}
+
+#[cfg(all(feature = "cli", not(feature = "jsonschema")))]
+impl<T: ServerlessEnum> CliValueParserHelper<T> {
+    /// Accepts only the enum's wire names; the method parses the string.
+    pub fn value_parser(&self) -> ::clap::builder::ValueParser {
+        ::clap::builder::ValueParser::new(::clap::builder::PossibleValuesParser::new(
+            T::VARIANTS.iter().copied(),
+        ))
+    }
+}
+
+/// Fallback trait used by [`CliValueParserHelper`] when the parameter type does
+/// not implement [`ServerlessEnum`].
+#[cfg(feature = "cli")]
+#[doc(hidden)]
+pub trait CliValueParserFallback {
+    /// Returns the parser for a non-enum parameter.
+    fn value_parser(&self) -> ::clap::builder::ValueParser;
+}
+
+#[cfg(all(feature = "cli", feature = "jsonschema"))]
+impl<T> CliValueParserFallback for CliValueParserHelper<T>
+where
+    T: schemars::JsonSchema + std::str::FromStr + Clone + Send + Sync + 'static,
+    <T as std::str::FromStr>::Err: std::fmt::Display,
+{
+    /// Fallback: [`SchemaValueParser`], which parses into `T`.
+    fn value_parser(&self) -> ::clap::builder::ValueParser {
+        ::clap::builder::ValueParser::new(SchemaValueParser::<T>::new())
+    }
+}
+
+#[cfg(all(feature = "cli", not(feature = "jsonschema")))]
+impl<T> CliValueParserFallback for CliValueParserHelper<T> {
+    /// Fallback: the raw string; the method parses it.
+    fn value_parser(&self) -> ::clap::builder::ValueParser {
+        ::clap::builder::ValueParser::string()
+    }
+}
+
+/// A C-like enum whose variants travel as plain strings.
+///
+/// Implemented by `#[derive(ServerlessEnum)]`. Spec generators use the variant
+/// list to constrain parameters of this type, e.g. OpenAPI emits
+/// `{"type": "string", "enum": ["Active", "Inactive"]}` instead of a bare object.
+pub trait ServerlessEnum {
+    /// Wire names of the variants in declaration order (honours `#[serde(rename)]`
+    /// and `#[serde(rename_all)]`).
+    const VARIANTS: &'static [&'static str];
+}
+
+/// A type that can describe its own JSON schema, using its wire names.
+///
+/// Implemented by `#[derive(ServerlessSchema)]` for structs (field names follow
+/// `#[serde(rename)]` / `#[serde(rename_all)]`) and by `#[derive(ServerlessEnum)]`
+/// for enums. Spec generators use it in place of a bare `{"type": "object"}`.
+pub trait ServerlessSchema {
+    /// The JSON schema of this type.
+    fn json_schema() -> serde_json::Value;
+}
+
+/// Build the schema of a `ServerlessSchema` type, cutting reference cycles.
+///
+/// Called by `#[derive(ServerlessSchema)]`. While `build` runs for `type_name`,
+/// a nested request for the same type (mutually recursive structs such as
+/// `Team { lead: Member }` / `Member { team: Option<Box<Team>> }`) answers a
+/// plain `{"type": "object"}` instead of recursing forever.
+#[doc(hidden)]
+pub fn serverless_schema_guarded(
+    type_name: &'static str,
+    build: impl FnOnce() -> serde_json::Value,
+) -> serde_json::Value {
+    thread_local! {
+        static IN_PROGRESS: std::cell::RefCell<Vec<&'static str>> =
+            const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    /// Pops the type again even if `build` panics.
+    struct Pop;
+    impl Drop for Pop {
+        fn drop(&mut self) {
+            IN_PROGRESS.with(|stack| stack.borrow_mut().pop());
+        }
+    }
+
+    let cycle = IN_PROGRESS.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let cycle = stack.contains(&type_name);
+        if !cycle {
+            stack.push(type_name);
+        }
+        cycle
+    });
+    if cycle {
+        return serde_json::json!({ "type": "object" });
+    }
+    let _pop = Pop;
+    build()
+}
+
+/// Fallback trait used by [`SchemaHelper`] when the type does not implement
+/// [`ServerlessSchema`].
+///
+/// Same autoref-specialization pattern as [`ErrorVariantsFallback`]: generated
+/// spec code brings this trait into scope with `use ... as _`.
+#[doc(hidden)]
+pub trait SchemaFallback {
+    /// Returns `{"type": json_type}`.
+    fn json_schema(&self, json_type: &str) -> serde_json::Value;
+}
+
+/// Helper used by generated spec code to build the JSON schema of a parameter.
+///
+/// Resolves to [`ServerlessSchema::json_schema`] when `T: ServerlessSchema`, and
+/// to the plain inferred type otherwise.
+#[doc(hidden)]
+pub struct SchemaHelper<T>(std::marker::PhantomData<T>);
+
+impl<T> SchemaHelper<T> {
+    /// Create a helper for the type `T`.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+impl<T: ServerlessSchema> SchemaHelper<T> {
+    /// Returns the schema the type describes itself with.
+    pub fn json_schema(&self, _json_type: &str) -> serde_json::Value {
+        T::json_schema()
+    }
+}
+
+impl<T> SchemaFallback for SchemaHelper<T> {
+    /// Fallback: the type name inferred at macro expansion time.
+    fn json_schema(&self, json_type: &str) -> serde_json::Value {
+        serde_json::json!({ "type": json_type })
+    }
+}
+
 /// Runtime method metadata with string-based types.
 ///
 /// This is a simplified, serialization-friendly representation of method
 /// information intended for runtime introspection and tooling. Types are
 /// stored as strings rather than `syn` AST nodes.
 ///
+/// `#[introspect]` generates `service_methods() -> &'static [MethodInfo]` on the
+/// impl block it is applied to.
+///
 /// **Not to be confused with [`server_less_parse::MethodInfo`]**, which is
 /// the richer, `syn`-based representation used internally by proc macros
 /// during code generation. The parse version retains full type information
@@ -709,6 +1697,39 @@ mod tests {
         ); // RPC fallback
     }
 
+    #[cfg(feature = "http")]
+    #[test]
+    fn test_http_created_location_encodes_id() {
+        let location = |value: serde_json::Value| {
+            http_created_location("/api/items/", &value).map(|v| v.to_str().unwrap().to_string())
+        };
+        assert_eq!(location(serde_json::json!({"id": 7})).unwrap(), "/api/items/7");
+        assert_eq!(
+            location(serde_json::json!({"id": "a b/c?d"})).unwrap(),
+            "/api/items/a%20b%2Fc%3Fd"
+        );
+        assert_eq!(location(serde_json::json!({"name": "x"})), None);
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn test_byte_range() {
+        let range = |h: &str| byte_range(Some(&axum::http::HeaderValue::from_str(h).unwrap()), 10);
+        assert_eq!(byte_range(None, 10), ByteRange::Full);
+        assert_eq!(range("bytes=0-3"), ByteRange::Partial(0, 3));
+        assert_eq!(range("bytes=4-"), ByteRange::Partial(4, 9));
+        assert_eq!(range("bytes=8-20"), ByteRange::Partial(8, 9));
+        assert_eq!(range("bytes=-3"), ByteRange::Partial(7, 9));
+        assert_eq!(range("bytes=-30"), ByteRange::Partial(0, 9));
+        assert_eq!(range("bytes=10-"), ByteRange::Unsatisfiable);
+        assert_eq!(range("bytes=-0"), ByteRange::Unsatisfiable);
+        // Ignored: other units, multiple ranges, malformed or reversed ranges
+        assert_eq!(range("items=0-3"), ByteRange::Full);
+        assert_eq!(range("bytes=0-1,4-5"), ByteRange::Full);
+        assert_eq!(range("bytes=a-b"), ByteRange::Full);
+        assert_eq!(range("bytes=5-2"), ByteRange::Full);
+    }
+
     #[test]
     fn test_path_inference() {
         assert_eq!(infer_path("create_user", HttpMethod::Post), "/users");
@@ -0,0 +1,186 @@
+//! Prometheus request metrics for `#[serve(metrics = "...")]`.
+//!
+//! [`HttpMetrics`] owns a private Prometheus registry. Generated `serve()` /
+//! `router()` code wraps the combined router with [`HttpMetrics::layer`], which
+//! records every request, and exposes [`HttpMetrics::render`] on the metrics path.
+//!
+//! Requests are labelled with the HTTP method (`OTHER` for non-standard ones),
+//! the matched route template (e.g. `/users/{id}`), so neither explodes label
+//! cardinality, and the response status code.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use axum::extract::{MatchedPath, Request, State};
+use axum::middleware::Next;
+use axum::response::Response;
+use prometheus::{Encoder, HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry, TextEncoder};
+
+/// Route label used for requests that did not match any registered route.
+const UNMATCHED_ROUTE: &str = "<unmatched>";
+
+/// Method label used for requests with a non-standard method, which clients
+/// can otherwise make up without limit.
+const OTHER_METHOD: &str = "OTHER";
+
+/// Per-route request counters and latency histograms.
+#[derive(Clone)]
+pub struct HttpMetrics {
+    registry: Registry,
+    requests: IntCounterVec,
+    duration: HistogramVec,
+}
+
+impl HttpMetrics {
+    /// Create a fresh set of metrics in a new, private registry.
+    pub fn new() -> Self {
+        let labels = &["method", "route", "status"];
+        let requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "Total number of HTTP requests handled."),
+            labels,
+        )
+        .expect("BUG: http_requests_total metric definition is invalid");
+        let duration = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "HTTP request latency in seconds.",
+            ),
+            labels,
+        )
+        .expect("BUG: http_request_duration_seconds metric definition is invalid");
+
+        let registry = Registry::new();
+        registry
+            .register(Box::new(requests.clone()))
+            .expect("BUG: http_requests_total registered twice");
+        registry
+            .register(Box::new(duration.clone()))
+            .expect("BUG: http_request_duration_seconds registered twice");
+
+        Self {
+            registry,
+            requests,
+            duration,
+        }
+    }
+
+    /// The underlying registry, for registering application-specific metrics
+    /// that should be exported alongside the request metrics.
+    pub fn registry(&self) -> &Registry {
+        &self.registry
+    }
+
+    /// Record one handled request.
+    pub fn observe(&self, method: &str, route: &str, status: u16, seconds: f64) {
+        let status = status.to_string();
+        let labels = [method, route, status.as_str()];
+        self.requests.with_label_values(&labels).inc();
+        self.duration.with_label_values(&labels).observe(seconds);
+    }
+
+    /// Render all metrics in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .expect("BUG: Prometheus text encoding failed");
+        String::from_utf8(buffer).expect("BUG: Prometheus text encoding produced invalid UTF-8")
+    }
+
+    /// Wrap `router` with a middleware that records every request into `metrics`.
+    pub fn layer(metrics: Arc<Self>, router: axum::Router) -> axum::Router {
+        router.layer(axum::middleware::from_fn_with_state(metrics, track_request))
+    }
+}
+
+impl Default for HttpMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for HttpMetrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HttpMetrics").finish_non_exhaustive()
+    }
+}
+
+async fn track_request(
+    State(metrics): State<Arc<HttpMetrics>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let method = method_label(request.method());
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| UNMATCHED_ROUTE.to_string());
+    let start = Instant::now();
+
+    let response = next.run(request).await;
+
+    metrics.observe(
+        method,
+        &route,
+        response.status().as_u16(),
+        start.elapsed().as_secs_f64(),
+    );
+    response
+}
+
+/// The `method` label for `method`: its name for the standard verbs,
+/// [`OTHER_METHOD`] for anything else.
+fn method_label(method: &axum::http::Method) -> &'static str {
+    use axum::http::Method;
+
+    match *method {
+        Method::GET => "GET",
+        Method::POST => "POST",
+        Method::PUT => "PUT",
+        Method::DELETE => "DELETE",
+        Method::PATCH => "PATCH",
+        Method::HEAD => "HEAD",
+        Method::OPTIONS => "OPTIONS",
+        Method::CONNECT => "CONNECT",
+        Method::TRACE => "TRACE",
+        _ => OTHER_METHOD,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_includes_observed_labels() {
+        let metrics = HttpMetrics::new();
+        metrics.observe("GET", "/users/{id}", 200, 0.01);
+        metrics.observe("GET", "/users/{id}", 200, 0.02);
+
+        let text = metrics.render();
+        assert!(text.contains(
+            r#"http_requests_total{method="GET",route="/users/{id}",status="200"} 2"#
+        ));
+        assert!(text.contains("http_request_duration_seconds_bucket"));
+    }
+
+    #[test]
+    fn registries_are_independent() {
+        let a = HttpMetrics::new();
+        let b = HttpMetrics::new();
+        a.observe("POST", "/users", 201, 0.0);
+        assert!(!b.render().contains("/users"));
+    }
+
+    #[test]
+    fn non_standard_methods_share_one_label() {
+        use axum::http::Method;
+
+        assert_eq!(method_label(&Method::PATCH), "PATCH");
+        for name in ["FOO1", "FOO2", "get"] {
+            let method = Method::from_bytes(name.as_bytes()).unwrap();
+            assert_eq!(method_label(&method), OTHER_METHOD);
+        }
+    }
+}
@@ -0,0 +1,218 @@
+//! Helpers for writing `#[grpc(split = true)]` schemas as a set of files.
+//!
+//! A split service writes its `service` block to `{service}.proto` and its messages
+//! to a `common.proto` shared by every service written into the same directory.
+//! Each message there is preceded by a `// service: Name` line naming the service
+//! that generated it.
+
+/// Merge freshly generated `common.proto` content into an existing file.
+///
+/// Messages already in `existing` keep their position; one with the same name as a
+/// generated message is replaced by it when the same service wrote it (or its text is
+/// identical), and new messages are appended. The `syntax`, `package` and `option`
+/// lines come from `generated`, and `import` lines from both files are kept.
+///
+/// Fails with [`std::io::ErrorKind::InvalidData`] when the two files declare
+/// different packages, or when another service already wrote a different message
+/// under the same name.
+pub fn merge_common_proto(existing: &str, generated: &str) -> std::io::Result<String> {
+    let existing = ProtoFile::parse(existing);
+    let generated = ProtoFile::parse(generated);
+
+    if existing.package.is_some() && existing.package != generated.package {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!(
+                "common.proto declares `{}` but this service generates `{}`",
+                existing.package.as_deref().unwrap_or_default(),
+                generated.package.as_deref().unwrap_or_default(),
+            ),
+        ));
+    }
+
+    let mut imports = existing.imports;
+    for import in generated.imports {
+        if !imports.contains(&import) {
+            imports.push(import);
+        }
+    }
+    imports.sort();
+
+    let mut messages = existing.messages;
+    for message in generated.messages {
+        match messages.iter_mut().find(|existing| existing.name == message.name) {
+            Some(slot) if slot.service == message.service || slot.body == message.body => {
+                *slot = message
+            }
+            Some(slot) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!(
+                        "common.proto already has a different message `{}` from `{}`; \
+                         `{}` cannot replace it",
+                        message.name,
+                        slot.service.as_deref().unwrap_or("another service"),
+                        message.service.as_deref().unwrap_or("this service"),
+                    ),
+                ));
+            }
+            None => messages.push(message),
+        }
+    }
+
+    let mut out = String::new();
+    let package = generated
+        .package
+        .map(|package| vec![format!("package {package};")])
+        .unwrap_or_default();
+    for section in [generated.syntax, package, imports, generated.options] {
+        for line in &section {
+            out.push_str(line);
+            out.push('\n');
+        }
+        if !section.is_empty() {
+            out.push('\n');
+        }
+    }
+    let bodies: Vec<_> = messages.into_iter().map(ProtoMessage::into_text).collect();
+    out.push_str(&bodies.join("\n"));
+    out.push('\n');
+    Ok(out)
+}
+
+/// The parts of a generated `.proto` file that [`merge_common_proto`] works with.
+struct ProtoFile {
+    syntax: Vec<String>,
+    package: Option<String>,
+    imports: Vec<String>,
+    options: Vec<String>,
+    messages: Vec<ProtoMessage>,
+}
+
+struct ProtoMessage {
+    name: String,
+    /// The service named by the `// service: Name` line before the message.
+    service: Option<String>,
+    /// The full `message Name { ... }` text.
+    body: String,
+}
+
+impl ProtoMessage {
+    fn into_text(self) -> String {
+        match self.service {
+            Some(service) => format!("{SERVICE_MARKER}{service}\n{}", self.body),
+            None => self.body,
+        }
+    }
+}
+
+/// Prefix of the comment line naming the service that generated the next message.
+const SERVICE_MARKER: &str = "// service: ";
+
+impl ProtoFile {
+    fn parse(source: &str) -> Self {
+        let mut file = ProtoFile {
+            syntax: Vec::new(),
+            package: None,
+            imports: Vec::new(),
+            options: Vec::new(),
+            messages: Vec::new(),
+        };
+        let mut lines = source.lines();
+        let mut service = None;
+        while let Some(line) = lines.next() {
+            let trimmed = line.trim();
+            if let Some(name) = trimmed.strip_prefix(SERVICE_MARKER) {
+                service = Some(name.trim().to_string());
+            } else if let Some(rest) = trimmed.strip_prefix("message ") {
+                let name = rest.trim_end_matches('{').trim().to_string();
+                let mut body = vec![line.to_string()];
+                if !trimmed.ends_with('}') {
+                    for line in lines.by_ref() {
+                        body.push(line.to_string());
+                        if line.trim() == "}" {
+                            break;
+                        }
+                    }
+                }
+                file.messages.push(ProtoMessage {
+                    name,
+                    service: service.take(),
+                    body: body.join("\n"),
+                });
+            } else if trimmed.starts_with("import ") {
+                file.imports.push(trimmed.to_string());
+            } else if let Some(package) = trimmed.strip_prefix("package ") {
+                file.package = Some(package.trim_end_matches(';').trim().to_string());
+            } else if trimmed.starts_with("syntax ") {
+                file.syntax.push(trimmed.to_string());
+            } else if trimmed.starts_with("option ") {
+                file.options.push(trimmed.to_string());
+            }
+        }
+        file
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USERS: &str = "syntax = \"proto3\";\n\npackage app.v1;\n\n\
+        import \"google/protobuf/timestamp.proto\";\n\n\
+        // service: UserService\n\
+        message GetUserRequest {\n  string id = 1;\n}\n\
+        // service: UserService\n\
+        message GetUserResponse {\n  google.protobuf.Timestamp result = 1;\n}\n";
+
+    #[test]
+    fn merge_into_empty_file_keeps_generated() {
+        let merged = merge_common_proto("", USERS).unwrap();
+        assert_eq!(merged, USERS);
+    }
+
+    #[test]
+    fn merge_replaces_same_name_and_appends_new() {
+        let users = "syntax = \"proto3\";\n\npackage app.v1;\n\n\
+            // service: UserService\n\
+            message GetUserRequest {\n  string id = 1;\n  bool full = 2;\n}\n\
+            // service: UserService\n\
+            message GetOrderRequest {\n  string id = 1;\n}\n";
+        let merged = merge_common_proto(USERS, users).unwrap();
+        assert!(merged.contains("import \"google/protobuf/timestamp.proto\";"));
+        assert!(merged.contains("message GetUserRequest {\n  string id = 1;\n  bool full = 2;\n}"));
+        assert_eq!(merged.matches("message GetUserRequest").count(), 1);
+        let response = merged.find("message GetUserResponse").unwrap();
+        let order = merged.find("message GetOrderRequest").unwrap();
+        assert!(
+            response < order,
+            "existing messages keep their position:\n{merged}"
+        );
+    }
+
+    #[test]
+    fn merge_keeps_identical_message_from_other_service() {
+        let admin = "syntax = \"proto3\";\n\npackage app.v1;\n\n\
+            // service: AdminService\n\
+            message GetUserRequest {\n  string id = 1;\n}\n";
+        let merged = merge_common_proto(USERS, admin).unwrap();
+        assert_eq!(merged.matches("message GetUserRequest").count(), 1);
+    }
+
+    #[test]
+    fn merge_rejects_different_message_from_other_service() {
+        let admin = "syntax = \"proto3\";\n\npackage app.v1;\n\n\
+            // service: AdminService\n\
+            message GetUserRequest {\n  uint64 id = 1;\n}\n";
+        let err = merge_common_proto(USERS, admin).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("`GetUserRequest` from `UserService`"), "{err}");
+    }
+
+    #[test]
+    fn merge_rejects_other_package() {
+        let other = "syntax = \"proto3\";\n\npackage billing.v1;\n";
+        let err = merge_common_proto(USERS, other).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,109 @@
+//! Response headers computed at runtime, for `#[http]` handlers.
+//!
+//! `#[response(header = ..., value = ...)]` covers headers known at compile
+//! time. When a header depends on the result, such as a `Location` pointing at
+//! a freshly created resource, return the value wrapped in [`WithHeaders`]:
+//!
+//! ```ignore
+//! #[http]
+//! impl UserService {
+//!     #[response(status = 201)]
+//!     fn create_user(&self, name: String) -> WithHeaders<User> {
+//!         let user = self.store.insert(name);
+//!         let location = format!("/users/{}", user.id);
+//!         WithHeaders::new(user).header("location", location)
+//!     }
+//! }
+//! ```
+//!
+//! The wrapper also works inside `Result` and `Option`. The handler sends
+//! `value` as usual and adds `headers` to the response. Generated clients return
+//! the wrapper filled with the response's headers. Other protocols serialize
+//! `value` alone.
+
+use http::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::{Serialize, Serializer};
+
+/// A response value plus HTTP headers chosen at runtime.
+#[derive(Debug, Clone, Default)]
+pub struct WithHeaders<T> {
+    /// The response body, sent exactly as an unwrapped return value would be.
+    pub value: T,
+    /// Headers added to the response. They replace any default value with the
+    /// same name, such as `content-type`.
+    pub headers: HeaderMap,
+}
+
+impl<T> WithHeaders<T> {
+    /// Wrap `value` with no headers yet.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            headers: HeaderMap::new(),
+        }
+    }
+
+    /// Add a header, keeping earlier values of the same name.
+    ///
+    /// # Panics
+    ///
+    /// If the name or value is not valid in HTTP, such as a value containing a
+    /// newline. For values built from untrusted input, check them with
+    /// [`HeaderValue::try_from`] and insert into `headers` directly.
+    #[track_caller]
+    pub fn header<K, V>(mut self, name: K, value: V) -> Self
+    where
+        K: TryInto<HeaderName>,
+        V: TryInto<HeaderValue>,
+    {
+        let name = name
+            .try_into()
+            .unwrap_or_else(|_| panic!("WithHeaders::header: invalid HTTP header name"));
+        let value = value.try_into().unwrap_or_else(|_| {
+            panic!("WithHeaders::header: invalid value for HTTP header `{name}`")
+        });
+        self.headers.append(name, value);
+        self
+    }
+
+    /// The value and the headers.
+    pub fn into_parts(self) -> (T, HeaderMap) {
+        (self.value, self.headers)
+    }
+}
+
+/// Serializes as `value` alone. Headers only travel over HTTP.
+impl<T: Serialize> Serialize for WithHeaders<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_appends_values() {
+        let wrapped = WithHeaders::new(1)
+            .header("x-tag", "a")
+            .header("x-tag", "b")
+            .header("location", "/items/1");
+        let tags: Vec<_> = wrapped.headers.get_all("x-tag").iter().collect();
+        assert_eq!(tags, ["a", "b"]);
+        assert_eq!(wrapped.headers.len(), 3);
+        assert_eq!(serde_json::to_string(&wrapped).unwrap(), "1");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid value for HTTP header `x-bad`")]
+    fn header_panics_on_invalid_value() {
+        let _ = WithHeaders::new(1).header("x-bad", "line\nbreak");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid HTTP header name")]
+    fn header_panics_on_invalid_name() {
+        let _ = WithHeaders::new(1).header("bad name", "value");
+    }
+}
@@ -0,0 +1,140 @@
+//! In-process HTTP test client for `#[http]` services.
+//!
+//! Generated `http_test_client()` wraps `http_router()` in an [`HttpTestClient`],
+//! which drives each request through the router with `tower::ServiceExt::oneshot`
+//! — no listener, port or network involved — and buffers the answer into an
+//! [`HttpTestResponse`].
+//!
+//! ```ignore
+//! let client = UserService::new().http_test_client();
+//!
+//! let created = client.post("/users", &json!({"name": "Ada"})).await;
+//! assert_eq!(created.status(), 200);
+//!
+//! let user: User = client.get("/users/1").await.json();
+//! ```
+
+use axum::Router;
+use axum::body::{Body, Bytes};
+use axum::http::{HeaderMap, Method, Request, StatusCode, header};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tower::ServiceExt;
+
+/// Sends requests straight to a router, for integration tests.
+#[derive(Clone)]
+pub struct HttpTestClient {
+    router: Router,
+}
+
+impl HttpTestClient {
+    /// Wrap a router; see also the generated `http_test_client()`.
+    pub fn new(router: Router) -> Self {
+        Self { router }
+    }
+
+    /// `GET uri`.
+    pub async fn get(&self, uri: &str) -> HttpTestResponse {
+        self.send(Method::GET, uri, None).await
+    }
+
+    /// `DELETE uri`.
+    pub async fn delete(&self, uri: &str) -> HttpTestResponse {
+        self.send(Method::DELETE, uri, None).await
+    }
+
+    /// `POST uri` with `body` as JSON.
+    pub async fn post(&self, uri: &str, body: &impl Serialize) -> HttpTestResponse {
+        self.send(Method::POST, uri, Some(to_json(body))).await
+    }
+
+    /// `PUT uri` with `body` as JSON.
+    pub async fn put(&self, uri: &str, body: &impl Serialize) -> HttpTestResponse {
+        self.send(Method::PUT, uri, Some(to_json(body))).await
+    }
+
+    /// `PATCH uri` with `body` as JSON.
+    pub async fn patch(&self, uri: &str, body: &impl Serialize) -> HttpTestResponse {
+        self.send(Method::PATCH, uri, Some(to_json(body))).await
+    }
+
+    /// Send an arbitrary request, e.g. one with custom headers.
+    pub async fn request(&self, request: Request<Body>) -> HttpTestResponse {
+        let response = self
+            .router
+            .clone()
+            .oneshot(request)
+            .await
+            .unwrap_or_else(|never| match never {});
+        let (parts, body) = response.into_parts();
+        let body = axum::body::to_bytes(body, usize::MAX)
+            .await
+            .expect("failed to read response body");
+        HttpTestResponse {
+            status: parts.status,
+            headers: parts.headers,
+            body,
+        }
+    }
+
+    async fn send(&self, method: Method, uri: &str, json: Option<Vec<u8>>) -> HttpTestResponse {
+        let builder = Request::builder().method(method).uri(uri);
+        let request = match json {
+            Some(json) => builder
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(json)),
+            None => builder.body(Body::empty()),
+        }
+        .unwrap_or_else(|e| panic!("invalid test request for `{uri}`: {e}"));
+        self.request(request).await
+    }
+}
+
+fn to_json(body: &impl Serialize) -> Vec<u8> {
+    serde_json::to_vec(body).expect("failed to serialize test request body as JSON")
+}
+
+/// A fully buffered response from [`HttpTestClient`].
+#[derive(Debug, Clone)]
+pub struct HttpTestResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl HttpTestResponse {
+    /// The response status.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The response headers.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// A header value as text, if present and valid UTF-8.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    /// The raw body.
+    pub fn bytes(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// The body as UTF-8 text. Panics if it is not valid UTF-8.
+    pub fn text(&self) -> &str {
+        std::str::from_utf8(&self.body).expect("response body is not valid UTF-8")
+    }
+
+    /// The body decoded from JSON. Panics, showing the body, if it does not decode.
+    pub fn json<T: DeserializeOwned>(&self) -> T {
+        serde_json::from_slice(&self.body).unwrap_or_else(|e| {
+            panic!(
+                "response body is not the expected JSON ({e}): {}",
+                String::from_utf8_lossy(&self.body)
+            )
+        })
+    }
+}
@@ -17,6 +17,7 @@
 //! - Message schemas for parameters and results
 //! - Subscribe/publish operations
 //! - Server information
+//! - Message `examples` from `#[example(request = "...", response = "...")]` on methods
 //!
 //! # Generated Methods
 //!
@@ -111,8 +112,6 @@ pub(crate) fn expand_asyncapi(
     args: AsyncApiArgs,
     mut impl_block: ItemImpl,
 ) -> syn::Result<TokenStream2> {
-    // L3: reject generic impl blocks (consistent with all other protocol macros).
-    crate::reject_generic_impl(&impl_block)?;
     let app_meta = extract_app_meta(&mut impl_block.attrs);
     let struct_name = get_impl_name(&impl_block)?;
     let (impl_generics, _ty_generics, where_clause) = impl_block.generics.split_for_impl();
@@ -140,13 +139,25 @@ pub(crate) fn expand_asyncapi(
         .server
         .unwrap_or_else(|| "ws://localhost:8080".to_string());
 
-    // Generate channel specs for each method
-    let channel_specs: Vec<String> = methods.iter().map(generate_channel_spec).collect();
-    let channels_json = channel_specs.join(",\n");
-
-    // Generate message schemas
-    let message_specs: Vec<String> = methods.iter().map(generate_message_spec).collect();
-    let messages_json = message_specs.join(",\n");
+    // Channel and message specs for each method, as statements so #[cfg]
+    // guards individual methods
+    let spec_pushes: Vec<TokenStream2> = methods
+        .iter()
+        .map(|m| {
+            let channel = generate_channel_spec(m);
+            let message = generate_message_spec(m);
+            let cfg_attrs = &m.cfg_attrs;
+            quote! {
+                #(#cfg_attrs)*
+                {
+                    __channels.push(#channel);
+                    __messages.push(#message);
+                }
+            }
+        })
+        .collect();
+    let example_inserts: Vec<TokenStream2> =
+        methods.iter().flat_map(generate_message_examples).collect();
 
     let maybe_impl = if crate::is_protocol_impl_emitter(&impl_block, "asyncapi") {
         quote! { #impl_block }
@@ -159,14 +170,19 @@ pub(crate) fn expand_asyncapi(
 
         impl #impl_generics #self_ty #where_clause {
             /// Get the AsyncAPI specification for this service.
+            #[allow(unused_mut)]
             pub fn asyncapi_spec() -> ::server_less::serde_json::Value {
-                let channels_str = concat!("{", #channels_json, "}");
-                let messages_str = concat!("{", #messages_json, "}");
+                let mut __channels: Vec<&str> = Vec::new();
+                let mut __messages: Vec<&str> = Vec::new();
+                #(#spec_pushes)*
+                let channels_str = format!("{{{}}}", __channels.join(","));
+                let messages_str = format!("{{{}}}", __messages.join(","));
 
                 let channels: ::server_less::serde_json::Value =
-                    ::server_less::serde_json::from_str(channels_str).unwrap_or_default();
-                let messages: ::server_less::serde_json::Value =
-                    ::server_less::serde_json::from_str(messages_str).unwrap_or_default();
+                    ::server_less::serde_json::from_str(&channels_str).unwrap_or_default();
+                let mut messages: ::server_less::serde_json::Value =
+                    ::server_less::serde_json::from_str(&messages_str).unwrap_or_default();
+                #(#example_inserts)*
 
                 ::server_less::serde_json::json!({
                     "asyncapi": "2.6.0",
@@ -275,6 +291,45 @@ fn generate_message_spec(method: &MethodInfo) -> String {
     )
 }
 
+/// Tokens attaching a method's `#[example]` payloads to its request and response
+/// messages as AsyncAPI `examples`.
+fn generate_message_examples(method: &MethodInfo) -> Vec<TokenStream2> {
+    let cap_name = capitalize(&method.name_str().to_lower_camel_case());
+    let sides = [
+        (
+            format!("{cap_name}Request"),
+            method
+                .examples
+                .iter()
+                .filter_map(|e| e.request.as_deref())
+                .collect::<Vec<_>>(),
+        ),
+        (
+            format!("{cap_name}Response"),
+            method
+                .examples
+                .iter()
+                .filter_map(|e| e.response.as_deref())
+                .collect(),
+        ),
+    ];
+    sides
+        .into_iter()
+        .filter(|(_, payloads)| !payloads.is_empty())
+        .map(|(message, payloads)| {
+            let payloads = payloads.into_iter().map(server_less_rpc::example_value_tokens);
+            quote! {
+                if let Some(__message) = messages.get_mut(#message).and_then(|m| m.as_object_mut()) {
+                    __message.insert(
+                        "examples".to_string(),
+                        ::server_less::serde_json::json!([#({ "payload": (#payloads) }),*]),
+                    );
+                }
+            }
+        })
+        .collect()
+}
+
 /// Generate parameter property
 fn generate_param_property(param: &ParamInfo) -> String {
     let name = param.name_str().to_lower_camel_case();
@@ -316,9 +371,18 @@ fn get_json_schema_ty(ty: &syn::Type) -> String {
     }
     // Vec<T> → {"type": "array", "items": <inner_schema>}
     if let Some(inner) = unwrap_vec_type(ty) {
-        let inner_schema = get_json_schema_ty(inner);
+        // Nested 128-bit integers are not sent as strings, only top-level ones.
+        let inner_schema = if server_less_rpc::is_wide_integer(inner) {
+            r#"{"type": "integer"}"#.to_string()
+        } else {
+            get_json_schema_ty(inner)
+        };
         return format!(r#"{{"type": "array", "items": {}}}"#, inner_schema);
     }
+    // i128/u128/Decimal travel as decimal strings.
+    if server_less_rpc::is_decimal_type(ty) {
+        return r#"{"type": "string", "format": "decimal"}"#.to_string();
+    }
     let type_str = quote!(#ty).to_string();
     if type_str.contains("String") || type_str.contains("str") {
         r#"{"type": "string"}"#.to_string()
@@ -106,7 +106,6 @@ impl Parse for CapnpArgs {
 }
 
 pub(crate) fn expand_capnp(args: CapnpArgs, mut impl_block: ItemImpl) -> syn::Result<TokenStream2> {
-    crate::reject_generic_impl(&impl_block)?;
     let _app_meta = extract_app_meta(&mut impl_block.attrs);
     let struct_name = get_impl_name(&impl_block)?;
     let (impl_generics, _ty_generics, where_clause) = impl_block.generics.split_for_impl();
@@ -19,6 +19,8 @@
 //! - `#[cli(helper)]` — Self-documenting alias for `skip` (for display formatters, internal logic)
 //! - `#[cli(hidden)]` — Include the subcommand but hide it from `--help`
 //! - `#[cli(default)]` — Make this the default action when no subcommand is given
+//! - `#[cli(confirm)]` — Prompt "Are you sure? [y/N]" on stdin before running; `--yes`/`-y`
+//!   skips the prompt. `#[cli(confirm = "Delete the user?")]` sets the question.
 //! - `#[cli(display_with = "fn_name")]` — Use a custom function for text output formatting.
 //!   The function is called as `self.fn_name(&return_value)` and can live in any impl block
 //!   on the same type (not just the `#[cli]` block). Only affects text output; `--json`/`--jq`
@@ -37,6 +39,10 @@
 //! - Required parameters → Positional arguments
 //! - `Option<T>` parameters → Optional flags (`--name <NAME>`)
 //! - `bool` parameters → Boolean flags (`--verbose`)
+//! - `Vec<T>` parameters → Repeatable flags (`--tag a --tag b` or `--tag a,b`); with
+//!   `#[param(positional)]`, every remaining positional value
+//! - `#[derive(ServerlessEnum)]` parameters → Restricted to their variants
+//!   (`--status <active|inactive>`), validated by clap and listed in `--help`
 //!
 //! # Generated Methods
 //!
@@ -104,10 +110,11 @@ use syn::{ItemImpl, Token, parse::Parse};
 
 // Import Context helpers
 use crate::context::{
-    generate_cli_context_extraction, partition_context_params,
+    generate_cli_context_extraction, http_only_error, is_cancellation_token, is_supplied_param,
+    never_cancelled, partition_context_params,
 };
 use crate::app::extract_app_meta;
-use crate::server_attrs::{has_server_hidden, has_server_skip, validate_server_attrs};
+use crate::server_attrs::{has_server_hidden, skips_operation, validate_server_attrs};
 
 /// Arguments for the #[cli] attribute
 #[derive(Default)]
@@ -148,6 +155,10 @@ pub(crate) struct CliArgs {
     pub input_schema: Option<bool>,
     /// Inject the per-leaf `--output-schema` flag. Default `true`.
     pub output_schema: Option<bool>,
+    /// Inject the global `--output <json|text>` format flag. Default `true`.
+    /// `#[cli(output = false)]` drops it; a colliding `output` parameter then
+    /// becomes legal.
+    pub output: Option<bool>,
 }
 
 /// Which injected meta-surface flags are enabled for a `#[cli]` projection.
@@ -160,6 +171,7 @@ pub(crate) struct MetaFlags {
     pub manual: bool,
     pub input_schema: bool,
     pub output_schema: bool,
+    pub output: bool,
 }
 
 impl Parse for CliArgs {
@@ -252,6 +264,10 @@ impl Parse for CliArgs {
                     let lit: syn::LitBool = input.parse()?;
                     args.output_schema = Some(lit.value());
                 }
+                "output" => {
+                    let lit: syn::LitBool = input.parse()?;
+                    args.output = Some(lit.value());
+                }
                 other => {
                     if other == "about" {
                         return Err(syn::Error::new(
@@ -272,7 +288,7 @@ impl Parse for CliArgs {
                     const VALID: &[&str] = &[
                         "name", "version", "description", "homepage", "global",
                         "defaults", "no_sync", "no_async", "description_prefix",
-                        "manual", "input_schema", "output_schema",
+                        "manual", "input_schema", "output_schema", "output",
                     ];
                     let suggestion = crate::did_you_mean(other, VALID)
                         .map(|s| format!(" — did you mean `{s}`?"))
@@ -282,7 +298,7 @@ impl Parse for CliArgs {
                         format!(
                             "unknown argument `{other}`{suggestion}\n\
                              \n\
-                             Valid arguments: name, version, description, homepage, global, defaults, no_sync, no_async, description_prefix, manual, input_schema, output_schema\n\
+                             Valid arguments: name, version, description, homepage, global, defaults, no_sync, no_async, description_prefix, manual, input_schema, output_schema, output\n\
                              \n\
                              Example: #[cli(name = \"my-app\", description = \"My CLI tool\")]\n\
                              Bare flags: #[cli(no_sync)] or #[cli(no_async)]\n\
@@ -302,14 +318,15 @@ impl Parse for CliArgs {
     }
 }
 
-/// Check if a method has `#[cli(skip)]`, `#[cli(helper)]`, or `#[server(skip)]`.
+/// Check if a method has `#[cli(skip)]`, `#[cli(helper)]`, or `#[server(skip)]`, or is
+/// a `#[webhook]`.
 ///
 /// `#[cli(helper)]` is a self-documenting alias for `#[cli(skip)]` — use it on
 /// display formatters, internal logic, and other methods that should not become
 /// CLI subcommands. Alternatively, place helper methods in a separate impl block
 /// (without `#[cli]`).
 fn has_cli_skip(method: &MethodInfo) -> bool {
-    if has_server_skip(method) {
+    if skips_operation(method) {
         return true;
     }
     for attr in &method.method.attrs {
@@ -381,6 +398,41 @@ fn has_cli_manual_false(method: &MethodInfo) -> bool {
     false
 }
 
+/// The parameters that become CLI arguments: all but `Context` and supplied ones.
+fn cli_params(params: &[ParamInfo]) -> syn::Result<Vec<&ParamInfo>> {
+    let (_, mut regular) = partition_context_params(params)?;
+    regular.retain(|p| !is_supplied_param(p));
+    Ok(regular)
+}
+
+/// Compile error if a positional `Vec<T>` parameter is followed by another positional.
+///
+/// The list takes every remaining value, so a later positional could never be filled.
+fn check_positional_lists(partitioned: &server_less_parse::PartitionedMethods) -> syn::Result<()> {
+    for m in partitioned.leaf.iter().chain(partitioned.slug_mounts.iter()) {
+        let regular = cli_params(&m.params)?;
+        let positionals: Vec<_> = regular.iter().filter(|p| p.is_positional).collect();
+        for pair in positionals.windows(2) {
+            let (p, next) = (pair[0], pair[1]);
+            if p.is_vec {
+                return Err(syn::Error::new(
+                    p.name.span(),
+                    format!(
+                        "positional list `{list}` must be the last positional parameter; \
+                         `{next}` comes after it\n\
+                         \n\
+                         A positional Vec takes every remaining value, so `{next}` could never \
+                         be filled. Move `{list}` after it, or make one of them a flag.",
+                        list = p.name_str(),
+                        next = next.name_str(),
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Compile error if any leaf/slug parameter's kebab flag name collides with a
 /// **currently-injected** global meta-surface flag. Disabling a meta-surface frees
 /// its name. See docs/design/cli-manual-projection.md, "Collision guard".
@@ -417,15 +469,26 @@ fn check_reserved_flag_collisions(
                 "the --output-schema surface (disable with #[cli(output_schema = false)])",
             ));
         }
+        if surfaces.output {
+            r.push((
+                "output",
+                "the --output format flag (disable with #[cli(output = false)])",
+            ));
+        }
         r
     };
 
     // Leaf params register on *this* command, so they collide with this command's
     // injected flags — governed by `meta`. Slug-mount params register on the *child*
     // command (the mount's own `#[cli]`), whose meta-surfaces default on and are not
-    // visible here; reserve all three conservatively so we never miss a collision the
+    // visible here; reserve them all conservatively so we never miss a collision the
     // child would hit at clap-build time.
-    let all_on = MetaFlags { manual: true, input_schema: true, output_schema: true };
+    let all_on = MetaFlags {
+        manual: true,
+        input_schema: true,
+        output_schema: true,
+        output: true,
+    };
     let leaf_reserved = reserved_for(meta);
     let slug_reserved = reserved_for(all_on);
 
@@ -454,7 +517,7 @@ fn check_reserved_flag_collisions(
     ];
     for (methods, reserved) in groups {
         for m in methods.iter() {
-            let (_, regular) = partition_context_params(&m.params)?;
+            let regular = cli_params(&m.params)?;
             for p in &regular {
                 let kebab = cli_param_name(p);
                 if let Some((flag, what)) = reserved.iter().find(|(name, _)| *name == kebab) {
@@ -489,6 +552,17 @@ fn check_reserved_flag_collisions(
                         ),
                     ));
                 }
+                if kebab == "yes" && get_cli_confirm(m)?.is_some() {
+                    return Err(syn::Error::new(
+                        p.name.span(),
+                        format!(
+                            "parameter `{param}` collides with the `--yes` flag that \
+                             #[cli(confirm)] adds to skip the confirmation prompt — rename the \
+                             parameter",
+                            param = p.name_str(),
+                        ),
+                    ));
+                }
             }
         }
     }
@@ -520,6 +594,47 @@ fn has_cli_hidden(method: &MethodInfo) -> bool {
     false
 }
 
+/// Prompt for `#[cli(confirm)]` / `#[cli(confirm = "...")]`, or `None` when the
+/// method runs without asking. The bare form prompts "Are you sure?".
+fn get_cli_confirm(method: &MethodInfo) -> syn::Result<Option<String>> {
+    let mut prompt = None;
+    for attr in &method.method.attrs {
+        if attr.path().is_ident("cli") {
+            // Errors propagate: a malformed `confirm` must not silently drop the prompt.
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("confirm") {
+                    if meta.input.peek(Token![=]) {
+                        let lit: syn::LitStr = meta.value()?.parse()?;
+                        prompt = Some(lit.value());
+                    } else {
+                        prompt = Some("Are you sure?".to_string());
+                    }
+                } else if meta.input.peek(Token![=]) {
+                    let _: syn::Expr = meta.value()?.parse()?;
+                } else if !meta.input.is_empty() && !meta.input.peek(Token![,]) {
+                    // Other keys' arguments, e.g. `aliases["a", "b"]`.
+                    let _: proc_macro2::TokenTree = meta.input.parse()?;
+                }
+                Ok(())
+            })?;
+        }
+    }
+    Ok(prompt)
+}
+
+/// The `--yes` switch registered on a `#[cli(confirm)]` command. Takes `-y` as well
+/// unless a parameter already claims that short flag.
+fn confirm_yes_arg(params: &[&ParamInfo]) -> TokenStream2 {
+    let short = (!params.iter().any(|p| p.short_flag == Some('y'))).then(|| quote! { .short('y') });
+    quote! {
+        ::server_less::clap::Arg::new("yes")
+            .long("yes")
+            #short
+            .help("Skip the confirmation prompt")
+            .action(::server_less::clap::ArgAction::SetTrue)
+    }
+}
+
 /// Collect all `#[cli(alias = "...")]` values on a method (repeatable).
 ///
 /// Each alias becomes a **hidden** clap alias for the generated subcommand: the
@@ -616,11 +731,9 @@ fn cli_param_name(param: &ParamInfo) -> String {
 /// numeric/bool literals pass through. clap re-parses the string through the arg's value
 /// parser, so this round-trips back to the param's type.
 fn clap_default_value(raw: &str) -> String {
-    if raw.len() >= 2 && raw.starts_with('"') && raw.ends_with('"') {
-        raw[1..raw.len() - 1].to_string()
-    } else {
-        raw.to_string()
-    }
+    server_less_rpc::default_string_literal(raw)
+        .unwrap_or(raw)
+        .to_string()
 }
 
 /// Build an ordered list of group display names.
@@ -669,21 +782,15 @@ fn strip_cli_attrs(impl_block: &ItemImpl) -> ItemImpl {
             method
                 .attrs
                 .retain(|attr| !attr.path().is_ident("cli") && !attr.path().is_ident("server"));
-            // Strip #[param(...)] from function parameters
-            for input in &mut method.sig.inputs {
-                if let syn::FnArg::Typed(pat_type) = input {
-                    pat_type.attrs.retain(|attr| !attr.path().is_ident("param"));
-                }
-            }
         }
     }
+    crate::strip_param_attrs(&mut block);
     block
 }
 
 // partition_methods is now shared from server_less_parse
 
 pub(crate) fn expand_cli(args: CliArgs, mut impl_block: ItemImpl) -> syn::Result<TokenStream2> {
-    crate::reject_generic_impl(&impl_block)?;
     let app_meta = extract_app_meta(&mut impl_block.attrs);
     let args = CliArgs {
         name: args.name.or(app_meta.name),
@@ -745,6 +852,7 @@ pub(crate) fn expand_cli(args: CliArgs, mut impl_block: ItemImpl) -> syn::Result
         manual: args.manual.unwrap_or(true),
         input_schema: args.input_schema.unwrap_or(true),
         output_schema: args.output_schema.unwrap_or(true),
+        output: args.output.unwrap_or(true),
     };
 
     for m in &methods {
@@ -756,6 +864,7 @@ pub(crate) fn expand_cli(args: CliArgs, mut impl_block: ItemImpl) -> syn::Result
     // collides with a *currently-injected* global flag is a compile error spanned
     // to the parameter. Disabling a meta-surface frees its name.
     check_reserved_flag_collisions(&partitioned, meta, &global_flags)?;
+    check_positional_lists(&partitioned)?;
 
     // Resolve method groups — consider all methods (leaf + mounts) for group discovery
     let group_registry = extract_groups(&impl_block)?;
@@ -920,9 +1029,9 @@ pub(crate) fn expand_cli(args: CliArgs, mut impl_block: ItemImpl) -> syn::Result
     // Parent-level args for the default action so that flags like
     // `app --flag` are parsed (and shown in `app --help`) when no subcommand is specified.
     let default_parent_args: Vec<TokenStream2> = if let Some(dm) = default_method {
-        let (_, regular_params) = partition_context_params(&dm.params)?;
+        let regular_params = cli_params(&dm.params)?;
         let mut pos_idx = 0usize;
-        regular_params
+        let mut args: Vec<TokenStream2> = regular_params
             .iter()
             .map(|p| {
                 let idx = if p.is_positional {
@@ -933,7 +1042,11 @@ pub(crate) fn expand_cli(args: CliArgs, mut impl_block: ItemImpl) -> syn::Result
                 };
                 generate_arg(p, has_defaults, idx)
             })
-            .collect()
+            .collect();
+        if get_cli_confirm(dm)?.is_some() {
+            args.push(confirm_yes_arg(&regular_params));
+        }
+        args
     } else {
         vec![]
     };
@@ -1154,10 +1267,10 @@ pub(crate) fn expand_cli(args: CliArgs, mut impl_block: ItemImpl) -> syn::Result
     // any `#[cli(default)]` None arm so `tool --manual` means "manual of the tree",
     // not "run the default action".
     let manual_dispatch_intercept = if meta.manual {
-        let emit = manual_emit_tokens(&syn::Ident::new(
-            "matches",
-            proc_macro2::Span::call_site(),
-        ));
+        let emit = manual_emit_tokens(
+            &syn::Ident::new("matches", proc_macro2::Span::call_site()),
+            meta,
+        );
         quote! {
             if matches.get_flag("manual") && matches.subcommand().is_none() {
                 let __nodes = <Self as ::server_less::CliSubcommand>::cli_manual_nodes(self, "");
@@ -1170,8 +1283,8 @@ pub(crate) fn expand_cli(args: CliArgs, mut impl_block: ItemImpl) -> syn::Result
     };
 
     // Built-in output formatting flags. `--json`/`--jsonl`/`--jq`/`--params-json`
-    // are always present; the meta-surface flags are gated by their toggle so a
-    // disabled surface neither appears in `--help` nor is queried at dispatch.
+    // are always present; `--output` and the meta-surface flags are gated by their
+    // toggle so a disabled flag neither appears in `--help` nor is queried at dispatch.
     let input_schema_flag = meta.input_schema.then(|| quote! {
         .arg(
             ::server_less::clap::Arg::new("input-schema")
@@ -1190,6 +1303,16 @@ pub(crate) fn expand_cli(args: CliArgs, mut impl_block: ItemImpl) -> syn::Result
                 .help("Print JSON Schema of the subcommand's return type and exit")
         )
     });
+    let output_flag = meta.output.then(|| quote! {
+        .arg(
+            ::server_less::clap::Arg::new("output")
+                .long("output")
+                .value_name("FORMAT")
+                .value_parser(["json", "text"])
+                .global(true)
+                .help("Output format: `json` (same as --json) or `text` (default)")
+        )
+    });
     let manual_flag = meta.manual.then(|| quote! {
         .arg(
             ::server_less::clap::Arg::new("manual")
@@ -1214,6 +1337,7 @@ pub(crate) fn expand_cli(args: CliArgs, mut impl_block: ItemImpl) -> syn::Result
                 .global(true)
                 .help("Output machine-readable JSON")
         )
+        #output_flag
         .arg(
             ::server_less::clap::Arg::new("jq")
                 .long("jq")
@@ -1348,6 +1472,7 @@ pub(crate) fn expand_cli(args: CliArgs, mut impl_block: ItemImpl) -> syn::Result
     Ok(quote! {
         #clean_impl_block
 
+        #[allow(deprecated)]
         impl #impl_generics ::server_less::CliSubcommand for #self_ty #where_clause {
             fn cli_command() -> ::server_less::clap::Command {
                 let mut __cmd = ::server_less::clap::Command::new(#app_name)
@@ -1404,6 +1529,7 @@ pub(crate) fn expand_cli(args: CliArgs, mut impl_block: ItemImpl) -> syn::Result
             }
         }
 
+        #[allow(deprecated)]
         impl #impl_generics #self_ty #where_clause {
             #[doc = #cli_command_doc]
             pub fn cli_command() -> ::server_less::clap::Command {
@@ -1456,7 +1582,7 @@ fn generate_leaf_subcommand(
     let alias_tokens = aliases.iter().map(|a| quote! { .alias(#a) });
 
     // Filter out Context parameters - they're injected, not CLI args
-    let (_, regular_params) = partition_context_params(&method.params)?;
+    let regular_params = cli_params(&method.params)?;
 
     // Every regular param becomes a clap arg. Params can never name a declared global
     // flag (that is a compile error in `check_reserved_flag_collisions`), so there is
@@ -1475,6 +1601,10 @@ fn generate_leaf_subcommand(
             generate_arg(p, has_defaults, idx)
         })
         .collect();
+    let yes_arg = get_cli_confirm(method)?.map(|_| {
+        let arg = confirm_yes_arg(&regular_params);
+        quote! { .arg(#arg) }
+    });
 
     Ok(quote! {
         ::server_less::clap::Command::new(#name)
@@ -1483,6 +1613,7 @@ fn generate_leaf_subcommand(
             #hide
             #(#alias_tokens)*
             #(.arg(#args))*
+            #yes_arg
     })
 }
 
@@ -1535,7 +1666,7 @@ fn generate_slug_mount_subcommand(
     let aliases = get_cli_aliases(method);
     let alias_tokens = aliases.iter().map(|a| quote! { __cmd = __cmd.alias(#a); });
 
-    let (_, regular_params) = partition_context_params(&method.params)?;
+    let regular_params = cli_params(&method.params)?;
 
     // Generate positional args for slug params (before subcommands)
     let slug_args: Vec<_> = regular_params
@@ -1660,9 +1791,8 @@ fn generate_arg(
     };
 
     // Compute the leaf type for the value parser (strip Vec<> / Option<> wrappers).
-    // SchemaValueParser<T> requires T: JsonSchema + FromStr; for non-enum types it
-    // is a transparent pass-through, so emitting it unconditionally is harmless.
-    #[cfg(feature = "jsonschema")]
+    // `CliValueParserHelper` restricts `ServerlessEnum` types to their variants; other
+    // types get `SchemaValueParser` (with `jsonschema`) or the raw string.
     let value_parser = if param.is_bool {
         quote! {}
     } else {
@@ -1673,10 +1803,13 @@ fn generate_arg(
         } else {
             param.ty.clone()
         };
-        quote! { .value_parser(::server_less::SchemaValueParser::<#inner>::new()) }
+        quote! {
+            .value_parser({
+                use ::server_less::CliValueParserFallback as _;
+                ::server_less::CliValueParserHelper::<#inner>::new().value_parser()
+            })
+        }
     };
-    #[cfg(not(feature = "jsonschema"))]
-    let value_parser = quote! {};
 
     if param.is_bool {
         let help = match &param.help_text {
@@ -1690,6 +1823,22 @@ fn generate_arg(
                 .action(::server_less::clap::ArgAction::SetTrue)
                 #help
         }
+    } else if param.is_vec && param.is_positional {
+        // A positional list takes every remaining value: `app add a b c`.
+        let idx = positional_index.unwrap_or(1);
+        let help = match &param.help_text {
+            Some(text) => quote! { .help(#text) },
+            None => quote! { .help(concat!("One or more: ", #name)) },
+        };
+        quote! {
+            ::server_less::clap::Arg::new(#name)
+                .index(#idx)
+                .num_args(1..)
+                .action(::server_less::clap::ArgAction::Append)
+                .required(false)
+                #value_parser
+                #help
+        }
     } else if param.is_vec {
         let help = match &param.help_text {
             Some(text) => quote! { .help(#text) },
@@ -1768,7 +1917,8 @@ fn generate_leaf_match_arm(
     let method_name = &method.name;
 
     // Partition Context vs regular parameters
-    let (context_param, regular_params) = partition_context_params(&method.params)?;
+    let (context_param, mut regular_params) = partition_context_params(&method.params)?;
+    regular_params.retain(|p| !is_supplied_param(p));
 
     // ── Input schema (compile-time JSON) ──────────────────────────────
     let input_schema = {
@@ -1776,11 +1926,23 @@ fn generate_leaf_match_arm(
         let mut required = Vec::new();
         for p in &regular_params {
             let name_str = p.name_str();
-            let schema = type_to_json_schema(&Some(p.ty.clone()));
+            let mut schema = type_to_json_schema(&Some(p.ty.clone()));
+            if let Some(raw) = &p.default_value {
+                let value = server_less_rpc::default_value_tokens(raw);
+                schema = quote! {
+                    {
+                        let mut __prop = #schema;
+                        if let Some(__obj) = __prop.as_object_mut() {
+                            __obj.insert("default".to_string(), #value);
+                        }
+                        __prop
+                    }
+                };
+            }
             props.push(quote! {
                 __props.insert(#name_str.to_string(), #schema);
             });
-            if !p.is_optional && !p.is_bool {
+            if !p.is_optional && !p.is_bool && p.default_value.is_none() {
                 required.push(quote! { #name_str });
             }
         }
@@ -1833,9 +1995,24 @@ fn generate_leaf_match_arm(
         }
     };
 
+    // ── Call arguments, in declaration order ──────────────────────────
+    let arg_names: Vec<_> = method
+        .params
+        .iter()
+        .map(|p| {
+            if context_param.is_some_and(|c| c.name == p.name) {
+                quote! { __ctx }
+            } else if is_cancellation_token(&p.ty) {
+                never_cancelled()
+            } else {
+                let name = &p.name;
+                quote! { #name }
+            }
+        })
+        .collect();
+
     // ── Param extraction: normal CLI args ─────────────────────────────
     let mut arg_extractions = Vec::new();
-    let mut arg_names = Vec::new();
 
     // Generate Context extraction if needed
     if context_param.is_some() {
@@ -1843,7 +2020,6 @@ fn generate_leaf_match_arm(
         arg_extractions.push(quote! {
             let __ctx = #call;
         });
-        arg_names.push(quote! { __ctx });
     }
 
     // Generate regular parameter extractions
@@ -1869,12 +2045,16 @@ fn generate_leaf_match_arm(
                 });
             }
             #[cfg(not(feature = "jsonschema"))]
-            arg_extractions.push(quote! {
-                let #name: Vec<String> = sub_matches
-                    .get_many::<String>(#name_str)
-                    .map(|vs| vs.cloned().collect())
-                    .unwrap_or_default();
-            });
+            {
+                let ty = &p.ty;
+                arg_extractions.push(quote! {
+                    let #name: #ty = sub_matches
+                        .get_many::<String>(#name_str)
+                        .map(|vs| vs.map(|s| s.parse()).collect::<Result<_, _>>())
+                        .transpose()?
+                        .unwrap_or_default();
+                });
+            }
         } else if p.is_optional {
             let ty = &p.ty;
             // When jsonschema is active, value is stored as inner T; wrap in Option.
@@ -1936,19 +2116,16 @@ fn generate_leaf_match_arm(
                     .ok_or_else(|| format!("Missing required argument: {}", #name_str))?;
             });
         }
-        arg_names.push(quote! { #name });
     }
 
     // ── Param extraction: --params-json ───────────────────────────────
     let mut json_extractions = Vec::new();
-    let mut json_arg_names = Vec::new();
 
     if context_param.is_some() {
         let (_extraction, call) = generate_cli_context_extraction();
         json_extractions.push(quote! {
             let __ctx = #call;
         });
-        json_arg_names.push(quote! { __ctx });
     }
 
     for p in &regular_params {
@@ -1984,7 +2161,6 @@ fn generate_leaf_match_arm(
                         .map_err(|e| format!("Invalid value for '{}': {}", #name_str, e)))?;
             });
         }
-        json_arg_names.push(quote! { #name });
     }
 
     // ── Method call ───────────────────────────────────────────────────
@@ -2021,14 +2197,12 @@ fn generate_leaf_match_arm(
     };
 
     let call = gen_call(&arg_names);
-    let json_call = gen_call(&json_arg_names);
 
     // Extract output format flags
-    let format_extraction = quote! {
-        let __jsonl = sub_matches.get_flag("jsonl");
-        let __json = sub_matches.get_flag("json");
-        let __jq: Option<&String> = sub_matches.get_one::<String>("jq");
-    };
+    let format_extraction = format_flag_tokens(
+        &syn::Ident::new("sub_matches", proc_macro2::Span::call_site()),
+        meta,
+    );
 
     let display_with = get_display_with(method);
 
@@ -2167,10 +2341,10 @@ fn generate_leaf_match_arm(
     // manual). The aggregate at a container is handled in `cli_dispatch`; here we
     // are at the leaf the user navigated to, so its subtree is itself.
     let leaf_manual_node = generate_leaf_manual_node(method)?;
-    let manual_emit = manual_emit_tokens(&syn::Ident::new(
-        "sub_matches",
-        proc_macro2::Span::call_site(),
-    ));
+    let manual_emit = manual_emit_tokens(
+        &syn::Ident::new("sub_matches", proc_macro2::Span::call_site()),
+        meta,
+    );
     // Each meta-surface arm is gated on its toggle: a disabled flag is never
     // registered, and `get_flag` on an unregistered id panics at runtime.
     let manual_arm = meta.manual.then(|| quote! {
@@ -2215,6 +2389,45 @@ fn generate_leaf_match_arm(
         })
         .collect();
 
+    // `#[cli(confirm)]`: ask before running, after the schema/manual short-circuits
+    // (which never run the method) and before either extraction path.
+    let confirm = get_cli_confirm(method)?.map(|prompt| {
+        quote! {
+            if !sub_matches.get_flag("yes") && !::server_less::cli_confirm(#prompt)? {
+                return Err("aborted (pass --yes to skip the confirmation)".into());
+            }
+        }
+    });
+
+    // A method with an `#[http]`-only parameter cannot be called from the CLI.
+    let invocation = if let Some(message) = http_only_error(method) {
+        quote! {
+            let _ = sub_matches;
+            Err(#message.into())
+        }
+    } else {
+        quote! {
+            // --params-json: extract all params from JSON blob
+            if let Some(__params_json_str) = sub_matches.get_one::<String>("params-json") {
+                let __json_obj: ::server_less::serde_json::Value = ::server_less::serde_json::from_str(__params_json_str)
+                    .map_err(|e| format!("Invalid JSON in --params-json: {}", e))?;
+                let __json_obj = __json_obj.as_object()
+                    .ok_or_else(|| "Expected a JSON object for --params-json".to_string())?;
+                #(#json_extractions)*
+                #call
+                #format_extraction
+                #output
+            } else {
+                // Normal CLI arg extraction
+                #(#arg_extractions)*
+                #call
+                #format_extraction
+                #output
+            }
+            Ok(())
+        }
+    };
+
     let arm_body = quote! {
         #manual_arm
         // Schema flags: print and exit without running the method
@@ -2223,24 +2436,9 @@ fn generate_leaf_match_arm(
 
         #(#global_delivery)*
 
-        // --params-json: extract all params from JSON blob
-        if let Some(__params_json_str) = sub_matches.get_one::<String>("params-json") {
-            let __json_obj: ::server_less::serde_json::Value = ::server_less::serde_json::from_str(__params_json_str)
-                .map_err(|e| format!("Invalid JSON in --params-json: {}", e))?;
-            let __json_obj = __json_obj.as_object()
-                .ok_or_else(|| "Expected a JSON object for --params-json".to_string())?;
-            #(#json_extractions)*
-            #json_call
-            #format_extraction
-            #output
-        } else {
-            // Normal CLI arg extraction
-            #(#arg_extractions)*
-            #call
-            #format_extraction
-            #output
-        }
-        Ok(())
+        #confirm
+
+        #invocation
     };
 
     Ok(if none_arm {
@@ -2261,17 +2459,32 @@ fn generate_leaf_match_arm(
     })
 }
 
+/// Tokens binding `__jsonl`, `__json` and `__jq` from the format flags in
+/// `matches_ident`. `--output json` is the same as `--json`; `--output text` (the
+/// default) leaves the other flags in charge.
+fn format_flag_tokens(matches_ident: &syn::Ident, meta: MetaFlags) -> TokenStream2 {
+    let output_json = meta.output.then(|| {
+        quote! {
+            || #matches_ident.get_one::<String>("output").map(String::as_str) == Some("json")
+        }
+    });
+    quote! {
+        let __jsonl = #matches_ident.get_flag("jsonl");
+        let __json = #matches_ident.get_flag("json") #output_json;
+        let __jq: Option<&String> = #matches_ident.get_one::<String>("jq");
+    }
+}
+
 /// Tokens that format a `__nodes: Vec<CliManualNode>` binding according to the
 /// active format flags (`--json` / `--jsonl` / `--jq`), falling back to
 /// human-readable text when no format flag is set. `matches_ident` is the
 /// `ArgMatches` to read the format flags from (the global flags propagate to
 /// every level, so either the leaf's `sub_matches` or the dispatch `matches`
 /// works).
-fn manual_emit_tokens(matches_ident: &syn::Ident) -> TokenStream2 {
+fn manual_emit_tokens(matches_ident: &syn::Ident, meta: MetaFlags) -> TokenStream2 {
+    let format_flags = format_flag_tokens(matches_ident, meta);
     quote! {
-        let __json = #matches_ident.get_flag("json");
-        let __jsonl = #matches_ident.get_flag("jsonl");
-        let __jq: Option<&String> = #matches_ident.get_one::<String>("jq");
+        #format_flags
         if __json || __jsonl || __jq.is_some() {
             let __doc = ::server_less::cli_manual_to_json(&__nodes);
             let __formatted = ::server_less::cli_format_output(
@@ -2288,7 +2501,7 @@ fn manual_emit_tokens(matches_ident: &syn::Ident) -> TokenStream2 {
 /// for a leaf method. Mirrors the `input-schema` flag's compile-time JSON, but yields
 /// a value instead of printing — used by the `--manual` aggregate.
 fn leaf_input_schema_expr(method: &MethodInfo) -> syn::Result<TokenStream2> {
-    let (_, regular_params) = partition_context_params(&method.params)?;
+    let regular_params = cli_params(&method.params)?;
     let mut props = Vec::new();
     let mut required = Vec::new();
     for p in &regular_params {
@@ -2405,7 +2618,7 @@ fn generate_static_mount_manual_node(method: &MethodInfo) -> syn::Result<TokenSt
 /// per-leaf detail is reachable by invoking `tool <slug-mount> <id> --manual`.
 fn generate_slug_mount_manual_node(method: &MethodInfo) -> syn::Result<TokenStream2> {
     let name = cli_name(method);
-    let (_, regular_params) = partition_context_params(&method.params)?;
+    let regular_params = cli_params(&method.params)?;
     let slug_names: Vec<String> = regular_params
         .iter()
         .map(|p| format!("<{}>", cli_param_name(p)))
@@ -2488,7 +2701,7 @@ fn generate_slug_mount_arm(method: &MethodInfo) -> syn::Result<TokenStream2> {
         )
     })?;
 
-    let (_, regular_params) = partition_context_params(&method.params)?;
+    let regular_params = cli_params(&method.params)?;
 
     let mut slug_extractions = Vec::new();
     let mut slug_names = Vec::new();
@@ -2529,7 +2742,7 @@ fn generate_slug_mount_arm_async(
         )
     })?;
 
-    let (_, regular_params) = partition_context_params(&method.params)?;
+    let regular_params = cli_params(&method.params)?;
 
     let mut slug_extractions = Vec::new();
     let mut slug_names = Vec::new();
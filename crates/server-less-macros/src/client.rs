@@ -0,0 +1,547 @@
+//! Typed HTTP client generation macro.
+//!
+//! Generates a `{Type}Client` struct whose async methods mirror the impl's
+//! signatures and call the routes that `#[http]` serves for them.
+//!
+//! # Lockstep With `#[http]`
+//!
+//! Routes are resolved with the same `infer_http_method` / `infer_path` logic and
+//! `#[route(...)]` overrides as `#[http]`, and parameters are placed the same way:
+//! - Path parameters (`id`, `*_id`, `#[param(path)]`) are substituted into the URL
+//! - Body parameters are sent as a JSON object (POST/PUT/PATCH by default)
+//! - Query and header parameters are sent as strings (`Display` / `FromStr`)
+//!
+//! The URL prefix is taken from `#[client(prefix = "...")]`, or from a sibling
+//! `#[http(prefix = "...")]` when none is given. A sibling
+//! `#[http(visibility = "pub")]` likewise leaves private methods off the client,
+//! a sibling `#[http(rpc_style = true)]` resolves paths the same way, and a
+//! sibling `#[http(envelope = true)]` makes the client unwrap `{"data": ...}`.
+//!
+//! # Return Types
+//!
+//! Every client method returns `Result<_, ClientError>`:
+//! - `T` → `Result<T, ClientError>`
+//! - `Option<T>` → `Result<Option<T>, ClientError>` (404 → `None`, or a `null`
+//!   body with `#[response(none = "null")]`)
+//! - `Result<T, E>` → `Result<T, ClientError>` (error statuses → `ClientError::Status`)
+//! - `()` → `Result<(), ClientError>`
+//!
+//! `Vec<u8>` / `Bytes` values are read from the raw response body.
+//!
+//! Streaming methods (`impl Stream` / `impl Iterator`) and mounts are not
+//! projected onto the client.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use server_less::{client, http};
+//!
+//! #[derive(Clone)]
+//! struct UserService;
+//!
+//! #[client]  // FIRST - sees #[route]/#[param] before #[http] strips them
+//! #[http(prefix = "/api")]
+//! impl UserService {
+//!     fn get_user(&self, id: u32) -> Option<User> { /* ... */ }
+//! }
+//!
+//! let users = UserServiceClient::new("http://users.internal", reqwest::Client::new());
+//! let user = users.get_user(42).await?;
+//! ```
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use server_less_parse::{
+    HttpMethod, MethodInfo, ParamLocation, ReturnInfo, extract_methods_with_visibility,
+    get_impl_name, partition_methods,
+};
+use syn::{ItemImpl, Token, parse::Parse};
+
+use crate::context::{is_framework_param, is_raw_json};
+use crate::openapi_gen::{
+    ResponseOverride, RouteOverride, bind_prefix_params, infer_http_method, infer_path,
+    normalize_prefix, sibling_http_args,
+};
+use crate::server_attrs::{has_server_skip, validate_server_attrs};
+
+/// Arguments for the #[client] attribute
+#[derive(Default)]
+pub(crate) struct ClientArgs {
+    /// URL prefix for all paths (defaults to the sibling `#[http(prefix)]`)
+    pub prefix: Option<String>,
+}
+
+impl Parse for ClientArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut args = ClientArgs::default();
+
+        while !input.is_empty() {
+            let ident: syn::Ident = input.parse()?;
+
+            match ident.to_string().as_str() {
+                "prefix" => {
+                    input.parse::<Token![=]>()?;
+                    let lit: syn::LitStr = input.parse()?;
+                    args.prefix = Some(normalize_prefix(&lit)?);
+                }
+                other => {
+                    const VALID: &[&str] = &["prefix"];
+                    let suggestion = crate::did_you_mean(other, VALID)
+                        .map(|s| format!(" — did you mean `{s}`?"))
+                        .unwrap_or_default();
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!(
+                            "unknown argument `{other}`{suggestion}\n\
+                             Valid arguments: prefix\n\
+                             Example: #[client(prefix = \"/api/v1\")]\n\
+                             \n\
+                             Hint: without arguments, the prefix of a sibling #[http] is used"
+                        ),
+                    ));
+                }
+            }
+
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(args)
+    }
+}
+
+/// Whether another protocol macro is stacked on the same impl block.
+fn has_protocol_sibling(impl_block: &ItemImpl) -> bool {
+    impl_block.attrs.iter().any(|attr| {
+        crate::PROTOCOL_PRIORITY
+            .iter()
+            .chain(["server"].iter())
+            .any(|name| attr.path().is_ident(name))
+    })
+}
+
+pub(crate) fn expand_client(args: ClientArgs, impl_block: ItemImpl) -> syn::Result<TokenStream2> {
+
+    let struct_name = get_impl_name(&impl_block)?;
+    let client_name = format_ident!("{}Client", struct_name);
+    let sibling = sibling_http_args(&impl_block)?;
+    let mut methods = extract_methods_with_visibility(&impl_block, sibling.visibility)?;
+
+    let prefix = match args.prefix {
+        Some(prefix) => prefix,
+        None => sibling.prefix.unwrap_or_default(),
+    };
+    bind_prefix_params(&prefix, &mut methods);
+
+    for m in &methods {
+        validate_server_attrs(m)?;
+    }
+    let partitioned = partition_methods(&methods, has_server_skip);
+
+    let mut client_methods = Vec::new();
+    for method in &partitioned.leaf {
+        let overrides = RouteOverride::parse_from_attrs(&method.method.attrs)?;
+        if overrides.skip
+            || overrides.webhook
+            || method.return_info.is_stream
+            || method.return_info.is_iterator
+            || server_less_rpc::returns_status_tuple(&method.return_info)
+        {
+            continue;
+        }
+        client_methods.push(generate_client_method(
+            &prefix,
+            sibling.rpc_style,
+            sibling.envelope,
+            method,
+            &overrides,
+        )?);
+    }
+
+    // When stacked on top of #[http] (or another protocol), leave the impl block
+    // untouched so the sibling can still read its #[route]/#[param] attributes.
+    let emitted_impl = if has_protocol_sibling(&impl_block) {
+        quote! { #impl_block }
+    } else {
+        let stripped = strip_client_attrs(&impl_block);
+        quote! { #stripped }
+    };
+
+    let client_doc = format!(
+        "Typed HTTP client for the `{struct_name}` service, generated by `#[client]`.\n\n\
+         Each method calls the route that `#[http]` serves for the method of the same name."
+    );
+
+    Ok(quote! {
+        #emitted_impl
+
+        #[doc = #client_doc]
+        #[derive(Debug, Clone)]
+        pub struct #client_name {
+            base_url: ::std::string::String,
+            client: ::server_less::reqwest::Client,
+        }
+
+        impl #client_name {
+            /// Create a client that sends requests to `base_url` using `client`.
+            pub fn new(
+                base_url: impl ::std::convert::Into<::std::string::String>,
+                client: ::server_less::reqwest::Client,
+            ) -> Self {
+                Self {
+                    base_url: base_url.into(),
+                    client,
+                }
+            }
+
+            /// The base URL requests are sent to.
+            pub fn base_url(&self) -> &str {
+                &self.base_url
+            }
+
+            #(#client_methods)*
+        }
+    })
+}
+
+/// Strip `#[route]`, `#[response]` and `#[param]` when `#[client]` is the only macro.
+fn strip_client_attrs(impl_block: &ItemImpl) -> ItemImpl {
+    let mut block = impl_block.clone();
+    for item in &mut block.items {
+        if let syn::ImplItem::Fn(method) = item {
+            method
+                .attrs
+                .retain(|attr| {
+                    !attr.path().is_ident("route")
+                        && !attr.path().is_ident("response")
+                        && !attr.path().is_ident("webhook")
+                });
+            for input in &mut method.sig.inputs {
+                if let syn::FnArg::Typed(pat_type) = input {
+                    pat_type.attrs.retain(|attr| !attr.path().is_ident("param"));
+                }
+            }
+        }
+    }
+    block
+}
+
+fn generate_client_method(
+    prefix: &str,
+    rpc_style: bool,
+    envelope: bool,
+    method: &MethodInfo,
+    overrides: &RouteOverride,
+) -> syn::Result<TokenStream2> {
+    let method_name = &method.name;
+    let method_name_str = method.name_str();
+
+    let http_method = match overrides.method {
+        Some(ref m) => HttpMethod::parse(m).ok_or_else(|| {
+            syn::Error::new(
+                overrides.method_span.unwrap_or_else(|| method_name.span()),
+                format!(
+                    "unknown HTTP method `{m}`\n\
+                     \n\
+                     Supported methods: GET, POST, PUT, PATCH, DELETE"
+                ),
+            )
+        })?,
+        None => infer_http_method(&method_name_str),
+    };
+    let path = match overrides.path {
+        Some(ref p) => p.clone(),
+        None => infer_path(
+            &method_name_str,
+            &http_method,
+            &method.params,
+            rpc_style,
+            overrides.resource.as_deref(),
+        ),
+    };
+    let full_path = format!("{}{}", prefix, path);
+
+    // Parameter placement must match `generate_param_handling` in http.rs, which
+    // decides the default body/query split from the inferred (not overridden) verb.
+    let default_has_body = matches!(
+        infer_http_method(&method_name_str),
+        HttpMethod::Post | HttpMethod::Put | HttpMethod::Patch
+    );
+
+    let mut fn_params = Vec::new();
+    let mut path_params = Vec::new();
+    let mut query_stmts = Vec::new();
+    let mut body_stmts = Vec::new();
+    let mut header_stmts = Vec::new();
+    let mut raw_body = None;
+
+    for param in &method.params {
+        if is_framework_param(param, &method.params) {
+            continue;
+        }
+        let name = &param.name;
+        // `#[param(with)]` values are sent as the text the server's `parse` reads.
+        let wire_ty: syn::Type = match (&param.with, param.is_optional) {
+            (Some(_), true) => syn::parse_quote! { ::std::option::Option<::std::string::String> },
+            (Some(_), false) => syn::parse_quote! { ::std::string::String },
+            (None, _) => param.ty.clone(),
+        };
+        let ty = &wire_ty;
+        fn_params.push(quote! { #name: #ty });
+        // A `RawJson` is sent verbatim as the whole body.
+        if is_raw_json(ty) {
+            raw_body = Some(name);
+            continue;
+        }
+
+        let wire_name = param.wire_name.clone().unwrap_or_else(|| param.name_str());
+        let location = match param.location {
+            Some(ref location) => location.clone(),
+            None if param.is_id => ParamLocation::Path,
+            None if default_has_body => ParamLocation::Body,
+            None => ParamLocation::Query,
+        };
+
+        // i128/u128/Decimal go over the wire as strings.
+        let body_value = server_less_rpc::decimal_wire_value(Some(ty), quote! { &#name });
+        let optional_body_value = server_less_rpc::decimal_wire_value(
+            server_less_parse::unwrap_option_type(ty),
+            quote! { __value },
+        );
+        match location {
+            ParamLocation::Path => path_params.push(param),
+            ParamLocation::Query if param.is_optional => query_stmts.push(quote! {
+                if let ::std::option::Option::Some(__value) = &#name {
+                    __request = __request.query(&[(#wire_name, __value.to_string())]);
+                }
+            }),
+            ParamLocation::Query => query_stmts.push(quote! {
+                __request = __request.query(&[(#wire_name, #name.to_string())]);
+            }),
+            ParamLocation::Body if param.is_optional => body_stmts.push(quote! {
+                if let ::std::option::Option::Some(__value) = &#name {
+                    __body.insert(
+                        #wire_name.to_string(),
+                        ::server_less::serde_json::to_value(#optional_body_value)
+                            .map_err(::server_less::ClientError::Encode)?,
+                    );
+                }
+            }),
+            ParamLocation::Body => body_stmts.push(quote! {
+                __body.insert(
+                    #wire_name.to_string(),
+                    ::server_less::serde_json::to_value(#body_value)
+                        .map_err(::server_less::ClientError::Encode)?,
+                );
+            }),
+            ParamLocation::Header if param.is_optional => header_stmts.push(quote! {
+                if let ::std::option::Option::Some(__value) = &#name {
+                    __request = __request.header(#wire_name, __value.to_string());
+                }
+            }),
+            ParamLocation::Header => header_stmts.push(quote! {
+                __request = __request.header(#wire_name, #name.to_string());
+            }),
+        }
+    }
+
+    // Substitute path parameters into `{...}` segments positionally, the same
+    // order axum's tuple `Path` extractor binds them in.
+    path_params.sort_by_key(|p| p.prefix_position.unwrap_or(usize::MAX));
+    let mut path_params = path_params.into_iter();
+    let mut segment_exprs = Vec::new();
+    for segment in full_path.split('/').filter(|s| !s.is_empty()) {
+        if segment.starts_with('{') && segment.ends_with('}') {
+            let param = path_params.next().ok_or_else(|| {
+                syn::Error::new_spanned(
+                    &method.method.sig,
+                    format!(
+                        "#[client] cannot fill path segment `{segment}` of `{full_path}`: \
+                         no parameter is bound to the path\n\
+                         \n\
+                         Hint: name the parameter `id` / `*_id`, or mark it with #[param(path)]"
+                    ),
+                )
+            })?;
+            let name = &param.name;
+            segment_exprs.push(quote! { &#name.to_string() });
+        } else {
+            segment_exprs.push(quote! { #segment });
+        }
+    }
+
+    let verb = format_ident!("{}", http_method.as_str());
+    let body_tokens = if let Some(name) = raw_body {
+        quote! { __request = __request.json(&#name); }
+    } else if body_stmts.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            let mut __body = ::server_less::serde_json::Map::new();
+            #(#body_stmts)*
+            __request = __request.json(&__body);
+        }
+    };
+
+    let ret = &method.return_info;
+    // With `#[response(none = "null")]` the server sends `null` rather than 404.
+    let none_as_null = ResponseOverride::parse_from_attrs(&method.method.attrs)?.none_as_null;
+    let value_ty = if ret.is_option {
+        &ret.some_type
+    } else if ret.is_result {
+        &ret.ok_type
+    } else {
+        &ret.ty
+    };
+    let (output_ty, read) = match value_ty
+        .as_ref()
+        .and_then(server_less_rpc::with_headers_inner)
+    {
+        // Read the body as `T`, then wrap it with the response's headers.
+        Some(inner) => {
+            let unwrapped = if ret.is_option {
+                server_less_parse::parse_return_type(&syn::parse_quote! { -> Option<#inner> })
+            } else {
+                server_less_parse::parse_return_type(&syn::parse_quote! { -> #inner })
+            };
+            let (inner_ty, inner_read) = response_reader(&unwrapped, envelope, none_as_null);
+            let wrap = quote! {
+                |value| ::server_less::WithHeaders { value, headers: __headers }
+            };
+            if ret.is_option {
+                (
+                    quote! { ::std::option::Option<::server_less::WithHeaders<#inner>> },
+                    quote! {{
+                        let __headers = __response.headers().clone();
+                        let __value: ::std::result::Result<#inner_ty, ::server_less::ClientError> =
+                            #inner_read;
+                        __value.map(|__v| __v.map(#wrap))
+                    }},
+                )
+            } else {
+                (
+                    quote! { ::server_less::WithHeaders<#inner_ty> },
+                    quote! {{
+                        let __headers = __response.headers().clone();
+                        let __value: ::std::result::Result<#inner_ty, ::server_less::ClientError> =
+                            #inner_read;
+                        __value.map(#wrap)
+                    }},
+                )
+            }
+        }
+        None => response_reader(ret, envelope, none_as_null),
+    };
+
+    let docs = method
+        .docs
+        .clone()
+        .unwrap_or_else(|| format!("Call `{method_name_str}` on the remote service."));
+    let route_doc = format!("`{} {}`", http_method.as_str(), full_path);
+    let cfg_attrs = &method.cfg_attrs;
+
+    Ok(quote! {
+        #(#cfg_attrs)*
+        #[doc = #docs]
+        #[doc = ""]
+        #[doc = #route_doc]
+        #[allow(unused_mut)]
+        pub async fn #method_name(
+            &self,
+            #(#fn_params),*
+        ) -> ::std::result::Result<#output_ty, ::server_less::ClientError> {
+            let __url = ::server_less::client_url(&self.base_url, &[#(#segment_exprs),*])?;
+            let mut __request = self
+                .client
+                .request(::server_less::reqwest::Method::#verb, __url);
+            #(#query_stmts)*
+            #(#header_stmts)*
+            #body_tokens
+            let __response = __request.send().await?;
+            #read
+        }
+    })
+}
+
+/// The output type of a client method and the expression reading it from
+/// `__response`.
+fn response_reader(
+    ret: &ReturnInfo,
+    envelope: bool,
+    none_as_null: bool,
+) -> (TokenStream2, TokenStream2) {
+    let (read_json, read_optional_json) = if envelope {
+        (
+            quote! { client_read_data_json },
+            quote! { client_read_optional_data_json },
+        )
+    } else {
+        (
+            quote! { client_read_json },
+            quote! { client_read_optional_json },
+        )
+    };
+    // i128/u128/Decimal values arrive as strings; decode them through `DecimalString`.
+    let decimal = |ty: &Option<syn::Type>| {
+        ty.clone().filter(|ty| {
+            server_less_parse::unwrap_option_type(ty).is_none()
+                && server_less_rpc::is_decimal_type(ty)
+        })
+    };
+    if ret.is_unit {
+        (
+            quote! { () },
+            quote! { ::server_less::client_read_unit(__response).await },
+        )
+    } else if server_less_rpc::returns_bytes(ret) && ret.is_option {
+        // Byte buffers arrive as the raw body; `Bytes` is built from the `Vec<u8>`.
+        let some = &ret.some_type;
+        (
+            quote! { ::std::option::Option<#some> },
+            quote! {
+                ::server_less::client_read_optional_bytes(__response)
+                    .await
+                    .map(|__v| __v.map(::std::convert::Into::into))
+            },
+        )
+    } else if server_less_rpc::returns_bytes(ret) {
+        let value_ty = if ret.is_result { &ret.ok_type } else { &ret.ty };
+        (
+            quote! { #value_ty },
+            quote! {
+                ::server_less::client_read_bytes(__response)
+                    .await
+                    .map(::std::convert::Into::into)
+            },
+        )
+    } else if ret.is_option {
+        let some = &ret.some_type;
+        let read = match (decimal(some), none_as_null) {
+            (Some(d), true) => quote! {
+                ::server_less::#read_json::<::std::option::Option<::server_less::DecimalString<#d>>>(__response)
+                    .await
+                    .map(|__v| __v.map(|__d| __d.0))
+            },
+            (Some(d), false) => quote! {
+                ::server_less::#read_optional_json::<::server_less::DecimalString<#d>>(__response)
+                    .await
+                    .map(|__v| __v.map(|__d| __d.0))
+            },
+            (None, true) => quote! { ::server_less::#read_json(__response).await },
+            (None, false) => quote! { ::server_less::#read_optional_json(__response).await },
+        };
+        (quote! { ::std::option::Option<#some> }, read)
+    } else {
+        let value_ty = if ret.is_result { &ret.ok_type } else { &ret.ty };
+        let read = match decimal(value_ty) {
+            Some(d) => quote! {
+                ::server_less::#read_json::<::server_less::DecimalString<#d>>(__response)
+                    .await
+                    .map(|__d| __d.0)
+            },
+            None => quote! { ::server_less::#read_json(__response).await },
+        };
+        (quote! { #value_ty }, read)
+    }
+}
@@ -15,11 +15,20 @@
 //! Creates Protocol Buffers schemas for Connect:
 //! - Methods → RPC service definitions
 //! - Uses proto3 syntax
+//! - `()` returns use `google.protobuf.Empty`
 //! - Compatible with buf.build tooling
 //!
+//! # GET Requests
+//!
+//! Query-like methods (`get_*`, `list_*`, `find_*`, ... — the same names `#[graphql]`
+//! treats as queries) are marked `option idempotency_level = NO_SIDE_EFFECTS;`, which
+//! lets Connect clients call them with HTTP GET and cache the responses.
+//!
 //! # Generated Methods
 //!
 //! - `connect_schema() -> &'static str` - Generated .proto schema for Connect
+//! - `connect_paths() -> Vec<&'static str>` - Endpoint path of every method
+//! - `connect_get_paths() -> Vec<&'static str>` - Paths of the GET-eligible methods
 //!
 //! # Example
 //!
@@ -95,7 +104,6 @@ impl Parse for ConnectArgs {
 }
 
 pub(crate) fn expand_connect(args: ConnectArgs, mut impl_block: ItemImpl) -> syn::Result<TokenStream2> {
-    crate::reject_generic_impl(&impl_block)?;
     let app_meta = extract_app_meta(&mut impl_block.attrs);
     let struct_name = get_impl_name(&impl_block)?;
     let (impl_generics, _ty_generics, where_clause) = impl_block.generics.split_for_impl();
@@ -121,11 +129,17 @@ pub(crate) fn expand_connect(args: ConnectArgs, mut impl_block: ItemImpl) -> syn
 
     let proto_messages: Vec<String> = methods.iter().flat_map(generate_proto_messages).collect();
 
+    let imports = if methods.iter().any(|m| m.return_info.is_unit) {
+        "\nimport \"google/protobuf/empty.proto\";\n"
+    } else {
+        ""
+    };
+
     let proto_schema = format!(
         r#"syntax = "proto3";
 
 package {package};
-
+{imports}
 // Connect service definition
 // Compatible with connect-go, connect-es, connect-swift, etc.
 service {service_name} {{
@@ -141,12 +155,15 @@ service {service_name} {{
     );
 
     // Generate Connect-specific paths
-    let connect_paths: Vec<String> = methods
+    let connect_path = |m: &MethodInfo| {
+        let method_name = m.name_str().to_upper_camel_case();
+        format!("/{}.{}/{}", package, service_name, method_name)
+    };
+    let connect_paths: Vec<String> = methods.iter().map(connect_path).collect();
+    let connect_get_paths: Vec<String> = methods
         .iter()
-        .map(|m| {
-            let method_name = m.name_str().to_upper_camel_case();
-            format!("/{}.{}/{}", package, service_name, method_name)
-        })
+        .filter(|m| crate::is_query_method(&m.name_str()))
+        .map(connect_path)
         .collect();
 
     let maybe_impl = if crate::is_protocol_impl_emitter(&impl_block, "connect") {
@@ -169,6 +186,14 @@ service {service_name} {{
                 vec![#(#connect_paths),*]
             }
 
+            /// Get the Connect endpoint paths that also accept GET requests.
+            ///
+            /// These are the side-effect-free methods, marked
+            /// `idempotency_level = NO_SIDE_EFFECTS` in the schema.
+            pub fn connect_get_paths() -> Vec<&'static str> {
+                vec![#(#connect_get_paths),*]
+            }
+
             /// Write the Connect schema to a file.
             pub fn write_connect(path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
                 std::fs::write(path, Self::connect_schema())
@@ -181,7 +206,12 @@ service {service_name} {{
 fn generate_proto_method(method: &MethodInfo) -> String {
     let method_name = method.name_str().to_upper_camel_case();
     let request_name = format!("{}Request", method_name);
-    let response_name = format!("{}Response", method_name);
+    let response_name = if method.return_info.is_unit {
+        // Nothing to return: use the well-known empty message
+        "google.protobuf.Empty".to_string()
+    } else {
+        format!("{}Response", method_name)
+    };
 
     let doc = method
         .docs
@@ -189,10 +219,18 @@ fn generate_proto_method(method: &MethodInfo) -> String {
         .map(|d| format!("  // {}\n", d))
         .unwrap_or_default();
 
-    format!(
-        "{}  rpc {}({}) returns ({});",
-        doc, method_name, request_name, response_name
-    )
+    if crate::is_query_method(&method.name_str()) {
+        // Side-effect free: Connect clients may issue (and cache) GET requests
+        format!(
+            "{}  rpc {}({}) returns ({}) {{\n    option idempotency_level = NO_SIDE_EFFECTS;\n  }}",
+            doc, method_name, request_name, response_name
+        )
+    } else {
+        format!(
+            "{}  rpc {}({}) returns ({});",
+            doc, method_name, request_name, response_name
+        )
+    }
 }
 
 /// Generate proto message definitions for a method
@@ -218,15 +256,15 @@ fn generate_proto_messages(method: &MethodInfo) -> Vec<String> {
 
     // Generate response message
     let ret = &method.return_info;
-    let response_msg = if ret.is_unit {
-        format!("message {} {{}}", response_name)
-    } else {
-        let proto_type = rust_type_to_proto(&ret.ty);
-        format!(
-            "message {} {{\n  {} result = 1;\n}}",
-            response_name, proto_type
-        )
-    };
+    if ret.is_unit {
+        // The rpc returns google.protobuf.Empty
+        return vec![request_msg];
+    }
+    let proto_type = rust_type_to_proto(&ret.ty);
+    let response_msg = format!(
+        "message {} {{\n  {} result = 1;\n}}",
+        response_name, proto_type
+    );
 
     vec![request_msg, response_msg]
 }
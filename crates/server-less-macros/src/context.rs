@@ -3,9 +3,13 @@
 //! This module provides helpers for detecting and injecting server_less::Context
 //! parameters across different protocol implementations (HTTP, WebSocket, CLI, etc.).
 
+#[cfg(any(feature = "http", feature = "cli"))]
 use proc_macro2::TokenStream as TokenStream2;
+#[cfg(any(feature = "http", feature = "cli"))]
 use quote::quote;
-use server_less_parse::{MethodInfo, ParamInfo};
+#[cfg(any(feature = "mcp", feature = "cli", feature = "jsonrpc", feature = "ws"))]
+use server_less_parse::MethodInfo;
+use server_less_parse::ParamInfo;
 use syn::Type;
 
 /// Check if a type is server_less::Context (fully qualified)
@@ -78,6 +82,138 @@ pub fn should_inject_context(ty: &Type, method_params: &[ParamInfo]) -> bool {
     }
 }
 
+/// Check if a type is `Inject<T>`, a shared dependency pulled from the HTTP router.
+///
+/// Matches `Inject<T>` and `server_less::Inject<T>`; like `Context`, the param is
+/// extracted by the framework and never treated as request input.
+#[cfg(any(
+    feature = "http",
+    feature = "openapi",
+    feature = "client",
+    feature = "mcp",
+    feature = "cli",
+    feature = "jsonrpc",
+    feature = "ws",
+    feature = "markdown"
+))]
+pub fn is_inject(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    let segments: Vec<_> = type_path.path.segments.iter().collect();
+    match segments.as_slice() {
+        [.., last] if last.ident != "Inject" => false,
+        [.., last] if !matches!(last.arguments, syn::PathArguments::AngleBracketed(_)) => false,
+        [_] => true,
+        [.., parent, _] => parent.ident == "server_less",
+        [] => false,
+    }
+}
+
+/// Check if a type is a `CancellationToken`, injected per request by `#[http]`.
+///
+/// Matches the bare name, `server_less::CancellationToken` and
+/// `tokio_util::sync::CancellationToken`, which all name the same re-exported
+/// type; any other path is the user's own type.
+#[cfg(any(
+    feature = "http",
+    feature = "openapi",
+    feature = "client",
+    feature = "mcp",
+    feature = "cli",
+    feature = "jsonrpc",
+    feature = "ws",
+    feature = "markdown"
+))]
+pub fn is_cancellation_token(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    if type_path.qself.is_some() {
+        return false;
+    }
+    let path = quote::ToTokens::to_token_stream(&type_path.path)
+        .to_string()
+        .replace(' ', "");
+    matches!(
+        path.trim_start_matches("::"),
+        "CancellationToken"
+            | "server_less::CancellationToken"
+            | "tokio_util::sync::CancellationToken"
+    )
+}
+
+/// Check if a type is `RawJson`, bound by `#[http]` to the whole raw request body.
+///
+/// Matches any path ending in `RawJson` without generic arguments.
+#[cfg(any(feature = "http", feature = "openapi", feature = "client"))]
+pub fn is_raw_json(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    type_path.path.segments.last().is_some_and(|last| {
+        last.ident == "RawJson" && matches!(last.arguments, syn::PathArguments::None)
+    })
+}
+
+/// Check if a parameter is supplied by the framework (`Context`, `Inject<T>`,
+/// `CancellationToken` or a `#[param(extractor)]`) rather than bound from named
+/// request values, so specs and clients skip it.
+#[cfg(any(feature = "http", feature = "openapi", feature = "client"))]
+pub fn is_framework_param(param: &ParamInfo, method_params: &[ParamInfo]) -> bool {
+    param.is_extractor
+        || should_inject_context(&param.ty, method_params)
+        || is_inject(&param.ty)
+        || is_cancellation_token(&param.ty)
+}
+
+/// Check if a parameter can only be supplied by `#[http]` (`Inject<T>`, read from the
+/// router, or a `#[param(extractor)]`), so other protocols leave it out of their
+/// arguments.
+#[cfg(any(
+    feature = "mcp",
+    feature = "cli",
+    feature = "jsonrpc",
+    feature = "ws",
+    feature = "markdown"
+))]
+pub fn is_http_only_param(param: &ParamInfo) -> bool {
+    is_inject(&param.ty) || param.is_extractor
+}
+
+/// Check if a non-HTTP protocol leaves a parameter out of its inputs: `#[http]`-only
+/// ones, and a `CancellationToken`, which it fills with [`never_cancelled`].
+#[cfg(any(
+    feature = "mcp",
+    feature = "cli",
+    feature = "jsonrpc",
+    feature = "ws",
+    feature = "markdown"
+))]
+pub fn is_supplied_param(param: &ParamInfo) -> bool {
+    is_http_only_param(param) || is_cancellation_token(&param.ty)
+}
+
+/// The `CancellationToken` argument outside `#[http]`: these protocols have no
+/// request whose end could cancel it, so the token never fires.
+#[cfg(any(feature = "mcp", feature = "cli", feature = "jsonrpc", feature = "ws"))]
+pub fn never_cancelled() -> proc_macro2::TokenStream {
+    quote::quote! { ::server_less::CancellationToken::new() }
+}
+
+/// The error a non-HTTP protocol reports when asked to call a method with an
+/// `#[http]`-only parameter, or `None` if it has none.
+#[cfg(any(feature = "mcp", feature = "cli", feature = "jsonrpc", feature = "ws"))]
+pub fn http_only_error(method: &MethodInfo) -> Option<String> {
+    let param = method.params.iter().find(|p| is_http_only_param(p))?;
+    let ty = &param.ty;
+    Some(format!(
+        "'{}' takes `{}`, which only #[http] provides",
+        method.name,
+        quote::quote!(#ty).to_string().replace(' ', "")
+    ))
+}
+
 /// Generic helper: check if a type should be treated as a special injectable param.
 ///
 /// Two-pass detection strategy (same logic used for both Context and WsSender):
@@ -85,6 +221,7 @@ pub fn should_inject_context(ty: &Type, method_params: &[ParamInfo]) -> bool {
 /// - If `has_qualified_in_impl` is false: Both bare and qualified forms are injected
 ///
 /// Pass the predicate pair from whichever type family you're detecting.
+#[cfg(feature = "ws")]
 pub fn should_inject_special_param(
     ty: &Type,
     is_qualified: fn(&Type) -> bool,
@@ -103,6 +240,7 @@ pub fn should_inject_special_param(
 /// Generic helper: scan all methods to detect if any use the qualified form of a special param.
 ///
 /// Pass the `is_qualified` predicate for the type you're scanning for.
+#[cfg(feature = "ws")]
 pub fn has_qualified_special_param(
     methods: &[MethodInfo],
     is_qualified: fn(&Type) -> bool,
@@ -161,6 +299,7 @@ pub fn partition_context_params(
 /// Returns `(extraction, call)` where:
 /// - `extraction` is the axum extractor token (e.g., `headers: HeaderMap`)
 /// - `call` is the code to create and populate the Context
+#[cfg(feature = "http")]
 pub fn generate_http_context_extraction() -> (TokenStream2, TokenStream2) {
     let extraction = quote! {
         __context_headers: ::server_less::axum::http::HeaderMap
@@ -194,6 +333,7 @@ pub fn generate_http_context_extraction() -> (TokenStream2, TokenStream2) {
 ///
 /// Returns `(extraction, call)` - though for CLI there's no extractor needed,
 /// just the call to create the Context.
+#[cfg(feature = "cli")]
 pub fn generate_cli_context_extraction() -> (TokenStream2, TokenStream2) {
     let extraction = quote! {}; // No extractor needed for CLI
 
@@ -261,8 +401,33 @@ mod tests {
             short_flag: None,
             help_text: None,
             is_positional: false,
+            min: None,
+            max: None,
+            pattern: None,
+            with: None,
+            is_extractor: false,
+            prefix_position: None,
         }];
         assert!(!should_inject_context(&bare_ctx, &method_has_qualified));
         assert!(should_inject_context(&qualified_ctx, &method_has_qualified));
     }
+
+    #[test]
+    fn test_is_cancellation_token() {
+        for ty in [
+            parse_quote! { CancellationToken },
+            parse_quote! { server_less::CancellationToken },
+            parse_quote! { ::tokio_util::sync::CancellationToken },
+        ] {
+            assert!(is_cancellation_token(&ty), "{}", quote::quote!(#ty));
+        }
+
+        for ty in [
+            parse_quote! { jobs::CancellationToken },
+            parse_quote! { tokio_util::CancellationToken },
+            parse_quote! { CancellationToken<Job> },
+        ] {
+            assert!(!is_cancellation_token(&ty), "{}", quote::quote!(#ty));
+        }
+    }
 }
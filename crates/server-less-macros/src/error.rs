@@ -3,10 +3,13 @@
 //! ```ignore
 //! #[derive(ServerlessError)]
 //! enum MyError {
-//!     #[error(code = NotFound, message = "User not found")]
+//!     #[error(code = NotFound, message = "User not found", app_code = "USER_NOT_FOUND")]
 //!     UserNotFound,
 //!     #[error(code = InvalidInput)]
 //!     ValidationFailed(String),
+//!     // `{0}` / `{field}` interpolate the variant's data
+//!     #[error(code = NotFound, message = "order {id} not found")]
+//!     OrderNotFound { id: u64 },
 //!     // Code inferred from variant name
 //!     Unauthorized,
 //! }
@@ -31,10 +34,12 @@ use syn::{Data, DeriveInput, Fields, Ident, Token, parse::Parse, punctuated::Pun
 struct ErrorVariantArgs {
     /// Error code (e.g., NotFound, InvalidInput, or numeric 404)
     code: Option<ErrorCodeSpec>,
-    /// Custom message
-    message: Option<String>,
+    /// Custom message; may interpolate fields with `{0}` or `{field}`
+    message: Option<syn::LitStr>,
     /// JSON-RPC numeric error code override (e.g. -32602)
     jsonrpc_code: Option<i32>,
+    /// Application-specific error code (e.g. "USER_NOT_FOUND")
+    app_code: Option<String>,
 }
 
 enum ErrorCodeSpec {
@@ -86,7 +91,7 @@ impl Parse for ErrorVariantArgs {
                         ..
                     }) = &nv.value
                     {
-                        args.message = Some(s.value());
+                        args.message = Some(s.clone());
                     } else {
                         return Err(syn::Error::new_spanned(
                             &nv.value,
@@ -133,10 +138,26 @@ impl Parse for ErrorVariantArgs {
                     };
                     args.jsonrpc_code = Some(parsed_code);
                 }
+                syn::Meta::NameValue(nv) if nv.path.is_ident("app_code") => {
+                    if let syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(s),
+                        ..
+                    }) = &nv.value
+                    {
+                        args.app_code = Some(s.value());
+                    } else {
+                        return Err(syn::Error::new_spanned(
+                            &nv.value,
+                            "app_code must be a string literal\n\
+                             \n\
+                             Example: #[error(code = NotFound, app_code = \"USER_NOT_FOUND\")]",
+                        ));
+                    }
+                }
                 other => {
                     return Err(syn::Error::new_spanned(
                         other,
-                        "unknown attribute. Valid: code, message, jsonrpc_code",
+                        "unknown attribute. Valid: code, message, jsonrpc_code, app_code",
                     ));
                 }
             }
@@ -170,8 +191,10 @@ pub fn expand_serverless_error(input: DeriveInput) -> syn::Result<TokenStream> {
 
     let mut error_code_arms = Vec::new();
     let mut jsonrpc_code_arms = Vec::new();
+    let mut app_code_arms = Vec::new();
     let mut message_arms = Vec::new();
     let mut display_arms = Vec::new();
+    let mut variant_infos = Vec::new();
 
     for variant in &data_enum.variants {
         let variant_name = &variant.ident;
@@ -284,13 +307,19 @@ pub fn expand_serverless_error(input: DeriveInput) -> syn::Result<TokenStream> {
             }
         };
 
-        // Determine message
-        let message_expr = if let Some(msg) = args.message {
-            quote! { #msg.to_string() }
-        } else {
-            // Use variant name, converting CamelCase to "Camel case"
-            let readable = camel_to_sentence(&variant_name_str);
-            quote! { #readable.to_string() }
+        // Determine message: custom, or variant name converted CamelCase to "Camel case"
+        let message_str = args
+            .message
+            .as_ref()
+            .map(|lit| unescape_braces(&lit.value()))
+            .unwrap_or_else(|| camel_to_sentence(&variant_name_str));
+        let template = match &args.message {
+            Some(lit) => expand_message_template(lit, &variant.fields)?,
+            None => None,
+        };
+        let message_expr = match &template {
+            Some(format_args) => quote! { ::std::format!(#format_args) },
+            None => quote! { #message_str.to_string() },
         };
 
         // Generate match arms based on variant fields
@@ -305,8 +334,9 @@ pub fn expand_serverless_error(input: DeriveInput) -> syn::Result<TokenStream> {
                     .collect();
                 let pattern = quote! { Self::#variant_name(#(#field_names),*) };
 
-                // If single String field, include it in display
-                if fields.unnamed.len() == 1 {
+                // If single String field, include it in display (unless the message
+                // template already places it)
+                if fields.unnamed.len() == 1 && template.is_none() {
                     (
                         pattern.clone(),
                         quote! { write!(f, "{}: {}", #variant_name_str, _0) },
@@ -340,6 +370,24 @@ pub fn expand_serverless_error(input: DeriveInput) -> syn::Result<TokenStream> {
             #pattern => #jsonrpc_code_expr
         });
 
+        let app_code_expr = match &args.app_code {
+            Some(app_code) => quote! { ::std::option::Option::Some(#app_code) },
+            None => quote! { ::std::option::Option::None },
+        };
+        app_code_arms.push(quote! {
+            #pattern => #app_code_expr
+        });
+
+        variant_infos.push(quote! {
+            ::server_less::ErrorVariantInfo {
+                name: #variant_name_str,
+                code: #error_code,
+                jsonrpc_code: #jsonrpc_code_expr,
+                app_code: #app_code_expr,
+                message: #message_str,
+            }
+        });
+
         message_arms.push(quote! {
             #pattern => #message_expr
         });
@@ -368,6 +416,16 @@ pub fn expand_serverless_error(input: DeriveInput) -> syn::Result<TokenStream> {
                     #(#message_arms,)*
                 }
             }
+
+            fn app_code(&self) -> ::std::option::Option<&'static str> {
+                match self {
+                    #(#app_code_arms,)*
+                }
+            }
+
+            fn error_variants() -> Vec<::server_less::ErrorVariantInfo> {
+                vec![#(#variant_infos),*]
+            }
         }
 
         impl ::std::fmt::Display for #name {
@@ -382,6 +440,120 @@ pub fn expand_serverless_error(input: DeriveInput) -> syn::Result<TokenStream> {
     })
 }
 
+/// Turn a message with `{0}` / `{field}` placeholders into `format!` arguments.
+///
+/// Placeholders refer to the bindings used in the generated match patterns
+/// (`_0`, `_1`, ... for tuple variants, field names for struct variants) and may
+/// carry a format spec (`{0:?}`, `{amount:.2}`). Returns `None` when the message
+/// has no placeholders or escaped braces, so it stays a plain string.
+fn expand_message_template(lit: &syn::LitStr, fields: &Fields) -> syn::Result<Option<TokenStream>> {
+    let template = lit.value();
+    let mut format_str = String::new();
+    let mut args = Vec::new();
+    let mut needs_format = false;
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                needs_format = true;
+                format_str.push_str("{{");
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                needs_format = true;
+                format_str.push_str("}}");
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => placeholder.push(c),
+                        None => {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                "unclosed `{` in error message\n\
+                                 \n\
+                                 Hint: write `{{` for a literal brace",
+                            ));
+                        }
+                    }
+                }
+                let (name, spec) = match placeholder.split_once(':') {
+                    Some((name, spec)) => (name.trim(), format!(":{spec}")),
+                    None => (placeholder.trim(), String::new()),
+                };
+                args.push(placeholder_binding(lit, name, fields)?);
+                needs_format = true;
+                format_str.push('{');
+                format_str.push_str(&spec);
+                format_str.push('}');
+            }
+            '}' => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "unmatched `}` in error message\n\
+                     \n\
+                     Hint: write `}}` for a literal brace",
+                ));
+            }
+            c => format_str.push(c),
+        }
+    }
+
+    if !needs_format {
+        return Ok(None);
+    }
+    Ok(Some(quote! { #format_str, #(#args),* }))
+}
+
+/// The message template as documentation text: `{{`/`}}` become literal braces,
+/// placeholders such as `{0}` are kept as written.
+fn unescape_braces(template: &str) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        if matches!(c, '{' | '}') && chars.peek() == Some(&c) {
+            chars.next();
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Resolve a message placeholder to the pattern binding for that field.
+fn placeholder_binding(lit: &syn::LitStr, name: &str, fields: &Fields) -> syn::Result<Ident> {
+    let available = match fields {
+        Fields::Unit => Vec::new(),
+        Fields::Unnamed(fields) => (0..fields.unnamed.len()).map(|i| i.to_string()).collect(),
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .filter_map(|f| f.ident.as_ref().map(|i| i.to_string()))
+            .collect(),
+    };
+
+    if available.iter().any(|field| field == name) {
+        return Ok(match fields {
+            Fields::Unnamed(_) => quote::format_ident!("_{}", name),
+            _ => quote::format_ident!("{}", name),
+        });
+    }
+
+    let hint = if available.is_empty() {
+        "this variant has no fields to interpolate".to_string()
+    } else {
+        let listed: Vec<String> = available.iter().map(|f| format!("{{{f}}}")).collect();
+        format!("available placeholders: {}", listed.join(", "))
+    };
+    Err(syn::Error::new_spanned(
+        lit,
+        format!("unknown placeholder `{{{name}}}` in error message; {hint}"),
+    ))
+}
+
 /// Convert CamelCase to "Camel case" sentence
 fn camel_to_sentence(s: &str) -> String {
     let mut result = String::new();
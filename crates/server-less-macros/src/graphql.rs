@@ -18,12 +18,24 @@
 //!
 //! Rust types are mapped to GraphQL types:
 //! - `String` → String
-//! - `i32`, `i64` → Int
+//! - `i32`, `i64`, `u32`, `u64` → Int (values keep their full 64-bit range)
 //! - `f32`, `f64` → Float
 //! - `bool` → Boolean
 //! - `Vec<T>` → [T]
 //! - `Option<T>` → T (nullable)
 //!
+//! # Relay Connections
+//!
+//! A method returning `server_less::Connection<T>` is exposed as a `{T}Connection`
+//! object (`edges { cursor node }`, `pageInfo`), and a `ConnectionArgs` parameter
+//! becomes the `first`/`after`/`last`/`before` arguments:
+//!
+//! ```ignore
+//! fn list_users(&self, page: ConnectionArgs) -> Connection<User> {
+//!     Connection::paginate(self.users(), &page)
+//! }
+//! ```
+//!
 //! # Custom Scalars
 //!
 //! async-graphql provides built-in support for common custom scalars:
@@ -45,10 +57,43 @@
 //! }
 //! ```
 //!
+//! Domain types can be exposed as named scalars with `#[graphql(scalars(...))]`.
+//! Values cross the boundary through their `Serialize`/`Deserialize` impls:
+//!
+//! ```ignore
+//! #[graphql(scalars(Money, Email))]
+//! impl BillingService {
+//!     fn get_balance(&self, owner: Email) -> Money { /* ... */ }
+//! }
+//! ```
+//!
+//! # Subscriptions
+//!
+//! A method returning `impl Stream<Item = T>` becomes a field of the
+//! `{Type}Subscription` root instead of a query or mutation. `graphql_router()` then
+//! also serves `/graphql/ws`, speaking `graphql-transport-ws` (or the legacy
+//! `graphql-ws`, picked from `Sec-WebSocket-Protocol`) for Apollo and urql clients.
+//! The stream must not borrow the service:
+//!
+//! ```ignore
+//! fn ticks(&self, count: i32) -> impl Stream<Item = i32> + use<> {
+//!     futures::stream::iter(1..=count)
+//! }
+//! ```
+//!
+//! Subscription fields of mounted child services are not merged.
+//!
+//! # Query Limits
+//!
+//! `#[graphql(max_depth = 10, max_complexity = 1000)]` rejects queries nested
+//! deeper than `max_depth` or scoring above `max_complexity` (one point per
+//! field) before they execute.
+//!
 //! # Generated Methods
 //!
 //! - `graphql_schema(self) -> async_graphql::dynamic::Schema` - Dynamic schema
 //! - `graphql_router(self) -> axum::Router` - HTTP + Playground server
+//! - `graphql_schema_arc` / `graphql_router_arc(self: Arc<Self>)` - The same, from an `Arc`
 //! - `graphql_sdl(self) -> String` - Schema Definition Language
 //!
 //! # Example
@@ -86,7 +131,7 @@ use quote::quote;
 use server_less_parse::{MethodInfo, extract_methods, get_impl_name, partition_methods};
 use syn::{ItemImpl, Token, parse::Parse};
 
-use crate::server_attrs::{has_server_hidden, has_server_skip, validate_server_attrs};
+use crate::server_attrs::{has_server_hidden, skips_operation, validate_server_attrs};
 
 /// Arguments for the #[graphql] attribute
 #[derive(Default)]
@@ -96,6 +141,12 @@ pub(crate) struct GraphqlArgs {
     pub enums: Vec<syn::Ident>,
     /// Input types to register with the schema (from #[graphql_input])
     pub inputs: Vec<syn::Ident>,
+    /// User-defined types exposed as named scalars, serialized through serde
+    pub scalars: Vec<syn::Ident>,
+    /// Deepest selection nesting a query may use (`max_depth = 10`)
+    pub max_depth: Option<usize>,
+    /// Highest complexity score a query may have (`max_complexity = 1000`)
+    pub max_complexity: Option<usize>,
 }
 
 impl Parse for GraphqlArgs {
@@ -125,8 +176,38 @@ impl Parse for GraphqlArgs {
                     let input_types = content.parse_terminated(syn::Ident::parse, Token![,])?;
                     args.inputs = input_types.into_iter().collect();
                 }
+                "scalars" => {
+                    // Parse scalars(Type1, Type2, ...)
+                    let content;
+                    syn::parenthesized!(content in input);
+                    let scalar_types = content.parse_terminated(syn::Ident::parse, Token![,])?;
+                    args.scalars = scalar_types.into_iter().collect();
+                }
+                "max_depth" | "max_complexity" => {
+                    input.parse::<Token![=]>()?;
+                    let lit: syn::LitInt = input.parse()?;
+                    let limit: usize = lit.base10_parse()?;
+                    if limit == 0 {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            format!("`{ident}` must be at least 1"),
+                        ));
+                    }
+                    if ident == "max_depth" {
+                        args.max_depth = Some(limit);
+                    } else {
+                        args.max_complexity = Some(limit);
+                    }
+                }
                 other => {
-                    const VALID: &[&str] = &["name", "enums", "inputs"];
+                    const VALID: &[&str] = &[
+                        "name",
+                        "enums",
+                        "inputs",
+                        "scalars",
+                        "max_depth",
+                        "max_complexity",
+                    ];
                     let suggestion = crate::did_you_mean(other, VALID)
                         .map(|s| format!(" — did you mean `{s}`?"))
                         .unwrap_or_default();
@@ -135,12 +216,14 @@ impl Parse for GraphqlArgs {
                         format!(
                             "unknown argument `{other}`{suggestion}\n\
                              \n\
-                             Valid arguments: name, enums, inputs\n\
+                             Valid arguments: name, enums, inputs, scalars, max_depth, max_complexity\n\
                              \n\
                              Examples:\n\
                              - #[graphql(name = \"UserAPI\")]\n\
                              - #[graphql(enums(Status, Priority))]\n\
                              - #[graphql(inputs(CreateUserInput))]\n\
+                             - #[graphql(scalars(Money, Email))]\n\
+                             - #[graphql(max_depth = 10, max_complexity = 1000)]\n\
                              - #[graphql(name = \"MyAPI\", enums(Status), inputs(CreateUserInput))]"
                         ),
                     ));
@@ -157,7 +240,6 @@ impl Parse for GraphqlArgs {
 }
 
 pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn::Result<TokenStream2> {
-    crate::reject_generic_impl(&impl_block)?;
     let app_meta = extract_app_meta(&mut impl_block.attrs);
     // args.name takes precedence over app_meta.name for GraphQL schema naming.
     // TODO: wire effective_name into the GraphQL schema name (used where struct name is currently hardcoded).
@@ -172,7 +254,7 @@ pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn
         validate_server_attrs(m)?;
     }
     // Partition into leaf methods (skip-filtered) and mount points (&T return types).
-    let partitioned = partition_methods(&methods, has_server_skip);
+    let partitioned = partition_methods(&methods, skips_operation);
 
     // Hidden methods are excluded from schema/SDL (not visible in type) but remain callable.
     let visible_leaf: Vec<_> = partitioned
@@ -184,19 +266,60 @@ pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn
 
     let leaf_methods = &visible_leaf;
 
-    let (query_methods, mutation_methods): (Vec<_>, Vec<_>) = leaf_methods
+    // Stream-returning methods become subscription fields; the rest are split into
+    // queries and mutations by name.
+    let (subscription_methods, request_methods): (Vec<_>, Vec<_>) = leaf_methods
         .iter()
         .copied()
-        .partition(|m| is_query_method(&m.name_str()));
+        .partition(|m| m.return_info.is_stream);
+    let (query_methods, mutation_methods): (Vec<_>, Vec<_>) = request_methods
+        .into_iter()
+        .partition(|m| crate::is_query_method(&m.name_str()));
 
-    let query_fields = generate_field_registrations(&query_methods);
-    let mutation_fields = generate_field_registrations(&mutation_methods);
+    let query_fields = generate_field_registrations(&query_methods, &args.scalars);
+    let mutation_fields = generate_field_registrations(&mutation_methods, &args.scalars);
 
     let query_resolvers = generate_resolver_dispatch(&struct_name, &query_methods);
     let mutation_resolvers = generate_resolver_dispatch(&struct_name, &mutation_methods);
 
     let query_type_name = format!("{}Query", struct_name);
     let mutation_type_name = format!("{}Mutation", struct_name);
+    let subscription_type_name = format!("{}Subscription", struct_name);
+
+    let has_subscriptions = !subscription_methods.is_empty();
+    let subscription_fields = subscription_methods
+        .iter()
+        .map(|m| {
+            let field_code = generate_subscription_registration(m, &args.scalars)?;
+            let cfg_attrs = &m.cfg_attrs;
+            Ok(quote! {
+                #(#cfg_attrs)*
+                { #field_code }
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    // The subscription root, its registration and the `Schema::build` argument naming it.
+    let (subscription_object, subscription_registration, subscription_root) = if has_subscriptions {
+        (
+            quote! {
+                let subscription = {
+                    let service = service.clone();
+                    let mut obj = Subscription::new(#subscription_type_name);
+                    #(
+                        {
+                            let service = service.clone();
+                            #subscription_fields
+                        }
+                    )*
+                    obj
+                };
+            },
+            quote! { .register(subscription) },
+            quote! { Some(#subscription_type_name) },
+        )
+    } else {
+        (quote! {}, quote! {}, quote! { None })
+    };
 
     // Generate mount composition calls — for each static mount `fn child(&self) -> &ChildService`,
     // inline the child's query and mutation fields into this service's schema objects.
@@ -242,7 +365,13 @@ pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn
 
     // Collect custom scalars used across all non-skipped methods (mounts manage their own scalars).
     let custom_scalars = collect_custom_scalars(leaf_methods);
+    // User-declared scalars from #[graphql(scalars(...))] are registered whether or not
+    // a method uses them, like enums and inputs.
+    let user_scalars = args.scalars.iter().map(|ident| ident.to_string());
     let scalar_registrations: Vec<_> = custom_scalars
+        .into_iter()
+        .chain(user_scalars)
+        .collect::<std::collections::BTreeSet<_>>()
         .iter()
         .map(|name| {
             quote! {
@@ -251,6 +380,9 @@ pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn
         })
         .collect();
 
+    // Object types behind Relay `Connection<T>` return types.
+    let connection_registrations = generate_connection_registrations(leaf_methods);
+
     // Generate enum type registrations from #[graphql(enums(...))]
     let enum_registrations: Vec<_> = args
         .enums
@@ -273,6 +405,13 @@ pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn
         })
         .collect();
 
+    // Query limits enforced by async-graphql before execution.
+    let depth_limit = args.max_depth.map(|depth| quote! { .limit_depth(#depth) });
+    let complexity_limit = args
+        .max_complexity
+        .map(|complexity| quote! { .limit_complexity(#complexity) });
+    let schema_limits = quote! { #depth_limit #complexity_limit };
+
     // Build the schema_build expression, based on what combination of own mutations and mounts
     // are present.
     //
@@ -302,12 +441,15 @@ pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn
             };
 
             // mutation_field_count > 0 because we have own mutations; always register.
-            Schema::build(#query_type_name, Some(#mutation_type_name), None)
+            Schema::build(#query_type_name, Some(#mutation_type_name), #subscription_root)
                 .register(query)
                 .register(mutation)
+                #subscription_registration
                 #(#scalar_registrations)*
+                #(#connection_registrations)*
                 #(#enum_registrations)*
                 #(#input_registrations)*
+                #schema_limits
                 .finish()
                 .expect("Failed to build GraphQL schema")
         }
@@ -326,12 +468,15 @@ pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn
                 obj
             };
 
-            Schema::build(#query_type_name, Some(#mutation_type_name), None)
+            Schema::build(#query_type_name, Some(#mutation_type_name), #subscription_root)
                 .register(query)
                 .register(mutation)
+                #subscription_registration
                 #(#scalar_registrations)*
+                #(#connection_registrations)*
                 #(#enum_registrations)*
                 #(#input_registrations)*
+                #schema_limits
                 .finish()
                 .expect("Failed to build GraphQL schema")
         }
@@ -348,20 +493,26 @@ pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn
             };
 
             if mutation_field_count > 0 {
-                Schema::build(#query_type_name, Some(#mutation_type_name), None)
+                Schema::build(#query_type_name, Some(#mutation_type_name), #subscription_root)
                     .register(query)
                     .register(mutation)
+                    #subscription_registration
                     #(#scalar_registrations)*
+                    #(#connection_registrations)*
                     #(#enum_registrations)*
                     #(#input_registrations)*
+                    #schema_limits
                     .finish()
                     .expect("Failed to build GraphQL schema")
             } else {
-                Schema::build(#query_type_name, None::<&str>, None)
+                Schema::build(#query_type_name, None::<&str>, #subscription_root)
                     .register(query)
+                    #subscription_registration
                     #(#scalar_registrations)*
+                    #(#connection_registrations)*
                     #(#enum_registrations)*
                     #(#input_registrations)*
+                    #schema_limits
                     .finish()
                     .expect("Failed to build GraphQL schema")
             }
@@ -369,11 +520,14 @@ pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn
     } else {
         // No mutations and no mounts — query-only schema.
         quote! {
-            Schema::build(#query_type_name, None::<&str>, None)
+            Schema::build(#query_type_name, None::<&str>, #subscription_root)
                 .register(query)
+                #subscription_registration
                 #(#scalar_registrations)*
+                #(#connection_registrations)*
                 #(#enum_registrations)*
                 #(#input_registrations)*
+                #schema_limits
                 .finish()
                 .expect("Failed to build GraphQL schema")
         }
@@ -382,11 +536,68 @@ pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn
     // Generate the field-merging helpers used by parent services that mount this service.
     // These allow a parent's `graphql_schema` to inline this service's fields into its own
     // query/mutation Objects without creating a nested schema.
-    let merge_query_helper = generate_merge_query_helper(&struct_name, &query_methods);
-    let merge_mutation_helper = generate_merge_mutation_helper(&struct_name, &mutation_methods);
+    let merge_query_helper =
+        generate_merge_query_helper(&struct_name, &query_methods, &args.scalars);
+    let merge_mutation_helper =
+        generate_merge_mutation_helper(&struct_name, &mutation_methods, &args.scalars);
+
+    // Subscriptions are served over WebSocket (graphql-transport-ws, or the legacy
+    // graphql-ws protocol, picked from `Sec-WebSocket-Protocol`) next to `/graphql`.
+    let (playground_config, subscription_route, subscription_openapi_path) = if has_subscriptions {
+        (
+            quote! {
+                ::async_graphql::http::GraphQLPlaygroundConfig::new("/graphql")
+                    .subscription_endpoint("/graphql/ws")
+            },
+            quote! {
+                .route_service(
+                    "/graphql/ws",
+                    ::async_graphql_axum::GraphQLSubscription::new(schema.clone()),
+                )
+            },
+            quote! {
+                ,
+                ::server_less::OpenApiPath {
+                    path: "/graphql/ws".to_string(),
+                    method: "get".to_string(),
+                    operation: ::server_less::OpenApiOperation {
+                        summary: Some("GraphQL subscriptions over WebSocket".to_string()),
+                        description: None,
+                        operation_id: Some("graphql_subscriptions".to_string()),
+                        tags: vec!["graphql".to_string()],
+                        deprecated: false,
+                        parameters: vec![],
+                        request_body: None,
+                        responses: {
+                            let mut r = ::server_less::serde_json::Map::new();
+                            r.insert("101".to_string(), ::server_less::serde_json::json!({
+                                "description": "Switching Protocols - WebSocket upgrade successful"
+                            }));
+                            r
+                        },
+                        extra: {
+                            let mut e = ::server_less::serde_json::Map::new();
+                            e.insert("x-websocket-protocol".to_string(), ::server_less::serde_json::json!({
+                                "subprotocols": ["graphql-transport-ws", "graphql-ws"]
+                            }));
+                            e
+                        },
+                    },
+                }
+            },
+        )
+    } else {
+        (
+            quote! { ::async_graphql::http::GraphQLPlaygroundConfig::new("/graphql") },
+            quote! {},
+            quote! {},
+        )
+    };
 
     let maybe_impl = if crate::is_protocol_impl_emitter(&impl_block, "graphql") {
-        quote! { #impl_block }
+        let mut clean_impl = impl_block.clone();
+        crate::strip_webhook_attrs(&mut clean_impl);
+        quote! { #clean_impl }
     } else {
         quote! {}
     };
@@ -394,6 +605,7 @@ pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn
     Ok(quote! {
         #maybe_impl
 
+        #[allow(deprecated)]
         impl #impl_generics #self_ty #where_clause {
             /// Convert a `serde_json::Value` into an `async_graphql::Value` recursively.
             ///
@@ -405,20 +617,9 @@ pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn
                 match json_val {
                     ::serde_json::Value::Null => ::async_graphql::Value::Null,
                     ::serde_json::Value::Bool(b) => ::async_graphql::Value::Boolean(b),
-                    ::serde_json::Value::Number(n) => {
-                        if let Some(i) = n.as_i64() {
-                            ::async_graphql::Value::Number((i as i32).into())
-                        } else if let Some(f) = n.as_f64() {
-                            match ::serde_json::to_value(f) {
-                                Ok(::serde_json::Value::Number(num)) => {
-                                    ::async_graphql::Value::Number(num.into())
-                                }
-                                _ => ::async_graphql::Value::String(f.to_string()),
-                            }
-                        } else {
-                            ::async_graphql::Value::Number(n.into())
-                        }
-                    }
+                    // async-graphql's `Number` is `serde_json::Number`, so i64/u64/f64
+                    // pass through without narrowing.
+                    ::serde_json::Value::Number(n) => ::async_graphql::Value::Number(n),
                     ::serde_json::Value::String(s) => ::async_graphql::Value::String(s),
                     ::serde_json::Value::Array(arr) => {
                         let values: Vec<_> = arr
@@ -457,14 +658,44 @@ pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn
                 }
             }
 
+            /// Resolve a field of a connection, edge or page-info object by reading
+            /// `key` from the parent's `Value::Object`.
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            fn __graphql_parent_field(
+                ctx: &::async_graphql::dynamic::ResolverContext<'_>,
+                key: &str,
+            ) -> ::async_graphql::Result<Option<::async_graphql::Value>> {
+                match ctx.parent_value.as_value() {
+                    Some(::async_graphql::Value::Object(fields)) => Ok(fields
+                        .get(key)
+                        .filter(|value| !matches!(value, ::async_graphql::Value::Null))
+                        .cloned()),
+                    _ => Err(::async_graphql::Error::new(format!(
+                        "BUG: field `{}` resolved without an object parent",
+                        key
+                    ))),
+                }
+            }
+
             /// Build the GraphQL dynamic schema
             pub fn graphql_schema(self) -> ::async_graphql::dynamic::Schema
             where
                 Self: Clone + Send + Sync + 'static,
+            {
+                Self::graphql_schema_arc(::std::sync::Arc::new(self))
+            }
+
+            /// Like `graphql_schema()`, but sharing an existing `Arc` of the service.
+            pub fn graphql_schema_arc(
+                self: ::std::sync::Arc<Self>,
+            ) -> ::async_graphql::dynamic::Schema
+            where
+                Self: Send + Sync + 'static,
             {
                 use ::async_graphql::dynamic::*;
 
-                let service = ::std::sync::Arc::new(self);
+                let service = self;
 
                 let query = {
                     let service = service.clone();
@@ -480,6 +711,8 @@ pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn
                     obj
                 };
 
+                #subscription_object
+
                 #schema_build
             }
 
@@ -490,11 +723,19 @@ pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn
             pub fn graphql_router(self) -> ::server_less::axum::Router
             where
                 Self: Clone + Send + Sync + 'static,
+            {
+                Self::graphql_router_arc(::std::sync::Arc::new(self))
+            }
+
+            /// Like `graphql_router()`, but sharing an existing `Arc` of the service.
+            pub fn graphql_router_arc(self: ::std::sync::Arc<Self>) -> ::server_less::axum::Router
+            where
+                Self: Send + Sync + 'static,
             {
                 use ::server_less::axum::routing::{get, post};
                 use ::server_less::axum::response::IntoResponse;
 
-                let schema = self.graphql_schema();
+                let schema = self.graphql_schema_arc();
 
                 async fn graphql_handler(
                     schema: ::server_less::axum::extract::State<::async_graphql::dynamic::Schema>,
@@ -505,14 +746,13 @@ pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn
 
                 async fn playground() -> impl IntoResponse {
                     ::server_less::axum::response::Html(
-                        ::async_graphql::http::playground_source(
-                            ::async_graphql::http::GraphQLPlaygroundConfig::new("/graphql")
-                        )
+                        ::async_graphql::http::playground_source(#playground_config)
                     )
                 }
 
                 ::server_less::axum::Router::new()
                     .route("/graphql", get(playground).post(graphql_handler))
+                    #subscription_route
                     .with_state(schema)
             }
 
@@ -621,6 +861,7 @@ pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn
                             extra: ::server_less::serde_json::Map::new(),
                         },
                     }
+                    #subscription_openapi_path
                 ]
             }
 
@@ -655,24 +896,14 @@ pub(crate) fn expand_graphql(args: GraphqlArgs, mut impl_block: ItemImpl) -> syn
     })
 }
 
-fn is_query_method(name: &str) -> bool {
-    name.starts_with("get_")
-        || name.starts_with("fetch_")
-        || name.starts_with("read_")
-        || name.starts_with("list_")
-        || name.starts_with("find_")
-        || name.starts_with("search_")
-        || name.starts_with("count_")
-        || name.starts_with("exists_")
-        || name.starts_with("is_")
-        || name.starts_with("has_")
-}
-
-fn generate_field_registrations(methods: &[&MethodInfo]) -> Vec<TokenStream2> {
+fn generate_field_registrations(
+    methods: &[&MethodInfo],
+    scalars: &[syn::Ident],
+) -> Vec<TokenStream2> {
     methods
         .iter()
         .map(|m| {
-            let field_code = generate_field_registration(m);
+            let field_code = generate_field_registration(m, scalars);
             let cfg_attrs = &m.cfg_attrs;
             quote! {
                 #(#cfg_attrs)*
@@ -682,14 +913,30 @@ fn generate_field_registrations(methods: &[&MethodInfo]) -> Vec<TokenStream2> {
         .collect()
 }
 
-fn generate_field_registration(method: &MethodInfo) -> TokenStream2 {
-    let method_name = method.name_str();
-    let method_ident = &method.name;
-    let field_name = method_name.to_lower_camel_case();
-    let description = method.docs.clone().unwrap_or_default();
+/// The parts of a field shared by query, mutation and subscription fields: its
+/// description, deprecation, argument registrations, and the resolver code that
+/// extracts the arguments and calls the method.
+struct FieldInputs {
+    field_name: String,
+    description: Option<TokenStream2>,
+    deprecation: Option<TokenStream2>,
+    arg_registrations: Vec<TokenStream2>,
+    arg_extractions: Vec<TokenStream2>,
+    method_call: TokenStream2,
+}
 
-    let ret = &method.return_info;
-    let (type_ref, is_list) = infer_graphql_type_ref(ret);
+fn field_inputs(method: &MethodInfo, scalars: &[syn::Ident]) -> FieldInputs {
+    let method_ident = &method.name;
+    let field_name = method.name_str().to_lower_camel_case();
+    // Descriptions are Markdown, so the doc comment goes through with its line breaks intact.
+    let description = method
+        .docs
+        .as_deref()
+        .map(|docs| quote! { .description(#docs) });
+    let deprecation = field_deprecation(method).map(|reason| match reason {
+        Some(reason) => quote! { .deprecation(Some(#reason)) },
+        None => quote! { .deprecation(None) },
+    });
 
     // Partition params: context params are injected; only user params go into the GraphQL schema.
     let (_ctx_param, user_params) =
@@ -698,8 +945,17 @@ fn generate_field_registration(method: &MethodInfo) -> TokenStream2 {
     let arg_registrations: Vec<_> = user_params
         .iter()
         .map(|p| {
+            if is_connection_args(&p.ty) {
+                return quote! {
+                    .argument(InputValue::new("first", TypeRef::named(TypeRef::INT)))
+                    .argument(InputValue::new("after", TypeRef::named(TypeRef::STRING)))
+                    .argument(InputValue::new("last", TypeRef::named(TypeRef::INT)))
+                    .argument(InputValue::new("before", TypeRef::named(TypeRef::STRING)))
+                };
+            }
             let arg_name = p.name_str();
-            let gql_type = rust_type_to_graphql(&p.ty);
+            let gql_type = user_scalar_name(&p.ty, scalars)
+                .unwrap_or_else(|| rust_type_to_graphql(&p.ty).to_string());
             let is_required = !p.is_optional;
             if is_required {
                 quote! {
@@ -717,7 +973,24 @@ fn generate_field_registration(method: &MethodInfo) -> TokenStream2 {
         let arg_name = p.name_str();
         let param_name = &p.name;
         let ty = &p.ty;
-        if p.is_optional {
+        if is_connection_args(ty) {
+            quote! {
+                let __sl_page_size = |name: &str| -> ::async_graphql::Result<Option<usize>> {
+                    match ctx.args.try_get(name) {
+                        Ok(v) if !v.is_null() => v.deserialize::<usize>().map(Some).map_err(|_| {
+                            ::async_graphql::Error::new(format!("`{}` must not be negative", name))
+                        }),
+                        _ => Ok(None),
+                    }
+                };
+                let #param_name: #ty = ::server_less::ConnectionArgs {
+                    first: __sl_page_size("first")?,
+                    after: ctx.args.try_get("after").ok().and_then(|v| v.deserialize().ok()),
+                    last: __sl_page_size("last")?,
+                    before: ctx.args.try_get("before").ok().and_then(|v| v.deserialize().ok()),
+                };
+            }
+        } else if p.is_optional {
             quote! {
                 let #param_name: #ty = ctx.args.try_get(#arg_name).ok()
                     .and_then(|v| v.deserialize().ok());
@@ -748,6 +1021,28 @@ fn generate_field_registration(method: &MethodInfo) -> TokenStream2 {
         quote! { service.#method_ident(#(#param_names),*) }
     };
 
+    FieldInputs {
+        field_name,
+        description,
+        deprecation,
+        arg_registrations,
+        arg_extractions,
+        method_call,
+    }
+}
+
+fn generate_field_registration(method: &MethodInfo, scalars: &[syn::Ident]) -> TokenStream2 {
+    let FieldInputs {
+        field_name,
+        description,
+        deprecation,
+        arg_registrations,
+        arg_extractions,
+        method_call,
+    } = field_inputs(method, scalars);
+    let ret = &method.return_info;
+    let (type_ref, is_list) = infer_graphql_type_ref(ret, scalars);
+
     let result_conversion = if ret.is_unit {
         quote! {
             #method_call;
@@ -807,22 +1102,123 @@ fn generate_field_registration(method: &MethodInfo) -> TokenStream2 {
                 #result_conversion
             })
         })
-        .description(#description)
+        #description
+        #deprecation
         #(#arg_registrations)*;
         obj = obj.field(field);
     }
 }
 
-fn infer_graphql_type_ref(ret: &server_less_parse::ReturnInfo) -> (TokenStream2, bool) {
+/// Register a subscription field for a method returning `impl Stream<Item = T>`.
+///
+/// Each stream item is resolved like a query result; an `Err` item is sent as a
+/// GraphQL error. async-graphql drives the stream,
+/// so it must not borrow the service (`impl Stream<Item = T> + use<>`).
+fn generate_subscription_registration(
+    method: &MethodInfo,
+    scalars: &[syn::Ident],
+) -> syn::Result<TokenStream2> {
+    let FieldInputs {
+        field_name,
+        description,
+        deprecation,
+        arg_registrations,
+        arg_extractions,
+        method_call,
+    } = field_inputs(method, scalars);
+    let Some(item) = &method.return_info.stream_item else {
+        return Err(syn::Error::new_spanned(
+            &method.method.sig.output,
+            "GraphQL subscriptions need a `Stream<Item = T>` return type",
+        ));
+    };
+    let item_ret = server_less_parse::parse_return_type(&syn::parse_quote! { -> #item });
+    let (type_ref, _) = infer_graphql_type_ref(&item_ret, scalars);
+
+    let item_conversion = if item_ret.is_result {
+        quote! {
+            item.map(Self::__graphql_to_value)
+                .map_err(|e| ::async_graphql::Error::new(format!("{}", e)))
+        }
+    } else {
+        quote! { Ok::<_, ::async_graphql::Error>(Self::__graphql_to_value(item)) }
+    };
+
+    Ok(quote! {
+        let field = SubscriptionField::new(#field_name, #type_ref, move |ctx| {
+            let service = service.clone();
+            SubscriptionFieldFuture::new(async move {
+                #(#arg_extractions)*
+                let stream = #method_call;
+                Ok(::async_graphql::futures_util::StreamExt::map(stream, |item| {
+                    #item_conversion
+                }))
+            })
+        })
+        #description
+        #deprecation
+        #(#arg_registrations)*;
+        obj = obj.field(field);
+    })
+}
+
+/// Deprecation of a GraphQL field, from `#[deprecated]` or (when stacked with
+/// `#[http]`) `#[route(deprecated)]`.
+///
+/// `Some(None)` is a deprecation without a reason.
+fn field_deprecation(method: &MethodInfo) -> Option<Option<String>> {
+    if let Some(deprecation) = &method.deprecation {
+        return Some(deprecation.note.clone().or_else(|| deprecation.notice()));
+    }
+    let mut deprecated = false;
+    for attr in method
+        .method
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("route"))
+    {
+        // Other keys belong to #[http], which reports its own errors.
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("deprecated") {
+                deprecated = if meta.input.peek(syn::Token![=]) {
+                    meta.value()?.parse::<syn::LitBool>()?.value()
+                } else {
+                    true
+                };
+            } else if meta.input.peek(syn::Token![=]) {
+                meta.value()?.parse::<syn::Expr>()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        });
+    }
+    deprecated.then_some(None)
+}
+
+fn infer_graphql_type_ref(
+    ret: &server_less_parse::ReturnInfo,
+    scalars: &[syn::Ident],
+) -> (TokenStream2, bool) {
     if ret.is_unit {
         (quote! { TypeRef::named_nn(TypeRef::BOOLEAN) }, false)
+    } else if let Some(node) = connection_node_type(ret) {
+        let connection = ConnectionTypes::of(node).connection;
+        if ret.is_option {
+            (quote! { TypeRef::named(#connection) }, false)
+        } else {
+            (quote! { TypeRef::named_nn(#connection) }, false)
+        }
     } else if let Some(ref ty) = ret.ty {
         let type_str = quote!(#ty).to_string();
 
         let is_list = type_str.contains("Vec");
 
-        // Check for custom scalars first (async-graphql built-ins)
-        let base_type = if type_str.contains("DateTime") {
+        // Check for custom scalars first (user-declared, then async-graphql built-ins)
+        let base_type = if let Some(name) = user_scalar_name(ty, scalars) {
+            quote! { #name }
+        } else if type_str.contains("DateTime") {
             quote! { "DateTime" }
         } else if type_str.contains("Uuid") {
             quote! { "UUID" }
@@ -904,6 +1300,29 @@ fn generate_resolver_arm(_struct_name: &syn::Ident, method: &MethodInfo) -> Toke
     }
 }
 
+/// The scalar name for a type declared in `#[graphql(scalars(...))]`, looking through
+/// `Option`, `Vec` and `Result`.
+fn user_scalar_name(ty: &syn::Type, scalars: &[syn::Ident]) -> Option<String> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if matches!(
+        segment.ident.to_string().as_str(),
+        "Option" | "Vec" | "Result"
+    ) {
+        if let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+            && let Some(syn::GenericArgument::Type(inner)) = args.args.first()
+        {
+            return user_scalar_name(inner, scalars);
+        }
+        return None;
+    }
+    scalars
+        .contains(&segment.ident)
+        .then(|| segment.ident.to_string())
+}
+
 fn rust_type_to_graphql(ty: &syn::Type) -> &'static str {
     let type_str = quote!(#ty).to_string();
 
@@ -1011,6 +1430,132 @@ fn collect_custom_scalars(methods: &[&MethodInfo]) -> Vec<String> {
     scalars.into_iter().collect()
 }
 
+/// The node type `T` of a method returning a Relay `Connection<T>`, possibly
+/// wrapped in `Option` or `Result`.
+fn connection_node_type(ret: &server_less_parse::ReturnInfo) -> Option<&syn::Type> {
+    let ty = ret
+        .ok_type
+        .as_ref()
+        .or(ret.some_type.as_ref())
+        .or(ret.ty.as_ref())?;
+    if let syn::Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+        && segment.ident == "Connection"
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(inner)) = args.args.first()
+    {
+        return Some(inner);
+    }
+    None
+}
+
+/// Whether a parameter is a `ConnectionArgs`, expanded into `first`/`after`/`last`/`before`.
+fn is_connection_args(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Path(type_path)
+        if type_path.path.segments.last().is_some_and(|s| s.ident == "ConnectionArgs"))
+}
+
+/// GraphQL type names generated for a `Connection<T>`.
+struct ConnectionTypes {
+    /// `{Node}Connection`
+    connection: String,
+    /// `{Node}Edge`
+    edge: String,
+    /// GraphQL type of `edge.node`
+    node: &'static str,
+}
+
+impl ConnectionTypes {
+    /// Scalar nodes are named after their GraphQL type (`StringConnection`); other
+    /// nodes are exposed as JSON and named after the Rust type (`UserConnection`).
+    fn of(node: &syn::Type) -> Self {
+        let node_type = map_inner_type_to_graphql(&quote!(#node).to_string());
+        let prefix = match node {
+            syn::Type::Path(type_path) if node_type == "JSON" => type_path
+                .path
+                .segments
+                .last()
+                .map(|s| s.ident.to_string())
+                .filter(|ident| ident != "Value")
+                .unwrap_or_else(|| node_type.to_string()),
+            _ => node_type.to_string(),
+        };
+        Self {
+            connection: format!("{prefix}Connection"),
+            edge: format!("{prefix}Edge"),
+            node: node_type,
+        }
+    }
+}
+
+/// Register the connection, edge and `PageInfo` object types behind every
+/// `Connection<T>` return type. Their fields read from the `Value::Object` the
+/// serialized connection resolves to.
+fn generate_connection_registrations(methods: &[&MethodInfo]) -> Vec<TokenStream2> {
+    let mut seen = std::collections::BTreeSet::new();
+    let mut registrations = Vec::new();
+    for method in methods {
+        let Some(node) = connection_node_type(&method.return_info) else {
+            continue;
+        };
+        let types = ConnectionTypes::of(node);
+        if !seen.insert(types.connection.clone()) {
+            continue;
+        }
+        let ConnectionTypes {
+            connection,
+            edge,
+            node,
+        } = types;
+        registrations.push(quote! {
+            .register(
+                Object::new(#edge)
+                    .field(Field::new("cursor", TypeRef::named_nn(TypeRef::STRING), |ctx| {
+                        FieldFuture::new(async move { Self::__graphql_parent_field(&ctx, "cursor") })
+                    }))
+                    .field(Field::new("node", TypeRef::named_nn(#node), |ctx| {
+                        FieldFuture::new(async move { Self::__graphql_parent_field(&ctx, "node") })
+                    }))
+            )
+            .register(
+                Object::new(#connection)
+                    .field(Field::new("edges", TypeRef::named_nn_list_nn(#edge), |ctx| {
+                        FieldFuture::new(async move { Self::__graphql_parent_field(&ctx, "edges") })
+                    }))
+                    .field(Field::new("pageInfo", TypeRef::named_nn("PageInfo"), |ctx| {
+                        FieldFuture::new(async move { Self::__graphql_parent_field(&ctx, "pageInfo") })
+                    }))
+            )
+        });
+    }
+    if !registrations.is_empty() {
+        let page_info_fields = [
+            (
+                "hasNextPage",
+                quote! { TypeRef::named_nn(TypeRef::BOOLEAN) },
+            ),
+            (
+                "hasPreviousPage",
+                quote! { TypeRef::named_nn(TypeRef::BOOLEAN) },
+            ),
+            ("startCursor", quote! { TypeRef::named(TypeRef::STRING) }),
+            ("endCursor", quote! { TypeRef::named(TypeRef::STRING) }),
+        ]
+        .into_iter()
+        .map(|(name, type_ref)| {
+            quote! {
+                .field(Field::new(#name, #type_ref, |ctx| {
+                    FieldFuture::new(async move { Self::__graphql_parent_field(&ctx, #name) })
+                }))
+            }
+        });
+        registrations.push(quote! {
+            .register(Object::new("PageInfo") #(#page_info_fields)*)
+        });
+    }
+    registrations
+}
+
 /// Generate the `__graphql_merge_query_fields` helper method for a service.
 ///
 /// This is called by parent services that mount this service as a child. It inlines
@@ -1018,8 +1563,9 @@ fn collect_custom_scalars(methods: &[&MethodInfo]) -> Vec<String> {
 fn generate_merge_query_helper(
     _struct_name: &syn::Ident,
     query_methods: &[&MethodInfo],
+    scalars: &[syn::Ident],
 ) -> TokenStream2 {
-    let field_registrations = generate_field_registrations(query_methods);
+    let field_registrations = generate_field_registrations(query_methods, scalars);
 
     quote! {
         /// Merge this service's query fields into an existing Object builder.
@@ -1057,9 +1603,10 @@ fn generate_merge_query_helper(
 fn generate_merge_mutation_helper(
     _struct_name: &syn::Ident,
     mutation_methods: &[&MethodInfo],
+    scalars: &[syn::Ident],
 ) -> TokenStream2 {
     let field_count = mutation_methods.len();
-    let field_registrations = generate_field_registrations(mutation_methods);
+    let field_registrations = generate_field_registrations(mutation_methods, scalars);
 
     quote! {
         /// Merge this service's mutation fields into an existing Object builder.
@@ -19,11 +19,60 @@
 //! - `bool` → bool
 //! - `Vec<T>` → repeated T
 //! - `Option<T>` → optional T
+//! - `DateTime<Tz>`, `SystemTime` → google.protobuf.Timestamp
+//! - `Duration` → google.protobuf.Duration
+//! - `()` return → google.protobuf.Empty
+//!
+//! Imports for the well-known types are added when they are used.
+//!
+//! # File Options
+//!
+//! `#[grpc(syntax = "proto2")]` switches the `syntax` line (default `"proto3"`);
+//! proto2 fields are labelled `required`/`optional`. `go_package` and `java_package`
+//! are emitted as `option` lines after the package:
+//!
+//! ```ignore
+//! #[grpc(package = "users.v1", go_package = "example.com/gen/users/v1", java_package = "com.example.users.v1")]
+//! ```
+//!
+//! # Split Files
+//!
+//! `#[grpc(split = true)]` additionally generates `write_grpc_dir(dir)`, which writes
+//! the service to `{service}.proto` and its messages to a `common.proto` that the
+//! service file imports. Services sharing a package can write into the same directory:
+//! each merges its messages into the existing `common.proto`, replacing the ones it wrote
+//! before. A different message of the same name from another service is an error.
+//! Start from an empty directory to drop messages of removed methods.
+//!
+//! # HTTP Annotations
+//!
+//! `#[grpc(http_annotations = true)]` gives every rpc a `google.api.http` option
+//! naming the verb and path `#[http]` serves the method on, so a grpc-gateway or
+//! gRPC-Web proxy can transcode REST calls onto the same service. Routes follow
+//! the same inference and `#[route(...)]` overrides as `#[http]`, including the
+//! `prefix`, `rpc_style` and `visibility` of a sibling `#[http(...)]`, which must be
+//! placed below `#[grpc]` (the other order is a compile error);
+//! methods that take a JSON body over HTTP get `body: "*"`.
+//! `google/api/annotations.proto` is imported.
+//!
+//! ```ignore
+//! #[grpc(package = "users.v1", http_annotations = true)]
+//! #[http(prefix = "/v1")]
+//! impl UserService {
+//!     // rpc GetUser(GetUserRequest) returns (GetUserResponse) {
+//!     //   option (google.api.http) = {
+//!     //     get: "/v1/users/{id}"
+//!     //   };
+//!     // }
+//!     fn get_user(&self, id: u32) -> User { /* ... */ }
+//! }
+//! ```
 //!
 //! # Generated Methods
 //!
 //! - `grpc_schema() -> &'static str` - Generated .proto schema
 //! - `write_grpc(path)` - Write .proto schema to a file
+//! - `grpc_service_schema()`, `grpc_common_schema()`, `write_grpc_dir(dir)` - With `split = true`
 //! - `validate_schema() -> Result<(), SchemaValidationError>` - Validate if schema path provided
 //! - `assert_schema_matches()` - Panic if validation fails (for tests)
 //!
@@ -47,14 +96,15 @@
 
 use crate::app::extract_app_meta;
 use crate::context::partition_context_params;
+use crate::openapi_gen::{RouteOverride, infer_http_method, infer_path, sibling_http_args};
 use crate::server_attrs::{has_server_hidden, has_server_skip, validate_server_attrs};
 use heck::{ToSnakeCase, ToUpperCamelCase};
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 use server_less_parse::{
-    MethodInfo, ParamInfo, extract_methods, get_impl_name, unwrap_option_type, unwrap_result_ok_type,
-    unwrap_vec_type,
+    HttpMethod, MethodInfo, ParamInfo, extract_methods, extract_methods_with_visibility,
+    get_impl_name, unwrap_option_type, unwrap_result_ok_type, unwrap_vec_type,
 };
 use syn::{ItemImpl, Token, parse::Parse};
 
@@ -62,6 +112,14 @@ use syn::{ItemImpl, Token, parse::Parse};
 pub(crate) struct GrpcArgs {
     package: Option<String>,
     schema: Option<String>,
+    /// `syntax = "proto2"` or `"proto3"` (the default)
+    syntax: Option<String>,
+    go_package: Option<String>,
+    java_package: Option<String>,
+    /// Also generate a service file plus a shared `common.proto` (`write_grpc_dir`)
+    split: bool,
+    /// Annotate each rpc with the `google.api.http` route `#[http]` serves it on
+    http_annotations: bool,
 }
 
 impl Parse for GrpcArgs {
@@ -79,15 +137,53 @@ impl Parse for GrpcArgs {
                     let lit: syn::LitStr = input.parse()?;
                     args.schema = Some(lit.value());
                 }
+                "syntax" => {
+                    let lit: syn::LitStr = input.parse()?;
+                    let syntax = lit.value();
+                    if syntax != "proto2" && syntax != "proto3" {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            format!(
+                                "unsupported syntax `{syntax}`. Valid values: \"proto2\", \"proto3\""
+                            ),
+                        ));
+                    }
+                    args.syntax = Some(syntax);
+                }
+                "go_package" => {
+                    let lit: syn::LitStr = input.parse()?;
+                    args.go_package = Some(proto_option_value(&lit)?);
+                }
+                "java_package" => {
+                    let lit: syn::LitStr = input.parse()?;
+                    args.java_package = Some(proto_option_value(&lit)?);
+                }
+                "split" => {
+                    let lit: syn::LitBool = input.parse()?;
+                    args.split = lit.value;
+                }
+                "http_annotations" => {
+                    let lit: syn::LitBool = input.parse()?;
+                    args.http_annotations = lit.value;
+                }
                 other => {
-                    const VALID: &[&str] = &["package", "schema"];
+                    const VALID: &[&str] = &[
+                        "package",
+                        "schema",
+                        "syntax",
+                        "go_package",
+                        "java_package",
+                        "split",
+                        "http_annotations",
+                    ];
                     let suggestion = crate::did_you_mean(other, VALID)
                         .map(|s| format!(" — did you mean `{s}`?"))
                         .unwrap_or_default();
                     return Err(syn::Error::new(
                         ident.span(),
                         format!(
-                            "unknown argument `{other}`{suggestion}. Valid arguments: package, schema"
+                            "unknown argument `{other}`{suggestion}. Valid arguments: package, schema, \
+                             syntax, go_package, java_package, split, http_annotations"
                         ),
                     ));
                 }
@@ -100,8 +196,25 @@ impl Parse for GrpcArgs {
     }
 }
 
+/// Validate a string that is written verbatim into a `.proto` file option.
+///
+/// Quotes, backslashes and control characters would end or corrupt the
+/// option's string literal, so they are rejected rather than escaped.
+fn proto_option_value(lit: &syn::LitStr) -> syn::Result<String> {
+    let value = lit.value();
+    if let Some(c) = value
+        .chars()
+        .find(|c| matches!(c, '"' | '\\') || c.is_control())
+    {
+        return Err(syn::Error::new(
+            lit.span(),
+            format!("{c:?} is not allowed in a .proto file option"),
+        ));
+    }
+    Ok(value)
+}
+
 pub(crate) fn expand_grpc(args: GrpcArgs, mut impl_block: ItemImpl) -> syn::Result<TokenStream2> {
-    crate::reject_generic_impl(&impl_block)?;
     let app_meta = extract_app_meta(&mut impl_block.attrs);
     let struct_name = get_impl_name(&impl_block)?;
     let (impl_generics, _ty_generics, where_clause) = impl_block.generics.split_for_impl();
@@ -121,15 +234,65 @@ pub(crate) fn expand_grpc(args: GrpcArgs, mut impl_block: ItemImpl) -> syn::Resu
         .or(app_meta.name.map(|n| n.to_snake_case()))
         .unwrap_or_else(|| struct_name_str.to_snake_case());
     let service_name = struct_name_str.clone();
+    let syntax = args.syntax.as_deref().unwrap_or("proto3");
+    let proto2 = syntax == "proto2";
+
+    let options: String = [
+        ("go_package", &args.go_package),
+        ("java_package", &args.java_package),
+    ]
+    .into_iter()
+    .filter_map(|(name, value)| {
+        value
+            .as_ref()
+            .map(|value| format!("option {name} = \"{value}\";\n"))
+    })
+    .collect();
+    let options = if options.is_empty() {
+        options
+    } else {
+        format!("\n{options}")
+    };
 
-    let proto_methods: Vec<String> = methods.iter().map(generate_proto_method).collect();
-    let proto_messages: Vec<String> = methods.iter().flat_map(generate_proto_messages).collect();
+    let http_rules = if args.http_annotations {
+        let sibling = sibling_http_args(&impl_block)?;
+        let prefix = sibling.prefix.unwrap_or_default();
+        // Methods `#[http(visibility = "pub")]` leaves out get no route.
+        let routed: Vec<String> = extract_methods_with_visibility(&impl_block, sibling.visibility)?
+            .iter()
+            .map(MethodInfo::name_str)
+            .collect();
+        let mut methods = methods.clone();
+        crate::openapi_gen::bind_prefix_params(&prefix, &mut methods);
+        methods
+            .iter()
+            .map(|m| {
+                if routed.contains(&m.name_str()) {
+                    http_rule(m, &prefix, sibling.rpc_style)
+                } else {
+                    Ok(None)
+                }
+            })
+            .collect::<syn::Result<Vec<_>>>()?
+    } else {
+        vec![None; methods.len()]
+    };
+    let proto_methods: Vec<String> = methods
+        .iter()
+        .zip(&http_rules)
+        .map(|(m, rule)| generate_proto_method(m, rule.as_deref()))
+        .collect();
+    let proto_messages: Vec<String> = methods
+        .iter()
+        .flat_map(|m| generate_proto_messages(m, proto2))
+        .collect();
+    let imports = proto_imports(&[], &[&proto_methods, &proto_messages]);
 
     let proto_schema = format!(
-        r#"syntax = "proto3";
+        r#"syntax = "{syntax}";
 
 package {package};
-
+{imports}{options}
 service {service_name} {{
 {methods}
 }}
@@ -142,6 +305,66 @@ service {service_name} {{
         messages = proto_messages.join("\n")
     );
 
+    let split_methods = if args.split {
+        let service_file = format!("{}.proto", service_name.to_snake_case());
+        let service_schema = format!(
+            r#"syntax = "{syntax}";
+
+package {package};
+{imports}{options}
+service {service_name} {{
+{methods}
+}}
+"#,
+            imports = proto_imports(&["common.proto"], &[&proto_methods]),
+            methods = proto_methods.join("\n"),
+        );
+        let common_schema = format!(
+            r#"syntax = "{syntax}";
+
+package {package};
+{imports}{options}
+{messages}
+"#,
+            imports = proto_imports(&[], &[&proto_messages]),
+            // Tag each message with its service so `merge_common_proto` can tell
+            // another service's message of the same name apart from an older copy.
+            messages = proto_messages
+                .iter()
+                .map(|message| format!("// service: {service_name}\n{message}"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+        quote! {
+            /// The `service` definition alone, importing its messages from `common.proto`.
+            pub fn grpc_service_schema() -> &'static str {
+                #service_schema
+            }
+            /// This service's messages, written to the shared `common.proto`.
+            pub fn grpc_common_schema() -> &'static str {
+                #common_schema
+            }
+            /// Write the service to `dir/{service}.proto` and merge its messages into
+            /// `dir/common.proto`, keeping messages other services wrote there.
+            pub fn write_grpc_dir(dir: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+                let dir = dir.as_ref();
+                std::fs::create_dir_all(dir)?;
+                std::fs::write(dir.join(#service_file), Self::grpc_service_schema())?;
+                let common_path = dir.join("common.proto");
+                let existing = match std::fs::read_to_string(&common_path) {
+                    Ok(existing) => existing,
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+                    Err(err) => return Err(err),
+                };
+                let merged =
+                    ::server_less::merge_common_proto(&existing, Self::grpc_common_schema())?;
+                std::fs::write(common_path, merged)
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let validation_method = if let Some(schema_path) = &args.schema {
         quote! {
             /// Validate that the generated schema matches the expected schema file.
@@ -193,7 +416,10 @@ service {service_name} {{
         quote! {}
     };
 
-    let maybe_impl = if crate::is_protocol_impl_emitter(&impl_block, "grpc") {
+    // Reading a sibling `#[http]` needs `#[grpc]` placed above it, so hand the
+    // impl block on for `#[http]` to expand.
+    let http_below = impl_block.attrs.iter().any(|attr| attr.path().is_ident("http"));
+    let maybe_impl = if http_below || crate::is_protocol_impl_emitter(&impl_block, "grpc") {
         quote! { #impl_block }
     } else {
         quote! {}
@@ -209,11 +435,13 @@ service {service_name} {{
                 std::fs::write(path, Self::grpc_schema())
             }
             #validation_method
+            #split_methods
         }
     })
 }
 
-fn generate_proto_method(method: &MethodInfo) -> String {
+/// An `rpc` line, with a `google.api.http` option block when `http_rule` is given.
+fn generate_proto_method(method: &MethodInfo, http_rule: Option<&str>) -> String {
     let method_name = method.name_str().to_upper_camel_case();
     let request_name = format!("{}Request", method_name);
     let response_name = format!("{}Response", method_name);
@@ -225,22 +453,73 @@ fn generate_proto_method(method: &MethodInfo) -> String {
 
     // Check if this is a streaming response (returns impl Stream<Item = T>)
     let ret = &method.return_info;
-    if ret.is_stream {
+    let returns = if ret.is_unit {
+        // Nothing to return: use the well-known empty message
+        "google.protobuf.Empty".to_string()
+    } else if ret.is_stream {
         // Server streaming RPC
-        format!(
-            "{}  rpc {}({}) returns (stream {});",
-            doc, method_name, request_name, response_name
-        )
+        format!("stream {}", response_name)
     } else {
         // Unary RPC
-        format!(
-            "{}  rpc {}({}) returns ({});",
-            doc, method_name, request_name, response_name
-        )
+        response_name
+    };
+    let end = match http_rule {
+        Some(rule) => format!(" {{\n    option (google.api.http) = {{\n{rule}    }};\n  }}"),
+        None => ";".to_string(),
+    };
+    format!(
+        "{}  rpc {}({}) returns ({}){}",
+        doc, method_name, request_name, returns, end
+    )
+}
+
+/// Body of the `google.api.http` option for the route `#[http]` gives `method`
+/// (same verb and path inference, `#[route]` overrides, and sibling
+/// `#[http(prefix, rpc_style)]`), or `None` when `#[http]` does not serve it.
+fn http_rule(method: &MethodInfo, prefix: &str, rpc_style: bool) -> syn::Result<Option<String>> {
+    let overrides = RouteOverride::parse_from_attrs(&method.method.attrs)?;
+    if overrides.skip || overrides.webhook {
+        return Ok(None);
     }
+    let name = method.name_str();
+    let inferred = infer_http_method(&name);
+    let verb = match overrides.method.as_deref() {
+        Some(m) => HttpMethod::parse(m).ok_or_else(|| {
+            syn::Error::new(
+                overrides.method_span.unwrap_or_else(|| method.name.span()),
+                format!(
+                    "unknown HTTP method `{m}`\n\
+                     \n\
+                     Supported methods: GET, POST, PUT, PATCH, DELETE"
+                ),
+            )
+        })?,
+        None => inferred,
+    };
+    let path = match overrides.path {
+        Some(path) => path,
+        None => infer_path(
+            &name,
+            &verb,
+            &method.params,
+            rpc_style,
+            overrides.resource.as_deref(),
+        ),
+    };
+    // Like `#[http]`, the body/query split follows the inferred verb; fields not
+    // bound by the path travel in the body.
+    let body = matches!(
+        inferred,
+        HttpMethod::Post | HttpMethod::Put | HttpMethod::Patch
+    ) && !matches!(verb, HttpMethod::Get | HttpMethod::Delete);
+    let verb = verb.as_str().to_lowercase();
+    let body = if body { "      body: \"*\"\n" } else { "" };
+    Ok(Some(format!("      {verb}: \"{prefix}{path}\"\n{body}")))
 }
 
-fn generate_proto_messages(method: &MethodInfo) -> Vec<String> {
+/// Request and response messages for a method. proto2 requires every singular field
+/// to carry a label, so non-`Option` fields are marked `required` there.
+fn generate_proto_messages(method: &MethodInfo, proto2: bool) -> Vec<String> {
     let method_name = method.name_str().to_upper_camel_case();
     let request_name = format!("{}Request", method_name);
     let response_name = format!("{}Response", method_name);
@@ -249,7 +528,7 @@ fn generate_proto_messages(method: &MethodInfo) -> Vec<String> {
     let request_fields: Vec<String> = schema_params
         .iter()
         .enumerate()
-        .map(|(i, p)| generate_proto_field(p, i + 1))
+        .map(|(i, p)| generate_proto_field(p, i + 1, proto2))
         .collect();
     let request_msg = format!(
         "message {} {{\n{}\n}}",
@@ -258,35 +537,52 @@ fn generate_proto_messages(method: &MethodInfo) -> Vec<String> {
     );
     let ret = &method.return_info;
     let response_msg = if ret.is_unit {
-        format!("message {} {{\n}}", response_name)
+        // The rpc returns google.protobuf.Empty
+        return vec![request_msg];
     } else if ret.is_stream {
         // For streaming responses, use the stream item type
         let proto_type = rust_type_to_proto(&ret.stream_item);
+        let label = field_label(proto2, &proto_type, false);
         format!(
-            "message {} {{\n  {} result = 1;\n}}",
-            response_name, proto_type
+            "message {} {{\n  {}{} result = 1;\n}}",
+            response_name, label, proto_type
         )
     } else {
         let proto_type = rust_type_to_proto(&ret.ty);
+        let label = field_label(proto2, &proto_type, ret.is_option);
         format!(
-            "message {} {{\n  {} result = 1;\n}}",
-            response_name, proto_type
+            "message {} {{\n  {}{} result = 1;\n}}",
+            response_name, label, proto_type
         )
     };
     vec![request_msg, response_msg]
 }
 
-fn generate_proto_field(param: &ParamInfo, field_num: usize) -> String {
+fn generate_proto_field(param: &ParamInfo, field_num: usize, proto2: bool) -> String {
     let name = param.name_str().to_snake_case();
-    // Unwrap Option<T> — the `optional` keyword is emitted separately below
+    // Unwrap Option<T> — the `optional` label is emitted separately below
     let ty = if let Some(inner) = unwrap_option_type(&param.ty) {
         inner.clone()
     } else {
         param.ty.clone()
     };
     let proto_type = rust_type_to_proto(&Some(ty));
-    let optional = if param.is_optional { "optional " } else { "" };
-    format!("  {}{} {} = {};", optional, proto_type, name, field_num)
+    let label = field_label(proto2, &proto_type, param.is_optional);
+    format!("  {}{} {} = {};", label, proto_type, name, field_num)
+}
+
+/// Label for a singular field: `optional` for `Option<T>`, and `required` otherwise
+/// under proto2. `repeated` types already carry their label.
+fn field_label(proto2: bool, proto_type: &str, optional: bool) -> &'static str {
+    if proto_type.starts_with("repeated ") {
+        ""
+    } else if optional {
+        "optional "
+    } else if proto2 {
+        "required "
+    } else {
+        ""
+    }
 }
 
 fn rust_type_to_proto(ty: &Option<syn::Type>) -> String {
@@ -312,6 +608,38 @@ fn rust_type_to_proto(ty: &Option<syn::Type>) -> String {
     rust_type_to_proto_scalar(ty).to_string()
 }
 
+/// Well-known types (and the `google.api.http` option) and the files that define them.
+const WELL_KNOWN_TYPES: &[(&str, &str)] = &[
+    ("google.api.http", "google/api/annotations.proto"),
+    ("google.protobuf.Duration", "google/protobuf/duration.proto"),
+    ("google.protobuf.Empty", "google/protobuf/empty.proto"),
+    ("google.protobuf.Timestamp", "google/protobuf/timestamp.proto"),
+];
+
+/// `import` lines for `files` plus every well-known type referenced by the generated
+/// definitions.
+fn proto_imports(files: &[&str], definitions: &[&[String]]) -> String {
+    let well_known = WELL_KNOWN_TYPES
+        .iter()
+        .filter(|(name, _)| {
+            definitions
+                .iter()
+                .flat_map(|defs| defs.iter())
+                .any(|def| def.contains(name))
+        })
+        .map(|(_, file)| file);
+    let imports: String = files
+        .iter()
+        .chain(well_known)
+        .map(|file| format!("import \"{file}\";\n"))
+        .collect();
+    if imports.is_empty() {
+        imports
+    } else {
+        format!("\n{imports}")
+    }
+}
+
 fn rust_type_to_proto_scalar(ty: &syn::Type) -> &'static str {
     // Use exact path-segment matching to avoid false positives on user-defined wrapper types
     // (e.g. `MyI32Wrapper` must not match `i32`, `MyString` must not match `String`).
@@ -329,6 +657,9 @@ fn rust_type_to_proto_scalar(ty: &syn::Type) -> &'static str {
         Some("f32") => "float",
         Some("f64") => "double",
         Some("bool") => "bool",
+        Some("DateTime") | Some("SystemTime") => "google.protobuf.Timestamp",
+        Some("Duration") => "google.protobuf.Duration",
+        _ if matches!(ty, syn::Type::Tuple(t) if t.elems.is_empty()) => "google.protobuf.Empty",
         _ => {
             // NOTE: unknown type mapping — if this type should map to a specific proto type,
             // add it to rust_type_to_proto_scalar(). Defaulting to bytes.
@@ -19,6 +19,12 @@
 //! - `list_users` → `GET /users`
 //! - `update_user` → `PUT /users/{id}`
 //!
+//! Names without a known prefix are pluralized like resources (`do_thing` →
+//! `POST /do-things`). `#[http(rpc_style = true)]` maps them to the kebab-cased
+//! method name instead (`do_thing` → `POST /do-thing`).
+//! `#[route(resource = "people")]` replaces the resource segment outright, so
+//! `get_person` maps to `GET /people/{id}` instead of `GET /persons/{id}`.
+//!
 //! # Parameter Binding
 //!
 //! Parameters are automatically bound based on HTTP method:
@@ -111,8 +117,15 @@
 //! # Generated Methods
 //!
 //! - `http_router() -> axum::Router` - Complete router with all endpoints
+//! - `http_router_arc(self: Arc<Self>) -> axum::Router` - The same, sharing an existing `Arc`
+//! - `http_test_client() -> HttpTestClient` - In-process client over `http_router()` for tests
 //! - `http_openapi_paths() -> Vec<OpenApiPath>` - OpenAPI path fragments for composition
+//! - `http_openapi_webhooks() -> Vec<OpenApiPath>` - `#[webhook]` operations, keyed by name
 //! - `http_openapi_spec() -> serde_json::Value` - Full OpenAPI 3.0 spec (unless `openapi = false`)
+//! - `http_collection() -> serde_json::Value` - Postman or Insomnia collection (with `collection = "..."`)
+//!
+//! With `openapi_path = "..."`, `http_router()` also answers GET on that path with
+//! the spec, and `docs_path = "..."` adds a Swagger UI page for it.
 //!
 //! # Example
 //!
@@ -148,14 +161,20 @@
 use heck::ToSnakeCase;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote};
-use server_less_parse::{MethodInfo, extract_methods, get_impl_name, partition_methods};
+use server_less_parse::{
+    MethodInfo, MethodVisibility, ParamInfo, extract_methods_with_visibility, get_impl_name,
+    partition_methods,
+};
 use syn::{GenericArgument, ItemImpl, PathArguments, Token, Type, parse::Parse};
 
 use crate::app::extract_app_meta;
 use crate::server_attrs::{has_server_hidden, has_server_skip, validate_server_attrs};
 
 // Import Context helpers
-use crate::context::{generate_http_context_extraction, partition_context_params};
+use crate::context::{
+    generate_http_context_extraction, is_cancellation_token, is_inject, is_raw_json,
+    partition_context_params,
+};
 
 use server_less_parse::HttpMethod;
 
@@ -180,6 +199,10 @@ pub(crate) struct HttpArgs {
     pub prefix: Option<String>,
     /// Whether to generate OpenAPI spec (default: true)
     pub openapi: Option<bool>,
+    /// Route in `http_router()` serving `http_openapi_spec()` as JSON (default: none)
+    pub openapi_path: Option<syn::LitStr>,
+    /// Route in `http_router()` serving a Swagger UI page for `openapi_path` (default: none)
+    pub docs_path: Option<syn::LitStr>,
     /// Application name (used as OpenAPI info.title, overrides struct name)
     pub name: Option<String>,
     /// Human-readable description (used as OpenAPI info.description)
@@ -198,11 +221,38 @@ pub(crate) struct HttpArgs {
     /// showing the parameter name and its `{:?}` value. Set on the impl block to enable for
     /// all methods, or on a specific method via `#[http(trace = true)]`.
     pub trace: bool,
+    /// Which methods become routes (`visibility = "pub"` ignores private methods;
+    /// default: all)
+    pub visibility: MethodVisibility,
+    /// Whether methods without a known verb prefix map to `/<kebab-method-name>`
+    /// instead of a pluralized resource path (default: false)
+    pub rpc_style: bool,
+    /// Whether successful non-streaming JSON responses are wrapped in
+    /// `{"data": ...}` (default: false)
+    pub envelope: bool,
+    /// Whether JSON response bodies are indented (default: false)
+    pub pretty: bool,
+    /// Whether `null` object fields are left out of JSON response bodies
+    /// (default: false)
+    pub skip_nulls: bool,
+    /// Whether `create_*` methods answer `201 Created` with a `Location` header
+    /// naming the created resource (default: false)
+    pub created_location: bool,
+    /// Request collection format returned by `http_collection()`: `"postman"`
+    /// or `"insomnia"` (default: none)
+    pub collection: Option<String>,
+    /// Status answered when a path, query, header or body parameter cannot be
+    /// deserialized (default: 400)
+    pub deserialize_error_status: Option<u16>,
+    /// Media type of JSON request and response bodies, e.g. a vendor type like
+    /// `"application/vnd.myapi.v1+json"` (default: `application/json`)
+    pub content_type: Option<String>,
 }
 
 impl Parse for HttpArgs {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let mut args = HttpArgs::default();
+        let mut created_location_span = None;
 
         while !input.is_empty() {
             let ident: syn::Ident = input.parse()?;
@@ -211,7 +261,7 @@ impl Parse for HttpArgs {
                 "prefix" => {
                     input.parse::<Token![=]>()?;
                     let lit: syn::LitStr = input.parse()?;
-                    args.prefix = Some(lit.value());
+                    args.prefix = Some(crate::openapi_gen::normalize_prefix(&lit)?);
                 }
                 "openapi" => {
                     if input.peek(Token![=]) {
@@ -223,6 +273,14 @@ impl Parse for HttpArgs {
                         args.openapi = Some(true);
                     }
                 }
+                "openapi_path" => {
+                    input.parse::<Token![=]>()?;
+                    args.openapi_path = Some(input.parse()?);
+                }
+                "docs_path" => {
+                    input.parse::<Token![=]>()?;
+                    args.docs_path = Some(input.parse()?);
+                }
                 "debug" => {
                     input.parse::<Token![=]>()?;
                     let lit: syn::LitBool = input.parse()?;
@@ -253,9 +311,103 @@ impl Parse for HttpArgs {
                     let lit: syn::LitStr = input.parse()?;
                     args.homepage = Some(lit.value());
                 }
+                "visibility" => {
+                    input.parse::<Token![=]>()?;
+                    let lit: syn::LitStr = input.parse()?;
+                    args.visibility = MethodVisibility::parse_lit(&lit)?;
+                }
+                "rpc_style" => {
+                    input.parse::<Token![=]>()?;
+                    let lit: syn::LitBool = input.parse()?;
+                    args.rpc_style = lit.value();
+                }
+                "envelope" => {
+                    input.parse::<Token![=]>()?;
+                    let lit: syn::LitBool = input.parse()?;
+                    args.envelope = lit.value();
+                }
+                "pretty" => {
+                    input.parse::<Token![=]>()?;
+                    let lit: syn::LitBool = input.parse()?;
+                    args.pretty = lit.value();
+                }
+                "skip_nulls" => {
+                    input.parse::<Token![=]>()?;
+                    let lit: syn::LitBool = input.parse()?;
+                    args.skip_nulls = lit.value();
+                }
+                "created_location" => {
+                    input.parse::<Token![=]>()?;
+                    let lit: syn::LitBool = input.parse()?;
+                    args.created_location = lit.value();
+                    created_location_span = Some(ident.span());
+                }
+                "collection" => {
+                    input.parse::<Token![=]>()?;
+                    let lit: syn::LitStr = input.parse()?;
+                    const FORMATS: &[&str] = &["postman", "insomnia"];
+                    let format = lit.value();
+                    if !FORMATS.contains(&format.as_str()) {
+                        let suggestion = crate::did_you_mean(&format, FORMATS)
+                            .map(|s| format!(" — did you mean `{s}`?"))
+                            .unwrap_or_default();
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            format!(
+                                "unknown collection format `{format}`{suggestion}\n\
+                                 \n\
+                                 Supported formats: postman, insomnia"
+                            ),
+                        ));
+                    }
+                    args.collection = Some(format);
+                }
+                "deserialize_error_status" => {
+                    input.parse::<Token![=]>()?;
+                    let lit: syn::LitInt = input.parse()?;
+                    let status: u16 = lit.base10_parse()?;
+                    if !(400..=499).contains(&status) {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            format!(
+                                "`deserialize_error_status` must be a 4xx status, got {status}\n\
+                                 \n\
+                                 Hint: 400 (the default) or 422 are the usual choices"
+                            ),
+                        ));
+                    }
+                    args.deserialize_error_status = Some(status);
+                }
+                "content_type" => {
+                    input.parse::<Token![=]>()?;
+                    let lit: syn::LitStr = input.parse()?;
+                    let content_type = lit.value();
+                    let essence = content_type.split(';').next().unwrap_or("").trim();
+                    let is_json = essence == "application/json"
+                        || (essence.starts_with("application/") && essence.ends_with("+json"));
+                    let printable = content_type
+                        .bytes()
+                        .all(|b| b == b' ' || b.is_ascii_graphic());
+                    if !is_json || !printable {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            format!(
+                                "`content_type` must be a JSON media type, got `{content_type}`\n\
+                                 \n\
+                                 Hint: application/json or a vendor type ending in +json, e.g. \
+                                 \"application/vnd.myapi.v1+json\""
+                            ),
+                        ));
+                    }
+                    args.content_type = Some(content_type);
+                }
                 other => {
-                    const VALID: &[&str] =
-                        &["prefix", "openapi", "name", "description", "version", "homepage", "debug", "trace"];
+                    const VALID: &[&str] = &[
+                        "prefix", "openapi", "openapi_path", "docs_path", "name", "description",
+                        "version", "homepage", "debug", "trace", "visibility", "rpc_style",
+                        "envelope", "pretty", "skip_nulls", "created_location",
+                        "collection", "deserialize_error_status", "content_type",
+                    ];
                     let suggestion = crate::did_you_mean(other, VALID)
                         .map(|s| format!(" — did you mean `{s}`?"))
                         .unwrap_or_default();
@@ -263,10 +415,19 @@ impl Parse for HttpArgs {
                         ident.span(),
                         format!(
                             "unknown argument `{other}`{suggestion}\n\
-                             Valid arguments: prefix, openapi, name, description, version, homepage, debug, trace\n\
+                             Valid arguments: prefix, openapi, openapi_path, docs_path, name, description, version, homepage, debug, trace, visibility, rpc_style, envelope, pretty, skip_nulls, created_location, collection, deserialize_error_status, content_type\n\
                              Examples:\n\
                              - #[http(prefix = \"/api/v1\")]\n\
                              - #[http(openapi = false)]\n\
+                             - #[http(openapi_path = \"/openapi.json\", docs_path = \"/docs\")]\n\
+                             - #[http(visibility = \"pub\")]\n\
+                             - #[http(rpc_style = true)]\n\
+                             - #[http(envelope = true)]\n\
+                             - #[http(pretty = true, skip_nulls = true)]\n\
+                             - #[http(created_location = true)]\n\
+                             - #[http(collection = \"postman\")]\n\
+                             - #[http(deserialize_error_status = 422)]\n\
+                             - #[http(content_type = \"application/vnd.myapi.v1+json\")]\n\
                              - #[http(name = \"My API\", description = \"Does the thing\")]\n\
                              - #[http(debug = true)]\n\
                              \n\
@@ -281,15 +442,30 @@ impl Parse for HttpArgs {
             }
         }
 
+        // RPC-style routes post to `/{method-name}`, so there is no collection
+        // for the created resource to live under.
+        if let Some(span) = created_location_span.filter(|_| args.created_location && args.rpc_style)
+        {
+            return Err(syn::Error::new(
+                span,
+                "created_location = true cannot be combined with rpc_style = true: \
+                 RPC-style routes have no collection path to point `Location` at",
+            ));
+        }
+
         Ok(args)
     }
 }
 
-/// Strip `#[param]`, `#[route]`, `#[response]`, and per-method `#[http]` attributes
+/// Strip `#[param]`, `#[route]`, `#[response]`, `#[webhook]` and per-method `#[http]` attributes
 /// from the impl block before re-emitting it, so rustc does not encounter unknown
 /// or macro attributes on function parameters / methods in the generated output.
+///
+/// `#[webhook]` stays while a stacked sibling still needs to skip those methods.
 fn strip_http_attrs(impl_block: &ItemImpl) -> ItemImpl {
     let mut block = impl_block.clone();
+    crate::strip_webhook_attrs(&mut block);
+    crate::strip_param_attrs(&mut block);
     for item in &mut block.items {
         if let syn::ImplItem::Fn(method) = item {
             // Strip method-level HTTP attributes (route, response, and per-method http).
@@ -298,19 +474,46 @@ fn strip_http_attrs(impl_block: &ItemImpl) -> ItemImpl {
                     && !attr.path().is_ident("response")
                     && !attr.path().is_ident("http")
             });
-            // Strip #[param(...)] from function parameters.
-            for input in &mut method.sig.inputs {
-                if let syn::FnArg::Typed(pat_type) = input {
-                    pat_type.attrs.retain(|attr| !attr.path().is_ident("param"));
+        }
+    }
+    block
+}
+
+/// `#[grpc(http_annotations = true)]` reads this `#[http]` attribute, which is
+/// only still there when `#[grpc]` expands first, i.e. is placed above it.
+/// Seeing it below means its annotations would silently ignore our arguments.
+fn check_grpc_http_annotations_order(impl_block: &ItemImpl) -> syn::Result<()> {
+    for attr in &impl_block.attrs {
+        if !attr.path().is_ident("grpc") || !matches!(attr.meta, syn::Meta::List(_)) {
+            continue;
+        }
+        let mut annotations = None;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("http_annotations") {
+                let lit: syn::LitBool = meta.value()?.parse()?;
+                if lit.value() {
+                    annotations = Some(lit.span());
                 }
+            } else if meta.input.peek(Token![=]) {
+                // Other #[grpc] arguments are validated by #[grpc] itself.
+                let _: syn::Lit = meta.value()?.parse()?;
             }
+            Ok(())
+        })?;
+        if let Some(span) = annotations {
+            return Err(syn::Error::new(
+                span,
+                "#[grpc(http_annotations = true)] must be placed above #[http] to read its \
+                 routes\n\
+                 \n\
+                 Hint: move #[grpc(...)] before #[http(...)] on this impl block",
+            ));
         }
     }
-    block
+    Ok(())
 }
 
 pub(crate) fn expand_http(args: HttpArgs, mut impl_block: ItemImpl) -> syn::Result<TokenStream2> {
-    crate::reject_generic_impl(&impl_block)?;
     let app_meta = extract_app_meta(&mut impl_block.attrs);
     let args = HttpArgs {
         name: args.name.or(app_meta.name),
@@ -320,12 +523,24 @@ pub(crate) fn expand_http(args: HttpArgs, mut impl_block: ItemImpl) -> syn::Resu
         ..args
     };
 
+    check_grpc_http_annotations_order(&impl_block)?;
+
     let struct_name = get_impl_name(&impl_block)?;
     let (impl_generics, _ty_generics, where_clause) = impl_block.generics.split_for_impl();
     let self_ty = &impl_block.self_ty;
-    let methods = extract_methods(&impl_block)?;
+    let mut methods = extract_methods_with_visibility(&impl_block, args.visibility)?;
 
     let prefix = args.prefix.unwrap_or_default();
+    crate::openapi_gen::bind_prefix_params(&prefix, &mut methods);
+    let rpc_style = args.rpc_style;
+    let created_location = args.created_location;
+    let deserialize_error_status = args.deserialize_error_status.unwrap_or(400);
+    let content_type = args.content_type;
+    let json_format = JsonFormat {
+        envelope: args.envelope,
+        pretty: args.pretty,
+        skip_nulls: args.skip_nulls,
+    };
     let generate_openapi = args.openapi.unwrap_or(true);
     let impl_debug = args.debug;
     let impl_trace = args.trace;
@@ -348,6 +563,9 @@ pub(crate) fn expand_http(args: HttpArgs, mut impl_block: ItemImpl) -> syn::Resu
     }
     let partitioned = partition_methods(&methods, has_server_skip);
 
+    // `(verb, path, method)` rows for `SERVER_LESS_ROUTES=1`
+    let mut route_table: Vec<(String, String, String)> = Vec::new();
+
     // Generate mount routes (static mounts only)
     let mut mount_routes = Vec::new();
     let mut mount_openapi_calls = Vec::new();
@@ -361,6 +579,11 @@ pub(crate) fn expand_http(args: HttpArgs, mut impl_block: ItemImpl) -> syn::Resu
                 "BUG: mount method must have a reference return type (&T)",
             )
         })?;
+        route_table.push((
+            "*".to_string(),
+            format!("{mount_path}/..."),
+            format!("{method_name} (mount)"),
+        ));
 
         mount_routes.push(quote! {
             .nest_service(#mount_path, <#inner_ty as ::server_less::HttpMount>::http_mount_router(
@@ -384,15 +607,63 @@ pub(crate) fn expand_http(args: HttpArgs, mut impl_block: ItemImpl) -> syn::Resu
     // Maps normalized route signature (e.g., "GET /users/{*}") to (method_name, original_path)
     let mut route_signatures: std::collections::HashMap<String, (String, String)> =
         std::collections::HashMap::new();
+    // Maps OpenAPI operationId to the method that claims it
+    let mut operation_ids: std::collections::HashMap<String, String> =
+        std::collections::HashMap::new();
+    // `#[webhook]` methods: documented, never routed
+    let mut webhook_methods: Vec<(MethodInfo, RouteOverride)> = Vec::new();
+    let mut webhook_names: std::collections::HashMap<String, String> =
+        std::collections::HashMap::new();
+    // `(verb, path, method, allow_duplicate)` of every unconditional route, for
+    // the shadowing check
+    let mut route_verbs: Vec<(String, String, &MethodInfo, bool)> = Vec::new();
 
     for method in &partitioned.leaf {
         let overrides = RouteOverride::parse_from_attrs(&method.method.attrs)?;
-        let response_overrides = ResponseOverride::parse_from_attrs(&method.method.attrs)?;
+        let mut response_overrides = ResponseOverride::parse_from_attrs(&method.method.attrs)?;
 
         if overrides.skip {
             continue;
         }
 
+        if overrides.webhook {
+            let name = overrides
+                .webhook_name
+                .clone()
+                .unwrap_or_else(|| method.name_str());
+            if let Some(existing_method) = webhook_names.get(&name) {
+                return Err(syn::Error::new_spanned(
+                    &method.method.sig,
+                    format!(
+                        "Duplicate webhook `{name}`: already used by method '{existing_method}'\n\
+                         \n\
+                         Hint: give one of them a distinct #[webhook(name = \"...\")]"
+                    ),
+                ));
+            }
+            webhook_names.insert(name, method.name_str());
+            if !overrides.hidden && !has_server_hidden(method) {
+                webhook_methods.push(((*method).clone(), overrides));
+            }
+            continue;
+        }
+
+        let prefix_params = crate::openapi_gen::prefix_param_names(&prefix);
+        if let Some((_, name)) = prefix_params.iter().enumerate().find(|(position, _)| {
+            !method.params.iter().any(|p| p.prefix_position == Some(*position))
+        }) {
+            return Err(syn::Error::new_spanned(
+                &method.method.sig,
+                format!(
+                    "'{}' does not bind the prefix parameter `{{{name}}}` of `{prefix}`\n\
+                     \n\
+                     Hint: add a `{name}` parameter (or one with #[param(name = \"{name}\")]); \
+                     every route under the prefix receives it",
+                    method.name
+                ),
+            ));
+        }
+
         // Check for duplicate routes
         let http_method_enum = if let Some(ref m) = overrides.method {
             match m.as_str() {
@@ -426,7 +697,13 @@ pub(crate) fn expand_http(args: HttpArgs, mut impl_block: ItemImpl) -> syn::Resu
         let path = if let Some(ref p) = overrides.path {
             p.clone()
         } else {
-            infer_path(&method.name_str(), &http_method_enum, &method.params)
+            infer_path(
+                &method.name_str(),
+                &http_method_enum,
+                &method.params,
+                rpc_style,
+                overrides.resource.as_deref(),
+            )
         };
         let full_path = format!("{}{}", prefix, path);
 
@@ -434,7 +711,11 @@ pub(crate) fn expand_http(args: HttpArgs, mut impl_block: ItemImpl) -> syn::Resu
         let normalized_path = normalize_path_for_duplicate_check(&full_path);
         let route_sig = format!("{} {}", http_method_enum.as_str(), normalized_path);
 
-        if let Some((existing_method, existing_path)) = route_signatures.get(&route_sig) {
+        // `#[route(allow_duplicate)]` opts a method out on either side of the
+        // check, e.g. for methods behind mutually exclusive `#[cfg]`s.
+        if !overrides.allow_duplicate
+            && let Some((existing_method, existing_path)) = route_signatures.get(&route_sig)
+        {
             let hint_msg = if existing_path != &full_path {
                 format!(
                     "Duplicate route: {} {} is structurally identical to {} defined by method '{}'\n\
@@ -446,7 +727,8 @@ pub(crate) fn expand_http(args: HttpArgs, mut impl_block: ItemImpl) -> syn::Resu
                      Hint: You can either:\n\
                      1. Use #[route(skip)] to exclude one method from HTTP routing\n\
                      2. Use #[route(path = \"/custom\")] to use a completely different path\n\
-                     3. Use #[route(method = \"PATCH\")] to use a different HTTP method",
+                     3. Use #[route(method = \"PATCH\")] to use a different HTTP method\n\
+                     4. Use #[route(allow_duplicate)] if the two are never compiled together",
                     http_method_enum.as_str(),
                     full_path,
                     existing_path,
@@ -463,7 +745,8 @@ pub(crate) fn expand_http(args: HttpArgs, mut impl_block: ItemImpl) -> syn::Resu
                      Hint: You can either:\n\
                      1. Use #[route(skip)] to exclude this method from HTTP routing\n\
                      2. Use #[route(path = \"/custom\")] to use a different path\n\
-                     3. Use #[route(method = \"PATCH\")] to use a different HTTP method",
+                     3. Use #[route(method = \"PATCH\")] to use a different HTTP method\n\
+                     4. Use #[route(allow_duplicate)] if the two are never compiled together",
                     http_method_enum.as_str(),
                     full_path,
                     existing_method
@@ -472,29 +755,173 @@ pub(crate) fn expand_http(args: HttpArgs, mut impl_block: ItemImpl) -> syn::Resu
 
             return Err(syn::Error::new_spanned(&method.method.sig, hint_msg));
         }
-        route_signatures.insert(
-            route_sig.clone(),
-            (method.name_str(), full_path.clone()),
+        if !overrides.allow_duplicate {
+            route_signatures.insert(route_sig.clone(), (method.name_str(), full_path.clone()));
+        }
+        if method.cfg_attrs.is_empty() {
+            route_docs.push(format!("- `{}`", route_sig));
+        } else {
+            route_docs.push(format!("- `{}` (under `#[cfg]`)", route_sig));
+        }
+        route_table.push((
+            http_method_enum.as_str().to_string(),
+            full_path.clone(),
+            method.name_str(),
+        ));
+        if method.cfg_attrs.is_empty() {
+            route_verbs.push((
+                http_method_enum.as_str().to_string(),
+                full_path.clone(),
+                method,
+                overrides.allow_duplicate,
+            ));
+        }
+
+        let operation_id = overrides
+            .operation_id
+            .clone()
+            .unwrap_or_else(|| method.name_str());
+        if let Some(existing_method) = operation_ids.get(&operation_id) {
+            return Err(syn::Error::new_spanned(
+                &method.method.sig,
+                format!(
+                    "Duplicate operationId `{operation_id}`: already used by method '{existing_method}'\n\
+                     \n\
+                     Hint: give one of them a distinct #[route(operation_id = \"...\")]"
+                ),
+            ));
+        }
+        operation_ids.insert(operation_id, method.name_str());
+
+        if let Some(span) = overrides.ranges_span {
+            if !server_less_rpc::returns_bytes(&method.return_info) {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "#[route(ranges)] needs a method returning bytes, but '{}' does not\n\
+                         \n\
+                         Hint: return Vec<u8> or Bytes (optionally in Option or Result)",
+                        method.name
+                    ),
+                ));
+            }
+            if response_overrides.status.is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    "#[route(ranges)] answers 200 or 206 itself and cannot be combined \
+                     with #[response(status = ...)]",
+                ));
+            }
+        }
+
+        if response_overrides.status.is_some()
+            && server_less_rpc::returns_status_tuple(&method.return_info)
+        {
+            return Err(syn::Error::new_spanned(
+                &method.method.sig.output,
+                format!(
+                    "'{}' returns its own StatusCode and cannot also use #[response(status = ...)]\n\
+                     \n\
+                     Hint: drop the attribute, or return the body alone",
+                    method.name
+                ),
+            ));
+        }
+
+        if let Some(span) = overrides.sse_buffer_span
+            && !method.return_info.is_stream
+        {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "#[route(sse_buffer = ...)] needs a method returning a stream, but '{}' does not\n\
+                     \n\
+                     Hint: return `impl Stream<Item = T>`; iterators are already sent as the client reads",
+                    method.name
+                ),
+            ));
+        }
+
+        if let Some(span) = overrides.stream_span {
+            if !method.return_info.is_stream && !method.return_info.is_iterator {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "#[route(stream = ...)] needs a method returning a stream or iterator, but '{}' does not\n\
+                         \n\
+                         Hint: return `impl Stream<Item = T>` or `impl Iterator<Item = T>`",
+                        method.name
+                    ),
+                ));
+            }
+            if overrides.ndjson && overrides.sse_buffer.is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    "#[route(sse_buffer = ...)] buffers SSE events and cannot be combined \
+                     with #[route(stream = \"ndjson\")]",
+                ));
+            }
+        }
+
+        if created_location {
+            crate::openapi_gen::apply_created_location(
+                method,
+                &http_method_enum,
+                &mut response_overrides,
+            );
+        }
+        // A configured `deserialize_error_status` is documented on every method
+        // reading request input.
+        if let Some(status) = args.deserialize_error_status
+            && method
+                .params
+                .iter()
+                .any(|p| !crate::context::is_framework_param(p, &method.params))
+        {
+            response_overrides.invalid_input_status = Some(status);
+        }
+        let ret = &method.return_info;
+
+        crate::openapi_gen::apply_content_type(
+            ret,
+            content_type.as_deref(),
+            &mut response_overrides,
         );
-        route_docs.push(format!("- `{}`", route_sig));
+
+        if let Some(span) = overrides.timeout_span
+            && !method.is_async
+        {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "#[route(timeout = ...)] needs an async method, but '{}' is not\n\
+                     \n\
+                     Hint: a synchronous method blocks until it returns, so it cannot be timed out",
+                    method.name
+                ),
+            ));
+        }
 
         // Per-method debug flag: method-level `#[http(debug = true)]` OR impl-level flag.
         let method_debug = impl_debug || has_http_debug(method);
         // Per-method trace flag: method-level `#[http(trace = true)]` OR impl-level flag.
         let method_trace = impl_trace || has_http_trace(method);
         let cfg_attrs = &method.cfg_attrs;
-        let raw_handler = generate_handler(&struct_name, self_ty, method, &response_overrides, method_debug, method_trace)?;
+        let raw_handler = generate_handler(&struct_name, self_ty, &impl_block.generics, method, &response_overrides, json_format, overrides.ranges, overrides.sse_buffer, overrides.ndjson, overrides.timeout, deserialize_error_status, method_debug, method_trace)?;
         handlers.push(quote! {
             #(#cfg_attrs)*
             #raw_handler
         });
 
-        let raw_route = generate_route(&prefix, method, &overrides, &struct_name)?;
+        let raw_route = generate_route(&prefix, rpc_style, method, &overrides, &struct_name, &impl_block.generics)?;
         // Emit as a rebinding statement so #[cfg] can be applied per-route.
-        routes.push(quote! {
-            #(#cfg_attrs)*
-            let router = router #raw_route;
-        });
+        routes.push((
+            route_specificity(&full_path),
+            quote! {
+                #(#cfg_attrs)*
+                let router = router #raw_route;
+            },
+        ));
 
         // Always collect for http_openapi_paths() (used by #[openapi] and #[serve])
         // Exclude from OpenAPI if hidden via #[route(hidden)] or #[server(hidden)]
@@ -507,23 +934,65 @@ pub(crate) fn expand_http(args: HttpArgs, mut impl_block: ItemImpl) -> syn::Resu
         }
     }
 
+    check_route_shadowing(&route_verbs)?;
+    // Register static routes before parameter routes (stable, so equally
+    // specific routes keep their source order).
+    routes.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let routes: Vec<TokenStream2> = routes.into_iter().map(|(_, route)| route).collect();
+
+    if std::env::var("SERVER_LESS_ROUTES").as_deref() == Ok("1") {
+        eprint!(
+            "{}",
+            format_route_table(&struct_name.to_string(), &route_table)
+        );
+    }
+
     // Build route documentation
+    let router_doc_head = "Create an axum Router for this service.\n\n\
+        Unmatched paths and wrong verbs get axum's empty 404 and 405. `#[serve]` answers \
+        them with JSON errors; on a hand-built router, attach \
+        `server_less::json_not_found` and `server_less::json_method_not_allowed` once \
+        every router is merged.";
     let router_doc = if route_docs.is_empty() {
-        "Create an axum Router for this service.".to_string()
+        router_doc_head.to_string()
     } else {
-        format!(
-            "Create an axum Router for this service.\n\n# Routes\n\n{}",
-            route_docs.join("\n")
-        )
+        format!("{router_doc_head}\n\n# Routes\n\n{}", route_docs.join("\n"))
     };
 
     // Generate OpenAPI paths method (always available for composition)
-    let openapi_paths_fn =
-        crate::openapi_gen::generate_openapi_paths(&prefix, &openapi_methods)?;
+    let openapi_paths_fn = crate::openapi_gen::generate_openapi_paths(
+        &struct_name,
+        &prefix,
+        rpc_style,
+        content_type.as_deref().unwrap_or("application/json"),
+        args.envelope,
+        &openapi_methods,
+    )?;
     let openapi_paths_doc = format!(
-        "Get OpenAPI paths for this service ({} route{}).",
-        route_docs.len(),
-        if route_docs.len() == 1 { "" } else { "s" }
+        "Get OpenAPI paths for this service ({}).",
+        count_doc(openapi_methods.iter().map(|(m, _, _)| m), "route")
+    );
+    let service_tag = crate::openapi_gen::service_tag(&struct_name.to_string());
+    let webhook_pushes = webhook_methods.iter().map(|(method, overrides)| {
+        let mut overrides = overrides.clone();
+        if overrides.tags.is_empty() {
+            overrides.tags.push(service_tag.clone());
+        }
+        let (name, operation) = crate::openapi_gen::webhook_operation(method, &overrides);
+        let cfg_attrs = &method.cfg_attrs;
+        quote! {
+            #(#cfg_attrs)*
+            webhooks.push(::server_less::OpenApiPath {
+                path: #name.to_string(),
+                method: "post".to_string(),
+                operation: ::server_less::serde_json::from_value(#operation)
+                    .expect("BUG: webhook operation must deserialize as OpenApiOperation"),
+            });
+        }
+    });
+    let openapi_webhooks_doc = format!(
+        "Get OpenAPI 3.1 webhooks for this service ({}), keyed by name in `path`.",
+        count_doc(webhook_methods.iter().map(|(m, _)| m), "webhook")
     );
     let openapi_paths_method = quote! {
         #[doc = #openapi_paths_doc]
@@ -532,6 +1001,14 @@ pub(crate) fn expand_http(args: HttpArgs, mut impl_block: ItemImpl) -> syn::Resu
             #(#mount_openapi_calls)*
             paths
         }
+
+        #[doc = #openapi_webhooks_doc]
+        pub fn http_openapi_webhooks() -> ::std::vec::Vec<::server_less::OpenApiPath> {
+            #[allow(unused_mut)]
+            let mut webhooks = ::std::vec::Vec::new();
+            #(#webhook_pushes)*
+            webhooks
+        }
     };
 
     // Conditionally generate OpenAPI spec method.
@@ -540,9 +1017,27 @@ pub(crate) fn expand_http(args: HttpArgs, mut impl_block: ItemImpl) -> syn::Resu
         let openapi_doc = "Get HTTP-only OpenAPI 3.0 specification for this service.\n\n\
              Includes all paths (own + mounted children). Use `http_openapi_paths()` for composable path fragments.\n\
              For multi-protocol specs, use the `openapi_spec()` method generated by `#[serve]` or `#[openapi]`.";
-        quote! {
-            #[doc = #openapi_doc]
-            pub fn http_openapi_spec() -> ::server_less::serde_json::Value {
+        // Webhooks are an OpenAPI 3.1 feature.
+        let (spec_version, webhooks_collect, webhooks_entry) = if webhook_methods.is_empty() {
+            ("3.0.0", quote! {}, quote! {})
+        } else {
+            (
+                "3.1.0",
+                quote! {
+                    let mut webhooks = ::server_less::serde_json::Map::new();
+                    for webhook in Self::http_openapi_webhooks() {
+                        let op = ::server_less::serde_json::to_value(&webhook.operation)
+                            .expect("BUG: OpenApiOperation must be serializable");
+                        webhooks.insert(webhook.path, ::server_less::serde_json::json!({ "post": op }));
+                    }
+                },
+                quote! { , "webhooks": webhooks },
+            )
+        };
+        let spec_body = crate::openapi_gen::memoize_spec(
+            &impl_block.generics,
+            quote! {
+                #webhooks_collect
                 let mut paths = ::server_less::serde_json::Map::new();
                 for path_info in Self::http_openapi_paths() {
                     let path_item = paths.entry(path_info.path.clone())
@@ -554,7 +1049,7 @@ pub(crate) fn expand_http(args: HttpArgs, mut impl_block: ItemImpl) -> syn::Resu
                     }
                 }
                 ::server_less::serde_json::json!({
-                    "openapi": "3.0.0",
+                    "openapi": #spec_version,
                     "info": {
                         "title": #openapi_title,
                         "version": #openapi_version
@@ -562,13 +1057,45 @@ pub(crate) fn expand_http(args: HttpArgs, mut impl_block: ItemImpl) -> syn::Resu
                         #openapi_contact_entry
                     },
                     "paths": paths
+                    #webhooks_entry
                 })
+            },
+        );
+        quote! {
+            #[doc = #openapi_doc]
+            pub fn http_openapi_spec() -> ::server_less::serde_json::Value {
+                #spec_body
             }
         }
     } else {
         quote! {}
     };
 
+    let collection_method = args.collection.as_deref().map(|format| {
+        let builder = format_ident!("{format}_collection");
+        let label = match format {
+            "postman" => "Postman",
+            _ => "Insomnia",
+        };
+        let doc = format!(
+            "Get a {label} collection with one request per route, filled in with sample parameters."
+        );
+        quote! {
+            #[doc = #doc]
+            pub fn http_collection() -> ::server_less::serde_json::Value {
+                ::server_less::#builder(#openapi_title, &Self::http_openapi_paths())
+            }
+        }
+    });
+
+    let spec_routes = generate_spec_routes(
+        &impl_block,
+        args.openapi_path.as_ref(),
+        args.docs_path.as_ref(),
+        generate_openapi,
+        &openapi_title,
+    )?;
+
     let clean_impl = if crate::is_protocol_impl_emitter(&impl_block, "http") {
         let stripped = strip_http_attrs(&impl_block);
         quote! { #stripped }
@@ -579,6 +1106,8 @@ pub(crate) fn expand_http(args: HttpArgs, mut impl_block: ItemImpl) -> syn::Resu
     Ok(quote! {
         #clean_impl
 
+        // Handlers call the user's methods, which may be #[deprecated] on purpose.
+        #[allow(deprecated)]
         impl #impl_generics ::server_less::HttpMount for #self_ty #where_clause {
             fn http_mount_router(self: ::std::sync::Arc<Self>) -> ::server_less::axum::Router {
                 use ::server_less::axum::routing::{get, post, put, patch, delete};
@@ -597,37 +1126,188 @@ pub(crate) fn expand_http(args: HttpArgs, mut impl_block: ItemImpl) -> syn::Resu
             }
         }
 
+        #[allow(deprecated)]
         impl #impl_generics #self_ty #where_clause {
             #[doc = #router_doc]
             pub fn http_router(self) -> ::server_less::axum::Router
             where
                 Self: Clone + Send + Sync + 'static,
+            {
+                Self::http_router_arc(::std::sync::Arc::new(self))
+            }
+
+            /// Like `http_router()`, but sharing an existing `Arc` of the service,
+            /// so several routers can be built without cloning the service itself.
+            pub fn http_router_arc(self: ::std::sync::Arc<Self>) -> ::server_less::axum::Router
+            where
+                Self: Send + Sync + 'static,
             {
                 use ::server_less::axum::routing::{get, post, put, patch, delete};
 
-                let state = ::std::sync::Arc::new(self);
+                let state = self;
                 let router = ::server_less::axum::Router::new();
                 #(#routes)*
+                #spec_routes
                 let router = router
                     #(#mount_routes)*
                     .with_state(state);
                 router
             }
 
+            /// Add this service's routes to an existing axum Router, for
+            /// composing with hand-written routes.
+            ///
+            /// Panics like `Router::merge` if a route is already registered
+            /// on `router`.
+            pub fn http_routes_into(self, router: ::server_less::axum::Router) -> ::server_less::axum::Router
+            where
+                Self: Clone + Send + Sync + 'static,
+            {
+                router.merge(self.http_router())
+            }
+
+            /// Client sending requests straight to `http_router()`, without a
+            /// network, for integration tests.
+            pub fn http_test_client(self) -> ::server_less::HttpTestClient
+            where
+                Self: Clone + Send + Sync + 'static,
+            {
+                ::server_less::HttpTestClient::new(self.http_router())
+            }
+
             #openapi_paths_method
 
             #openapi_method
+
+            #collection_method
         }
 
         #(#handlers)*
     })
 }
 
+/// The error for an `#[http]` spec route on a path `#[serve]` already serves
+/// its `serves` on.
+fn serve_route_conflict(path: &syn::LitStr, serves: &str) -> syn::Error {
+    let hint = if serves == "combined spec" {
+        "pick another path, or use #[serve(http, openapi = false)]"
+    } else {
+        "pick another path; #[serve(http)] registers this one itself"
+    };
+    syn::Error::new(
+        path.span(),
+        format!(
+            "#[serve] already serves the {serves} at {}\n\
+             \n\
+             Hint: {hint}",
+            path.value()
+        ),
+    )
+}
+
+/// Routes serving the spec (`openapi_path`) and its Swagger UI page (`docs_path`)
+/// from `http_router()`.
+fn generate_spec_routes(
+    impl_block: &ItemImpl,
+    openapi_path: Option<&syn::LitStr>,
+    docs_path: Option<&syn::LitStr>,
+    generate_openapi: bool,
+    title: &str,
+) -> syn::Result<TokenStream2> {
+    if let Some(docs) = docs_path
+        && openapi_path.is_none()
+    {
+        return Err(syn::Error::new(
+            docs.span(),
+            "docs_path needs a spec route to point at\n\
+             \n\
+             Hint: add openapi_path, e.g. #[http(openapi_path = \"/openapi.json\", docs_path = \"/docs\")]",
+        ));
+    }
+    let Some(spec_path) = openapi_path else {
+        return Ok(quote! {});
+    };
+    if !generate_openapi {
+        return Err(syn::Error::new(
+            spec_path.span(),
+            "openapi_path requires the OpenAPI spec, but openapi = false\n\
+             \n\
+             Hint: remove openapi = false, or drop openapi_path",
+        ));
+    }
+    for path in [Some(spec_path), docs_path].into_iter().flatten() {
+        if !path.value().starts_with('/') {
+            return Err(syn::Error::new(
+                path.span(),
+                format!("route path must start with '/', got \"{}\"", path.value()),
+            ));
+        }
+    }
+    if let Some(docs) = docs_path
+        && docs.value() == spec_path.value()
+    {
+        return Err(syn::Error::new(
+            docs.span(),
+            "docs_path is the same as openapi_path",
+        ));
+    }
+
+    // #[serve] registers its own health, spec and other routes next to ours;
+    // two routes on one path make axum panic.
+    for attr in &impl_block.attrs {
+        if !attr.path().is_ident("serve") {
+            continue;
+        }
+        let serve_args = match &attr.meta {
+            syn::Meta::Path(_) => ServeArgs::default(),
+            _ => attr.parse_args::<ServeArgs>()?,
+        };
+        if !serve_args.protocols.iter().any(|p| p == "http") {
+            continue;
+        }
+        let serve_routes = serve_args.fixed_routes();
+        for path in [Some(spec_path), docs_path].into_iter().flatten() {
+            if let Some((_, serves)) = serve_routes.iter().find(|(p, _)| *p == path.value()) {
+                return Err(serve_route_conflict(path, serves));
+            }
+        }
+    }
+
+    let docs_route = match docs_path {
+        Some(docs) => quote! {
+            let router = router.route(
+                #docs,
+                get(|| async {
+                    ::server_less::axum::response::Html(
+                        ::server_less::openapi_docs_html(#title, #spec_path)
+                    )
+                }),
+            );
+        },
+        None => quote! {},
+    };
+    Ok(quote! {
+        let router = router.route(
+            #spec_path,
+            get(|| async { ::server_less::axum::Json(Self::http_openapi_spec()) }),
+        );
+        #docs_route
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
 fn generate_handler(
     struct_name: &syn::Ident,
     self_ty: &syn::Type,
+    generics: &syn::Generics,
     method: &MethodInfo,
     response_overrides: &ResponseOverride,
+    json_format: JsonFormat,
+    ranges: bool,
+    sse_buffer: Option<usize>,
+    ndjson: bool,
+    timeout: Option<u64>,
+    deserialize_error_status: u16,
     debug: bool,
     trace: bool,
 ) -> syn::Result<TokenStream2> {
@@ -637,9 +1317,17 @@ fn generate_handler(
     let struct_name_snake = struct_name.to_string().to_snake_case();
     let handler_name = format_ident!("__server_less_http_{}_{}", struct_name_snake, method_name);
     let method_name_str = method_name.to_string();
-
-    let (param_extractions, param_pre_stmts, param_calls, param_names) =
-        generate_param_handling(method)?;
+    let generics = crate::handler_generics(generics, self_ty);
+    let (impl_generics, _ty_generics, where_clause) = generics.split_for_impl();
+
+    let (mut param_extractions, param_pre_stmts, param_calls, param_names) =
+        generate_param_handling(method, deserialize_error_status)?;
+    if response_overrides.created_location {
+        param_extractions.insert(
+            0,
+            quote! { __sl_uri: ::server_less::axum::extract::OriginalUri },
+        );
+    }
 
     // When tracing is enabled, bind each user-visible parameter to a named local variable
     // (`__sl_param_{name}`) so we can log its value immediately after extraction.
@@ -678,7 +1366,19 @@ fn generate_handler(
         (method_call, Vec::new())
     };
 
-    let response = generate_response_handling(method, &call, response_overrides)?;
+    let response = generate_response_handling(
+        method,
+        &call,
+        response_overrides,
+        json_format,
+        ranges,
+        sse_buffer,
+        ndjson,
+    )?;
+    // `#[route(ranges)]` reads the request's `Range` header.
+    let range_extraction = ranges.then(|| {
+        quote! { __sl_range_headers: ::server_less::axum::http::HeaderMap, }
+    });
 
     // When pre_stmts exist they contain early returns of type `Response<Body>`, so the final
     // expression must also return that same concrete type — otherwise Rust's type checker rejects
@@ -691,13 +1391,26 @@ fn generate_handler(
     } else {
         response
     };
+    // `#[route(timeout = N)]` races the method against the clock.
+    let response = match timeout {
+        Some(secs) => quote! {
+            ::server_less::http_with_timeout(#secs, async {
+                use ::server_less::axum::response::IntoResponse as _;
+                (#response).into_response()
+            })
+            .await
+        },
+        None => response,
+    };
 
     let handler = if debug {
         quote! {
-            async fn #handler_name(
+            #[allow(deprecated)]
+            async fn #handler_name #impl_generics (
                 state_extractor: ::server_less::axum::extract::State<::std::sync::Arc<#self_ty>>,
+                #range_extraction
                 #(#param_extractions),*
-            ) -> impl ::server_less::axum::response::IntoResponse {
+            ) -> impl ::server_less::axum::response::IntoResponse #where_clause {
                 let state = state_extractor.0;
                 eprintln!("[server-less] {} called", #method_name_str);
                 #(#param_pre_stmts)*
@@ -709,10 +1422,12 @@ fn generate_handler(
         }
     } else if trace {
         quote! {
-            async fn #handler_name(
+            #[allow(deprecated)]
+            async fn #handler_name #impl_generics (
                 state_extractor: ::server_less::axum::extract::State<::std::sync::Arc<#self_ty>>,
+                #range_extraction
                 #(#param_extractions),*
-            ) -> impl ::server_less::axum::response::IntoResponse {
+            ) -> impl ::server_less::axum::response::IntoResponse #where_clause {
                 let state = state_extractor.0;
                 #(#param_pre_stmts)*
                 #(#param_trace_stmts)*
@@ -721,10 +1436,12 @@ fn generate_handler(
         }
     } else {
         quote! {
-            async fn #handler_name(
+            #[allow(deprecated)]
+            async fn #handler_name #impl_generics (
                 state_extractor: ::server_less::axum::extract::State<::std::sync::Arc<#self_ty>>,
+                #range_extraction
                 #(#param_extractions),*
-            ) -> impl ::server_less::axum::response::IntoResponse {
+            ) -> impl ::server_less::axum::response::IntoResponse #where_clause {
                 let state = state_extractor.0;
                 #(#param_pre_stmts)*
                 #response
@@ -793,9 +1510,18 @@ fn has_http_trace(method: &MethodInfo) -> bool {
     false
 }
 
+/// Handler argument binding `var` to the axum extractor `extractor`, whose
+/// rejections answer `INVALID_INPUT` with `invalid_status` (see `HttpInput`).
+fn http_input(var: &syn::Ident, extractor: TokenStream2, invalid_status: u16) -> TokenStream2 {
+    quote! {
+        ::server_less::HttpInput(#var): ::server_less::HttpInput<#extractor, #invalid_status>
+    }
+}
+
 #[allow(clippy::type_complexity)]
 fn generate_param_handling(
     method: &MethodInfo,
+    invalid_status: u16,
 ) -> syn::Result<(Vec<TokenStream2>, Vec<TokenStream2>, Vec<TokenStream2>, Vec<Option<String>>)> {
     use server_less_parse::ParamLocation;
 
@@ -822,13 +1548,74 @@ fn generate_param_handling(
         param_names.push(None); // Context is injected; not user-visible for tracing
     }
 
-    // Group regular parameters by their actual location (respecting overrides)
+    // `Inject<T>` is an axum extractor itself, reading the dependency from the
+    // request extensions.
+    let (inject_params, regular_params): (Vec<_>, Vec<_>) =
+        regular_params.into_iter().partition(|p| is_inject(&p.ty));
+    for param in &inject_params {
+        let ty = &param.ty;
+        let var_ident = format_ident!("__sl_inject_{}", param.name_str());
+        extractions.push(quote! { #var_ident: #ty });
+        calls.push(quote! { #var_ident });
+        param_names.push(None);
+    }
+
+    // `#[param(extractor)]` types are axum extractors used as-is. They go after
+    // the generated extractors (see below), so one of them may read the body.
+    let (extractor_params, regular_params): (Vec<_>, Vec<_>) =
+        regular_params.into_iter().partition(|p| p.is_extractor);
+    let mut user_extractions = Vec::new();
+    for param in &extractor_params {
+        let ty = &param.ty;
+        let var_ident = format_ident!("__sl_extract_{}", param.name_str());
+        user_extractions.push(quote! { #var_ident: #ty });
+        calls.push(quote! { #var_ident });
+        param_names.push(None);
+    }
+
+    // A `CancellationToken` is created per request and cancelled by a guard that
+    // lives as long as the handler future: when the response is ready, or as soon
+    // as axum drops the future because the client went away. Streamed responses
+    // carry the guard until the stream ends (see `generate_response_handling`).
+    let (cancel_params, regular_params): (Vec<_>, Vec<_>) = regular_params
+        .into_iter()
+        .partition(|p| is_cancellation_token(&p.ty));
+    if !cancel_params.is_empty() {
+        pre_stmts.push(quote! {
+            let __sl_cancel = ::server_less::CancellationToken::new();
+            let __sl_cancel_guard = __sl_cancel.clone().drop_guard();
+        });
+    }
+    for _ in &cancel_params {
+        calls.push(quote! { __sl_cancel.clone() });
+        param_names.push(None);
+    }
+
+    // A `RawJson` parameter is the whole body, read by the last extractor.
+    let (raw_params, regular_params): (Vec<_>, Vec<_>) =
+        regular_params.into_iter().partition(|p| is_raw_json(&p.ty));
+    if let Some(extra) = raw_params.get(1) {
+        return Err(syn::Error::new_spanned(
+            &extra.ty,
+            format!(
+                "'{}' takes more than one RawJson parameter, but there is only one request body",
+                method.name
+            ),
+        ));
+    }
+    for _ in &raw_params {
+        calls.push(quote! { __sl_raw_body });
+        param_names.push(None);
+    }
+
+    // Group regular parameters by their actual location (respecting overrides)
     let mut path_params = Vec::new();
     let mut query_params = Vec::new();
     let mut body_params = Vec::new();
     let mut header_params = Vec::new();
 
     for param in regular_params {
+        check_bindable(param)?;
         match param.location.as_ref() {
             Some(ParamLocation::Path) => path_params.push(param),
             Some(ParamLocation::Query) => query_params.push(param),
@@ -847,6 +1634,9 @@ fn generate_param_handling(
         }
     }
 
+    // Prefix parameters come first in the URL, in prefix order.
+    path_params.sort_by_key(|p| p.prefix_position.unwrap_or(usize::MAX));
+
     // Generate path parameter extraction.
     // Axum only allows a single `Path` extractor per handler. For a single path
     // param we use `Path<T>` directly; for multiple we use a `Path<(T1, T2, ...)>`
@@ -855,16 +1645,45 @@ fn generate_param_handling(
         let param = &path_params[0];
         let ty = &param.ty;
         let var_ident = format_ident!("__sl_path_{}", param.name_str());
-        extractions.push(quote! {
-            #var_ident: ::server_less::axum::extract::Path<#ty>
-        });
-        calls.push(quote! { #var_ident.0 });
+        if param.with.is_some() {
+            extractions.push(http_input(
+                &var_ident,
+                quote! { ::server_less::axum::extract::Path<::std::string::String> },
+                invalid_status,
+            ));
+            pre_stmts.push(parse_path_with(
+                param,
+                &var_ident,
+                quote! { &#var_ident.0 },
+                invalid_status,
+            ));
+            calls.push(quote! { #var_ident });
+        } else {
+            extractions.push(http_input(
+                &var_ident,
+                quote! { ::server_less::axum::extract::Path<#ty> },
+                invalid_status,
+            ));
+            calls.push(quote! { #var_ident.0 });
+        }
         param_names.push(Some(param.name_str()));
     } else if path_params.len() > 1 {
-        let types: Vec<_> = path_params.iter().map(|p| &p.ty).collect();
-        extractions.push(quote! {
-            __sl_path_tuple: ::server_less::axum::extract::Path<(#(#types),*)>
-        });
+        // `#[param(with)]` slots are captured as text and parsed below.
+        let types: Vec<_> = path_params
+            .iter()
+            .map(|p| match p.with {
+                Some(_) => quote! { ::std::string::String },
+                None => {
+                    let ty = &p.ty;
+                    quote! { #ty }
+                }
+            })
+            .collect();
+        extractions.push(http_input(
+            &format_ident!("__sl_path_tuple"),
+            quote! { ::server_less::axum::extract::Path<(#(#types),*)> },
+            invalid_status,
+        ));
         // Destructure the tuple into individual bindings so they can be referenced
         // by name in the method call below.
         let var_idents: Vec<_> = path_params
@@ -876,16 +1695,46 @@ fn generate_param_handling(
             #(let #var_idents = __sl_path_tuple.0.#indices;)*
         });
         for (param, var) in path_params.iter().zip(var_idents.iter()) {
+            if param.with.is_some() {
+                pre_stmts.push(parse_path_with(
+                    param,
+                    var,
+                    quote! { &#var },
+                    invalid_status,
+                ));
+            }
             calls.push(quote! { #var });
             param_names.push(Some(param.name_str()));
         }
     }
 
-    // Generate body parameter extraction
+    // Generate body parameter extraction. Only the last extractor may consume
+    // the body, so the `Json` one is added after all the others.
+    let mut body_extraction = None;
+    if let Some(raw) = raw_params.first() {
+        if let Some(param) = body_params.first() {
+            return Err(syn::Error::new_spanned(
+                &param.name,
+                format!(
+                    "'{}' is read from the request body, which the RawJson parameter '{}' already takes\n\
+                     \n\
+                     Hint: move it to the path, query or headers, e.g. #[param(query)]",
+                    param.name, raw.name
+                ),
+            ));
+        }
+        body_extraction = Some(http_input(
+            &format_ident!("__sl_raw_body"),
+            quote! { ::server_less::RawJson },
+            invalid_status,
+        ));
+    }
     if !body_params.is_empty() {
-        extractions.push(quote! {
-            body_extractor: ::server_less::axum::extract::Json<::server_less::serde_json::Value>
-        });
+        body_extraction = Some(http_input(
+            &format_ident!("body_extractor"),
+            quote! { ::server_less::axum::extract::Json<::server_less::serde_json::Value> },
+            invalid_status,
+        ));
 
         // Collect known body field names for unknown-field warnings
         let body_known_names: Vec<String> = body_params
@@ -915,21 +1764,39 @@ fn generate_param_handling(
                 .clone()
                 .unwrap_or_else(|| param.name_str());
             let ty = &param.ty;
-            if param.is_optional {
+            if let Some(ref with) = param.with {
+                let parse = quote! {
+                    v.as_str()
+                        .ok_or_else(|| "expected a JSON string".to_string())
+                        .and_then(|v| #with::parse(v).map_err(|e| e.to_string()))
+                };
+                // An explicit `null` means absent, like a missing field.
+                let lookup = quote! { body_extractor.0.get(#name_str).filter(|v| !v.is_null()) };
+                let (stmt, call) =
+                    bind_with(param, "Request body field", &name_str, lookup, parse, invalid_status);
+                pre_stmts.push(stmt);
+                calls.push(call);
+            } else if param.is_optional {
                 let inner_ty = extract_option_inner(ty).unwrap_or_else(|| ty.clone());
                 let inner_ty_str = quote!(#inner_ty).to_string().replace(" ", "");
                 let var_ident = format_ident!("__sl_opt_{}", param.name_str());
+                let from_value =
+                    server_less_rpc::decimal_from_value(&inner_ty, quote! { v.clone() });
                 pre_stmts.push(quote! {
                     let #var_ident: ::std::option::Option<#inner_ty> = match body_extractor.0.get(#name_str) {
-                        ::std::option::Option::None => ::std::option::Option::None,
-                        ::std::option::Option::Some(v) => match ::server_less::serde_json::from_value::<#inner_ty>(v.clone()) {
+                        // An explicit `null` means absent, like a missing field.
+                        ::std::option::Option::None
+                        | ::std::option::Option::Some(::server_less::serde_json::Value::Null) => ::std::option::Option::None,
+                        ::std::option::Option::Some(v) => match #from_value {
                             ::std::result::Result::Ok(val) => ::std::option::Option::Some(val),
                             ::std::result::Result::Err(_) => {
-                                use ::server_less::axum::response::IntoResponse as _;
-                                return (
-                                    ::server_less::axum::http::StatusCode::BAD_REQUEST,
-                                    format!("Optional body field '{}' has invalid value (expected {})", #name_str, #inner_ty_str),
-                                ).into_response();
+                                return ::server_less::http_error_response(
+                                    #invalid_status,
+                                    ::server_less::ErrorResponse::new(
+                                        ::server_less::ErrorCode::InvalidInput,
+                                        format!("Optional body field '{}' has invalid value (expected {})", #name_str, #inner_ty_str),
+                                    ),
+                                );
                             }
                         }
                     };
@@ -938,17 +1805,20 @@ fn generate_param_handling(
             } else {
                 let ty_str = quote!(#ty).to_string().replace(" ", "");
                 let var_ident = format_ident!("__sl_req_{}", param.name_str());
+                let from_value = server_less_rpc::decimal_from_value(ty, quote! { v.clone() });
                 pre_stmts.push(quote! {
                     let #var_ident: #ty = match body_extractor.0.get(#name_str)
-                        .and_then(|v| ::server_less::serde_json::from_value::<#ty>(v.clone()).ok())
+                        .and_then(|v| #from_value.ok())
                     {
                         ::std::option::Option::Some(v) => v,
                         ::std::option::Option::None => {
-                            use ::server_less::axum::response::IntoResponse as _;
-                            return (
-                                ::server_less::axum::http::StatusCode::BAD_REQUEST,
-                                format!("Request body field '{}' is required (expected {})", #name_str, #ty_str),
-                            ).into_response();
+                            return ::server_less::http_error_response(
+                                #invalid_status,
+                                ::server_less::ErrorResponse::new(
+                                    ::server_less::ErrorCode::InvalidInput,
+                                    format!("Request body field '{}' is required (expected {})", #name_str, #ty_str),
+                                ),
+                            );
                         }
                     };
                 });
@@ -960,9 +1830,13 @@ fn generate_param_handling(
 
     // Generate query parameter extraction
     if !query_params.is_empty() {
-        extractions.push(quote! {
-            query_extractor: ::server_less::axum::extract::Query<::std::collections::HashMap<String, String>>
-        });
+        extractions.push(http_input(
+            &format_ident!("query_extractor"),
+            quote! {
+                ::server_less::axum::extract::Query<::std::collections::HashMap<String, String>>
+            },
+            invalid_status,
+        ));
 
         // Collect known query param names for unknown-param warnings
         let query_known_names: Vec<String> = query_params
@@ -992,21 +1866,31 @@ fn generate_param_handling(
             let ty = &param.ty;
 
             // Handle default values
-            if param.is_optional {
+            if let (Some(with), None) = (&param.with, &param.default_value) {
+                let parse = quote! { #with::parse(v).map_err(|e| e.to_string()) };
+                let lookup = quote! { query_extractor.0.get(#name_str) };
+                let (stmt, call) =
+                    bind_with(param, "Query parameter", &name_str, lookup, parse, invalid_status);
+                pre_stmts.push(stmt);
+                calls.push(call);
+            } else if param.is_optional {
                 let inner_ty = extract_option_inner(ty).unwrap_or_else(|| ty.clone());
                 let inner_ty_str = quote!(#inner_ty).to_string().replace(" ", "");
                 let var_ident = format_ident!("__sl_opt_{}", param.name_str());
+                let parse = parse_str(param, &inner_ty);
                 pre_stmts.push(quote! {
                     let #var_ident: ::std::option::Option<#inner_ty> = match query_extractor.0.get(#name_str) {
                         ::std::option::Option::None => ::std::option::Option::None,
-                        ::std::option::Option::Some(v) => match v.parse::<#inner_ty>() {
+                        ::std::option::Option::Some(v) => match #parse {
                             ::std::result::Result::Ok(val) => ::std::option::Option::Some(val),
                             ::std::result::Result::Err(_) => {
-                                use ::server_less::axum::response::IntoResponse as _;
-                                return (
-                                    ::server_less::axum::http::StatusCode::BAD_REQUEST,
-                                    format!("Optional query parameter '{}' has invalid value (expected {})", #name_str, #inner_ty_str),
-                                ).into_response();
+                                return ::server_less::http_error_response(
+                                    #invalid_status,
+                                    ::server_less::ErrorResponse::new(
+                                        ::server_less::ErrorCode::InvalidInput,
+                                        format!("Optional query parameter '{}' has invalid value (expected {})", #name_str, #inner_ty_str),
+                                    ),
+                                );
                             }
                         }
                     };
@@ -1026,25 +1910,29 @@ fn generate_param_handling(
                         ),
                     )
                 })?;
+                let parse = parse_str(param, ty);
                 calls.push(quote! {
                     query_extractor.0.get(#name_str)
-                        .and_then(|v| v.parse::<#ty>().ok())
+                        .and_then(|v| #parse.ok())
                         .unwrap_or(#default_expr)
                 });
             } else {
                 let ty_str = quote!(#ty).to_string().replace(" ", "");
                 let var_ident = format_ident!("__sl_req_{}", param.name_str());
+                let parse = parse_str(param, ty);
                 pre_stmts.push(quote! {
                     let #var_ident: #ty = match query_extractor.0.get(#name_str)
-                        .and_then(|v| v.parse::<#ty>().ok())
+                        .and_then(|v| #parse.ok())
                     {
                         ::std::option::Option::Some(v) => v,
                         ::std::option::Option::None => {
-                            use ::server_less::axum::response::IntoResponse as _;
-                            return (
-                                ::server_less::axum::http::StatusCode::BAD_REQUEST,
-                                format!("Query parameter '{}' is required (expected {})", #name_str, #ty_str),
-                            ).into_response();
+                            return ::server_less::http_error_response(
+                                #invalid_status,
+                                ::server_less::ErrorResponse::new(
+                                    ::server_less::ErrorCode::InvalidInput,
+                                    format!("Query parameter '{}' is required (expected {})", #name_str, #ty_str),
+                                ),
+                            );
                         }
                     };
                 });
@@ -1072,21 +1960,35 @@ fn generate_param_handling(
                 .unwrap_or_else(|| param.name_str());
             let ty = &param.ty;
 
-            if param.is_optional {
+            if let Some(ref with) = param.with {
+                let parse = quote! {
+                    v.to_str()
+                        .map_err(|e| e.to_string())
+                        .and_then(|v| #with::parse(v).map_err(|e| e.to_string()))
+                };
+                let lookup = quote! { headers.get(#name_str) };
+                let (stmt, call) =
+                    bind_with(param, "Header", &name_str, lookup, parse, invalid_status);
+                pre_stmts.push(stmt);
+                calls.push(call);
+            } else if param.is_optional {
                 let inner_ty = extract_option_inner(ty).unwrap_or_else(|| ty.clone());
                 let inner_ty_str = quote!(#inner_ty).to_string().replace(" ", "");
                 let var_ident = format_ident!("__sl_opt_{}", param.name_str());
+                let parse = parse_str(param, &inner_ty);
                 pre_stmts.push(quote! {
                     let #var_ident: ::std::option::Option<#inner_ty> = match headers.get(#name_str) {
                         ::std::option::Option::None => ::std::option::Option::None,
-                        ::std::option::Option::Some(raw) => match raw.to_str().ok().and_then(|v| v.parse::<#inner_ty>().ok()) {
+                        ::std::option::Option::Some(raw) => match raw.to_str().ok().and_then(|v| #parse.ok()) {
                             ::std::option::Option::Some(val) => ::std::option::Option::Some(val),
                             ::std::option::Option::None => {
-                                use ::server_less::axum::response::IntoResponse as _;
-                                return (
-                                    ::server_less::axum::http::StatusCode::BAD_REQUEST,
-                                    format!("Optional header '{}' has invalid value (expected {})", #name_str, #inner_ty_str),
-                                ).into_response();
+                                return ::server_less::http_error_response(
+                                    #invalid_status,
+                                    ::server_less::ErrorResponse::new(
+                                        ::server_less::ErrorCode::InvalidInput,
+                                        format!("Optional header '{}' has invalid value (expected {})", #name_str, #inner_ty_str),
+                                    ),
+                                );
                             }
                         }
                     };
@@ -1095,18 +1997,21 @@ fn generate_param_handling(
             } else {
                 let ty_str = quote!(#ty).to_string().replace(" ", "");
                 let var_ident = format_ident!("__sl_req_{}", param.name_str());
+                let parse = parse_str(param, ty);
                 pre_stmts.push(quote! {
                     let #var_ident: #ty = match headers.get(#name_str)
                         .and_then(|v| v.to_str().ok())
-                        .and_then(|v| v.parse::<#ty>().ok())
+                        .and_then(|v| #parse.ok())
                     {
                         ::std::option::Option::Some(v) => v,
                         ::std::option::Option::None => {
-                            use ::server_less::axum::response::IntoResponse as _;
-                            return (
-                                ::server_less::axum::http::StatusCode::BAD_REQUEST,
-                                format!("Header '{}' is required (expected {})", #name_str, #ty_str),
-                            ).into_response();
+                            return ::server_less::http_error_response(
+                                #invalid_status,
+                                ::server_less::ErrorResponse::new(
+                                    ::server_less::ErrorCode::InvalidInput,
+                                    format!("Header '{}' is required (expected {})", #name_str, #ty_str),
+                                ),
+                            );
                         }
                     };
                 });
@@ -1116,15 +2021,456 @@ fn generate_param_handling(
         }
     }
 
+    extractions.extend(user_extractions);
+    extractions.extend(body_extraction);
+
+    add_param_checks(method, &mut pre_stmts, &mut calls, &param_names)?;
+
+    // Arguments were collected grouped by source (injected, path, body, query,
+    // header); pass them to the method in declaration order. Unnamed entries are
+    // the injected params, in the order they were pushed above.
+    let declared_at = |param: &ParamInfo| {
+        method
+            .params
+            .iter()
+            .position(|p| std::ptr::eq(p, param))
+            .expect("BUG: param not found in its own method")
+    };
+    let mut injected_positions = context_param
+        .into_iter()
+        .chain(inject_params.iter().copied())
+        .chain(extractor_params.iter().copied())
+        .chain(cancel_params.iter().copied())
+        .chain(raw_params.iter().copied())
+        .map(declared_at);
+    let positions: Vec<usize> = param_names
+        .iter()
+        .map(|name| match name {
+            Some(name) => method
+                .params
+                .iter()
+                .position(|p| &p.name_str() == name)
+                .expect("BUG: param name not found in its own method"),
+            None => injected_positions
+                .next()
+                .expect("BUG: more unnamed arguments than injected params"),
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..calls.len()).collect();
+    order.sort_by_key(|&i| positions[i]);
+    let calls = order.iter().map(|&i| calls[i].clone()).collect();
+    let param_names = order.iter().map(|&i| param_names[i].clone()).collect();
+
     Ok((extractions, pre_stmts, calls, param_names))
 }
 
+/// Parse the `&str` bound to `v` as `ty`: through `#[param(with)]`'s `parse` when
+/// set, `FromStr` otherwise. Either way the expression is a `Result`.
+fn parse_str(param: &ParamInfo, ty: &syn::Type) -> TokenStream2 {
+    match param.with {
+        Some(ref with) => quote! { #with::parse(v) },
+        None => quote! { v.parse::<#ty>() },
+    }
+}
+
+/// Bind a `#[param(with)]` parameter read from the query, headers or body.
+///
+/// `lookup` is the request's `Option` of the raw value, bound to `v` for `parse`,
+/// a `Result<T, String>`. A failed parse answers `invalid_status` with the
+/// parser's error; a missing value answers it too unless the parameter is an
+/// `Option`. Returns the statement and the expression to pass to the method.
+fn bind_with(
+    param: &ParamInfo,
+    label: &str,
+    name_str: &str,
+    lookup: TokenStream2,
+    parse: TokenStream2,
+    invalid_status: u16,
+) -> (TokenStream2, TokenStream2) {
+    let reject = |message: TokenStream2| {
+        quote! {
+            return ::server_less::http_error_response(
+                #invalid_status,
+                ::server_less::ErrorResponse::new(
+                    ::server_less::ErrorCode::InvalidInput,
+                    #message,
+                ),
+            )
+        }
+    };
+    let invalid = reject(quote! {
+        format!("{} '{}' has invalid value: {}", #label, #name_str, __sl_err)
+    });
+    let ty = &param.ty;
+    let (var, missing, parsed) = if param.is_optional {
+        (
+            format_ident!("__sl_opt_{}", param.name_str()),
+            quote! { ::std::option::Option::None },
+            quote! { ::std::option::Option::Some(val) },
+        )
+    } else {
+        (
+            format_ident!("__sl_req_{}", param.name_str()),
+            reject(quote! { format!("{} '{}' is required", #label, #name_str) }),
+            quote! { val },
+        )
+    };
+    let stmt = quote! {
+        let #var: #ty = match #lookup {
+            ::std::option::Option::None => #missing,
+            ::std::option::Option::Some(v) => match #parse {
+                ::std::result::Result::Ok(val) => #parsed,
+                ::std::result::Result::Err(__sl_err) => #invalid,
+            },
+        };
+    };
+    (stmt, quote! { #var })
+}
+
+/// Rebind the raw path segment `raw` as `var` through `#[param(with)]`'s `parse`,
+/// answering `invalid_status` when it fails.
+fn parse_path_with(
+    param: &ParamInfo,
+    var: &syn::Ident,
+    raw: TokenStream2,
+    invalid_status: u16,
+) -> TokenStream2 {
+    let with = param
+        .with
+        .as_ref()
+        .expect("BUG: parse_path_with needs #[param(with)]");
+    let ty = &param.ty;
+    let name_str = param.name_str();
+    quote! {
+        let #var: #ty = match #with::parse(#raw) {
+            ::std::result::Result::Ok(v) => v,
+            ::std::result::Result::Err(__sl_err) => {
+                return ::server_less::http_error_response(
+                    #invalid_status,
+                    ::server_less::ErrorResponse::new(
+                        ::server_less::ErrorCode::InvalidInput,
+                        format!("Path parameter '{}' has invalid value: {}", #name_str, __sl_err),
+                    ),
+                );
+            }
+        };
+    }
+}
+
+/// Reject parameter types that no request location can produce, before they turn
+/// into a trait-bound error deep inside the generated handler.
+fn check_bindable(param: &ParamInfo) -> syn::Result<()> {
+    let ty = &param.ty;
+    let problem = match ty {
+        Type::Reference(_) => "is borrowed, but request values are extracted as owned data",
+        Type::ImplTrait(_) | Type::TraitObject(_) => "is not a concrete type",
+        Type::BareFn(_) | Type::Ptr(_) | Type::Never(_) | Type::Infer(_) => {
+            "cannot be parsed from request data"
+        }
+        Type::Path(type_path)
+            if type_path
+                .path
+                .segments
+                .last()
+                .is_some_and(|s| s.ident == "WsSender") =>
+        {
+            "is only injected by #[ws]"
+        }
+        _ => return Ok(()),
+    };
+    let ty_str = quote!(#ty).to_string().replace(" ", "");
+    Err(syn::Error::new_spanned(
+        ty,
+        format!(
+            "parameter `{}` cannot be bound from an HTTP request: `{}` {}\n\
+             \n\
+             Hint: take an owned, deserializable type (e.g. `String` instead of `&str`) and place it \
+             with #[param(path)], #[param(query)], #[param(body)] or #[param(header)].\n\
+             Values the framework provides come from `Context` or `Inject<T>`.",
+            param.name_str(),
+            ty_str,
+            problem
+        ),
+    ))
+}
+
+/// Validate extracted values against `#[param(min, max, pattern)]`, answering 400
+/// on violation. Constrained arguments are bound to `__sl_checked_{name}` so the
+/// check runs once, after all extraction and before the method call.
+fn add_param_checks(
+    method: &MethodInfo,
+    pre_stmts: &mut Vec<TokenStream2>,
+    calls: &mut [TokenStream2],
+    param_names: &[Option<String>],
+) -> syn::Result<()> {
+    for (call, name) in calls.iter_mut().zip(param_names) {
+        let Some(param) = name
+            .as_ref()
+            .and_then(|n| method.params.iter().find(|p| &p.name_str() == n))
+        else {
+            continue;
+        };
+        if param.min.is_none() && param.max.is_none() && param.pattern.is_none() {
+            continue;
+        }
+
+        let wire_name = param.wire_name.clone().unwrap_or_else(|| param.name_str());
+        let inner_ty = server_less_parse::unwrap_option_type(&param.ty).unwrap_or(&param.ty);
+        let json_type = match quote!(#inner_ty).to_string().as_str() {
+            // Sent as strings, but bounded like any other integer.
+            "i128" | "u128" => "integer",
+            _ if server_less_rpc::is_decimal_type(inner_ty) => {
+                return Err(syn::Error::new_spanned(
+                    &param.ty,
+                    format!(
+                        "#[param(min, max, pattern)] is not supported on decimal parameter `{}`",
+                        param.name_str()
+                    ),
+                ));
+            }
+            _ => server_less_rpc::infer_json_type(&param.ty),
+        };
+        let mut checks = Vec::new();
+
+        if param.min.is_some() || param.max.is_some() {
+            if json_type != "integer" && json_type != "number" {
+                return Err(syn::Error::new_spanned(
+                    &param.ty,
+                    format!(
+                        "#[param(min/max)] needs a numeric parameter, but `{}` is not\n\
+                         \n\
+                         Hint: use pattern = \"...\" to constrain strings",
+                        param.name_str()
+                    ),
+                ));
+            }
+            let bound = |b: &Option<String>| -> syn::Result<TokenStream2> {
+                let Some(text) = b else {
+                    return Ok(quote! { ::std::option::Option::None });
+                };
+                let is_float = text.contains(['.', 'e', 'E']);
+                let text = if json_type == "number" && !is_float {
+                    format!("{text}.0")
+                } else if json_type == "integer" && is_float {
+                    return Err(syn::Error::new_spanned(
+                        &param.ty,
+                        format!(
+                            "#[param] bound {text} is not an integer, but `{}` is",
+                            param.name_str()
+                        ),
+                    ));
+                } else {
+                    text.clone()
+                };
+                let lit: TokenStream2 = text.parse().expect("BUG: bound is a numeric literal");
+                Ok(quote! { ::std::option::Option::Some(#lit) })
+            };
+            let (min, max) = (bound(&param.min)?, bound(&param.max)?);
+            checks.push(quote! {
+                if let ::std::result::Result::Err(__sl_err) =
+                    ::server_less::check_param_range(#wire_name, __sl_value, #min, #max)
+                {
+                    return ::server_less::http_error_response(400, __sl_err);
+                }
+            });
+        }
+
+        if let Some(ref pattern) = param.pattern {
+            if json_type != "string" {
+                return Err(syn::Error::new_spanned(
+                    &param.ty,
+                    format!(
+                        "#[param(pattern)] needs a string parameter, but `{}` is not",
+                        param.name_str()
+                    ),
+                ));
+            }
+            if let Err(err) = regex::Regex::new(pattern) {
+                return Err(syn::Error::new_spanned(
+                    &param.ty,
+                    format!("invalid #[param(pattern)] for `{}`: {err}", param.name_str()),
+                ));
+            }
+            checks.push(quote! {
+                static __SL_PATTERN: ::std::sync::OnceLock<::server_less::__regex::Regex> =
+                    ::std::sync::OnceLock::new();
+                let __sl_pattern = __SL_PATTERN.get_or_init(|| {
+                    ::server_less::__regex::Regex::new(#pattern)
+                        .expect("BUG: pattern was checked at compile time")
+                });
+                if let ::std::result::Result::Err(__sl_err) =
+                    ::server_less::check_param_pattern(#wire_name, __sl_value, __sl_pattern)
+                {
+                    return ::server_less::http_error_response(400, __sl_err);
+                }
+            });
+        }
+
+        let checked = format_ident!("__sl_checked_{}", param.name_str());
+        let value_binding = if param.is_optional {
+            quote! {
+                if let ::std::option::Option::Some(__sl_value) = &#checked {
+                    #(#checks)*
+                }
+            }
+        } else {
+            quote! {
+                {
+                    let __sl_value = &#checked;
+                    #(#checks)*
+                }
+            }
+        };
+        pre_stmts.push(quote! {
+            let #checked = #call;
+            #value_binding
+        });
+        *call = quote! { #checked };
+    }
+    Ok(())
+}
+
+/// How successful JSON bodies are rendered, from `#[http(envelope, pretty, skip_nulls)]`.
+#[derive(Clone, Copy)]
+struct JsonFormat {
+    envelope: bool,
+    pretty: bool,
+    skip_nulls: bool,
+}
+
+impl JsonFormat {
+    /// Whether bodies need `http_json_response` rather than plain `Json`.
+    fn is_custom(self) -> bool {
+        self.pretty || self.skip_nulls
+    }
+
+    /// A response rendering `value` as JSON in this format.
+    fn render(self, value: TokenStream2) -> TokenStream2 {
+        let JsonFormat {
+            envelope,
+            pretty,
+            skip_nulls,
+        } = self;
+        if self.is_custom() {
+            quote! { ::server_less::http_json_response(#value, #envelope, #pretty, #skip_nulls) }
+        } else if envelope {
+            quote! { ::server_less::http_data_response(#value) }
+        } else {
+            quote! { ::server_less::axum::Json(#value).into_response() }
+        }
+    }
+}
+
 fn generate_response_handling(
     method: &MethodInfo,
     call: &TokenStream2,
     response_overrides: &ResponseOverride,
+    json_format: JsonFormat,
+    ranges: bool,
+    sse_buffer: Option<usize>,
+    ndjson: bool,
 ) -> syn::Result<TokenStream2> {
     let ret = &method.return_info;
+    let envelope = json_format.envelope;
+
+    // A stream is sent after the handler returns, so the request only ends with
+    // it: the cancellation guard moves into the stream instead.
+    let guarded_call;
+    let call = if (ret.is_stream || ret.is_iterator)
+        && method.params.iter().any(|p| is_cancellation_token(&p.ty))
+    {
+        let map = if ret.is_stream {
+            quote! { ::server_less::futures::StreamExt::map }
+        } else {
+            quote! { ::std::iter::Iterator::map }
+        };
+        guarded_call = quote! {
+            #map(#call, move |item| {
+                let _guard = &__sl_cancel_guard;
+                item
+            })
+        };
+        &guarded_call
+    } else {
+        call
+    };
+
+    if let Some(span) = response_overrides.none_span
+        && !ret.is_option
+    {
+        return Err(syn::Error::new(
+            span,
+            format!(
+                "`#[response(none = ...)]` only applies to methods returning `Option<T>`, \
+                 but `{}` does not",
+                method.name
+            ),
+        ));
+    }
+
+    // Body for a successful `value`: bare JSON, or `{"data": ...}` with `envelope`.
+    // Byte buffers are sent raw either way.
+    let value_ty = if ret.is_result {
+        ret.ok_type.as_ref()
+    } else if ret.is_option {
+        ret.some_type.as_ref()
+    } else {
+        ret.ty.as_ref()
+    };
+    // A `WithHeaders<T>` value is sent as its `T`, plus the headers it carries.
+    let with_headers = value_ty.and_then(server_less_rpc::with_headers_inner);
+    let value_ty = with_headers.or(value_ty);
+    let is_bytes = server_less_rpc::returns_bytes(ret);
+    // A `(StatusCode, ...)` tuple already is a response; send it unchanged.
+    let is_status_tuple = value_ty.is_some_and(server_less_rpc::is_status_tuple);
+    let wire_value = server_less_rpc::decimal_wire_value(value_ty, quote! { value });
+    let ok_value = if is_status_tuple {
+        quote! { ::server_less::axum::response::IntoResponse::into_response(value) }
+    } else if is_bytes && ranges {
+        quote! {
+            ::server_less::http_range_response(
+                __sl_range_headers.get(::server_less::axum::http::header::RANGE),
+                value,
+            )
+        }
+    } else if is_bytes {
+        quote! { ::server_less::http_bytes_response(value) }
+    } else {
+        json_format.render(wire_value.clone())
+    };
+    let ok_value = if with_headers.is_some() {
+        quote! {
+            {
+                use ::server_less::axum::response::IntoResponse;
+                let ::server_less::WithHeaders { value, headers: __sl_headers } = value;
+                let mut __sl_response = (#ok_value).into_response();
+                __sl_response.headers_mut().extend(__sl_headers);
+                __sl_response
+            }
+        }
+    } else {
+        ok_value
+    };
+    // `#[http(created_location = true)]` points `Location` at the created resource,
+    // under the path the request was posted to (before any `nest` stripped it).
+    let ok_value = if response_overrides.created_location {
+        quote! {
+            {
+                use ::server_less::axum::response::IntoResponse;
+                let __sl_location =
+                    ::server_less::http_created_location(__sl_uri.path(), &value);
+                let mut __sl_response = (#ok_value).into_response();
+                if let Some(location) = __sl_location {
+                    __sl_response
+                        .headers_mut()
+                        .insert(::server_less::axum::http::header::LOCATION, location);
+                }
+                __sl_response
+            }
+        }
+    } else {
+        ok_value
+    };
 
     let base_response = if ret.is_unit {
         quote! {
@@ -1134,35 +2480,52 @@ fn generate_response_handling(
             }
         }
     } else if ret.is_result {
+        let assert_error = server_less_rpc::error_type_assertion(
+            ret,
+            quote! { ::server_less::assert_handler_error },
+        );
         quote! {
             {
                 use ::server_less::axum::response::IntoResponse;
+                use ::server_less::ErrorResponseFallback as _;
                 use ::server_less::HttpStatusFallback as _;
+                #assert_error
                 match #call {
-                    Ok(value) => ::server_less::axum::Json(value).into_response(),
+                    Ok(value) => #ok_value,
                     Err(err) => {
                         let status_u16 = ::server_less::HttpStatusHelper(&err).http_status_code();
-                        let status = ::server_less::axum::http::StatusCode::from_u16(status_u16)
-                            .unwrap_or(::server_less::axum::http::StatusCode::INTERNAL_SERVER_ERROR);
-                        let body = ::server_less::serde_json::json!({
-                            "error": format!("{:?}", err),
-                            "message": format!("{}", err)
-                        });
-                        (status, ::server_less::axum::Json(body)).into_response()
+                        let error = ::server_less::ErrorResponseHelper(&err).error_response();
+                        ::server_less::http_error_response(status_u16, error)
                     }
                 }
             }
         }
     } else if ret.is_option {
+        let none_value = if response_overrides.none_as_null {
+            json_format.render(quote! { ::server_less::serde_json::Value::Null })
+        } else if envelope {
+            quote! {
+                ::server_less::http_error_response(
+                    404,
+                    ::server_less::ErrorResponse::new(::server_less::ErrorCode::NotFound, "Not found"),
+                )
+            }
+        } else {
+            quote! { ::server_less::axum::http::StatusCode::NOT_FOUND.into_response() }
+        };
         quote! {
             {
                 use ::server_less::axum::response::IntoResponse;
                 match #call {
-                    Some(value) => ::server_less::axum::Json(value).into_response(),
-                    None => ::server_less::axum::http::StatusCode::NOT_FOUND.into_response(),
+                    Some(value) => #ok_value,
+                    None => #none_value,
                 }
             }
         }
+    } else if ndjson && ret.is_iterator {
+        quote! { ::server_less::http_ndjson_response(::server_less::futures::stream::iter(#call)) }
+    } else if ndjson && ret.is_stream {
+        quote! { ::server_less::http_ndjson_response(#call) }
     } else if ret.is_iterator {
         quote! {
             {
@@ -1181,6 +2544,8 @@ fn generate_response_handling(
                 )
             }
         }
+    } else if let Some(capacity) = sse_buffer.filter(|_| ret.is_stream) {
+        quote! { ::server_less::http_sse_buffered(#call, #capacity) }
     } else if ret.is_stream {
         quote! {
             {
@@ -1198,11 +2563,24 @@ fn generate_response_handling(
                 )
             }
         }
+    } else if is_status_tuple
+        || is_bytes
+        || envelope
+        || json_format.is_custom()
+        || with_headers.is_some()
+        || response_overrides.created_location
+    {
+        quote! {
+            {
+                let value = #call;
+                #ok_value
+            }
+        }
     } else {
         quote! {
             {
-                let result = #call;
-                ::server_less::axum::Json(result)
+                let value = #call;
+                ::server_less::axum::Json(#wire_value)
             }
         }
     };
@@ -1219,25 +2597,28 @@ fn generate_response_handling(
 }
 
 /// Apply response overrides (status, headers, content-type) to a base response
+///
+/// Status and content type describe the success body, so error responses (a
+/// `None` 404, an `Err`) keep their own; custom headers are sent either way.
 fn apply_response_overrides(
     base_response: TokenStream2,
     overrides: &ResponseOverride,
 ) -> syn::Result<TokenStream2> {
-    let status_code = if let Some(status) = overrides.status {
+    let status_assignment = overrides.status.map(|status| {
         quote! {
-            ::server_less::axum::http::StatusCode::from_u16(#status)
-                .unwrap_or(::server_less::axum::http::StatusCode::OK)
+            *response.status_mut() = ::server_less::axum::http::StatusCode::from_u16(#status)
+                .unwrap_or(::server_less::axum::http::StatusCode::OK);
         }
-    } else {
-        quote! { ::server_less::axum::http::StatusCode::OK }
-    };
+    });
 
     let header_insertions: Vec<TokenStream2> = overrides
         .headers
         .iter()
         .map(|(name, value)| {
+            // `HeaderName::from_static` only accepts the lowercase form.
+            let name = name.to_ascii_lowercase();
             quote! {
-                headers.insert(
+                response.headers_mut().insert(
                     ::server_less::axum::http::header::HeaderName::from_static(#name),
                     ::server_less::axum::http::header::HeaderValue::from_static(#value)
                 );
@@ -1245,40 +2626,43 @@ fn apply_response_overrides(
         })
         .collect();
 
-    let content_type_insertion = if let Some(ref ct) = overrides.content_type {
+    let content_type_insertion = overrides.content_type.as_ref().map(|ct| {
         quote! {
-            headers.insert(
+            response.headers_mut().insert(
                 ::server_less::axum::http::header::CONTENT_TYPE,
                 ::server_less::axum::http::header::HeaderValue::from_static(#ct)
             );
         }
-    } else {
-        quote! {}
-    };
+    });
 
     Ok(quote! {
         {
             use ::server_less::axum::response::IntoResponse;
-            let base_response = #base_response;
-            let mut headers = ::server_less::axum::http::HeaderMap::new();
+            let mut response = (#base_response).into_response();
+            if response.status().is_success() {
+                #status_assignment
+                #content_type_insertion
+            }
             #(#header_insertions)*
-            #content_type_insertion
-            (#status_code, headers, base_response).into_response()
+            response
         }
     })
 }
 
 fn generate_route(
     prefix: &str,
+    rpc_style: bool,
     method: &MethodInfo,
     overrides: &RouteOverride,
     struct_name: &syn::Ident,
+    generics: &syn::Generics,
 ) -> syn::Result<TokenStream2> {
     let method_name = &method.name;
     // NOTE: to_snake_case can produce collisions for structs that differ only in
     // separator style (e.g. `UserService` and `User_Service` both → `user_service`).
     let struct_name_snake = struct_name.to_string().to_snake_case();
     let handler_name = format_ident!("__server_less_http_{}_{}", struct_name_snake, method_name);
+    let turbofish = crate::generic_turbofish(generics);
 
     let http_method = if let Some(ref m) = overrides.method {
         match m.as_str() {
@@ -1311,10 +2695,16 @@ fn generate_route(
 
     let path = if let Some(ref p) = overrides.path {
         let span = overrides.path_span.unwrap_or_else(|| method_name.span());
-        validate_http_path(p, span)?;
+        validate_http_path(p, span, &method.params)?;
         p.clone()
     } else {
-        infer_path(&method_name.to_string(), &http_method, &method.params)
+        infer_path(
+            &method_name.to_string(),
+            &http_method,
+            &method.params,
+            rpc_style,
+            overrides.resource.as_deref(),
+        )
     };
     let full_path = format!("{}{}", prefix, path);
 
@@ -1327,10 +2717,39 @@ fn generate_route(
     };
 
     Ok(quote! {
-        .route(#full_path, #method_fn(#handler_name))
+        .route(#full_path, #method_fn(#handler_name #turbofish))
     })
 }
 
+/// Count for a generated doc string, e.g. "2 routes". Methods behind `#[cfg]`
+/// may be compiled out, so they are counted separately: "2 routes, plus 1
+/// under `#[cfg]`".
+fn count_doc<'a>(methods: impl Iterator<Item = &'a MethodInfo>, noun: &str) -> String {
+    let (gated, always): (Vec<_>, Vec<_>) = methods.partition(|m| !m.cfg_attrs.is_empty());
+    let plural = |n: usize| if n == 1 { "" } else { "s" };
+    let mut doc = format!("{} {noun}{}", always.len(), plural(always.len()));
+    if !gated.is_empty() {
+        doc.push_str(&format!(", plus {} under `#[cfg]`", gated.len()));
+    }
+    doc
+}
+
+/// Render the route table printed by `SERVER_LESS_ROUTES=1`, one aligned
+/// `VERB  /path  method` row per route.
+fn format_route_table(type_name: &str, rows: &[(String, String, String)]) -> String {
+    let verb_width = rows.iter().map(|(verb, _, _)| verb.len()).max().unwrap_or(0);
+    let path_width = rows.iter().map(|(_, path, _)| path.len()).max().unwrap_or(0);
+    let mut out = format!("--- server-less: #[http] routes on {type_name} ---\n");
+    if rows.is_empty() {
+        out.push_str("(no routes)\n");
+    }
+    for (verb, path, method) in rows {
+        out.push_str(&format!("{verb:<verb_width$}  {path:<path_width$}  {method}\n"));
+    }
+    out.push_str(&format!("--- end #[http] routes on {type_name} ---\n"));
+    out
+}
+
 /// Normalize a path for duplicate detection by replacing all path parameters with a placeholder
 ///
 /// This ensures that paths like `/users/{id}` and `/users/{user_id}` are detected as duplicates,
@@ -1348,12 +2767,140 @@ fn normalize_path_for_duplicate_check(path: &str) -> String {
         .join("/")
 }
 
+/// How a path segment matches requests. axum tries static segments before
+/// parameters, and parameters before a `{*rest}` wildcard, whatever the order
+/// in which routes were registered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum SegmentKind {
+    Static,
+    Param,
+    Wildcard,
+}
+
+fn segment_kind(segment: &str) -> SegmentKind {
+    if segment.starts_with("{*") && segment.ends_with('}') {
+        SegmentKind::Wildcard
+    } else if segment.starts_with('{') && segment.ends_with('}') {
+        SegmentKind::Param
+    } else {
+        SegmentKind::Static
+    }
+}
+
+/// Sort key placing a route before the less specific routes it overlaps.
+fn route_specificity(path: &str) -> Vec<SegmentKind> {
+    path.split('/').map(segment_kind).collect()
+}
+
+/// For two route paths that some request could match both of, which one axum
+/// picks: `Less` if `a` wins, `Greater` if `b` wins, `Equal` if they are the
+/// same pattern. `None` if no request matches both. A `{*rest}` wildcard
+/// matches the remaining one or more segments.
+fn overlap_winner(a: &str, b: &str) -> Option<std::cmp::Ordering> {
+    let (mut a_segments, mut b_segments) = (a.split('/'), b.split('/'));
+    let mut winner = std::cmp::Ordering::Equal;
+    loop {
+        let (a_segment, b_segment) = match (a_segments.next(), b_segments.next()) {
+            (None, None) => return Some(winner),
+            (Some(a_segment), Some(b_segment)) => (a_segment, b_segment),
+            _ => return None,
+        };
+        let (a_kind, b_kind) = (segment_kind(a_segment), segment_kind(b_segment));
+        if a_kind == SegmentKind::Static && b_kind == SegmentKind::Static {
+            if a_segment != b_segment {
+                return None;
+            }
+        } else if winner == std::cmp::Ordering::Equal {
+            winner = a_kind.cmp(&b_kind);
+        }
+        if a_kind == SegmentKind::Wildcard || b_kind == SegmentKind::Wildcard {
+            return Some(winner);
+        }
+    }
+}
+
+/// Whether the path parameter `segment` of `method` would accept the literal
+/// segment `literal`, going by its type. Types that are not plainly strings,
+/// numbers or booleans are assumed to reject it.
+fn param_accepts(method: &MethodInfo, segment: &str, literal: &str) -> bool {
+    let name = segment.trim_start_matches('{').trim_start_matches('*').trim_end_matches('}');
+    let Some(param) = method.params.iter().find(|p| {
+        let wire = p.wire_name.clone().unwrap_or_else(|| p.name_str());
+        wire.trim_start_matches('_') == name.trim_start_matches('_')
+    }) else {
+        return false;
+    };
+    match server_less_rpc::infer_json_type(&param.ty) {
+        "string" => true,
+        "integer" | "number" => literal.parse::<f64>().is_ok(),
+        "boolean" => literal == "true" || literal == "false",
+        _ => false,
+    }
+}
+
+/// Reject a route that loses requests to a more specific path lacking its verb:
+/// axum routes by path before verb, so `GET /files/{file_id}` (with a `String`
+/// id) next to `POST /files/upload` answers `GET /files/upload` with 405
+/// rather than calling the `GET` method. `#[route(allow_duplicate)]` on either
+/// method skips the check for that pair.
+fn check_route_shadowing(routes: &[(String, String, &MethodInfo, bool)]) -> syn::Result<()> {
+    for (verb, path, method, allow_duplicate) in routes {
+        for (_, other_path, other_method, other_allow_duplicate) in routes {
+            if *allow_duplicate
+                || *other_allow_duplicate
+                || overlap_winner(other_path, path) != Some(std::cmp::Ordering::Less)
+            {
+                continue;
+            }
+            // Segments past a wildcard on either side are all taken by it, so
+            // zipping may stop short of the longer path.
+            let reachable = path
+                .split('/')
+                .zip(other_path.split('/'))
+                .all(|(segment, other)| {
+                    segment_kind(segment) == SegmentKind::Static
+                        || segment_kind(other) != SegmentKind::Static
+                        || param_accepts(method, segment, other)
+                });
+            let verb_covered = routes.iter().any(|(candidate_verb, candidate_path, _, _)| {
+                candidate_verb == verb
+                    && overlap_winner(candidate_path, other_path) == Some(std::cmp::Ordering::Equal)
+            });
+            if !reachable || verb_covered {
+                continue;
+            }
+            return Err(syn::Error::new_spanned(
+                &method.method.sig,
+                format!(
+                    "{verb} {path} is shadowed by {other_path} (method '{}')\n\
+                     \n\
+                     axum matches the more specific path {other_path} first and, since it has \
+                     no {verb} route, answers {verb} requests to it with 405 instead of calling \
+                     method '{}'.\n\
+                     \n\
+                     Hint: You can either:\n\
+                     1. Use #[route(path = \"/custom\")] to move one of the two routes\n\
+                     2. Add a {verb} method for {other_path}\n\
+                     3. Use #[route(allow_duplicate)] if requests to {other_path} never \
+                     mean method '{}'",
+                    other_method.name, method.name, method.name
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
 /// Validate HTTP path at compile time.
 ///
 /// `path_span` should be the span of the `#[route(path = "...")]` literal so
 /// that error diagnostics underline the problematic string rather than the
 /// method name.
-fn validate_http_path(path: &str, path_span: proc_macro2::Span) -> syn::Result<()> {
+fn validate_http_path(
+    path: &str,
+    path_span: proc_macro2::Span,
+    params: &[ParamInfo],
+) -> syn::Result<()> {
     // Check that path starts with /
     if !path.starts_with('/') {
         return Err(syn::Error::new(
@@ -1436,9 +2983,27 @@ fn validate_http_path(path: &str, path_span: proc_macro2::Span) -> syn::Result<(
 
     // Extract and validate path parameter names
     let mut param_names = std::collections::HashSet::new();
+    let segment_count = path.split('/').count();
     for (idx, part) in path.split('/').enumerate() {
         if part.starts_with('{') && part.ends_with('}') {
             let param_name = part.trim_start_matches('{').trim_end_matches('}');
+            // `{*rest}` captures the remaining segments, so it must come last.
+            let param_name = match param_name.strip_prefix('*') {
+                Some(_) if idx + 1 != segment_count => {
+                    return Err(syn::Error::new(
+                        path_span,
+                        format!(
+                            "HTTP path has wildcard '{{{}}}' before its last segment. Path: '{}'\n\
+                             \n\
+                             Hint: A wildcard captures the rest of the path, so it must be the \
+                             final segment, e.g., /files/{{*path}}",
+                            param_name, path
+                        ),
+                    ));
+                }
+                Some(name) => name,
+                None => param_name,
+            };
 
             // Check for empty parameter name
             if param_name.is_empty() {
@@ -1497,7 +3062,101 @@ fn validate_http_path(path: &str, path_span: proc_macro2::Span) -> syn::Result<(
         }
     }
 
-    Ok(())
+    check_path_params_match(path, path_span, &param_names, params)
+}
+
+/// Cross-reference the `{name}` segments of an explicit route path with the
+/// method parameters bound to the path (`#[param(path)]` or id-like), so a
+/// placeholder without a parameter, or a parameter missing from the path, is a
+/// compile error instead of a runtime extraction failure.
+fn check_path_params_match(
+    path: &str,
+    path_span: proc_macro2::Span,
+    placeholders: &std::collections::HashSet<String>,
+    params: &[ParamInfo],
+) -> syn::Result<()> {
+    use server_less_parse::ParamLocation;
+
+    // Same naming as `infer_path`: the wire name verbatim, otherwise the Rust
+    // name without a leading "unused" underscore.
+    let path_params: Vec<String> = params
+        .iter()
+        .filter(|p| match p.location {
+            Some(ref location) => *location == ParamLocation::Path,
+            None => p.is_id && !p.is_extractor,
+        })
+        .map(|p| match p.wire_name {
+            Some(ref wire) => wire.clone(),
+            None => {
+                let name = p.name_str();
+                name.strip_prefix('_').map(str::to_string).unwrap_or(name)
+            }
+        })
+        .collect();
+
+    // Keep the path's own order for a stable message.
+    let unbound: Vec<&str> = path
+        .split('/')
+        .filter_map(|part| part.strip_prefix('{')?.strip_suffix('}'))
+        .map(|name| name.trim_start_matches('*'))
+        .filter(|name| !path_params.iter().any(|p| p == name))
+        .collect();
+    let missing: Vec<&str> = path_params
+        .iter()
+        .filter(|name| !placeholders.contains(name.as_str()))
+        .map(String::as_str)
+        .collect();
+    if unbound.is_empty() && missing.is_empty() {
+        return Ok(());
+    }
+
+    let mut problems = Vec::new();
+    for name in &unbound {
+        problems.push(format!("- `{{{name}}}` has no matching method parameter"));
+    }
+    for name in &missing {
+        problems.push(format!(
+            "- parameter `{name}` is bound to the path, but the path has no `{{{name}}}`"
+        ));
+    }
+    Err(syn::Error::new(
+        path_span,
+        format!(
+            "HTTP path parameters do not match the method parameters. Path: '{}'\n\
+             {}\n\
+             \n\
+             Hint: name each `{{...}}` segment after a parameter (or its #[param(name = \"...\")]);\n\
+             id-like parameters not meant for the path need #[param(query)] or #[param(body)]",
+            path,
+            problems.join("\n")
+        ),
+    ))
+}
+
+/// A `mount("/prefix", Service)` directive on #[serve].
+pub(crate) struct ServeMount {
+    /// Path the service's router is nested under
+    pub path: syn::LitStr,
+    /// Type of the mounted `#[http]` service, whose OpenAPI paths are merged
+    pub ty: syn::Type,
+    /// Expression building the service (`Type = expr`; default: the type's unit value)
+    pub service: syn::Expr,
+}
+
+/// `cors(...)` options of `#[serve]`
+#[derive(Default)]
+pub(crate) struct ServeCors {
+    /// Allowed origins (`origins = "https://a.com,https://b.com"`; default: any)
+    pub origins: Vec<String>,
+    /// Send `Access-Control-Allow-Credentials: true` (`credentials`)
+    pub credentials: bool,
+    /// Preflight cache lifetime in seconds (`max_age = 3600`)
+    pub max_age: Option<u64>,
+    /// Methods allowed on top of the routes' own (`methods = "PUT"`)
+    pub methods: Vec<String>,
+    /// Request headers allowed on top of `content-type` and header params
+    /// (`headers = "authorization"`)
+    pub headers: Vec<String>,
 }
 
 /// Arguments for the #[serve] attribute
@@ -1505,8 +3164,39 @@ fn validate_http_path(path: &str, path_span: proc_macro2::Span) -> syn::Result<(
 pub(crate) struct ServeArgs {
     /// Protocols to serve (http, ws, jsonrpc, graphql)
     pub protocols: Vec<String>,
+    /// Other `#[http]` services nested under a path prefix
+    pub mounts: Vec<ServeMount>,
     /// Health check path (default: /health)
     pub health_path: Option<String>,
+    /// Readiness method called by the health route (`readiness` → `health_check`,
+    /// or `readiness = "method"`); `None` keeps the constant `"ok"` response.
+    pub readiness: Option<syn::Ident>,
+    /// Separate readiness probe path (`ready = "/readyz"`). When set, the health
+    /// path stays a constant liveness check and the readiness method answers here.
+    pub ready_path: Option<syn::LitStr>,
+    /// Seconds an async readiness method may run before the probe answers 503
+    /// (`readiness_timeout = N`; default: [`DEFAULT_READINESS_TIMEOUT`])
+    pub readiness_timeout: Option<syn::LitInt>,
+    /// Prometheus metrics path (`metrics = "/metrics"`); requires the `metrics` feature
+    pub metrics_path: Option<syn::LitStr>,
+    /// Swagger UI page for the combined spec (`docs = "/docs"`); requires OpenAPI
+    pub docs_path: Option<syn::LitStr>,
+    /// Route serving the route table as JSON (`discovery` for `/_routes`, or
+    /// `discovery = "/path"`; default: none)
+    pub discovery_path: Option<syn::LitStr>,
+    /// JSON 404 fallback for unmatched routes and JSON 405 for unmatched verbs
+    /// (default: true).
+    /// Set to false with `json_errors = false`
+    pub json_errors: Option<bool>,
+    /// Redirect unmatched `/path/` requests to `/path` with 308 (default: false)
+    pub redirect_slashes: bool,
+    /// Answer handler panics with a JSON 500 (default: false)
+    pub catch_panic: bool,
+    /// `Retry-After` seconds of the 503 sent while the maintenance flag is set
+    /// (`maintenance` or `maintenance(retry_after = N)`; default: none)
+    pub maintenance: Option<u32>,
+    /// CORS policy (`cors` or `cors(...)`; default: none)
+    pub cors: Option<ServeCors>,
     /// OpenAPI spec generation (default: true when protocols are present)
     /// Set to false with `openapi = false`
     pub openapi: Option<bool>,
@@ -1526,6 +3216,30 @@ impl ServeArgs {
     pub fn openapi_enabled(&self) -> bool {
         self.openapi.unwrap_or(true)
     }
+
+    /// Whether unmatched routes get a JSON 404 body.
+    /// Default: true (opt-out with `json_errors = false`)
+    pub fn json_errors_enabled(&self) -> bool {
+        self.json_errors.unwrap_or(true)
+    }
+
+    /// The paths `#[serve]` registers routes on itself, each with what it
+    /// serves there, so a second route on one of them (which makes axum panic
+    /// when the router is built) can be rejected at compile time.
+    pub(crate) fn fixed_routes(&self) -> Vec<(String, &'static str)> {
+        let mut routes = vec![(
+            self.health_path.clone().unwrap_or_else(|| "/health".to_string()),
+            "health check",
+        )];
+        routes.extend(self.ready_path.as_ref().map(|p| (p.value(), "readiness probe")));
+        if self.openapi_enabled() {
+            routes.push(("/openapi.json".to_string(), "combined spec"));
+            routes.extend(self.docs_path.as_ref().map(|p| (p.value(), "docs page")));
+        }
+        routes.extend(self.metrics_path.as_ref().map(|p| (p.value(), "metrics endpoint")));
+        routes.extend(self.discovery_path.as_ref().map(|p| (p.value(), "route table")));
+        routes
+    }
 }
 
 impl Parse for ServeArgs {
@@ -1545,6 +3259,32 @@ impl Parse for ServeArgs {
                     let lit: syn::LitStr = input.parse()?;
                     args.health_path = Some(lit.value());
                 }
+                "readiness" => {
+                    args.readiness = Some(parse_readiness_method(&ident, input)?);
+                }
+                "ready" => {
+                    input.parse::<Token![=]>()?;
+                    args.ready_path = Some(input.parse()?);
+                }
+                "readiness_timeout" => {
+                    args.readiness_timeout = Some(parse_readiness_timeout(input)?);
+                }
+                "metrics" => {
+                    input.parse::<Token![=]>()?;
+                    args.metrics_path = Some(input.parse()?);
+                }
+                "docs" => {
+                    input.parse::<Token![=]>()?;
+                    args.docs_path = Some(input.parse()?);
+                }
+                "discovery" => {
+                    args.discovery_path = Some(if input.peek(Token![=]) {
+                        input.parse::<Token![=]>()?;
+                        input.parse()?
+                    } else {
+                        syn::LitStr::new("/_routes", ident.span())
+                    });
+                }
                 "openapi" => {
                     if input.peek(Token![=]) {
                         input.parse::<Token![=]>()?;
@@ -1555,6 +3295,61 @@ impl Parse for ServeArgs {
                         args.openapi = Some(true);
                     }
                 }
+                "json_errors" => {
+                    if input.peek(Token![=]) {
+                        input.parse::<Token![=]>()?;
+                        let lit: syn::LitBool = input.parse()?;
+                        args.json_errors = Some(lit.value());
+                    } else {
+                        args.json_errors = Some(true);
+                    }
+                }
+                "mount" => {
+                    let mount = parse_serve_mount(input)?;
+                    if args
+                        .mounts
+                        .iter()
+                        .any(|m| m.path.value() == mount.path.value())
+                    {
+                        return Err(syn::Error::new(
+                            mount.path.span(),
+                            format!("`{}` is already mounted", mount.path.value()),
+                        ));
+                    }
+                    args.mounts.push(mount);
+                }
+                "redirect_slashes" => {
+                    if input.peek(Token![=]) {
+                        input.parse::<Token![=]>()?;
+                        let lit: syn::LitBool = input.parse()?;
+                        args.redirect_slashes = lit.value();
+                    } else {
+                        args.redirect_slashes = true;
+                    }
+                }
+                "catch_panic" => {
+                    if input.peek(Token![=]) {
+                        input.parse::<Token![=]>()?;
+                        let lit: syn::LitBool = input.parse()?;
+                        args.catch_panic = lit.value();
+                    } else {
+                        args.catch_panic = true;
+                    }
+                }
+                "maintenance" => {
+                    args.maintenance = Some(if input.peek(syn::token::Paren) {
+                        parse_serve_maintenance(input)?
+                    } else {
+                        DEFAULT_RETRY_AFTER
+                    });
+                }
+                "cors" => {
+                    args.cors = Some(if input.peek(syn::token::Paren) {
+                        parse_serve_cors(input)?
+                    } else {
+                        ServeCors::default()
+                    });
+                }
                 "name" => {
                     input.parse::<Token![=]>()?;
                     let lit: syn::LitStr = input.parse()?;
@@ -1577,8 +3372,10 @@ impl Parse for ServeArgs {
                 }
                 other => {
                     const VALID: &[&str] = &[
-                        "http", "ws", "jsonrpc", "graphql", "health", "openapi",
-                        "name", "description", "version", "homepage",
+                        "http", "ws", "jsonrpc", "graphql", "mount", "health", "ready",
+                        "readiness", "readiness_timeout", "metrics", "docs", "discovery",
+                        "openapi", "json_errors", "redirect_slashes", "catch_panic", "maintenance",
+                        "cors", "name", "description", "version", "homepage",
                     ];
                     let suggestion = crate::did_you_mean(other, VALID)
                         .map(|s| format!(" — did you mean `{s}`?"))
@@ -1589,11 +3386,23 @@ impl Parse for ServeArgs {
                             "unknown argument `{other}`{suggestion}\n\
                              \n\
                              Valid protocols: http, ws, jsonrpc, graphql\n\
-                             Valid options: health, openapi, name, description, version, homepage\n\
+                             Valid options: mount, health, ready, readiness, readiness_timeout, metrics, docs, discovery, openapi, json_errors, redirect_slashes, catch_panic, maintenance, cors, name, description, version, homepage\n\
                              \n\
                              Examples:\n\
                              - #[serve(http, ws, health = \"/status\")]\n\
+                             - #[serve(http, mount(\"/v1/users\", UserService))]\n\
+                             - #[serve(http, readiness)]\n\
+                             - #[serve(http, readiness, readiness_timeout = 2)]\n\
+                             - #[serve(http, health = \"/healthz\", ready = \"/readyz\")]\n\
+                             - #[serve(http, metrics = \"/metrics\")]\n\
+                             - #[serve(http, docs = \"/docs\")]\n\
+                             - #[serve(http, discovery)] or #[serve(http, discovery = \"/routes\")]\n\
                              - #[serve(http, openapi = false)]\n\
+                             - #[serve(http, json_errors = false)]\n\
+                             - #[serve(http, redirect_slashes)]\n\
+                             - #[serve(http, catch_panic)]\n\
+                             - #[serve(http, maintenance(retry_after = 120))]\n\
+                             - #[serve(http, cors(origins = \"https://app.example.com\", credentials, max_age = 3600))]\n\
                              - #[serve(http, name = \"My API\", description = \"Does the thing\")]"
                         ),
                     ));
@@ -1609,6 +3418,324 @@ impl Parse for ServeArgs {
     }
 }
 
+/// Parse the value of a `readiness` argument: bare `readiness` names the
+/// conventional `health_check` method, `readiness = "method"` names another.
+pub(crate) fn parse_readiness_method(
+    ident: &syn::Ident,
+    input: syn::parse::ParseStream,
+) -> syn::Result<syn::Ident> {
+    if input.peek(Token![=]) {
+        input.parse::<Token![=]>()?;
+        let lit: syn::LitStr = input.parse()?;
+        lit.parse()
+    } else {
+        Ok(syn::Ident::new("health_check", ident.span()))
+    }
+}
+
+/// Seconds an async readiness method may run before the probe gives up on it.
+pub(crate) const DEFAULT_READINESS_TIMEOUT: u64 = 5;
+
+/// Parse the `= N` of a `readiness_timeout` argument: whole seconds, at least 1.
+pub(crate) fn parse_readiness_timeout(input: syn::parse::ParseStream) -> syn::Result<syn::LitInt> {
+    input.parse::<Token![=]>()?;
+    let lit: syn::LitInt = input.parse()?;
+    if lit.base10_parse::<u64>()? == 0 {
+        return Err(syn::Error::new(
+            lit.span(),
+            "`readiness_timeout` must be at least 1 second",
+        ));
+    }
+    Ok(lit)
+}
+
+/// `Retry-After` seconds sent by a bare `maintenance` argument.
+const DEFAULT_RETRY_AFTER: u32 = 60;
+
+/// Parse the `(retry_after = N)` part of a `maintenance` argument.
+fn parse_serve_maintenance(input: syn::parse::ParseStream) -> syn::Result<u32> {
+    let content;
+    syn::parenthesized!(content in input);
+    let mut retry_after = DEFAULT_RETRY_AFTER;
+    while !content.is_empty() {
+        let ident: syn::Ident = content.parse()?;
+        if ident != "retry_after" {
+            return Err(syn::Error::new(
+                ident.span(),
+                format!(
+                    "unknown maintenance option `{ident}`\n\
+                     \n\
+                     Valid options: retry_after\n\
+                     \n\
+                     Example: #[serve(http, maintenance(retry_after = 120))]"
+                ),
+            ));
+        }
+        content.parse::<Token![=]>()?;
+        let lit: syn::LitInt = content.parse()?;
+        retry_after = lit.base10_parse()?;
+        if content.peek(Token![,]) {
+            content.parse::<Token![,]>()?;
+        }
+    }
+    Ok(retry_after)
+}
+
+/// Parse the `(...)` part of a `cors` argument.
+fn parse_serve_cors(input: syn::parse::ParseStream) -> syn::Result<ServeCors> {
+    let content;
+    let paren = syn::parenthesized!(content in input);
+    let mut cors = ServeCors::default();
+    let list = |lit: &syn::LitStr| -> Vec<String> {
+        lit.value()
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect()
+    };
+
+    while !content.is_empty() {
+        let ident: syn::Ident = content.parse()?;
+        match ident.to_string().as_str() {
+            "credentials" => cors.credentials = true,
+            "max_age" => {
+                content.parse::<Token![=]>()?;
+                let lit: syn::LitInt = content.parse()?;
+                cors.max_age = Some(lit.base10_parse()?);
+            }
+            "origins" => {
+                content.parse::<Token![=]>()?;
+                let lit: syn::LitStr = content.parse()?;
+                for origin in list(&lit) {
+                    if !(origin.starts_with("http://") || origin.starts_with("https://"))
+                        || origin.ends_with('/')
+                    {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            format!(
+                                "invalid CORS origin \"{origin}\"\n\
+                                 \n\
+                                 Hint: origins are scheme and host without a path, e.g. \"https://app.example.com\""
+                            ),
+                        ));
+                    }
+                    cors.origins.push(origin);
+                }
+            }
+            "methods" => {
+                content.parse::<Token![=]>()?;
+                cors.methods = list(&content.parse()?);
+            }
+            "headers" => {
+                content.parse::<Token![=]>()?;
+                cors.headers = list(&content.parse()?);
+            }
+            other => {
+                const VALID: &[&str] = &["origins", "credentials", "max_age", "methods", "headers"];
+                let suggestion = crate::did_you_mean(other, VALID)
+                    .map(|s| format!(" — did you mean `{s}`?"))
+                    .unwrap_or_default();
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!(
+                        "unknown cors option `{other}`{suggestion}\n\
+                         \n\
+                         Valid options: origins, credentials, max_age, methods, headers\n\
+                         \n\
+                         Example: cors(origins = \"https://app.example.com\", credentials, max_age = 3600)"
+                    ),
+                ));
+            }
+        }
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+    }
+
+    if cors.credentials && cors.origins.is_empty() {
+        return Err(syn::Error::new(
+            paren.span.join(),
+            "CORS `credentials` needs explicit `origins`: browsers refuse credentialed \
+             responses that allow any origin\n\
+             \n\
+             Hint: cors(credentials, origins = \"https://app.example.com\")",
+        ));
+    }
+    Ok(cors)
+}
+
+/// Parse the `("/prefix", Service)` or `("/prefix", Service = expr)` part of a
+/// `mount` argument.
+fn parse_serve_mount(input: syn::parse::ParseStream) -> syn::Result<ServeMount> {
+    let content;
+    syn::parenthesized!(content in input);
+    let path: syn::LitStr = content.parse()?;
+    content.parse::<Token![,]>()?;
+    let ty: syn::Type = content.parse()?;
+    let service = if content.peek(Token![=]) {
+        content.parse::<Token![=]>()?;
+        content.parse::<syn::Expr>()?
+    } else {
+        syn::parse2(quote! { #ty }).map_err(|_| {
+            syn::Error::new_spanned(
+                &ty,
+                "mount needs an expression to build this service\n\
+                 \n\
+                 Hint: mount(\"/prefix\", Service = Service::new())",
+            )
+        })?
+    };
+    if !content.is_empty() {
+        content.parse::<Token![,]>().map_err(|e| {
+            syn::Error::new(
+                e.span(),
+                "expected the service type, optionally followed by `= expr`\n\
+                 \n\
+                 Hint: mount(\"/prefix\", Service = Service::new())",
+            )
+        })?;
+    }
+
+    let value = path.value();
+    if !value.starts_with('/') || value == "/" || value.ends_with('/') {
+        return Err(syn::Error::new(
+            path.span(),
+            format!(
+                "invalid mount path \"{value}\"\n\
+                 \n\
+                 Hint: use a path starting with '/' and without a trailing slash, e.g. mount(\"/v1/users\", UserService)"
+            ),
+        ));
+    }
+    Ok(ServeMount { path, ty, service })
+}
+
+/// Build the statement that registers the health route.
+///
+/// Without a readiness method the route answers a constant `"ok"`. With one, the
+/// route calls it on a shared handle to the service and answers 503 when the
+/// returned `HealthStatus` is unhealthy. An async method that outlives `timeout`
+/// (default [`DEFAULT_READINESS_TIMEOUT`] seconds) is dropped and counts as
+/// unhealthy, so a hung dependency check can't hang the probe.
+fn generate_health_route(
+    impl_block: &ItemImpl,
+    health_path: &str,
+    readiness: Option<&syn::Ident>,
+    timeout: Option<&syn::LitInt>,
+) -> syn::Result<TokenStream2> {
+    let Some(method_name) = readiness else {
+        return Ok(quote! {
+            let router = router.route(
+                #health_path,
+                ::server_less::axum::routing::get(|| async { "ok" })
+            );
+        });
+    };
+
+    let method = impl_block
+        .items
+        .iter()
+        .find_map(|item| match item {
+            syn::ImplItem::Fn(f) if f.sig.ident == *method_name => Some(f),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            syn::Error::new(
+                method_name.span(),
+                format!(
+                    "readiness check `{method_name}` not found in this impl block\n\
+                     \n\
+                     Hint: add a method returning `bool` or `Result<_, _>`, e.g.\n\
+                     async fn {method_name}(&self) -> bool {{ self.db.ping().await.is_ok() }}\n\
+                     and mark it #[server(skip)] to keep it off the public API"
+                ),
+            )
+        })?;
+    let healthy = if method.sig.asyncness.is_some() {
+        let secs = match timeout {
+            Some(lit) => lit.base10_parse::<u64>()?,
+            None => DEFAULT_READINESS_TIMEOUT,
+        };
+        quote! {
+            ::server_less::tokio::time::timeout(
+                ::std::time::Duration::from_secs(#secs),
+                __sl_state.#method_name(),
+            )
+            .await
+            .is_ok_and(|status| status.is_healthy())
+        }
+    } else if let Some(lit) = timeout {
+        return Err(syn::Error::new(
+            lit.span(),
+            format!(
+                "`readiness_timeout` needs an async readiness method, but `{method_name}` is sync\n\
+                 \n\
+                 Hint: a blocking call can't be interrupted; make it `async fn {method_name}`"
+            ),
+        ));
+    } else {
+        quote! { __sl_state.#method_name().is_healthy() }
+    };
+
+    Ok(quote! {
+        let router = {
+            let __sl_health_state = ::std::sync::Arc::clone(&__sl_service);
+            router.route(
+                #health_path,
+                ::server_less::axum::routing::get(move || {
+                    let __sl_state = ::std::sync::Arc::clone(&__sl_health_state);
+                    async move {
+                        use ::server_less::HealthStatus as _;
+                        if #healthy {
+                            (::server_less::axum::http::StatusCode::OK, "ok")
+                        } else {
+                            (::server_less::axum::http::StatusCode::SERVICE_UNAVAILABLE, "unavailable")
+                        }
+                    }
+                })
+            )
+        };
+    })
+}
+
+/// Build the statements that expose Prometheus metrics on `path` and wrap the
+/// router so every request (including health and spec routes) is recorded.
+#[cfg(feature = "metrics")]
+fn generate_metrics_setup(path: &syn::LitStr) -> syn::Result<TokenStream2> {
+    Ok(quote! {
+        let router = {
+            let __sl_metrics = ::std::sync::Arc::new(::server_less::HttpMetrics::new());
+            let __sl_metrics_handle = ::std::sync::Arc::clone(&__sl_metrics);
+            let router = router.route(
+                #path,
+                ::server_less::axum::routing::get(move || {
+                    let __sl_metrics = ::std::sync::Arc::clone(&__sl_metrics_handle);
+                    async move {
+                        (
+                            [(
+                                ::server_less::axum::http::header::CONTENT_TYPE,
+                                "text/plain; version=0.0.4",
+                            )],
+                            __sl_metrics.render(),
+                        )
+                    }
+                })
+            );
+            ::server_less::HttpMetrics::layer(__sl_metrics, router)
+        };
+    })
+}
+
+#[cfg(not(feature = "metrics"))]
+fn generate_metrics_setup(path: &syn::LitStr) -> syn::Result<TokenStream2> {
+    Err(syn::Error::new(
+        path.span(),
+        "`metrics` requires the `metrics` feature of server-less\n\
+         \n\
+         Hint: server-less = { version = \"...\", features = [\"metrics\"] }",
+    ))
+}
+
 /// Coordinate multiple protocol handlers into a single server.
 pub(crate) fn expand_serve(args: ServeArgs, impl_block: ItemImpl) -> syn::Result<TokenStream2> {
     let struct_name = get_impl_name(&impl_block)?;
@@ -1616,6 +3743,8 @@ pub(crate) fn expand_serve(args: ServeArgs, impl_block: ItemImpl) -> syn::Result
     let self_ty = &impl_block.self_ty;
 
     let openapi_enabled = args.openapi_enabled();
+    let json_errors_enabled = args.json_errors_enabled();
+    let serve_routes = args.fixed_routes();
     let health_path = args.health_path.unwrap_or_else(|| "/health".to_string());
     let serve_title = args.name.unwrap_or_else(|| struct_name.to_string());
     let serve_version = match args.version {
@@ -1624,11 +3753,212 @@ pub(crate) fn expand_serve(args: ServeArgs, impl_block: ItemImpl) -> syn::Result
     };
 
     // Build router combination based on protocols
-    let router_setup = generate_router_setup(&args.protocols);
+    let router_setup = generate_router_setup(&args.protocols, &args.mounts);
+    // With a separate `ready` path, the health path becomes a constant liveness
+    // probe and the readiness method (defaulting to `health_check`) moves there.
+    let health_route = match args.ready_path {
+        Some(ref ready) => {
+            if ready.value() == health_path {
+                return Err(syn::Error::new(
+                    ready.span(),
+                    format!(
+                        "readiness path `{health_path}` is the same as the health path\n\
+                         \n\
+                         Hint: use distinct probes, e.g. #[serve(http, health = \"/healthz\", ready = \"/readyz\")]"
+                    ),
+                ));
+            }
+            let readiness = args
+                .readiness
+                .clone()
+                .unwrap_or_else(|| syn::Ident::new("health_check", ready.span()));
+            let liveness = generate_health_route(&impl_block, &health_path, None, None)?;
+            let readiness = generate_health_route(
+                &impl_block,
+                &ready.value(),
+                Some(&readiness),
+                args.readiness_timeout.as_ref(),
+            )?;
+            quote! {
+                #liveness
+                #readiness
+            }
+        }
+        None => {
+            if let (None, Some(lit)) = (&args.readiness, &args.readiness_timeout) {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "`readiness_timeout` has no readiness method to bound\n\
+                     \n\
+                     Hint: #[serve(http, readiness, readiness_timeout = 2)]",
+                ));
+            }
+            generate_health_route(
+                &impl_block,
+                &health_path,
+                args.readiness.as_ref(),
+                args.readiness_timeout.as_ref(),
+            )?
+        }
+    };
+    let fallback_setup = if json_errors_enabled {
+        quote! {
+            let router = router
+                .fallback(::server_less::json_not_found)
+                .method_not_allowed_fallback(::server_less::json_method_not_allowed);
+        }
+    } else {
+        quote! {}
+    };
+    // Innermost layer, so metrics and CORS see the 500 it turns a panic into.
+    let catch_panic_setup = if args.catch_panic {
+        quote! {
+            let router = router.layer(::server_less::axum::middleware::from_fn(
+                ::server_less::catch_panic,
+            ));
+        }
+    } else {
+        quote! {}
+    };
+    // Layered after the fallback, so unmatched paths are turned away too; the
+    // health probes stay reachable so orchestrators keep the instance around.
+    let maintenance_setup = match args.maintenance {
+        Some(retry_after) => {
+            let mut exempt = vec![health_path.clone()];
+            exempt.extend(args.ready_path.as_ref().map(syn::LitStr::value));
+            quote! {
+                let router = router.layer(::server_less::axum::middleware::from_fn_with_state(
+                    maintenance,
+                    |::server_less::axum::extract::State(switch): ::server_less::axum::extract::State<
+                        ::server_less::MaintenanceSwitch,
+                    >,
+                     request: ::server_less::axum::extract::Request,
+                     next: ::server_less::axum::middleware::Next| {
+                        ::server_less::http_maintenance_gate(
+                            switch,
+                            &[#(#exempt),*],
+                            #retry_after,
+                            request,
+                            next,
+                        )
+                    },
+                ));
+            }
+        }
+        None => quote! {},
+    };
+    // Layered after the fallback is set: `Router::layer` only wraps the routes and
+    // fallback present at that point, and unmatched paths end up in the fallback.
+    let redirect_setup = if args.redirect_slashes {
+        quote! {
+            let router = router.layer(::server_less::axum::middleware::from_fn(
+                ::server_less::redirect_trailing_slash,
+            ));
+        }
+    } else {
+        quote! {}
+    };
+    let metrics_setup = match args.metrics_path {
+        Some(ref path) => generate_metrics_setup(path)?,
+        None => quote! {},
+    };
+    // Outermost, so preflights are answered before any route, fallback or redirect.
+    let cors_setup = match args.cors {
+        Some(ref cors) => generate_cors_setup(cors, &args.protocols, &args.mounts),
+        None => quote! {},
+    };
+
+    if let Some(ref docs) = args.docs_path {
+        if !openapi_enabled {
+            return Err(syn::Error::new(
+                docs.span(),
+                "docs needs the combined OpenAPI spec, but openapi = false\n\
+                 \n\
+                 Hint: remove openapi = false, or drop docs",
+            ));
+        }
+        if !docs.value().starts_with('/') {
+            return Err(syn::Error::new(
+                docs.span(),
+                format!(
+                    "invalid docs path \"{}\"\n\
+                     \n\
+                     Hint: use a path starting with '/', e.g. docs = \"/docs\"",
+                    docs.value()
+                ),
+            ));
+        }
+        if let Some((_, serves)) = serve_routes
+            .iter()
+            .find(|(path, serves)| *serves != "docs page" && *path == docs.value())
+        {
+            return Err(syn::Error::new(
+                docs.span(),
+                format!(
+                    "docs path \"{}\" is already the {serves}\n\
+                     \n\
+                     Hint: use a path of its own, e.g. docs = \"/docs\"",
+                    docs.value()
+                ),
+            ));
+        }
+    }
+    // With #[serve] above #[http], the spec routes of `http_router()` are checked
+    // here; below it, #[http] checks them itself.
+    if args.protocols.iter().any(|p| p == "http") {
+        let sibling = crate::openapi_gen::sibling_http_args(&impl_block)?;
+        for path in [sibling.openapi_path, sibling.docs_path].iter().flatten() {
+            if let Some((_, serves)) = serve_routes.iter().find(|(p, _)| *p == path.value()) {
+                return Err(serve_route_conflict(path, serves));
+            }
+        }
+    }
+
+    let discovery_route = match args.discovery_path {
+        Some(ref discovery) => {
+            if !discovery.value().starts_with('/') || discovery.value() == health_path {
+                return Err(syn::Error::new(
+                    discovery.span(),
+                    format!(
+                        "invalid discovery path \"{}\"\n\
+                         \n\
+                         Hint: use a path starting with '/' other than the health path, e.g. discovery = \"/_routes\"",
+                        discovery.value()
+                    ),
+                ));
+            }
+            let sources = openapi_path_sources(&args.protocols, &args.mounts);
+            quote! {
+                let router = {
+                    let mut __sl_routes: ::std::vec::Vec<::server_less::OpenApiPath> = ::std::vec::Vec::new();
+                    #(__sl_routes.extend(#sources);)*
+                    let __sl_table = ::server_less::http_route_table(&__sl_routes);
+                    router.route(
+                        #discovery,
+                        ::server_less::axum::routing::get(move || {
+                            let __sl_table = __sl_table.clone();
+                            async move { ::server_less::axum::Json(__sl_table) }
+                        })
+                    )
+                };
+            }
+        }
+        None => quote! {},
+    };
 
     // Generate OpenAPI spec method and route if enabled
     let (openapi_spec_method, openapi_route) = if openapi_enabled {
-        let openapi_paths_merges = generate_openapi_merges(&args.protocols);
+        let openapi_paths_merges = generate_openapi_merges(&args.protocols, &args.mounts);
+        let spec_body = crate::openapi_gen::memoize_spec(
+            &impl_block.generics,
+            quote! {
+                ::server_less::OpenApiBuilder::new()
+                    .title(#serve_title)
+                    .version(#serve_version)
+                    #openapi_paths_merges
+                    .build()
+            },
+        );
 
         let method = quote! {
             /// Get the combined OpenAPI spec for all configured protocols.
@@ -1638,21 +3968,31 @@ pub(crate) fn expand_serve(args: ServeArgs, impl_block: ItemImpl) -> syn::Result
             ///
             /// Disable with `#[serve(http, openapi = false)]`.
             pub fn openapi_spec() -> ::server_less::serde_json::Value {
-                ::server_less::OpenApiBuilder::new()
-                    .title(#serve_title)
-                    .version(#serve_version)
-                    #openapi_paths_merges
-                    .build()
+                #spec_body
             }
         };
 
+        let docs_route = match args.docs_path {
+            Some(ref docs) => quote! {
+                let router = router.route(
+                    #docs,
+                    ::server_less::axum::routing::get(|| async {
+                        ::server_less::axum::response::Html(
+                            ::server_less::openapi_docs_html(#serve_title, "/openapi.json")
+                        )
+                    })
+                );
+            },
+            None => quote! {},
+        };
         let route = quote! {
             let router = router.route(
                 "/openapi.json",
                 ::server_less::axum::routing::get(|| async {
-                    ::server_less::axum::Json(#struct_name::openapi_spec())
+                    ::server_less::axum::Json(Self::openapi_spec())
                 })
             );
+            #docs_route
         };
 
         (method, route)
@@ -1660,46 +4000,206 @@ pub(crate) fn expand_serve(args: ServeArgs, impl_block: ItemImpl) -> syn::Result
         (quote! {}, quote! {})
     };
 
-    // Generate the serve method
-    let serve_impl = quote! {
-        impl #impl_generics #self_ty #where_clause {
-            /// Start serving all configured protocols.
-            pub async fn serve(self, addr: impl ::std::convert::AsRef<str>) -> ::std::io::Result<()>
-            where
-                Self: Clone + Send + Sync + 'static,
-            {
-                #router_setup
+    let serve_body = quote! {
+        #router_setup
 
-                // Add health check
-                let router = router.route(
-                    #health_path,
-                    ::server_less::axum::routing::get(|| async { "ok" })
-                );
+        // Add health check
+        #health_route
 
-                // Add OpenAPI spec endpoint
-                #openapi_route
+        // Add OpenAPI spec endpoint
+        #openapi_route
 
-                let listener = ::server_less::tokio::net::TcpListener::bind(addr.as_ref()).await?;
-                ::server_less::axum::serve(listener, router).await
-            }
+        // Add route table endpoint
+        #discovery_route
 
-            /// Build the combined router without starting the server.
-            pub fn router(self) -> ::server_less::axum::Router
-            where
-                Self: Clone + Send + Sync + 'static,
-            {
-                #router_setup
+        // Answer unmatched routes with a JSON 404
+        #fallback_setup
 
-                let router = router.route(
-                    #health_path,
-                    ::server_less::axum::routing::get(|| async { "ok" })
-                );
+        // Answer handler panics with a JSON 500
+        #catch_panic_setup
 
-                // Add OpenAPI spec endpoint
-                #openapi_route
+        // Answer 503 while the maintenance switch is on
+        #maintenance_setup
 
-                router
-            }
+        // Redirect `/path/` to `/path`
+        #redirect_setup
+
+        // Add metrics endpoint and request-recording layer
+        #metrics_setup
+
+        // Answer CORS preflights and mark cross-origin responses
+        #cors_setup
+
+        let listener = ::server_less::tokio::net::TcpListener::bind(addr.as_ref()).await?;
+        ::server_less::axum::serve(listener, router).await
+    };
+    let router_body = quote! {
+        #router_setup
+
+        #health_route
+
+        // Add OpenAPI spec endpoint
+        #openapi_route
+
+        #discovery_route
+
+        #fallback_setup
+
+        #catch_panic_setup
+
+        #maintenance_setup
+
+        #redirect_setup
+
+        #metrics_setup
+
+        #cors_setup
+
+        router
+    };
+
+    // With `maintenance`, the switch is part of the router it gates: `serve`,
+    // `serve_unix` and `router` build with one that stays off, the
+    // `*_with_maintenance` variants take the caller's.
+    let (serve_fns, unix_fns, router_fns) = if args.maintenance.is_some() {
+        (
+            quote! {
+                /// Start serving all configured protocols.
+                ///
+                /// Maintenance mode stays off; use `serve_with_maintenance` to
+                /// control it.
+                pub async fn serve(self, addr: impl ::std::convert::AsRef<str>) -> ::std::io::Result<()>
+                where
+                    Self: Clone + Send + Sync + 'static,
+                {
+                    self.serve_with_maintenance(addr, ::server_less::MaintenanceSwitch::new())
+                        .await
+                }
+
+                /// Start serving all configured protocols, answering 503 while
+                /// `maintenance` is on. Keep a clone of the switch to flip it.
+                pub async fn serve_with_maintenance(
+                    self,
+                    addr: impl ::std::convert::AsRef<str>,
+                    maintenance: ::server_less::MaintenanceSwitch,
+                ) -> ::std::io::Result<()>
+                where
+                    Self: Clone + Send + Sync + 'static,
+                {
+                    #serve_body
+                }
+            },
+            quote! {
+                /// Serve all configured protocols on a Unix domain socket at `path`
+                /// instead of a TCP address.
+                ///
+                /// Fails if a file already exists at `path`. Maintenance mode stays
+                /// off; use `serve_unix_with_maintenance` to control it.
+                #[cfg(unix)]
+                pub async fn serve_unix(
+                    self,
+                    path: impl ::std::convert::AsRef<::std::path::Path>,
+                ) -> ::std::io::Result<()>
+                where
+                    Self: Clone + Send + Sync + 'static,
+                {
+                    self.serve_unix_with_maintenance(path, ::server_less::MaintenanceSwitch::new())
+                        .await
+                }
+
+                /// Serve all configured protocols on a Unix domain socket at `path`,
+                /// answering 503 while `maintenance` is on. Keep a clone of the
+                /// switch to flip it.
+                ///
+                /// Fails if a file already exists at `path`.
+                #[cfg(unix)]
+                pub async fn serve_unix_with_maintenance(
+                    self,
+                    path: impl ::std::convert::AsRef<::std::path::Path>,
+                    maintenance: ::server_less::MaintenanceSwitch,
+                ) -> ::std::io::Result<()>
+                where
+                    Self: Clone + Send + Sync + 'static,
+                {
+                    let listener = ::server_less::tokio::net::UnixListener::bind(path)?;
+                    ::server_less::axum::serve(listener, self.router_with_maintenance(maintenance))
+                        .await
+                }
+            },
+            quote! {
+                /// Build the combined router without starting the server.
+                ///
+                /// Maintenance mode stays off; use `router_with_maintenance` to
+                /// control it.
+                pub fn router(self) -> ::server_less::axum::Router
+                where
+                    Self: Clone + Send + Sync + 'static,
+                {
+                    self.router_with_maintenance(::server_less::MaintenanceSwitch::new())
+                }
+
+                /// Build the combined router, answering 503 while `maintenance`
+                /// is on. Keep a clone of the switch to flip it.
+                pub fn router_with_maintenance(
+                    self,
+                    maintenance: ::server_less::MaintenanceSwitch,
+                ) -> ::server_less::axum::Router
+                where
+                    Self: Clone + Send + Sync + 'static,
+                {
+                    #router_body
+                }
+            },
+        )
+    } else {
+        (
+            quote! {
+                /// Start serving all configured protocols.
+                pub async fn serve(self, addr: impl ::std::convert::AsRef<str>) -> ::std::io::Result<()>
+                where
+                    Self: Clone + Send + Sync + 'static,
+                {
+                    #serve_body
+                }
+            },
+            quote! {
+                /// Serve all configured protocols on a Unix domain socket at `path`
+                /// instead of a TCP address.
+                ///
+                /// Fails if a file already exists at `path`.
+                #[cfg(unix)]
+                pub async fn serve_unix(
+                    self,
+                    path: impl ::std::convert::AsRef<::std::path::Path>,
+                ) -> ::std::io::Result<()>
+                where
+                    Self: Clone + Send + Sync + 'static,
+                {
+                    let listener = ::server_less::tokio::net::UnixListener::bind(path)?;
+                    ::server_less::axum::serve(listener, self.router()).await
+                }
+            },
+            quote! {
+                /// Build the combined router without starting the server.
+                pub fn router(self) -> ::server_less::axum::Router
+                where
+                    Self: Clone + Send + Sync + 'static,
+                {
+                    #router_body
+                }
+            },
+        )
+    };
+
+    // Generate the serve method
+    let serve_impl = quote! {
+        #[allow(deprecated)]
+        impl #impl_generics #self_ty #where_clause {
+            #serve_fns
+
+            #unix_fns
+
+            #router_fns
 
             #openapi_spec_method
         }
@@ -1712,8 +4212,61 @@ pub(crate) fn expand_serve(args: ServeArgs, impl_block: ItemImpl) -> syn::Result
     })
 }
 
-/// Generate OpenAPI merge calls for each enabled protocol.
-fn generate_openapi_merges(protocols: &[String]) -> TokenStream2 {
+/// Expressions for the `Vec<OpenApiPath>` of every enabled protocol and mounted
+/// service.
+fn openapi_path_sources(protocols: &[String], mounts: &[ServeMount]) -> Vec<TokenStream2> {
+    let mut sources = Vec::new();
+    for (protocol, paths) in [
+        ("http", quote! { Self::http_openapi_paths() }),
+        ("jsonrpc", quote! { Self::jsonrpc_openapi_paths() }),
+        ("graphql", quote! { Self::graphql_openapi_paths() }),
+        ("ws", quote! { Self::ws_openapi_paths() }),
+    ] {
+        if protocols.iter().any(|p| p == protocol) {
+            sources.push(paths);
+        }
+    }
+    for ServeMount { path, ty, .. } in mounts {
+        sources.push(quote! { ::server_less::http_mount_openapi_paths_of::<#ty>(#path) });
+    }
+    sources
+}
+
+/// Generate the `Cors` layer, allowing the methods and header parameters of every
+/// enabled protocol's routes plus `GET` for the built-in endpoints.
+fn generate_cors_setup(
+    cors: &ServeCors,
+    protocols: &[String],
+    mounts: &[ServeMount],
+) -> TokenStream2 {
+    let sources = openapi_path_sources(protocols, mounts);
+
+    let ServeCors {
+        origins,
+        credentials,
+        max_age,
+        methods,
+        headers,
+    } = cors;
+    let origins = (!origins.is_empty()).then(|| quote! { .origins([#(#origins),*]) });
+    let max_age = max_age.map(|seconds| quote! { .max_age(#seconds) });
+    let headers = (!headers.is_empty()).then(|| quote! { .headers([#(#headers),*]) });
+    quote! {
+        let mut cors_paths: ::std::vec::Vec<::server_less::OpenApiPath> = ::std::vec::Vec::new();
+        #(cors_paths.extend(#sources);)*
+        let router = ::server_less::Cors::new()
+            #origins
+            .credentials(#credentials)
+            #max_age
+            .methods(["GET" #(, #methods)*])
+            #headers
+            .from_paths(&cors_paths)
+            .layer(router);
+    }
+}
+
+/// Generate OpenAPI merge calls for each enabled protocol and mounted service.
+fn generate_openapi_merges(protocols: &[String], mounts: &[ServeMount]) -> TokenStream2 {
     let has_http = protocols.contains(&"http".to_string());
     let has_ws = protocols.contains(&"ws".to_string());
     let has_jsonrpc = protocols.contains(&"jsonrpc".to_string());
@@ -1724,6 +4277,7 @@ fn generate_openapi_merges(protocols: &[String]) -> TokenStream2 {
     if has_http {
         merges.push(quote! {
             .merge_paths(Self::http_openapi_paths())
+            .merge_webhooks(Self::http_openapi_webhooks())
         });
     }
     if has_jsonrpc {
@@ -1741,12 +4295,17 @@ fn generate_openapi_merges(protocols: &[String]) -> TokenStream2 {
             .merge_paths(Self::ws_openapi_paths())
         });
     }
+    for ServeMount { path, ty, .. } in mounts {
+        merges.push(quote! {
+            .merge_paths(::server_less::http_mount_openapi_paths_of::<#ty>(#path))
+        });
+    }
 
     quote! { #(#merges)* }
 }
 
-/// Generate router setup code based on enabled protocols
-fn generate_router_setup(protocols: &[String]) -> TokenStream2 {
+/// Generate router setup code based on enabled protocols and mounted services
+fn generate_router_setup(protocols: &[String], mounts: &[ServeMount]) -> TokenStream2 {
     let has_http = protocols.contains(&"http".to_string());
     let has_ws = protocols.contains(&"ws".to_string());
     let has_jsonrpc = protocols.contains(&"jsonrpc".to_string());
@@ -1756,32 +4315,114 @@ fn generate_router_setup(protocols: &[String]) -> TokenStream2 {
     let mut parts = Vec::new();
 
     if has_http {
-        parts.push(quote! { self.clone().http_router() });
+        parts.push(quote! { ::std::sync::Arc::clone(&__sl_service).http_router_arc() });
     }
     if has_ws {
-        parts.push(quote! { self.clone().ws_router() });
+        parts.push(quote! { ::std::sync::Arc::clone(&__sl_service).ws_router_arc() });
     }
     if has_jsonrpc {
-        parts.push(quote! { self.clone().jsonrpc_router() });
+        parts.push(quote! { ::std::sync::Arc::clone(&__sl_service).jsonrpc_router_arc() });
     }
     if has_graphql {
-        parts.push(quote! { self.clone().graphql_router() });
+        parts.push(quote! { ::std::sync::Arc::clone(&__sl_service).graphql_router_arc() });
     }
 
-    if parts.is_empty() {
-        quote! {
-            let router = ::server_less::axum::Router::new();
-        }
-    } else if parts.len() == 1 {
-        let first = &parts[0];
-        quote! {
-            let router = #first;
-        }
-    } else {
-        let first = &parts[0];
-        let rest = &parts[1..];
+    let combined = match parts.split_first() {
+        None => quote! { ::server_less::axum::Router::new() },
+        Some((first, rest)) => quote! { #first #(.merge(#rest))* },
+    };
+    // Every protocol router (and the readiness probe) shares this one `Arc`
+    // instead of cloning the service.
+    let router = quote! {
+        let __sl_service = ::std::sync::Arc::new(self);
+        let router = #combined;
+    };
+
+    if mounts.is_empty() {
+        return router;
+    }
+    let nests = mounts.iter().map(|ServeMount { path, ty, service }| {
         quote! {
-            let router = #first #(.merge(#rest))*;
+            .nest(#path, ::server_less::HttpMount::http_mount_router(::std::sync::Arc::<#ty>::new(#service)))
         }
+    });
+    quote! {
+        #router
+        let router = router #(#nests)*;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_route_table() {
+        let rows = vec![
+            ("GET".to_string(), "/items/{id}".to_string(), "get_item".to_string()),
+            ("DELETE".to_string(), "/items/{id}".to_string(), "delete_item".to_string()),
+            ("*".to_string(), "/admin/...".to_string(), "admin (mount)".to_string()),
+        ];
+        assert_eq!(
+            format_route_table("ItemService", &rows),
+            "--- server-less: #[http] routes on ItemService ---\n\
+             GET     /items/{id}  get_item\n\
+             DELETE  /items/{id}  delete_item\n\
+             *       /admin/...   admin (mount)\n\
+             --- end #[http] routes on ItemService ---\n"
+        );
+
+        assert!(format_route_table("Empty", &[]).contains("(no routes)\n"));
+    }
+
+    #[test]
+    fn test_count_doc_separates_cfg_methods() {
+        let methods: Vec<MethodInfo> = [
+            syn::parse_quote! { fn get_item(&self) {} },
+            syn::parse_quote! { fn list_items(&self) {} },
+            syn::parse_quote! { #[cfg(feature = "admin")] fn delete_item(&self) {} },
+        ]
+        .iter()
+        .map(|f| MethodInfo::parse(f).unwrap().unwrap())
+        .collect();
+        assert_eq!(count_doc(methods[..1].iter(), "route"), "1 route");
+        assert_eq!(
+            count_doc(methods.iter(), "route"),
+            "2 routes, plus 1 under `#[cfg]`"
+        );
+    }
+
+    #[test]
+    fn test_route_specificity_orders_static_first() {
+        let mut paths = vec!["/users/{id}/posts", "/users/{id}", "/users/me", "/users"];
+        paths.sort_by_key(|path| route_specificity(path));
+        assert_eq!(
+            paths,
+            ["/users", "/users/me", "/users/{id}", "/users/{id}/posts"]
+        );
+    }
+
+    #[test]
+    fn test_overlap_winner() {
+        use std::cmp::Ordering;
+        assert_eq!(
+            overlap_winner("/users/me", "/users/{id}"),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            overlap_winner("/users/{id}", "/users/me"),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            overlap_winner("/users/{id}", "/users/{user_id}"),
+            Some(Ordering::Equal)
+        );
+        // The first segment that differs in kind decides.
+        assert_eq!(
+            overlap_winner("/{org}/members", "/acme/{section}"),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(overlap_winner("/users/me", "/users/you"), None);
+        assert_eq!(overlap_winner("/users/{id}", "/users/{id}/posts"), None);
     }
 }
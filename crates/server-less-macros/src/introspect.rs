@@ -0,0 +1,107 @@
+//! `#[introspect]`: runtime method metadata.
+//!
+//! Each protocol macro already parses the impl block into
+//! [`server_less_parse::MethodInfo`]. This module stringifies that into
+//! `server_less::MethodInfo` and emits it as
+//! `service_methods() -> &'static [server_less::MethodInfo]`, so the same table is
+//! available whichever protocols are stacked on the impl.
+//!
+//! The table is opt-in: an inherent `service_methods()` on every annotated impl
+//! would clash between two impl blocks of one type, or with a user method of that
+//! name. It lists every method the macros parse: protocol attributes such as
+//! `#[cli(skip)]` choose what one protocol exposes, not what the service has.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use server_less_parse::{MethodInfo, extract_methods};
+use syn::ItemImpl;
+
+/// The impl block followed by its `service_methods()`.
+pub(crate) fn expand_introspect(impl_block: ItemImpl) -> syn::Result<TokenStream2> {
+    let methods = extract_methods(&impl_block)?;
+
+    let entries = methods.iter().map(method_entry);
+    let (impl_generics, _, where_clause) = impl_block.generics.split_for_impl();
+    let self_ty = &impl_block.self_ty;
+
+    Ok(quote! {
+        #impl_block
+
+        impl #impl_generics #self_ty #where_clause {
+            /// Name, docs, parameters and return type of every method on this
+            /// impl, built on the first call.
+            pub fn service_methods() -> &'static [::server_less::MethodInfo] {
+                static __SL_METHODS: ::std::sync::OnceLock<::std::vec::Vec<::server_less::MethodInfo>> =
+                    ::std::sync::OnceLock::new();
+                __SL_METHODS.get_or_init(|| {
+                    let mut __methods = ::std::vec::Vec::new();
+                    #(#entries)*
+                    __methods
+                })
+            }
+        }
+    })
+}
+
+/// `__methods.push(...)` for one method, carrying its `#[cfg]` attributes.
+fn method_entry(method: &MethodInfo) -> TokenStream2 {
+    let name = method.name.to_string();
+    let docs = match &method.docs {
+        Some(docs) => quote! { ::std::option::Option::Some(#docs.to_string()) },
+        None => quote! { ::std::option::Option::None },
+    };
+    let params = method.params.iter().map(|p| {
+        let name = p.name_str();
+        let ty = &p.ty;
+        let ty = type_string(&quote!(#ty).to_string());
+        let is_optional = p.is_optional;
+        let is_id = p.is_id;
+        quote! {
+            ::server_less::ParamInfo {
+                name: #name.to_string(),
+                ty: #ty.to_string(),
+                is_optional: #is_optional,
+                is_id: #is_id,
+            }
+        }
+    });
+    let return_type = match &method.return_info.ty {
+        Some(ty) => type_string(&quote!(#ty).to_string()),
+        None => "()".to_string(),
+    };
+    let is_async = method.is_async;
+    let is_streaming = method.return_info.is_stream;
+    let is_optional = method.return_info.is_option;
+    let is_result = method.return_info.is_result;
+    let group = match &method.group {
+        Some(group) => quote! { ::std::option::Option::Some(#group.to_string()) },
+        None => quote! { ::std::option::Option::None },
+    };
+    let cfg_attrs = &method.cfg_attrs;
+
+    quote! {
+        #(#cfg_attrs)*
+        __methods.push(::server_less::MethodInfo {
+            name: #name.to_string(),
+            docs: #docs,
+            params: ::std::vec![#(#params),*],
+            return_type: #return_type.to_string(),
+            is_async: #is_async,
+            is_streaming: #is_streaming,
+            is_optional: #is_optional,
+            is_result: #is_result,
+            group: #group,
+        });
+    }
+}
+
+/// Tidy the spacing `quote` puts into a type: `Vec < String >` → `Vec<String>`.
+fn type_string(ty: &str) -> String {
+    ty.replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace(" ::", "::")
+        .replace(":: ", "::")
+        .replace("& ", "&")
+}
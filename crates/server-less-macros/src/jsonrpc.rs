@@ -15,13 +15,14 @@
 //! - Single requests and batch requests
 //! - Notifications (requests without `id`)
 //! - Both sync and async methods
-//! - Positional and named parameters
+//! - Named (`{"a": 5, "b": 3}`) and positional (`[5, 3]`, in declaration order) parameters
 //!
 //! # Generated Methods
 //!
 //! - `jsonrpc_methods() -> Vec<String>` - List of available methods
 //! - `jsonrpc_handle_async(&self, request: Value).await` - Handle request (async)
 //! - `jsonrpc_router(self) -> axum::Router` - HTTP server at /rpc
+//! - `jsonrpc_router_arc(self: Arc<Self>) -> axum::Router` - The same, sharing an existing `Arc`
 //!
 //! # Example
 //!
@@ -55,7 +56,7 @@
 //! ```
 
 use crate::app::extract_app_meta;
-use crate::server_attrs::{has_server_hidden, has_server_skip, validate_server_attrs};
+use crate::server_attrs::{has_server_hidden, skips_operation, validate_server_attrs};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote};
 use server_less_parse::{MethodInfo, extract_methods, get_impl_name, partition_methods};
@@ -63,7 +64,9 @@ use server_less_rpc::{self, AsyncHandling};
 use syn::{ItemImpl, Token, parse::Parse};
 
 // Import Context helpers
-use crate::context::partition_context_params;
+use crate::context::{
+    http_only_error, is_cancellation_token, never_cancelled, partition_context_params,
+};
 
 /// Arguments for the #[jsonrpc] attribute
 #[derive(Default)]
@@ -106,7 +109,6 @@ impl Parse for JsonRpcArgs {
 }
 
 pub(crate) fn expand_jsonrpc(args: JsonRpcArgs, mut impl_block: ItemImpl) -> syn::Result<TokenStream2> {
-    crate::reject_generic_impl(&impl_block)?;
     // L7: app_meta is extracted to consume the __app_meta attr (preventing it from leaking
     // to downstream macros), but jsonrpc doesn't produce named artifacts that use it.
     let _app_meta = extract_app_meta(&mut impl_block.attrs);
@@ -120,7 +122,7 @@ pub(crate) fn expand_jsonrpc(args: JsonRpcArgs, mut impl_block: ItemImpl) -> syn
     for m in &methods {
         validate_server_attrs(m)?;
     }
-    let partitioned = partition_methods(&methods, has_server_skip);
+    let partitioned = partition_methods(&methods, skips_operation);
 
     // Separate hidden from visible leaf methods.
     // Hidden methods are still dispatchable but absent from method listings.
@@ -158,11 +160,6 @@ pub(crate) fn expand_jsonrpc(args: JsonRpcArgs, mut impl_block: ItemImpl) -> syn
         .collect::<syn::Result<Vec<_>>>()?;
 
     // method_names for jsonrpc_methods() and OpenRPC listing: visible only.
-    // Stored as plain strings for use in OpenAPI; also emitted as cfg-gated push statements.
-    let method_name_strings: Vec<String> = visible_leaf
-        .iter()
-        .map(|m| m.wire_name_or(|n| n))
-        .collect();
     // Statement-form for jsonrpc_methods() so #[cfg] guards individual names.
     let method_name_stmts: Vec<_> = visible_leaf
         .iter()
@@ -175,8 +172,15 @@ pub(crate) fn expand_jsonrpc(args: JsonRpcArgs, mut impl_block: ItemImpl) -> syn
             }
         })
         .collect();
-    // Keep a plain slice for OpenAPI path generation (compile-time known list).
-    let method_names = &method_name_strings;
+    // `vec!` elements for the OpenAPI method enum, each behind its method's #[cfg].
+    let method_name_elems: Vec<_> = visible_leaf
+        .iter()
+        .map(|m| {
+            let name = m.wire_name_or(|n| n);
+            let cfg_attrs = &m.cfg_attrs;
+            quote! { #(#cfg_attrs)* #name }
+        })
+        .collect();
 
     // Build method documentation (visible methods only)
     let jsonrpc_method_doc_entries: Vec<String> = visible_leaf
@@ -209,8 +213,8 @@ pub(crate) fn expand_jsonrpc(args: JsonRpcArgs, mut impl_block: ItemImpl) -> syn
         "Create an axum Router with JSON-RPC endpoint at `{}`.\n\n\
          Exposes {} method{}.",
         path,
-        method_names.len(),
-        if method_names.len() == 1 { "" } else { "s" }
+        visible_leaf.len(),
+        if visible_leaf.len() == 1 { "" } else { "s" }
     );
 
     // Generate mount dispatch arms and method names
@@ -296,6 +300,9 @@ pub(crate) fn expand_jsonrpc(args: JsonRpcArgs, mut impl_block: ItemImpl) -> syn
 
     let struct_name_snake = struct_name.to_string().to_lowercase();
     let handler_name = format_ident!("__server_less_jsonrpc_handler_{}", struct_name_snake);
+    let handler_turbofish = crate::generic_turbofish(&impl_block.generics);
+    let handler_generics = crate::handler_generics(&impl_block.generics, self_ty);
+    let (handler_impl_generics, _, handler_where_clause) = handler_generics.split_for_impl();
 
     // Generate dispatch signature and public API based on Context usage.
     // The private jsonrpc_dispatch returns Result<Value, (i32, String)> where
@@ -369,7 +376,10 @@ pub(crate) fn expand_jsonrpc(args: JsonRpcArgs, mut impl_block: ItemImpl) -> syn
     };
 
     let maybe_impl = if crate::is_protocol_impl_emitter(&impl_block, "jsonrpc") {
-        quote! { #impl_block }
+        let mut clean_impl = impl_block.clone();
+        crate::strip_webhook_attrs(&mut clean_impl);
+        crate::strip_param_attrs(&mut clean_impl);
+        quote! { #clean_impl }
     } else {
         quote! {}
     };
@@ -377,6 +387,7 @@ pub(crate) fn expand_jsonrpc(args: JsonRpcArgs, mut impl_block: ItemImpl) -> syn
     Ok(quote! {
         #maybe_impl
 
+        #[allow(deprecated)]
         impl #impl_generics ::server_less::JsonRpcMount for #self_ty #where_clause {
             fn jsonrpc_mount_methods() -> Vec<String> {
                 Self::jsonrpc_methods()
@@ -399,6 +410,7 @@ pub(crate) fn expand_jsonrpc(args: JsonRpcArgs, mut impl_block: ItemImpl) -> syn
             }
         }
 
+        #[allow(deprecated)]
         impl #impl_generics #self_ty #where_clause {
             #[doc = #jsonrpc_methods_doc]
             pub fn jsonrpc_methods() -> Vec<String> {
@@ -505,9 +517,17 @@ pub(crate) fn expand_jsonrpc(args: JsonRpcArgs, mut impl_block: ItemImpl) -> syn
             where
                 Self: Clone + Send + Sync + 'static,
             {
-                let state = ::std::sync::Arc::new(self);
+                Self::jsonrpc_router_arc(::std::sync::Arc::new(self))
+            }
+
+            /// Like `jsonrpc_router()`, but sharing an existing `Arc` of the service.
+            pub fn jsonrpc_router_arc(self: ::std::sync::Arc<Self>) -> ::server_less::axum::Router
+            where
+                Self: Send + Sync + 'static,
+            {
+                let state = self;
                 ::server_less::axum::Router::new()
-                    .route(#path, ::server_less::axum::routing::post(#handler_name))
+                    .route(#path, ::server_less::axum::routing::post(#handler_name #handler_turbofish))
                     .with_state(state)
             }
 
@@ -515,7 +535,7 @@ pub(crate) fn expand_jsonrpc(args: JsonRpcArgs, mut impl_block: ItemImpl) -> syn
             ///
             /// Returns a single POST endpoint for the JSON-RPC interface.
             pub fn jsonrpc_openapi_paths() -> ::std::vec::Vec<::server_less::OpenApiPath> {
-                let methods: Vec<&str> = vec![#(#method_names),*];
+                let methods: Vec<&str> = vec![#(#method_name_elems),*];
                 let methods_desc = methods.join(", ");
 
                 vec![
@@ -596,11 +616,11 @@ pub(crate) fn expand_jsonrpc(args: JsonRpcArgs, mut impl_block: ItemImpl) -> syn
             }
         }
 
-        async fn #handler_name(
+        async fn #handler_name #handler_impl_generics (
             ::server_less::axum::extract::State(state): ::server_less::axum::extract::State<::std::sync::Arc<#self_ty>>,
             __context_headers: ::server_less::axum::http::HeaderMap,
             ::server_less::axum::Json(request): ::server_less::axum::Json<::server_less::serde_json::Value>,
-        ) -> impl ::server_less::axum::response::IntoResponse {
+        ) -> impl ::server_less::axum::response::IntoResponse #handler_where_clause {
             use ::server_less::axum::response::IntoResponse;
 
             // Extract Context from headers
@@ -635,6 +655,9 @@ pub(crate) fn expand_jsonrpc(args: JsonRpcArgs, mut impl_block: ItemImpl) -> syn
 /// is used as the fallback.
 fn generate_jsonrpc_json_response(method: &MethodInfo) -> TokenStream2 {
     let ret = &method.return_info;
+    let ok_value = server_less_rpc::decimal_wire_value(ret.ok_type.as_ref(), quote! { value });
+    let some_value = server_less_rpc::decimal_wire_value(ret.some_type.as_ref(), quote! { value });
+    let plain_value = server_less_rpc::decimal_wire_value(ret.ty.as_ref(), quote! { result });
 
     if ret.is_unit {
         quote! {
@@ -662,23 +685,30 @@ fn generate_jsonrpc_json_response(method: &MethodInfo) -> TokenStream2 {
             }
         }
     } else if ret.is_result {
+        let assert_error = server_less_rpc::error_type_assertion(
+            ret,
+            quote! { ::server_less::assert_jsonrpc_error },
+        );
         quote! {
-            match result {
-                Ok(value) => ::server_less::serde_json::to_value(value)
-                    .map(Ok)
-                    .map_err(|e| Err((-32603i32, format!("Serialization error: {}", e))))
-                    .unwrap_or_else(|e| e),
-                Err(err) => {
-                    let __code = ::server_less::IntoErrorCode::jsonrpc_code(&err);
-                    let __msg = ::server_less::IntoErrorCode::message(&err);
-                    Err((__code, __msg))
+            {
+                #assert_error
+                match result {
+                    Ok(value) => ::server_less::serde_json::to_value(#ok_value)
+                        .map(Ok)
+                        .map_err(|e| Err((-32603i32, format!("Serialization error: {}", e))))
+                        .unwrap_or_else(|e| e),
+                    Err(err) => {
+                        let __code = ::server_less::IntoErrorCode::jsonrpc_code(&err);
+                        let __msg = ::server_less::IntoErrorCode::message(&err);
+                        Err((__code, __msg))
+                    }
                 }
             }
         }
     } else if ret.is_option {
         quote! {
             match result {
-                Some(value) => ::server_less::serde_json::to_value(value)
+                Some(value) => ::server_less::serde_json::to_value(#some_value)
                     .map(Ok)
                     .map_err(|e| Err((-32603i32, format!("Serialization error: {}", e))))
                     .unwrap_or_else(|e| e),
@@ -687,7 +717,7 @@ fn generate_jsonrpc_json_response(method: &MethodInfo) -> TokenStream2 {
         }
     } else {
         quote! {
-            ::server_less::serde_json::to_value(result)
+            ::server_less::serde_json::to_value(#plain_value)
                 .map(Ok)
                 .map_err(|e| Err((-32603i32, format!("Serialization error: {}", e))))
                 .unwrap_or_else(|e| e)
@@ -699,7 +729,10 @@ fn generate_jsonrpc_json_response(method: &MethodInfo) -> TokenStream2 {
 ///
 /// Like `server_less_rpc::generate_param_extraction` but maps errors to `(i32, String)`
 /// suitable for use in `jsonrpc_dispatch` which returns `Result<Value, (i32, String)>`.
-fn generate_jsonrpc_param_extraction(param: &server_less_parse::ParamInfo) -> TokenStream2 {
+fn generate_jsonrpc_param_extraction(
+    param: &server_less_parse::ParamInfo,
+    position: usize,
+) -> TokenStream2 {
     let name = &param.name;
     let name_str = param.name_str();
     let ty = &param.ty;
@@ -728,11 +761,12 @@ fn generate_jsonrpc_param_extraction(param: &server_less_parse::ParamInfo) -> To
             ty.clone()
         };
         let inner_ty_str = quote::quote!(#inner_ty).to_string().replace(" ", "");
+        let from_value = server_less_rpc::decimal_from_value(&inner_ty, quote! { __v.clone() });
         quote! {
-            let #name: #ty = match args.get(#name_str) {
+            let #name: #ty = match ::server_less::rpc_param(&args, #name_str, #position) {
                 None => None,
                 Some(__v) if __v.is_null() => None,
-                Some(__v) => match ::server_less::serde_json::from_value(__v.clone()) {
+                Some(__v) => match #from_value {
                     Ok(__val) => Some(__val),
                     Err(__e) => return Err((-32602i32, format!(
                         "Optional parameter '{}' has invalid type (expected {}): {}", #name_str, #inner_ty_str, __e
@@ -742,11 +776,12 @@ fn generate_jsonrpc_param_extraction(param: &server_less_parse::ParamInfo) -> To
         }
     } else {
         let ty_str = quote::quote!(#ty).to_string().replace(" ", "");
+        let from_value = server_less_rpc::decimal_from_value(ty, quote! { __val });
         quote! {
-            let __val = args.get(#name_str)
+            let __val = ::server_less::rpc_param(&args, #name_str, #position)
                 .ok_or_else(|| (-32602i32, format!("Missing required parameter: {} (expected {})", #name_str, #ty_str)))?
                 .clone();
-            let #name: #ty = ::server_less::serde_json::from_value::<#ty>(__val)
+            let #name: #ty = #from_value
                 .map_err(|e| (-32602i32, format!("Invalid parameter {} (expected {}): {}", #name_str, #ty_str, e)))?;
         }
     }
@@ -760,11 +795,23 @@ fn generate_sync_dispatch_arm(
 ) -> syn::Result<TokenStream2> {
     let method_name_str = method.wire_name_or(|n| n);
 
-    // Partition Context vs regular parameters
-    let (context_param, regular_params) = partition_context_params(&method.params)?;
+    if let Some(message) = http_only_error(method) {
+        return Ok(quote! {
+            #method_name_str => {
+                return Err(::server_less::ErrorResponse::new(
+                    ::server_less::ErrorCode::NotImplemented,
+                    #message,
+                ).into());
+            }
+        });
+    }
 
-    // If no Context, use default RPC dispatch with AsyncHandling::Error
-    if context_param.is_none() {
+    // Partition Context and CancellationToken vs regular parameters
+    let (context_param, mut regular_params) = partition_context_params(&method.params)?;
+    regular_params.retain(|p| !is_cancellation_token(&p.ty));
+
+    // If nothing is injected, use default RPC dispatch with AsyncHandling::Error
+    if regular_params.len() == method.params.len() {
         return Ok(server_less_rpc::generate_dispatch_arm(
             method,
             None,
@@ -772,15 +819,17 @@ fn generate_sync_dispatch_arm(
         ));
     }
 
-    // For Context methods: extract regular params but inject a fresh Context
+    // Otherwise extract regular params but inject a fresh Context and token
     let param_extractions = server_less_rpc::generate_param_extractions_for(&regular_params);
     let unknown_warn =
         server_less_rpc::generate_unknown_param_warning(&method_name_str, &regular_params);
 
     let mut arg_exprs = Vec::new();
     for param in &method.params {
-        if crate::context::should_inject_context(&param.ty, &method.params) {
+        if context_param.is_some_and(|c| c.name == param.name) {
             arg_exprs.push(quote! { ::server_less::Context::new() });
+        } else if is_cancellation_token(&param.ty) {
+            arg_exprs.push(never_cancelled());
         } else {
             let name = &param.name;
             arg_exprs.push(quote! { #name });
@@ -808,17 +857,27 @@ fn generate_sync_dispatch_arm(
 fn generate_dispatch_arm(method: &MethodInfo) -> syn::Result<TokenStream2> {
     let method_name_str = method.wire_name_or(|n| n);
 
-    // Partition Context vs regular parameters
-    let (context_param, regular_params) = partition_context_params(&method.params)?;
+    if let Some(message) = http_only_error(method) {
+        return Ok(quote! {
+            #method_name_str => {
+                return Err((-32601i32, #message.to_string()));
+            }
+        });
+    }
+
+    // Partition Context and CancellationToken vs regular parameters
+    let (context_param, mut regular_params) = partition_context_params(&method.params)?;
+    regular_params.retain(|p| !is_cancellation_token(&p.ty));
 
     let response = generate_jsonrpc_json_response(method);
 
-    if context_param.is_none() {
+    if regular_params.len() == method.params.len() {
         // No Context injection: generate jsonrpc-specific param extractions and call directly
         let param_extractions: Vec<_> = method
             .params
             .iter()
-            .map(generate_jsonrpc_param_extraction)
+            .enumerate()
+            .map(|(i, p)| generate_jsonrpc_param_extraction(p, i))
             .collect();
         let all_param_refs: Vec<&server_less_parse::ParamInfo> = method.params.iter().collect();
         let unknown_warn =
@@ -837,16 +896,19 @@ fn generate_dispatch_arm(method: &MethodInfo) -> syn::Result<TokenStream2> {
     // Generate extractions only for regular params (Context is already in scope as __ctx)
     let param_extractions: Vec<_> = regular_params
         .iter()
-        .map(|p| generate_jsonrpc_param_extraction(p))
+        .enumerate()
+        .map(|(i, p)| generate_jsonrpc_param_extraction(p, i))
         .collect();
     let unknown_warn =
         server_less_rpc::generate_unknown_param_warning(&method_name_str, &regular_params);
 
-    // Build argument list: Context first (if present), then regular params in order
+    // Build argument list: injected values and regular params in declaration order
     let mut arg_exprs = Vec::new();
     for param in &method.params {
-        if crate::context::should_inject_context(&param.ty, &method.params) {
+        if context_param.is_some_and(|c| c.name == param.name) {
             arg_exprs.push(quote! { __ctx.clone() });
+        } else if is_cancellation_token(&param.ty) {
+            arg_exprs.push(never_cancelled());
         } else {
             let name = &param.name;
             arg_exprs.push(quote! { #name });
@@ -953,14 +1015,17 @@ fn generate_slug_mount_dispatch(method: &MethodInfo) -> syn::Result<TokenStream2
     let slug_extractions: Vec<_> = method
         .params
         .iter()
-        .map(generate_jsonrpc_param_extraction)
+        .enumerate()
+        .map(|(i, p)| generate_jsonrpc_param_extraction(p, i))
         .collect();
     let slug_names: Vec<_> = method.params.iter().map(|p| &p.name).collect();
+    let slug_count = method.params.len();
 
     Ok(quote! {
         __method if __method.starts_with(#mount_prefix) => {
             let __stripped = &__method[#mount_prefix.len()..];
             #(#slug_extractions)*
+            let args = ::server_less::rpc_skip_positional(args, #slug_count);
             let __delegate = self.#method_name(#(#slug_names),*);
             <#inner_ty as ::server_less::JsonRpcMount>::jsonrpc_mount_dispatch_async(__delegate, __stripped, args).await
                 .map_err(|msg| (-32603i32, msg))
@@ -983,14 +1048,17 @@ fn generate_slug_mount_dispatch_sync(method: &MethodInfo) -> syn::Result<TokenSt
     let slug_extractions: Vec<_> = method
         .params
         .iter()
-        .map(server_less_rpc::generate_param_extraction)
+        .enumerate()
+        .map(|(i, p)| server_less_rpc::generate_param_extraction(p, i))
         .collect();
     let slug_names: Vec<_> = method.params.iter().map(|p| &p.name).collect();
+    let slug_count = method.params.len();
 
     Ok(quote! {
         __method if __method.starts_with(#mount_prefix) => {
             let __stripped = &__method[#mount_prefix.len()..];
             #(#slug_extractions)*
+            let args = ::server_less::rpc_skip_positional(args, #slug_count);
             let __delegate = self.#method_name(#(#slug_names),*);
             <#inner_ty as ::server_less::JsonRpcMount>::jsonrpc_mount_dispatch(__delegate, __stripped, args)
         }
@@ -22,6 +22,9 @@
 //! # Generated Methods
 //!
 //! - `jsonschema() -> serde_json::Value` - Complete JSON Schema
+//! - `json_schema_methods() -> serde_json::Value` - With `per_method = true`, a
+//!   `{"methodName": {"request": ..., "response": ...}}` map for validating both
+//!   sides of each call
 //!
 //! # Example
 //!
@@ -60,6 +63,8 @@ pub(crate) struct JsonSchemaArgs {
     title: Option<String>,
     /// Draft version (default: draft-07)
     draft: Option<String>,
+    /// Also generate `json_schema_methods()` with request/response schemas per method
+    per_method: bool,
 }
 
 impl Parse for JsonSchemaArgs {
@@ -79,15 +84,19 @@ impl Parse for JsonSchemaArgs {
                     let lit: syn::LitStr = input.parse()?;
                     args.draft = Some(lit.value());
                 }
+                "per_method" => {
+                    let lit: syn::LitBool = input.parse()?;
+                    args.per_method = lit.value;
+                }
                 other => {
-                    const VALID: &[&str] = &["title", "draft"];
+                    const VALID: &[&str] = &["title", "draft", "per_method"];
                     let suggestion = crate::did_you_mean(other, VALID)
                         .map(|s| format!(" — did you mean `{s}`?"))
                         .unwrap_or_default();
                     return Err(syn::Error::new(
                         ident.span(),
                         format!(
-                            "unknown argument `{other}`{suggestion}. Valid arguments: name, draft"
+                            "unknown argument `{other}`{suggestion}. Valid arguments: title, draft, per_method"
                         ),
                     ));
                 }
@@ -106,8 +115,6 @@ pub(crate) fn expand_jsonschema(
     args: JsonSchemaArgs,
     mut impl_block: ItemImpl,
 ) -> syn::Result<TokenStream2> {
-    // L3: reject generic impl blocks (consistent with all other protocol macros).
-    crate::reject_generic_impl(&impl_block)?;
     let app_meta = extract_app_meta(&mut impl_block.attrs);
     let struct_name = get_impl_name(&impl_block)?;
     let (impl_generics, _ty_generics, where_clause) = impl_block.generics.split_for_impl();
@@ -131,13 +138,42 @@ pub(crate) fn expand_jsonschema(
         .draft
         .unwrap_or_else(|| "http://json-schema.org/draft-07/schema#".to_string());
 
-    // Generate schema definitions for each method
-    let definitions: Vec<String> = methods
+    // Schema definitions for each method, as statements so #[cfg] guards
+    // individual methods
+    let definition_pushes: Vec<TokenStream2> = methods
         .iter()
-        .flat_map(generate_schema_definitions)
+        .map(|m| {
+            let definitions = generate_schema_definitions(m);
+            let cfg_attrs = &m.cfg_attrs;
+            quote! {
+                #(#cfg_attrs)*
+                __defs.extend([#(#definitions),*]);
+            }
+        })
         .collect();
 
-    let definitions_json = definitions.join(",\n");
+    let per_method_fn = if args.per_method {
+        let method_pushes = methods.iter().map(|m| {
+            let schema = generate_method_schema(m);
+            let cfg_attrs = &m.cfg_attrs;
+            quote! {
+                #(#cfg_attrs)*
+                __methods.push(#schema);
+            }
+        });
+        quote! {
+            /// Get request and response JSON Schemas keyed by method name.
+            #[allow(unused_mut)]
+            pub fn json_schema_methods() -> ::server_less::serde_json::Value {
+                let mut __methods: Vec<&str> = Vec::new();
+                #(#method_pushes)*
+                let methods_str = format!("{{{}}}", __methods.join(","));
+                ::server_less::serde_json::from_str(&methods_str).unwrap_or_default()
+            }
+        }
+    } else {
+        quote! {}
+    };
 
     let maybe_impl = if crate::is_protocol_impl_emitter(&impl_block, "jsonschema") {
         quote! { #impl_block }
@@ -150,10 +186,13 @@ pub(crate) fn expand_jsonschema(
 
         impl #impl_generics #self_ty #where_clause {
             /// Get JSON Schema for all request/response types.
+            #[allow(unused_mut)]
             pub fn json_schema() -> ::server_less::serde_json::Value {
-                let defs_str = concat!("{", #definitions_json, "}");
+                let mut __defs: Vec<&str> = Vec::new();
+                #(#definition_pushes)*
+                let defs_str = format!("{{{}}}", __defs.join(","));
                 let definitions: ::server_less::serde_json::Value =
-                    ::server_less::serde_json::from_str(defs_str).unwrap_or_default();
+                    ::server_less::serde_json::from_str(&defs_str).unwrap_or_default();
 
                 ::server_less::serde_json::json!({
                     "$schema": #draft,
@@ -162,6 +201,8 @@ pub(crate) fn expand_jsonschema(
                 })
             }
 
+            #per_method_fn
+
             /// Get JSON Schema as a pretty-printed string.
             pub fn json_schema_string() -> String {
                 ::server_less::serde_json::to_string_pretty(&Self::json_schema())
@@ -182,7 +223,24 @@ fn generate_schema_definitions(method: &MethodInfo) -> Vec<String> {
     let request_name = format!("{}Request", capitalize(&method_name));
     let response_name = format!("{}Response", capitalize(&method_name));
 
-    // Generate request schema
+    vec![
+        format!(r#""{}": {}"#, request_name, request_schema(method)),
+        format!(r#""{}": {}"#, response_name, response_schema(method)),
+    ]
+}
+
+/// Generate the `"methodName": {"request": ..., "response": ...}` entry for a method
+fn generate_method_schema(method: &MethodInfo) -> String {
+    format!(
+        r#""{}": {{"request": {}, "response": {}}}"#,
+        method.name_str().to_lower_camel_case(),
+        request_schema(method),
+        response_schema(method)
+    )
+}
+
+/// Schema for a method's parameters as a single object
+fn request_schema(method: &MethodInfo) -> String {
     let request_props: Vec<String> = method.params.iter().map(generate_property).collect();
 
     let required_fields: Vec<String> = method
@@ -192,20 +250,16 @@ fn generate_schema_definitions(method: &MethodInfo) -> Vec<String> {
         .map(|p| format!("\"{}\"", p.name_str().to_lower_camel_case()))
         .collect();
 
-    let request_schema = if request_props.is_empty() {
-        format!(
-            r#"
-        "{}": {{
+    if request_props.is_empty() {
+        r#"{
             "type": "object",
-            "properties": {{}},
+            "properties": {},
             "additionalProperties": false
-        }}"#,
-            request_name
-        )
+        }"#
+        .to_string()
     } else {
         format!(
-            r#"
-        "{}": {{
+            r#"{{
             "type": "object",
             "properties": {{
                 {}
@@ -213,29 +267,26 @@ fn generate_schema_definitions(method: &MethodInfo) -> Vec<String> {
             "required": [{}],
             "additionalProperties": false
         }}"#,
-            request_name,
             request_props.join(",\n                "),
             required_fields.join(", ")
         )
-    };
+    }
+}
 
-    // Generate response schema
+/// Schema for a method's return value, wrapped as `{"result": ...}`
+fn response_schema(method: &MethodInfo) -> String {
     let ret = &method.return_info;
-    let response_schema = if ret.is_unit {
-        format!(
-            r#"
-        "{}": {{
+    if ret.is_unit {
+        r#"{
             "type": "object",
-            "properties": {{}},
+            "properties": {},
             "additionalProperties": false
-        }}"#,
-            response_name
-        )
+        }"#
+        .to_string()
     } else {
         let result_schema = get_type_schema(&ret.ty);
         format!(
-            r#"
-        "{}": {{
+            r#"{{
             "type": "object",
             "properties": {{
                 "result": {}
@@ -243,11 +294,9 @@ fn generate_schema_definitions(method: &MethodInfo) -> Vec<String> {
             "required": ["result"],
             "additionalProperties": false
         }}"#,
-            response_name, result_schema
+            result_schema
         )
-    };
-
-    vec![request_schema, response_schema]
+    }
 }
 
 /// Generate a JSON Schema property
@@ -279,9 +328,18 @@ fn get_type_schema_ty(ty: &syn::Type) -> String {
     }
     // Vec<T> → {"type": "array", "items": <inner_schema>}
     if let Some(inner) = unwrap_vec_type(ty) {
-        let inner_schema = get_type_schema_ty(inner);
+        // Nested 128-bit integers are not sent as strings, only top-level ones.
+        let inner_schema = if server_less_rpc::is_wide_integer(inner) {
+            r#"{"type": "integer"}"#.to_string()
+        } else {
+            get_type_schema_ty(inner)
+        };
         return format!(r#"{{"type": "array", "items": {}}}"#, inner_schema);
     }
+    // i128/u128/Decimal travel as decimal strings.
+    if server_less_rpc::is_decimal_type(ty) {
+        return r#"{"type": "string", "format": "decimal"}"#.to_string();
+    }
     let type_str = quote!(#ty).to_string();
     if type_str.contains("HashMap") || type_str.contains("BTreeMap") {
         r#"{"type": "object", "additionalProperties": true}"#.to_string()
@@ -10,7 +10,6 @@ use syn::ItemEnum;
 #[cfg(feature = "graphql")]
 use syn::ItemStruct;
 use syn::{DeriveInput, ItemImpl, parse_macro_input};
-use syn::spanned::Spanned;
 
 /// Check that an impl block has at least one method, or emit a macro-specific error.
 ///
@@ -81,6 +80,7 @@ fn type_name(ty: &syn::Type) -> String {
 /// original impl block followed by generated code. To avoid duplicate method
 /// definitions, the preset emits the impl block from the first expand call
 /// and strips it from subsequent calls.
+#[cfg(any(feature = "http", feature = "jsonrpc", feature = "mcp", feature = "cli"))]
 fn strip_first_impl(tokens: TokenStream2) -> TokenStream2 {
     let file: syn::File = match syn::parse2(tokens.clone()) {
         Ok(file) => file,
@@ -145,24 +145,165 @@ pub(crate) fn is_protocol_impl_emitter(impl_block: &ItemImpl, current: &str) ->
     })
 }
 
-/// Returns an error if the impl block has generic type parameters.
-/// Protocol macros do not yet support generic impl blocks.
-pub(crate) fn reject_generic_impl(impl_block: &syn::ItemImpl) -> syn::Result<()> {
-    if !impl_block.generics.params.is_empty() {
-        let span = impl_block.generics.params.first()
-            .map(|p| p.span())
-            .unwrap_or_else(|| impl_block.generics.span());
-        return Err(syn::Error::new(
-            span,
-            "server-less macros do not yet support generic impl blocks — \
-             remove the type parameters or implement the trait manually; \
-             hint: consider using a concrete type, \
-             e.g. `impl MyService<ConcreteType> { ... }`",
-        ));
+/// Macros that read `#[webhook]`: `#[http]` and `#[openapi]` document the
+/// method, the runtime protocols skip it.
+#[cfg(any(
+    feature = "http",
+    feature = "mcp",
+    feature = "jsonrpc",
+    feature = "ws",
+    feature = "graphql"
+))]
+const WEBHOOK_READERS: &[&str] = &["http", "mcp", "jsonrpc", "ws", "graphql", "openapi"];
+
+/// Strip `#[webhook]` from methods once no sibling that reads it is left to expand.
+///
+/// Stacked macros expand outermost first, so the attribute must survive until
+/// the last reader has seen it.
+#[cfg(any(
+    feature = "http",
+    feature = "mcp",
+    feature = "jsonrpc",
+    feature = "ws",
+    feature = "graphql"
+))]
+pub(crate) fn strip_webhook_attrs(impl_block: &mut ItemImpl) {
+    let reader_pending = impl_block
+        .attrs
+        .iter()
+        .any(|attr| WEBHOOK_READERS.iter().any(|name| attr.path().is_ident(name)));
+    if reader_pending {
+        return;
+    }
+    for item in &mut impl_block.items {
+        if let syn::ImplItem::Fn(method) = item {
+            method.attrs.retain(|attr| !attr.path().is_ident("webhook"));
+        }
+    }
+}
+
+/// Macros that read `#[param(extractor)]`: `#[http]` passes the parameter to
+/// axum, the other runtime protocols leave the method out.
+#[cfg(any(
+    feature = "cli",
+    feature = "http",
+    feature = "mcp",
+    feature = "jsonrpc",
+    feature = "ws"
+))]
+const EXTRACTOR_READERS: &[&str] = &["cli", "http", "mcp", "jsonrpc", "ws"];
+
+/// Strip `#[param(...)]` from method parameters, keeping it on
+/// `#[param(extractor)]` ones while a sibling that reads it is left to expand.
+#[cfg(any(
+    feature = "cli",
+    feature = "http",
+    feature = "mcp",
+    feature = "jsonrpc",
+    feature = "ws"
+))]
+pub(crate) fn strip_param_attrs(impl_block: &mut ItemImpl) {
+    let reader_pending = impl_block
+        .attrs
+        .iter()
+        .any(|attr| EXTRACTOR_READERS.iter().any(|name| attr.path().is_ident(name)));
+    for item in &mut impl_block.items {
+        if let syn::ImplItem::Fn(method) = item {
+            for input in &mut method.sig.inputs {
+                if let syn::FnArg::Typed(pat_type) = input {
+                    let keep = reader_pending
+                        && server_less_parse::parse_param_attrs(&pat_type.attrs)
+                            .is_ok_and(|parsed| parsed.extractor);
+                    if !keep {
+                        pat_type.attrs.retain(|attr| !attr.path().is_ident("param"));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Whether a method name reads as side-effect free (`get_*`, `list_*`, `find_*`, ...).
+///
+/// GraphQL registers such methods as queries rather than mutations; Connect marks
+/// them `NO_SIDE_EFFECTS` so clients may call them with GET; MCP gives their tools
+/// a `readOnlyHint`.
+#[cfg(any(feature = "graphql", feature = "connect", feature = "mcp"))]
+pub(crate) fn is_query_method(name: &str) -> bool {
+    name.starts_with("get_")
+        || name.starts_with("fetch_")
+        || name.starts_with("read_")
+        || name.starts_with("list_")
+        || name.starts_with("find_")
+        || name.starts_with("search_")
+        || name.starts_with("count_")
+        || name.starts_with("exists_")
+        || name.starts_with("is_")
+        || name.starts_with("has_")
+}
+
+/// Prefix `#[mcp]` puts before every tool name: the `namespace` argument, or
+/// else the `#[app]` name, followed by `_` (nothing when both are absent).
+#[cfg(any(feature = "mcp", feature = "markdown"))]
+pub(crate) fn mcp_tool_prefix(namespace: Option<String>) -> String {
+    match namespace {
+        Some(namespace) if !namespace.is_empty() => format!("{namespace}_"),
+        _ => String::new(),
     }
-    Ok(())
 }
 
+/// Name `#[mcp]` lists and dispatches `method` under.
+#[cfg(any(feature = "mcp", feature = "markdown"))]
+pub(crate) fn mcp_tool_name(prefix: &str, method: &server_less_parse::MethodInfo) -> String {
+    format!("{}{}", prefix, method.name)
+}
+
+/// Generics for a free handler function generated alongside a (possibly
+/// generic) impl block.
+///
+/// Repeats the impl's parameters and where-clause. For generic impls the
+/// service type is additionally bounded `Send + Sync + 'static`, which axum
+/// needs to hold state in an `Arc` and to spawn per-connection futures.
+#[cfg(any(feature = "http", feature = "jsonrpc", feature = "ws"))]
+pub(crate) fn handler_generics(generics: &syn::Generics, self_ty: &syn::Type) -> syn::Generics {
+    let mut generics = generics.clone();
+    if !generics.params.is_empty() {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote! { #self_ty: ::std::marker::Send + ::std::marker::Sync + 'static });
+    }
+    generics
+}
+
+/// Turbofish (`::<T, N>`) naming an impl's type and const parameters.
+///
+/// Generated free functions (e.g. axum handlers) repeat the impl's generics, so
+/// references to them from inside the impl must spell the parameters out.
+/// Lifetimes are left to inference. Empty for non-generic impls.
+#[cfg(any(feature = "http", feature = "jsonrpc", feature = "ws"))]
+pub(crate) fn generic_turbofish(generics: &syn::Generics) -> TokenStream2 {
+    let args: Vec<TokenStream2> = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Type(ty) => {
+                let ident = &ty.ident;
+                Some(quote::quote! { #ident })
+            }
+            syn::GenericParam::Const(konst) => {
+                let ident = &konst.ident;
+                Some(quote::quote! { #ident })
+            }
+            syn::GenericParam::Lifetime(_) => None,
+        })
+        .collect();
+    if args.is_empty() {
+        TokenStream2::new()
+    } else {
+        quote::quote! { ::<#(#args),*> }
+    }
+}
 
 #[cfg(feature = "asyncapi")]
 mod asyncapi;
@@ -170,6 +311,8 @@ mod asyncapi;
 mod capnp;
 #[cfg(feature = "cli")]
 mod cli;
+#[cfg(feature = "client")]
+mod client;
 #[cfg(feature = "connect")]
 mod connect;
 mod context;
@@ -186,6 +329,7 @@ mod grpc;
 mod health;
 #[cfg(feature = "http")]
 mod http;
+mod introspect;
 #[cfg(feature = "jsonrpc")]
 mod jsonrpc;
 #[cfg(feature = "jsonschema")]
@@ -196,10 +340,18 @@ mod markdown;
 mod mcp;
 #[cfg(any(feature = "http", feature = "openapi"))]
 mod openapi;
-#[cfg(any(feature = "http", feature = "openapi"))]
+#[cfg(any(
+    feature = "http",
+    feature = "openapi",
+    feature = "client",
+    feature = "grpc",
+    feature = "markdown"
+))]
 mod openapi_gen;
 #[cfg(feature = "openrpc")]
 mod openrpc;
+mod serverless_enum;
+mod serverless_schema;
 #[cfg(feature = "smithy")]
 mod smithy;
 #[cfg(feature = "thrift")]
@@ -247,6 +399,19 @@ mod tool;
 /// }
 /// ```
 ///
+/// A `{name}` segment in the prefix is a path parameter. Every routed method
+/// must bind it with a parameter of that name, which is read from the path
+/// wherever it appears in the signature; a method without one is a compile
+/// error.
+///
+/// ```ignore
+/// #[http(prefix = "/tenants/{tenant_id}")]
+/// impl UserService {
+///     // GET /tenants/{tenant_id}/users/{id}
+///     async fn get_user(&self, tenant_id: String, id: u32) -> User { /* ... */ }
+/// }
+/// ```
+///
 /// # Per-Method Route Overrides
 ///
 /// ```ignore
@@ -274,6 +439,228 @@ mod tool;
 /// }
 /// ```
 ///
+/// `#[route(timeout = N)]` answers `504 Gateway Timeout` with a JSON error body
+/// when an async method has not returned after `N` seconds, and documents the
+/// 504 in OpenAPI.
+///
+/// Two methods answering the same verb on structurally identical paths
+/// (`/users/{id}` and `/users/{user_id}`) are a compile error; the same path
+/// under different verbs is fine. `#[route(allow_duplicate)]` on either method
+/// skips the check, for cases the macro cannot see through such as mutually
+/// exclusive `#[cfg]`s. A real overlap still panics when the router is built.
+///
+/// ```ignore
+/// #[http]
+/// impl UserService {
+///     #[cfg(feature = "v2")]
+///     async fn get_user(&self, id: u32) -> UserV2 { /* ... */ }
+///
+///     #[cfg(not(feature = "v2"))]
+///     #[route(allow_duplicate)]
+///     async fn fetch_user(&self, id: u32) -> User { /* ... */ }
+/// }
+/// ```
+///
+/// Static segments take precedence over parameters whatever the source order,
+/// and routes are registered most specific first: `GET /users/me` answers
+/// before `GET /users/{id}`. Because axum picks the path before the verb, a
+/// more specific path can still swallow requests meant for another verb: with
+/// `GET /files/{file_id}` (a `String` id) and `POST /files/upload`,
+/// `GET /files/upload` would answer 405. That is a compile error unless the
+/// static path also has the verb or the parameter's type cannot hold the
+/// segment (a `u32` id is never `upload`). `#[route(allow_duplicate)]` on
+/// either method accepts the 405. A final `{*rest}` wildcard segment captures
+/// the rest of the path and ranks below parameters.
+///
+/// # Public Methods Only
+///
+/// With `visibility = "pub"`, only `pub` / `pub(crate)` methods become routes;
+/// private methods are treated as internal helpers, as if marked `#[route(skip)]`.
+///
+/// ```ignore
+/// #[http(visibility = "pub")]
+/// impl UserService {
+///     // GET /users/{id}
+///     pub async fn get_user(&self, id: u32) -> Option<User> { /* ... */ }
+///
+///     // Not routed
+///     fn cache_key(&self, id: u32) -> String { /* ... */ }
+/// }
+/// ```
+///
+/// # Conditional Methods
+///
+/// `#[cfg(...)]` on a method carries over to everything generated from it. In
+/// a build where the method is compiled out it has no route, handler or client
+/// method, and is left out of the OpenAPI paths. The same holds for the other
+/// protocol macros and the OpenRPC, AsyncAPI and JSON Schema specs. Text
+/// schemas (`#[grpc]`, `#[markdown]`, ...) are fixed at compile time and still
+/// list every method.
+///
+/// ```ignore
+/// #[http]
+/// impl AdminService {
+///     // GET /tenants
+///     fn list_tenants(&self) -> Vec<Tenant> { /* ... */ }
+///
+///     // DELETE /tenants/{id}, only with the `multi-tenant` feature
+///     #[cfg(feature = "multi-tenant")]
+///     fn delete_tenant(&self, id: u32) { /* ... */ }
+/// }
+/// ```
+///
+/// # RPC-Style Action Paths
+///
+/// Methods without a known verb prefix are pluralized like resources by default
+/// (`do_thing` → `POST /do-things`). With `rpc_style = true` they keep their
+/// kebab-cased name instead; prefixed methods are unaffected.
+///
+/// ```ignore
+/// #[http(rpc_style = true)]
+/// impl JobService {
+///     // POST /rebuild-index
+///     async fn rebuild_index(&self) -> String { /* ... */ }
+///
+///     // GET /jobs/{id}
+///     async fn get_job(&self, id: u32) -> Option<Job> { /* ... */ }
+/// }
+/// ```
+///
+/// # Created Resources
+///
+/// With `created_location = true`, `create_*` methods answer `201 Created` and
+/// set `Location` to the resource's canonical path: the path the request was
+/// posted to (so `#[route(path)]` and prefix parameters carry through), followed
+/// by the percent-encoded `id` field of the returned value. A value without a
+/// string or numeric `id` still answers 201, just without the header. Methods
+/// with their own `#[response(status = ...)]` are left alone, and the option
+/// cannot be combined with `rpc_style = true`.
+///
+/// ```ignore
+/// #[http(prefix = "/api", created_location = true)]
+/// impl UserService {
+///     // POST /api/users → 201, Location: /api/users/42
+///     async fn create_user(&self, name: String) -> Result<User, UserError> { /* ... */ }
+/// }
+/// ```
+///
+/// # Binary Responses
+///
+/// Methods returning `Vec<u8>` or `Bytes` (also inside `Option` / `Result`) answer
+/// with the raw bytes as `application/octet-stream` instead of a JSON array, and
+/// are documented as `{"type": "string", "format": "binary"}`. A
+/// `#[response(content_type = "...")]` replaces the media type. The envelope
+/// below never wraps them.
+///
+/// ```ignore
+/// #[http]
+/// impl FileService {
+///     // GET /downloads/{id} → image/png body
+///     #[response(content_type = "image/png")]
+///     async fn get_download(&self, id: u32) -> Option<Vec<u8>> { /* ... */ }
+/// }
+/// ```
+///
+/// With `#[route(ranges)]`, a single `Range: bytes=...` request header is served
+/// as `206 Partial Content` with `Content-Range`, a range past the end answers
+/// `416`, and every response advertises `Accept-Ranges: bytes`, for media
+/// streaming and resumable downloads:
+///
+/// ```ignore
+/// #[http]
+/// impl FileService {
+///     #[route(ranges)]
+///     async fn get_video(&self, id: u32) -> Result<Bytes, FileError> { /* ... */ }
+/// }
+/// ```
+///
+/// # Dynamic Status Codes
+///
+/// A method returning an axum response tuple led by a `StatusCode`, such as
+/// `(StatusCode, Json<T>)` or `(StatusCode, HeaderMap, T)` (also inside `Option`
+/// / `Result`), picks its status at runtime. The tuple is sent through its
+/// `IntoResponse` impl as is, so the body must be a response itself (`Json<T>`,
+/// `String`, ...); neither the envelope nor `#[response(status = ...)]` applies,
+/// and `#[client]` generates no method for it.
+///
+/// ```ignore
+/// #[http]
+/// impl UserService {
+///     // PUT /users/{id} → 201 when created, 200 when replaced
+///     async fn update_user(&self, id: u32, name: String) -> (StatusCode, Json<User>) {
+///         let (user, created) = /* ... */;
+///         let status = if created { StatusCode::CREATED } else { StatusCode::OK };
+///         (status, Json(user))
+///     }
+/// }
+/// ```
+///
+/// # Response Envelope
+///
+/// With `envelope = true`, successful JSON bodies are wrapped as
+/// `{"data": <body>}` to match the `{"error": {...}}` body of failures. A `None`
+/// from an `Option` method answers 404 with a `NOT_FOUND` error body. Unit
+/// methods still answer 204 with no body, and streaming (SSE) responses are
+/// left as they are.
+///
+/// ```ignore
+/// #[http(envelope = true)]
+/// impl UserService {
+///     // GET /users/{id} → {"data": {"id": 1, "name": "..."}}
+///     async fn get_user(&self, id: u32) -> Option<User> { /* ... */ }
+/// }
+/// ```
+///
+/// # JSON Formatting
+///
+/// `pretty = true` indents JSON response bodies, which is handy when the API is
+/// read from a browser or `curl`. `skip_nulls = true` leaves out object fields
+/// whose value is `null` (at any depth), like `#[serde(skip_serializing_if =
+/// "Option::is_none")]` on every field. Both combine with `envelope`; error
+/// bodies, byte buffers and streaming responses are unaffected.
+///
+/// ```ignore
+/// #[http(pretty = true, skip_nulls = true)]
+/// impl UserService {
+///     // GET /users/{id} → {"id": 1, "name": "..."} without `"email": null`
+///     async fn get_user(&self, id: u32) -> Option<User> { /* ... */ }
+/// }
+/// ```
+///
+/// # Media Type
+///
+/// `content_type` labels successful JSON responses with a vendor media type
+/// instead of `application/json`, and the OpenAPI spec and request collections
+/// declare it for request and response bodies. It must be `application/json`
+/// or an `application/...+json` type. Error bodies stay `application/json`, and
+/// `#[response(content_type = "...")]` on a method still takes precedence.
+///
+/// ```ignore
+/// #[http(content_type = "application/vnd.myapi.v1+json")]
+/// impl UserService {
+///     // GET /users/{id} → Content-Type: application/vnd.myapi.v1+json
+///     async fn get_user(&self, id: u32) -> Option<User> { /* ... */ }
+/// }
+/// ```
+///
+/// # Associated Functions
+///
+/// Functions without a `self` receiver (constructors such as `fn new() -> Self`)
+/// are never exposed and need no marker. Putting `#[route]` or `#[response]` on
+/// one is a compile error; to expose an operation that needs no state, take
+/// `&self` anyway:
+///
+/// ```ignore
+/// #[http]
+/// impl UserService {
+///     // Not routed
+///     fn new() -> Self { /* ... */ }
+///
+///     // GET /versions
+///     fn get_version(&self) -> String { env!("CARGO_PKG_VERSION").into() }
+/// }
+/// ```
+///
 /// # Parameter Handling
 ///
 /// ```ignore
@@ -297,6 +684,54 @@ mod tool;
 /// }
 /// ```
 ///
+/// A missing or unparsable parameter, path segment or JSON body answers `400`
+/// with an `INVALID_INPUT` error body. APIs that report such failures as
+/// `422 Unprocessable Entity` can say so with
+/// `#[http(deserialize_error_status = 422)]`; any 4xx status is accepted, and
+/// the OpenAPI spec documents it on every method taking request input.
+///
+/// # Custom Extractors
+///
+/// `#[param(extractor)]` hands a parameter's type to axum unchanged, so any
+/// `FromRequestParts` type (an authenticated user, a cookie jar) can appear in
+/// a method signature. Extractor parameters are not request input: they stay
+/// out of the OpenAPI spec, path inference and generated clients. Only the last
+/// handler argument may consume the body, so a body-reading extractor (such as
+/// `String` or `Bytes`) is only allowed on methods without JSON body fields.
+/// Only `#[http]` can run an extractor: `#[mcp]`, `#[jsonrpc]`, `#[ws]` and
+/// `#[cli]` on the same impl answer calls to such a method with an error.
+///
+/// ```ignore
+/// #[http]
+/// impl NoteService {
+///     async fn get_note(&self, note_id: u32, #[param(extractor)] user: AuthUser) -> Note {
+///         /* ... */
+///     }
+///     // GET /notes/{note_id}, with `AuthUser` read from the request by axum
+/// }
+/// ```
+///
+/// # Request Cancellation
+///
+/// A `server_less::CancellationToken` parameter (tokio-util's type) is created
+/// per request and cancelled when the request ends: once the response is ready
+/// (for streams, once the stream is done), or as soon as the client disconnects
+/// and axum drops the handler. Pass it to spawned tasks or `select!` on
+/// `cancelled()` so expensive work stops with the request. Like `Context`, it is
+/// not request input and stays out of the OpenAPI spec and generated clients.
+/// Stacked `#[mcp]`, `#[jsonrpc]`, `#[ws]` and `#[cli]` pass a token that is
+/// never cancelled.
+///
+/// ```ignore
+/// #[http]
+/// impl ReportService {
+///     async fn get_report(&self, report_id: u32, cancel: CancellationToken) -> Report {
+///         let job = tokio::spawn(build_report(report_id, cancel.child_token()));
+///         job.await.unwrap()
+///     }
+/// }
+/// ```
+///
 /// # Error Handling
 ///
 /// ```ignore
@@ -318,6 +753,10 @@ mod tool;
 /// }
 /// ```
 ///
+/// The error type must implement `Debug`; with an `IntoErrorCode` impl (e.g.
+/// `#[derive(ServerlessError)]`) it also picks the status code. A missing
+/// `Debug` impl is reported on the error type in the method's signature.
+///
 /// # Server-Sent Events (SSE) Streaming
 ///
 /// Return `impl Stream<Item = T>` to enable Server-Sent Events streaming.
@@ -359,6 +798,16 @@ mod tool;
 /// data: {"id": 2}
 /// ```
 ///
+/// The stream is polled as the client reads, so a slow client slows the producer
+/// down. For live data such as metrics, where the producer should keep its pace,
+/// `#[route(sse_buffer = 256)]` drives the stream in a task into a buffer of that
+/// many events and drops the oldest when the client falls behind. The task, and
+/// with it the stream, is dropped when the client disconnects.
+///
+/// For clients that would rather read plain lines than SSE framing,
+/// `#[route(stream = "ndjson")]` sends the items as newline-delimited JSON
+/// (`application/x-ndjson`), one serialized item per line.
+///
 /// **Why `+ use<>`?**
 /// - Rust 2024 requires explicit capture of generic parameters in return position impl Trait
 /// - `+ use<>` captures all type parameters and lifetimes from the function context
@@ -385,10 +834,16 @@ mod tool;
 ///     }
 ///
 ///     // POST /api/v1/users with body: {"name": "...", "email": "..."}
+///     // The Location header is computed from the new user's id
 ///     #[response(status = 201)]
-///     #[response(header = "Location", value = "/api/v1/users/{id}")]
-///     async fn create_user(&self, name: String, email: String) -> Result<User, ApiError> {
-///         /* ... */
+///     async fn create_user(
+///         &self,
+///         name: String,
+///         email: String,
+///     ) -> Result<WithHeaders<User>, ApiError> {
+///         let user = /* ... */;
+///         let location = format!("/api/v1/users/{}", user.id);
+///         Ok(WithHeaders::new(user).header("location", location))
 ///     }
 ///
 ///     // PUT /api/v1/users/{user_id}
@@ -409,9 +864,32 @@ mod tool;
 /// }
 /// ```
 ///
+/// # Inspecting Routes
+///
+/// Building with `SERVER_LESS_ROUTES=1` prints each `#[http]` service's route
+/// table to stderr as the macro expands, showing what the naming conventions
+/// produced. Cargo does not rebuild when the variable changes, so touch the
+/// crate's sources (or `cargo clean -p my-crate`) first:
+///
+/// ```text
+/// $ SERVER_LESS_ROUTES=1 cargo build
+/// --- server-less: #[http] routes on UserService ---
+/// GET     /users       list_users
+/// GET     /users/{id}  get_user
+/// POST    /users       create_user
+/// DELETE  /users/{id}  delete_user
+/// --- end #[http] routes on UserService ---
+/// ```
+///
 /// # Generated Methods
 /// - `http_router() -> axum::Router` - Complete router with all endpoints
+/// - `http_router_arc(self: Arc<Self>) -> axum::Router` - The same, sharing an existing `Arc`
+/// - `http_routes_into(router: axum::Router) -> axum::Router` - The same routes added to a
+///   caller-provided router, for hand-written axum apps
+/// - `http_test_client() -> HttpTestClient` - Sends requests straight to `http_router()`,
+///   without a network, for tests: `service.http_test_client().get("/users/1").await`
 /// - `http_openapi_spec() -> serde_json::Value` - HTTP-only OpenAPI 3.0 specification (unless `openapi = false`)
+/// - `http_collection() -> serde_json::Value` - Postman or Insomnia collection (with `collection = "..."`)
 ///
 /// # OpenAPI Control
 ///
@@ -423,7 +901,36 @@ mod tool;
 /// impl MyService { /* ... */ }
 /// ```
 ///
+/// To serve the spec from `http_router()` itself, give it a path; `docs_path`
+/// adds a Swagger UI page (assets load from the unpkg CDN) pointing at it:
+///
+/// ```ignore
+/// #[http(openapi_path = "/openapi.json", docs_path = "/docs")]
+/// impl MyService { /* ... */ }
+/// ```
+///
+/// `#[serve(http)]` already serves the combined spec at `/openapi.json`, its
+/// health check and any `ready`, `docs`, `metrics` or `discovery` routes, so
+/// both paths must differ from those (or turn the serve spec off with
+/// `openapi = false`).
+///
 /// For standalone OpenAPI generation without HTTP routing, see `#[openapi]`.
+///
+/// # Request Collections
+///
+/// `collection = "postman"` (v2.1, which Bruno and HTTPie also import) or
+/// `collection = "insomnia"` adds `http_collection() -> serde_json::Value`, a
+/// ready-to-import collection with one request per documented route. Path,
+/// query and header parameters and JSON bodies are filled in with sample values
+/// from their schemas, and URLs start with a base URL variable defaulting to
+/// `http://localhost:3000`.
+///
+/// ```ignore
+/// #[http(collection = "postman")]
+/// impl MyService { /* ... */ }
+///
+/// std::fs::write("my-service.json", MyService::http_collection().to_string())?;
+/// ```
 #[cfg(feature = "http")]
 #[proc_macro_attribute]
 pub fn http(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -474,9 +981,16 @@ pub fn http(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// impl UserService { /* ... */ }
 /// ```
 ///
+/// # Tags
+///
+/// Operations without `#[route(tags = "...")]` or a `#[server(group = "...")]` are
+/// tagged with the struct name minus a `Service` suffix (`UserService` -> `User`),
+/// so specs merged from several services group by service.
+///
 /// # Generated Methods
 ///
-/// - `openapi_spec() -> serde_json::Value` - OpenAPI 3.0 specification
+/// - `openapi_spec() -> serde_json::Value` - OpenAPI 3.0 specification, built on the
+///   first call and cached (impls with type parameters rebuild it each call)
 ///
 /// # Combining with #[http]
 ///
@@ -538,12 +1052,34 @@ pub fn openapi(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Async Methods
+///
+/// `async fn` methods are awaited by the generated dispatch, next to sync ones.
+/// Inside an existing runtime, use the async entry points:
+///
+/// ```ignore
+/// #[cli]
+/// impl MyApp {
+///     async fn fetch(&self, url: String) -> String { /* ... */ }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     MyApp::default().cli_run_async().await
+/// }
+/// ```
+///
+/// `cli_run()` builds its own Tokio runtime for async methods and returns an
+/// error when called from within one.
+///
 /// # Generated Methods
 /// - `cli_command() -> clap::Command` - Complete CLI application
 /// - `cli_run(&self) -> Result<(), ...>` - Execute CLI and handle result (sync entry point with tokio)
-/// - `cli_run_with(&self, matches: &ArgMatches) -> Result<(), ...>` - Execute a pre-parsed command
+/// - `cli_run_with(&self, args) -> Result<(), ...>` - Same, with custom arguments
 /// - `cli_run_async(&self) -> impl Future<...>` - Runtime-agnostic async entry point
-/// - `cli_run_with_async(&self, matches: &ArgMatches) -> impl Future<...>` - Async with pre-parsed matches
+/// - `cli_run_with_async(&self, args) -> impl Future<...>` - Same, with custom arguments
+/// - `cli_dispatch_async(&self, &matches)` (`CliSubcommand`) - Async dispatch of
+///   pre-parsed `ArgMatches`
 #[cfg(feature = "cli")]
 #[proc_macro_attribute]
 pub fn cli(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -606,6 +1142,20 @@ pub fn cli(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// **Note:** Streaming methods require `mcp_call_async`, not `mcp_call`.
 ///
+/// # Tool Annotations
+///
+/// Tools carry MCP `annotations` hints: `get_*`, `list_*` and other read-style
+/// names get `readOnlyHint`, `delete_*` and `remove_*` get `destructiveHint`.
+/// Use `#[mcp_tool(...)]` to set them yourself (this replaces the inferred ones):
+///
+/// ```ignore
+/// #[mcp]
+/// impl FileTools {
+///     #[mcp_tool(destructive, idempotent)]
+///     fn truncate_file(&self, path: String) { /* ... */ }
+/// }
+/// ```
+///
 /// # Generated Methods
 /// - `mcp_tools() -> Vec<serde_json::Value>` - Tool definitions
 /// - `mcp_call(&self, name, args) -> Result<Value, String>` - Execute tool (sync only)
@@ -676,29 +1226,80 @@ pub fn mcp(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// As with `#[http]`, the error type must implement `Debug`, and
+/// `IntoErrorCode` (if implemented) supplies the error code.
+///
 /// # Client Usage
 ///
-/// Clients send JSON-RPC 2.0 messages over WebSocket:
+/// Clients send JSON-RPC style messages over WebSocket:
 ///
 /// ```json
 /// // Request
-/// {
-///   "jsonrpc": "2.0",
-///   "method": "send_message",
-///   "params": {"room": "general", "content": "Hello!"},
-///   "id": 1
-/// }
+/// {"method": "send_message", "params": {"room": "general", "content": "Hello!"}, "id": 1}
 ///
 /// // Response
-/// {
-///   "jsonrpc": "2.0",
-///   "result": {"id": 123, "room": "general", "content": "Hello!"},
-///   "id": 1
-/// }
+/// {"result": {"id": 123, "room": "general", "content": "Hello!"}, "id": 1}
+///
+/// // Error
+/// {"error": {"code": "NOT_FOUND", "message": "Unknown method: foo"}, "id": 1}
+/// ```
+///
+/// # Strict JSON-RPC 2.0
+///
+/// `jsonrpc = true` switches to full JSON-RPC 2.0 envelopes for generic
+/// JSON-RPC clients:
+///
+/// ```ignore
+/// #[ws(path = "/rpc", jsonrpc = true)]
+/// impl ChatService { /* ... */ }
+/// ```
+///
+/// ```json
+/// // Request
+/// {"jsonrpc": "2.0", "method": "send_message", "params": {"room": "general", "content": "Hello!"}, "id": 1}
+///
+/// // Response
+/// {"jsonrpc": "2.0", "result": {"id": 123, "room": "general", "content": "Hello!"}, "id": 1}
+///
+/// // Error
+/// {"jsonrpc": "2.0", "error": {"code": -32601, "message": "Method not found: foo"}, "id": 1}
+/// ```
+///
+/// Errors use the standard codes (`-32700` parse error, `-32600` invalid request,
+/// `-32601` method not found, `-32602` invalid params) and
+/// `IntoErrorCode::jsonrpc_code()` for method errors, with `app_code`/`details`
+/// under `data`. Requests without an `id` are notifications and get no reply;
+/// a batch array is answered with an array of responses.
+///
+/// # Message Size Limit
+///
+/// Public endpoints should cap what a client can send. `max_message` takes a
+/// byte count with an optional `b`, `kb`, `mb` or `gb` unit (powers of 1024):
+///
+/// ```ignore
+/// #[ws(path = "/ws", max_message = "64kb")]
+/// impl ChatService { /* ... */ }
+/// ```
+///
+/// The WebSocket transport refuses a larger frame or message, text or binary,
+/// before buffering it. The connection cannot go on after a refused frame, so it
+/// gets an `INVALID_INPUT` error reply (`-32600` with `jsonrpc = true`) and is
+/// closed.
+///
+/// # Subprotocols
+///
+/// Clients such as graphql-ws refuse a connection unless the server agrees on a
+/// `Sec-WebSocket-Protocol`. List the accepted names and the upgrade response
+/// echoes the first one the client offers:
+///
+/// ```ignore
+/// #[ws(path = "/ws", subprotocols = "json-rpc,graphql-ws")]
+/// impl ChatService { /* ... */ }
 /// ```
 ///
 /// # Generated Methods
 /// - `ws_router() -> axum::Router` - Router with WebSocket endpoint
+/// - `ws_router_arc(self: Arc<Self>) -> axum::Router` - The same, sharing an existing `Arc`
 /// - `ws_handle_message(msg) -> String` - Sync message handler
 /// - `ws_handle_message_async(msg) -> String` - Async message handler
 /// - `ws_methods() -> Vec<String>` - List of available methods
@@ -746,7 +1347,8 @@ pub fn ws(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// ```
 ///
 /// This generates:
-/// - `Calculator::jsonrpc_router()` returning an axum Router
+/// - `Calculator::jsonrpc_router()` returning an axum Router (`jsonrpc_router_arc()`
+///   builds it from an existing `Arc<Calculator>`)
 /// - `Calculator::jsonrpc_handle_async(request)` to handle JSON-RPC requests (async)
 /// - `Calculator::jsonrpc_methods()` listing available methods
 ///
@@ -754,6 +1356,10 @@ pub fn ws(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// - Named and positional parameters
 /// - Batch requests (array of requests)
 /// - Notifications (requests without id)
+///
+/// Methods returning `Result<T, E>` take the error's code from `IntoErrorCode`,
+/// so `E` must implement it (e.g. via `#[derive(ServerlessError)]`) as well as
+/// `Debug`; a missing impl is reported on `E` in the method's signature.
 #[cfg(feature = "jsonrpc")]
 #[proc_macro_attribute]
 pub fn jsonrpc(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -839,6 +1445,17 @@ pub fn openrpc(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// // Write to file
 /// UserService::write_markdown("docs/api.md")?;
 /// ```
+///
+/// # Options
+///
+/// - `title = "..."` - Document title (defaults to `"{Type} API"`)
+/// - `types = false` - Omit Rust types from signatures and parameter lists
+/// - `include_schemas = true` - Anchor each method by its OpenAPI `operationId`
+///   (`docs/api.md#create_user`) and add request/response schema tables
+/// - `protocols = "http, ws, jsonrpc, mcp"` - Add a section per listed protocol:
+///   REST routes, WebSocket and JSON-RPC method names with their endpoint, MCP
+///   tool names. Place `#[markdown]` above the protocol attributes so it can read
+///   their `prefix`, `path` and `namespace`.
 #[cfg(feature = "markdown")]
 #[proc_macro_attribute]
 pub fn markdown(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -1141,6 +1758,13 @@ pub fn smithy(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// // Write to file
 /// UserService::write_json_schema("schema.json")?;
 /// ```
+///
+/// # Options
+///
+/// - `title = "..."` - Schema title (defaults to the type name)
+/// - `draft = "..."` - `$schema` URI (defaults to draft-07)
+/// - `per_method = true` - Also generate `json_schema_methods()`, returning
+///   `{"getUser": {"request": {...}, "response": {...}}, ...}`
 #[cfg(feature = "jsonschema")]
 #[proc_macro_attribute]
 pub fn jsonschema(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -1273,9 +1897,47 @@ pub fn jsonschema(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// - `url::Url` → Url
 /// - `serde_json::Value` → JSON
 ///
+/// Your own types become named scalars with `scalars(...)`; they are converted
+/// through serde and may appear in parameters and return types:
+///
+/// ```ignore
+/// #[graphql(scalars(Money, Email))]
+/// impl BillingService {
+///     fn get_balance(&self, owner: Email) -> Money { /* ... */ }
+/// }
+/// ```
+///
+/// # Subscriptions
+///
+/// Methods returning `impl Stream<Item = T> + use<>` are subscription fields, and
+/// the router serves them at `/graphql/ws` over `graphql-transport-ws`
+/// (`connection_init`/`connection_ack`, `subscribe`, `next`, `complete`):
+///
+/// ```ignore
+/// #[graphql]
+/// impl PriceService {
+///     fn price_updates(&self, symbol: String) -> impl Stream<Item = Price> + use<> {
+///         self.feed.subscribe(symbol)
+///     }
+/// }
+/// ```
+///
+/// # Query Limits
+///
+/// Public endpoints should bound how much work one query can ask for:
+///
+/// ```ignore
+/// #[graphql(max_depth = 10, max_complexity = 1000)]
+/// impl UserService { /* ... */ }
+/// ```
+///
+/// async-graphql rejects a query nested deeper than `max_depth`, or with more
+/// than `max_complexity` fields in total, before running any resolver.
+///
 /// # Generated Methods
 /// - `graphql_schema() -> Schema` - async-graphql Schema
 /// - `graphql_router() -> axum::Router` - Router with /graphql endpoint
+/// - `graphql_schema_arc()` / `graphql_router_arc()` - The same, from an existing `Arc<Self>`
 /// - `graphql_sdl() -> String` - Schema Definition Language string
 #[cfg(feature = "graphql")]
 #[proc_macro_attribute]
@@ -1420,6 +2082,7 @@ pub fn graphql_input(_attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// // Now you can:
 /// // - service.serve("0.0.0.0:3000").await  // start server
+/// // - service.serve_unix("/run/app.sock").await  // start server on a Unix socket
 /// // - service.router()                     // get combined router
 /// ```
 ///
@@ -1429,7 +2092,63 @@ pub fn graphql_input(_attr: TokenStream, item: TokenStream) -> TokenStream {
 /// - `ws` - Include the WebSocket router (WS JSON-RPC)
 /// - `jsonrpc` - Include the JSON-RPC HTTP router
 /// - `graphql` - Include the GraphQL router
+/// - `mount("/prefix", Service)` - Nest another `#[http]` service's router under
+///   `/prefix` (repeatable). `Service` names the service type; a unit struct is
+///   used as-is, otherwise give the expression building it with
+///   `mount("/prefix", Service = Service::new())`, evaluated when the router is
+///   built. Its OpenAPI paths are merged with the prefix applied.
 /// - `health = "/path"` - Custom health check path (default: `/health`)
+/// - `readiness` - Answer the health route by calling `self.health_check()`
+///   (or `readiness = "method"`), returning 503 when it reports unhealthy.
+///   The method may be sync or async and return `bool` or `Result<_, _>`;
+///   mark it `#[server(skip)]` so it is not also exposed as a route.
+/// - `ready = "/path"` - Register a separate readiness probe. The health path
+///   then stays a constant liveness check, and the readiness method (default
+///   `health_check`) answers on this path instead, e.g.
+///   `#[serve(http, health = "/healthz", ready = "/readyz")]`.
+/// - `readiness_timeout = N` - Seconds an async readiness method may run before
+///   the probe drops it and answers 503 (default: 5), so a hung dependency check
+///   can't hang the probe. Blocking sync methods can't be interrupted, so setting
+///   it for one is an error.
+/// - `metrics = "/path"` - Expose Prometheus request metrics (counts and latency
+///   histograms labelled by method, route template and status; non-standard
+///   methods are labelled `OTHER`) collected by a middleware layer. Requires
+///   the `metrics` feature.
+/// - `docs = "/path"` - Serve a Swagger UI page for the combined spec at
+///   `/openapi.json`. Only available while OpenAPI is enabled; the path must
+///   differ from the health, `ready`, `metrics` and `discovery` paths and from
+///   a sibling `#[http(docs_path)]`.
+/// - `discovery` / `discovery = "/path"` - Serve the route table as JSON at
+///   `/_routes` (or the given path): one `{"method", "path", "operationId"}`
+///   object per operation of every protocol and mount, for dynamic clients and
+///   admin tooling.
+/// - `json_errors = false` - Keep axum's empty 404 for unmatched routes and
+///   empty 405 for a known path requested with the wrong verb. By default they
+///   answer with a `NOT_FOUND` / `METHOD_NOT_ALLOWED` JSON error body; the 405
+///   keeps axum's `Allow` header listing the path's verbs either way.
+/// - `redirect_slashes` - Answer unmatched `/path/` requests with a
+///   `308 Permanent Redirect` to `/path`, so clients that append a slash still
+///   reach the route.
+/// - `catch_panic` - Answer a panicking handler with a 500 `INTERNAL` JSON error
+///   body instead of closing the connection; the panic message is logged to
+///   stderr, not sent to the client.
+/// - `maintenance` / `maintenance(retry_after = 120)` - Generate
+///   `serve_with_maintenance(addr, switch)`, `router_with_maintenance(switch)`
+///   and, on Unix, `serve_unix_with_maintenance(path, switch)`, taking a
+///   `server_less::MaintenanceSwitch`. While the switch is on, every
+///   route except the health probes answers a 503 `UNAVAILABLE` JSON error with
+///   `Retry-After` (default: 60 seconds). Keep a clone of the switch to flip it
+///   while the server runs.
+/// - `cors` / `cors(...)` - Answer CORS preflights and add
+///   `Access-Control-Allow-*` headers for browser clients on other origins. The
+///   allowed methods and request headers come from the routes themselves (their
+///   verbs and `#[param(header)]` names, plus `content-type`), so a preflight
+///   names exactly what the API accepts. Options:
+///   `origins = "https://a.com,https://b.com"` (default: any origin),
+///   `credentials` (allow cookies; needs `origins`), `max_age = 3600` (preflight
+///   cache seconds), and `methods = "..."` / `headers = "..."` to allow more than
+///   the routes use, e.g.
+///   `#[serve(http, cors(credentials, max_age = 3600, origins = "https://app.example.com"))]`.
 #[cfg(feature = "http")]
 #[proc_macro_attribute]
 pub fn serve(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -1447,6 +2166,63 @@ pub fn serve(attr: TokenStream, item: TokenStream) -> TokenStream {
     }
 }
 
+/// Generate a typed HTTP client for an `#[http]` service.
+///
+/// Produces a `{Type}Client` struct holding a base URL and a `reqwest::Client`,
+/// with one async method per HTTP route. Routes, verbs and parameter placement
+/// are resolved with the same inference and `#[route]` / `#[param]` overrides as
+/// `#[http]`, so client and server stay in lockstep.
+///
+/// Place `#[client]` above `#[http]` so it sees the per-method attributes before
+/// `#[http]` strips them.
+///
+/// # Example
+///
+/// ```ignore
+/// use server_less::{client, http};
+///
+/// #[derive(Clone)]
+/// struct UserService;
+///
+/// #[client]
+/// #[http(prefix = "/api")]
+/// impl UserService {
+///     /// Create a user
+///     fn create_user(&self, name: String) -> User { /* ... */ }
+///
+///     /// Get a user by ID
+///     fn get_user(&self, id: u32) -> Option<User> { /* ... */ }
+/// }
+///
+/// let users = UserServiceClient::new("http://users.internal", reqwest::Client::new());
+/// let created: User = users.create_user("Ada".into()).await?;  // POST /api/users
+/// let found: Option<User> = users.get_user(1).await?;           // GET /api/users/1
+/// ```
+///
+/// # Options
+///
+/// - `prefix = "/path"` - URL prefix (defaults to the sibling `#[http(prefix)]`)
+///
+/// Every method returns `Result<_, server_less::ClientError>`. Declared
+/// `Result<T, E>` returns become `Result<T, ClientError>`, with error statuses
+/// reported as `ClientError::Status`; `Option<T>` maps 404 to `None`.
+#[cfg(feature = "client")]
+#[proc_macro_attribute]
+pub fn client(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as client::ClientArgs);
+    let impl_block = parse_impl_block!(item, "client");
+    check_not_empty_impl!(impl_block, "client");
+    let name = type_name(&impl_block.self_ty);
+
+    match client::expand_client(args, impl_block) {
+        Ok(tokens) => {
+            debug_emit("client", &name, &tokens);
+            tokens.into()
+        }
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
 /// Helper attribute for method-level HTTP route customization.
 ///
 /// This attribute is used within `#[http]` impl blocks to customize
@@ -1465,6 +2241,25 @@ pub fn serve(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 ///     #[route(hidden)]  // Hidden from OpenAPI but still routed
 ///     fn secret(&self) { }
+///
+///     /// Summary line, taken from the first doc line
+///     #[route(description = "Replaces the rest of the doc comment in OpenAPI")]
+///     fn documented(&self) { }
+///
+///     #[route(operation_id = "createUserV2")]  // OpenAPI operationId (default: method name)
+///     fn create_user(&self, name: String) { }
+///
+///     #[route(resource = "people")]  // GET /people/{id} instead of /persons/{id}
+///     fn get_person(&self, id: u32) { }
+///
+///     #[route(sse_buffer = 256)]  // Buffer at most 256 SSE events, dropping the oldest
+///     fn watch_metrics(&self) -> impl Stream<Item = Sample> + use<> { /* ... */ }
+///
+///     #[route(stream = "ndjson")]  // One JSON item per line instead of SSE events
+///     fn export_rows(&self) -> impl Stream<Item = Row> + use<> { /* ... */ }
+///
+///     #[route(timeout = 30)]  // 504 after 30 seconds, documented in OpenAPI
+///     async fn run_report(&self, id: u32) -> Report { /* ... */ }
 /// }
 /// ```
 #[cfg(feature = "http")]
@@ -1483,9 +2278,23 @@ pub fn route(_attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// - `status = <code>` - Custom HTTP status code (e.g., 201, 204)
 /// - `content_type = "<type>"` - Custom content type
-/// - `header = "<name>", value = "<value>"` - Add custom response header
+/// - `header = "<name>", value = "<value>"` - Add custom response header; both are
+///   checked at compile time
+/// - `none = "null"` - Answer `None` from an `Option<T>` method with 200 and a
+///   `null` body instead of 404 (`none = "404"` is the default)
+/// - `preload = "<url>"` - Add `Link: <url>; rel=preload`, which browsers (and
+///   servers or CDNs sending early hints) use to fetch the resource sooner;
+///   repeat it to preload several resources in one `Link` header
 ///
 /// Multiple `#[response(...)]` attributes can be combined on a single method.
+/// The first `status` declared is the one the handler answers with; a later
+/// attribute naming a different status documents an additional response in
+/// OpenAPI, with its own `content_type`, headers and `description`.
+///
+/// Headers that depend on the result, such as a `Location` built from a new
+/// resource's id, come from returning `server_less::WithHeaders<T>` (also inside
+/// `Result` or `Option`) instead. Its headers are added to the response, and the
+/// generated `#[client]` method returns them alongside the value.
 ///
 /// # Examples
 ///
@@ -1513,6 +2322,19 @@ pub fn route(_attr: TokenStream, item: TokenStream) -> TokenStream {
 ///     #[response(header = "Location", value = "/api/items/123")]
 ///     #[response(header = "X-Request-Id", value = "abc")]
 ///     fn create_with_headers(&self, name: String) -> Item { /* ... */ }
+///
+///     // Headers computed at runtime
+///     #[response(status = 201)]
+///     fn create_located(&self, name: String) -> WithHeaders<Item> {
+///         let item = /* ... */;
+///         let location = format!("/api/items/{}", item.id);
+///         WithHeaders::new(item).header("location", location)
+///     }
+///
+///     // Document a second status with a different media type
+///     #[response(status = 200, content_type = "application/json")]
+///     #[response(status = 206, content_type = "application/octet-stream")]
+///     fn get_video(&self, id: String) -> Video { /* ... */ }
 /// }
 /// ```
 #[cfg(feature = "http")]
@@ -1522,6 +2344,38 @@ pub fn response(_attr: TokenStream, item: TokenStream) -> TokenStream {
     item
 }
 
+/// Helper attribute documenting a method as an OpenAPI 3.1 webhook.
+///
+/// Used within `#[http]` and `#[openapi]` impl blocks for callbacks the server
+/// sends to subscribers. The method is not routed (nor projected onto a
+/// `#[client]`); instead it is emitted into the spec's `webhooks` section as a
+/// `POST` whose JSON body carries the method's parameters, and the spec version
+/// becomes `3.1.0`. Doc comments, `#[route(tags, description, operation_id,
+/// deprecated, hidden)]` apply as for routes. It is a no-op on its own.
+///
+/// # Example
+///
+/// ```ignore
+/// #[http]
+/// impl OrderService {
+///     /// An order left the warehouse
+///     #[webhook]  // keyed by method name: "order_shipped"
+///     fn order_shipped(&self, order_id: u64, tracking: Option<String>) { /* send it */ }
+///
+///     #[webhook(name = "orderCancelled")]
+///     fn order_cancelled(&self, order_id: u64, reason: String) { /* send it */ }
+/// }
+///
+/// // Also available on its own for composing specs:
+/// let webhooks = OrderService::http_openapi_webhooks();
+/// ```
+#[cfg(feature = "http")]
+#[proc_macro_attribute]
+pub fn webhook(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    // Pass through unchanged - the #[http] / #[openapi] macros parse these attributes
+    item
+}
+
 /// Helper attribute for parameter-level HTTP customization.
 ///
 /// This attribute is used on function parameters within `#[http]` impl blocks
@@ -1535,6 +2389,14 @@ pub fn response(_attr: TokenStream, item: TokenStream) -> TokenStream {
 /// - `path` - Force parameter to come from URL path
 /// - `body` - Force parameter to come from request body
 /// - `header` - Extract parameter from HTTP header
+/// - `min = <number>`, `max = <number>` - Inclusive bounds for numeric parameters
+/// - `pattern = "<regex>"` - Regex a string parameter must match
+/// - `with = "<module>"` - Read the raw text with `<module>::parse(&str) -> Result<T, E>`
+///   instead of `FromStr` / serde, for formats such as comma-separated lists;
+///   an `Err` answers 400 with its `Display` text. Body values must then be JSON
+///   strings, and `#[client]` methods take the parameter as that text (`String`).
+/// - `extractor` - Pass the parameter's type to axum as an extractor as-is
+///   (`#[http]` only; it cannot be combined with the other options)
 ///
 /// # Location Inference
 ///
@@ -1580,6 +2442,20 @@ pub fn response(_attr: TokenStream, item: TokenStream) -> TokenStream {
 ///         /* ... */
 ///     }
 ///
+///     // Validated input: out-of-range or non-matching values get a 400
+///     fn list_tags(
+///         &self,
+///         #[param(min = 1, max = 100)] limit: u32,
+///         #[param(pattern = "^[a-z]+$")] prefix: Option<String>,
+///     ) -> Vec<Tag> {
+///         /* ... */
+///     }
+///
+///     // Custom format: `?ids=1,2,3` parsed by `comma_list::parse`
+///     fn list_orders(&self, #[param(with = "comma_list")] ids: Vec<u64>) -> Vec<Order> {
+///         /* ... */
+///     }
+///
 ///     // Combine multiple options
 ///     fn advanced(
 ///         &self,
@@ -1595,6 +2471,10 @@ pub fn response(_attr: TokenStream, item: TokenStream) -> TokenStream {
 /// - Parameters with `name` are documented with their wire names
 /// - Parameters with `default` are marked as not required
 /// - Location overrides are reflected in OpenAPI specs
+/// - `min`, `max` and `pattern` become the schema's `minimum`, `maximum` and `pattern`
+///   (`#[http]` handlers also enforce them; other protocols only document them)
+/// - `with` parameters are documented as `{"type": "string"}`, their wire form
+///   (only `#[http]` handlers and `#[client]` methods apply `with`)
 #[cfg(any(feature = "http", feature = "cli", feature = "mcp"))]
 #[proc_macro_attribute]
 pub fn param(_attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -1602,6 +2482,57 @@ pub fn param(_attr: TokenStream, item: TokenStream) -> TokenStream {
     item
 }
 
+/// Helper attribute attaching an example exchange to a method.
+///
+/// `request` is the JSON of the method's parameters and `response` the JSON it
+/// returns; either may be omitted, and the attribute can be repeated. It is a
+/// no-op on its own.
+///
+/// # Used By
+///
+/// - `#[asyncapi]` - `examples` on the method's request and response messages
+/// - `#[markdown]` - Request/response code blocks under the method
+/// - `#[ws]` - The sample exchange in `ws_openapi_paths()` (first example found)
+///
+/// # Example
+///
+/// ```ignore
+/// #[asyncapi]
+/// impl ChatService {
+///     #[example(request = r#"{"text": "hi"}"#, response = r#""Sent: hi""#)]
+///     fn send_message(&self, text: String) -> String { /* ... */ }
+/// }
+/// ```
+#[cfg(any(feature = "asyncapi", feature = "markdown", feature = "ws"))]
+#[proc_macro_attribute]
+pub fn example(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    // Pass through unchanged - the #[asyncapi]/[markdown]/[ws] macros parse these attributes
+    item
+}
+
+/// Helper attribute setting a method's MCP tool annotations.
+///
+/// Flags: `read_only`, `destructive`, `idempotent` and `open_world`, emitted as
+/// the tool's `readOnlyHint`, `destructiveHint`, `idempotentHint` and
+/// `openWorldHint`. Each may be written `flag = false`. When present, it replaces
+/// the hints `#[mcp]` infers from the method name. It is a no-op on its own.
+///
+/// # Example
+///
+/// ```ignore
+/// #[mcp]
+/// impl SearchTools {
+///     #[mcp_tool(read_only, open_world)]
+///     fn web_search(&self, query: String) -> Vec<String> { /* ... */ }
+/// }
+/// ```
+#[cfg(feature = "mcp")]
+#[proc_macro_attribute]
+pub fn mcp_tool(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    // Pass through unchanged - the #[mcp] macro parses these attributes
+    item
+}
+
 // ============================================================================
 // Blessed Presets
 // ============================================================================
@@ -1636,6 +2567,10 @@ pub fn param(_attr: TokenStream, item: TokenStream) -> TokenStream {
 /// - `prefix` - URL prefix (e.g., `#[server(prefix = "/api")]`)
 /// - `openapi` - Toggle OpenAPI generation (default: true)
 /// - `health` - Custom health check path (default: `/health`)
+/// - `readiness` - Back the health route with a `health_check` method (see `#[serve]`)
+/// - `ready` - Separate readiness probe path, leaving `health` as liveness (see `#[serve]`)
+/// - `readiness_timeout` - Seconds an async readiness method may take (see `#[serve]`)
+/// - `metrics` - Prometheus metrics path (see `#[serve]`; requires the `metrics` feature)
 /// - `config` - Config struct type for config subcommand wiring (e.g., `#[server(config = MyConfig)]`)
 /// - `config_cmd` - Config subcommand name override or `false` to disable (default: `"config"`)
 /// - `name` - App name (forwarded from `#[app]`; default: kebab-case struct name)
@@ -1832,7 +2767,12 @@ pub fn program(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// # Attributes
 ///
 /// - `#[error(code = X)]` - Set error code (ErrorCode variant or HTTP status)
-/// - `#[error(message = "...")]` - Set custom message
+/// - `#[error(message = "...")]` - Set custom message. `{0}` (tuple variants) and
+///   `{field}` (struct variants) interpolate the variant's data, with optional format
+///   specs such as `{0:?}`; the formatted text is also the `Display` output.
+/// - `#[error(jsonrpc_code = -32602)]` - Override the JSON-RPC numeric code
+/// - `#[error(app_code = "USER_NOT_FOUND")]` - Stable application-specific code, sent as
+///   `app_code` in error responses alongside the protocol-level `code`
 ///
 /// Without attributes, the error code is inferred from the variant name.
 #[proc_macro_derive(ServerlessError, attributes(error))]
@@ -1849,6 +2789,92 @@ pub fn serverless_error(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Derive `ServerlessEnum` for a C-like enum.
+///
+/// Lists the variants' wire names in `ServerlessEnum::VARIANTS`, following
+/// `#[serde(rename = "...")]`, `#[serde(rename_all = "...")]` and skipping
+/// `#[serde(skip)]` variants. OpenAPI specs then describe parameters of this
+/// type as `{"type": "string", "enum": [...]}`.
+///
+/// Also implements `FromStr` over the same names, so the enum works as an HTTP
+/// query or path parameter. Don't combine it with a hand-written `FromStr`.
+///
+/// # Example
+///
+/// ```ignore
+/// use server_less::ServerlessEnum;
+///
+/// #[derive(Serialize, Deserialize, ServerlessEnum)]
+/// #[serde(rename_all = "lowercase")]
+/// enum Status {
+///     Active,
+///     Inactive,
+/// }
+///
+/// #[http]
+/// impl UserService {
+///     // GET /users?status=active — `status` is documented as one of "active", "inactive"
+///     fn list_users(&self, status: Option<Status>) -> Vec<User> { /* ... */ }
+/// }
+/// ```
+#[proc_macro_derive(ServerlessEnum, attributes(serde))]
+pub fn serverless_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident.to_string();
+
+    match serverless_enum::expand_serverless_enum(input) {
+        Ok(tokens) => {
+            debug_emit("ServerlessEnum", &name, &tokens);
+            tokens.into()
+        }
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derive `ServerlessSchema` for a struct with named fields.
+///
+/// Builds an object schema whose property names follow `#[serde(rename = "...")]`
+/// and `#[serde(rename_all = "...")]`, so OpenAPI request bodies describe the JSON
+/// the service actually accepts. `#[serde(skip)]` fields are omitted, and
+/// `Option` or `#[serde(default)]` fields are optional. Field doc comments become
+/// property descriptions.
+///
+/// Field types that implement `ServerlessSchema` themselves (or derive
+/// `ServerlessEnum`) are inlined; other types fall back to their inferred JSON type.
+///
+/// # Example
+///
+/// ```ignore
+/// use server_less::ServerlessSchema;
+///
+/// #[derive(Serialize, Deserialize, ServerlessSchema)]
+/// #[serde(rename_all = "camelCase")]
+/// struct Profile {
+///     /// Name shown to other users
+///     display_name: String,
+///     avatar_url: Option<String>,
+/// }
+///
+/// #[http]
+/// impl UserService {
+///     // The request body documents `displayName` and `avatarUrl`
+///     fn update_profile(&self, profile: Profile) -> Profile { /* ... */ }
+/// }
+/// ```
+#[proc_macro_derive(ServerlessSchema, attributes(serde))]
+pub fn serverless_schema(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident.to_string();
+
+    match serverless_schema::expand_serverless_schema(input) {
+        Ok(tokens) => {
+            debug_emit("ServerlessSchema", &name, &tokens);
+            tokens.into()
+        }
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
 /// Derive a standalone health-check endpoint.
 ///
 /// Generates a `health_router()` method returning an `axum::Router` with a single
@@ -1932,6 +2958,50 @@ pub fn app(args: TokenStream, item: TokenStream) -> TokenStream {
     }
 }
 
+/// Generate `service_methods() -> &'static [server_less::MethodInfo]` for an impl block.
+///
+/// Opt-in runtime metadata — name, docs, parameters and return type of every
+/// method — for admin UIs and other tooling. Stack it with any protocol macros;
+/// it lists the same methods whichever of them are present.
+///
+/// # Example
+///
+/// ```ignore
+/// use server_less::{http, introspect};
+///
+/// #[introspect]
+/// #[http]
+/// impl UserService {
+///     /// Get user by ID
+///     fn get_user(&self, id: String) -> Option<User> { /* ... */ }
+/// }
+///
+/// for method in UserService::service_methods() {
+///     println!("{}: {}", method.name, method.return_type);
+/// }
+/// ```
+///
+/// `service_methods()` is an inherent function, so use `#[introspect]` on at most
+/// one impl block per type.
+#[proc_macro_attribute]
+pub fn introspect(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if let Some(token) = proc_macro2::TokenStream::from(attr).into_iter().next() {
+        return syn::Error::new(token.span(), "#[introspect] takes no arguments")
+            .to_compile_error()
+            .into();
+    }
+    let impl_block = parse_impl_block!(item, "introspect");
+    let name = type_name(&impl_block.self_ty);
+
+    match introspect::expand_introspect(impl_block) {
+        Ok(tokens) => {
+            debug_emit("introspect", &name, &tokens);
+            tokens.into()
+        }
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
 /// Internal helper attribute — do not use directly.
 ///
 /// `#[__app_meta]` is injected by `#[app]` and consumed by downstream
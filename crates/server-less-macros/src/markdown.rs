@@ -10,6 +10,7 @@
 //! - Return type information
 //! - Async badge for async methods
 //! - Code examples
+//! - Request/response payloads from `#[example(request = "...", response = "...")]`
 //!
 //! # Format
 //!
@@ -19,6 +20,28 @@
 //! - Code blocks for examples
 //! - Links and cross-references
 //!
+//! With `include_schemas = true`, each method is anchored by its OpenAPI
+//! `operationId` and gets request/response schema tables built from the same
+//! type inference as `#[openapi]`, including the error statuses of a
+//! `#[derive(ServerlessError)]` type.
+//!
+//! # Protocol Sections
+//!
+//! `protocols = "http, ws, jsonrpc, mcp"` appends one section per listed
+//! protocol describing how the methods are reached over it: REST routes (verb
+//! and path, inferred like `#[http]` and honouring `#[route]`), WebSocket and
+//! JSON-RPC method names with their endpoint, and MCP tool names, each as that
+//! protocol names them. Paths, the `#[http]` prefix and visibility, and the MCP
+//! namespace (falling back to the `#[app]` name, like `#[mcp]`) are read from
+//! sibling attributes placed below `#[markdown]`.
+//!
+//! ```ignore
+//! #[markdown(protocols = "http, mcp")]
+//! #[http(prefix = "/api")]
+//! #[mcp(namespace = "pay")]
+//! impl PaymentService { /* ... */ }
+//! ```
+//!
 //! # Generated Methods
 //!
 //! - `markdown_docs() -> String` - Complete Markdown documentation
@@ -43,21 +66,34 @@
 //! ```
 
 use crate::app::extract_app_meta;
-use crate::server_attrs::{has_server_hidden, has_server_skip, validate_server_attrs};
+use crate::context::{is_supplied_param, should_inject_context};
+use crate::openapi_gen::{RouteOverride, http_route, sibling_http_args};
+use crate::server_attrs::{
+    has_server_hidden, has_server_skip, skips_operation, validate_server_attrs,
+};
 use heck::ToTitleCase;
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 use server_less_parse::{
-    MethodInfo, ParamInfo, extract_groups, extract_methods, get_impl_name, resolve_method_group,
+    MethodInfo, ParamInfo, extract_groups, extract_methods, extract_methods_with_visibility,
+    get_impl_name, resolve_method_group, unwrap_option_type, unwrap_result_ok_type,
+    unwrap_vec_type,
 };
-use syn::{ItemImpl, Token, parse::Parse};
+use syn::{ItemImpl, Token, parse::Parse, punctuated::Punctuated};
+
+/// Protocols `#[markdown(protocols = "...")]` can add a section for.
+const PROTOCOLS: &[&str] = &["http", "ws", "jsonrpc", "mcp"];
 
 /// Arguments for the #[markdown] attribute
 #[derive(Default)]
 pub(crate) struct MarkdownArgs {
     pub title: Option<String>,
     pub types: bool,
+    /// Render per-method request/response schema tables, anchored by OpenAPI operationId
+    pub include_schemas: bool,
+    /// Protocols to add a section for, in the order listed (`protocols = "http, ws"`)
+    pub protocols: Vec<String>,
 }
 
 impl Parse for MarkdownArgs {
@@ -65,6 +101,8 @@ impl Parse for MarkdownArgs {
         let mut args = MarkdownArgs {
             title: None,
             types: true,
+            include_schemas: false,
+            protocols: Vec::new(),
         };
 
         while !input.is_empty() {
@@ -81,15 +119,39 @@ impl Parse for MarkdownArgs {
                     let lit: syn::LitBool = input.parse()?;
                     args.types = lit.value();
                 }
+                "include_schemas" => {
+                    input.parse::<Token![=]>()?;
+                    let lit: syn::LitBool = input.parse()?;
+                    args.include_schemas = lit.value();
+                }
+                "protocols" => {
+                    input.parse::<Token![=]>()?;
+                    let lit: syn::LitStr = input.parse()?;
+                    for protocol in lit.value().split(',').map(str::trim) {
+                        if !PROTOCOLS.contains(&protocol) {
+                            let suggestion = crate::did_you_mean(protocol, PROTOCOLS)
+                                .map(|s| format!(" — did you mean `{s}`?"))
+                                .unwrap_or_default();
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                format!(
+                                    "unknown protocol `{protocol}`{suggestion}. \
+                                     Documented protocols: http, ws, jsonrpc, mcp"
+                                ),
+                            ));
+                        }
+                        args.protocols.push(protocol.to_string());
+                    }
+                }
                 other => {
-                    const VALID: &[&str] = &["title", "types"];
+                    const VALID: &[&str] = &["title", "types", "include_schemas", "protocols"];
                     let suggestion = crate::did_you_mean(other, VALID)
                         .map(|s| format!(" — did you mean `{s}`?"))
                         .unwrap_or_default();
                     return Err(syn::Error::new(
                         ident.span(),
                         format!(
-                            "unknown argument `{other}`{suggestion}. Valid arguments: name, types"
+                            "unknown argument `{other}`{suggestion}. Valid arguments: name, types, include_schemas, protocols"
                         ),
                     ));
                 }
@@ -108,8 +170,6 @@ pub(crate) fn expand_markdown(
     args: MarkdownArgs,
     mut impl_block: ItemImpl,
 ) -> syn::Result<TokenStream2> {
-    // L3: reject generic impl blocks (consistent with all other protocol macros).
-    crate::reject_generic_impl(&impl_block)?;
     let app_meta = extract_app_meta(&mut impl_block.attrs);
     let struct_name = get_impl_name(&impl_block)?;
     let generics_clone = impl_block.generics.clone();
@@ -126,29 +186,40 @@ pub(crate) fn expand_markdown(
         .filter(|m| !has_server_skip(m) && !has_server_hidden(m))
         .collect();
 
+    let app_name = app_meta.name;
     let title = args
         .title
-        .or(app_meta.name.map(|n| format!("{} API", n)))
+        .or(app_name.as_ref().map(|n| format!("{} API", n)))
         .unwrap_or_else(|| format!("{} API", struct_name_str));
     let show_types = args.types;
+    let include_schemas = args.include_schemas;
 
     // Partition methods by group
     let group_registry = extract_groups(&impl_block)?;
     let groups = partition_by_group(&methods, &group_registry)?;
 
     let mut methods_section = String::new();
+    let mut error_rows = ErrorRows::new(&impl_block.generics);
     for (group_name, group_methods) in &groups {
         if let Some(name) = group_name {
             methods_section.push_str(&format!("## {}\n\n", name));
         } else {
             methods_section.push_str("## Methods\n\n");
         }
-        let docs: Vec<String> = group_methods
+        let docs = group_methods
             .iter()
-            .map(|m| generate_method_doc(m, show_types))
-            .collect();
+            .map(|m| generate_method_doc(m, show_types, include_schemas, &mut error_rows))
+            .collect::<syn::Result<Vec<_>>>()?;
         methods_section.push_str(&docs.join("\n---\n\n"));
     }
+    for protocol in &args.protocols {
+        methods_section.push_str(&generate_protocol_section(
+            protocol,
+            &impl_block,
+            &methods,
+            app_name.as_ref(),
+        )?);
+    }
 
     let markdown = format!(
         "# {}\n\n{}\n\n{}",
@@ -157,6 +228,8 @@ pub(crate) fn expand_markdown(
         methods_section,
     );
 
+    let markdown_docs = error_rows.docs_body(&markdown);
+
     // Strip #[server(...)] from impl-level attrs (e.g. groups(...))
     let mut clean_impl = impl_block;
     clean_impl
@@ -169,7 +242,7 @@ pub(crate) fn expand_markdown(
         impl #impl_generics #self_ty #where_clause {
             /// Get the API documentation in Markdown format.
             pub fn markdown_docs() -> &'static str {
-                #markdown
+                #markdown_docs
             }
 
             /// Write the API documentation to a file.
@@ -244,11 +317,140 @@ fn generate_overview(
     overview
 }
 
-fn generate_method_doc(method: &MethodInfo, show_types: bool) -> String {
+/// Section describing how `methods` are reached over `protocol`.
+fn generate_protocol_section(
+    protocol: &str,
+    impl_block: &ItemImpl,
+    methods: &[MethodInfo],
+    app_name: Option<&String>,
+) -> syn::Result<String> {
+    let mut doc = String::new();
+    // `#[ws]` and `#[jsonrpc]` dispatch on the method name or its `#[server(name)]`.
+    let wire_names: Vec<_> = methods.iter().map(|m| (m.wire_name_or(|n| n), m)).collect();
+    match protocol {
+        "http" => {
+            let sibling = sibling_http_args(impl_block)?;
+            let prefix = sibling.prefix.unwrap_or_default();
+            // Methods `#[http(visibility = "pub")]` leaves out get no route.
+            let routed: Vec<String> =
+                extract_methods_with_visibility(impl_block, sibling.visibility)?
+                    .iter()
+                    .map(MethodInfo::name_str)
+                    .collect();
+            let mut methods: Vec<_> = methods
+                .iter()
+                .filter(|m| routed.contains(&m.name_str()))
+                .cloned()
+                .collect();
+            crate::openapi_gen::bind_prefix_params(&prefix, &mut methods);
+            doc.push_str("## HTTP Routes\n\n");
+            doc.push_str("| Method | Path | Handler |\n");
+            doc.push_str("|--------|------|---------|\n");
+            for method in &methods {
+                let overrides = RouteOverride::parse_from_attrs(&method.method.attrs)?;
+                if overrides.skip || overrides.hidden || overrides.webhook {
+                    continue;
+                }
+                let (verb, path) = http_route(method, &overrides, sibling.rpc_style);
+                doc.push_str(&format!(
+                    "| {} | `{}{}` | `{}` |\n",
+                    verb.as_str().to_uppercase(),
+                    prefix,
+                    path,
+                    method.name_str()
+                ));
+            }
+        }
+        "ws" => {
+            let path = sibling_str_arg(impl_block, "ws", "path").unwrap_or_else(|| "/ws".into());
+            doc.push_str("## WebSocket Methods\n\n");
+            doc.push_str(&format!(
+                "Connect to `{}` and send `{{\"method\": \"<name>\", \"params\": {{...}}}}` messages.\n\n",
+                path
+            ));
+            doc.push_str(&name_table("Method", &wire_names));
+        }
+        "jsonrpc" => {
+            let path =
+                sibling_str_arg(impl_block, "jsonrpc", "path").unwrap_or_else(|| "/rpc".into());
+            doc.push_str("## JSON-RPC Methods\n\n");
+            doc.push_str(&format!("POST JSON-RPC 2.0 requests to `{}`.\n\n", path));
+            doc.push_str(&name_table("Method", &wire_names));
+        }
+        "mcp" => {
+            let prefix = crate::mcp_tool_prefix(
+                sibling_str_arg(impl_block, "mcp", "namespace").or_else(|| app_name.cloned()),
+            );
+            let tools: Vec<_> = methods
+                .iter()
+                .filter(|m| !skips_operation(m))
+                .map(|m| (crate::mcp_tool_name(&prefix, m), m))
+                .collect();
+            doc.push_str("## MCP Tools\n\n");
+            doc.push_str(&name_table("Tool", &tools));
+        }
+        _ => unreachable!("BUG: protocols are validated against PROTOCOLS when parsed"),
+    }
+    doc.push('\n');
+    Ok(doc)
+}
+
+/// Table of each method's name on the wire and first doc line.
+fn name_table(heading: &str, methods: &[(String, &MethodInfo)]) -> String {
+    let mut table = format!("| {} | Description |\n|------|-------------|\n", heading);
+    for (name, method) in methods {
+        let summary = method
+            .docs
+            .as_deref()
+            .and_then(|docs| docs.lines().next())
+            .unwrap_or_default();
+        table.push_str(&format!("| `{}` | {} |\n", name, escape_cell(summary)));
+    }
+    table
+}
+
+/// A string argument of a sibling protocol attribute, e.g. the `path` of
+/// `#[ws(path = "/socket")]`.
+fn sibling_str_arg(impl_block: &ItemImpl, protocol: &str, key: &str) -> Option<String> {
+    impl_block
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(protocol))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .find_map(|meta| match meta {
+            syn::Meta::NameValue(nv) if nv.path.is_ident(key) => match nv.value {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(lit),
+                    ..
+                }) => Some(lit.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+}
+
+fn generate_method_doc(
+    method: &MethodInfo,
+    show_types: bool,
+    include_schemas: bool,
+    error_rows: &mut ErrorRows,
+) -> syn::Result<String> {
     let name = method.name_str();
     let title = name.replace('_', " ").to_title_case();
-
-    let mut doc = format!("### {}\n\n", title);
+    // Same operationId as the OpenAPI generator, so specs and docs can link to each other.
+    let operation_id = RouteOverride::parse_from_attrs(&method.method.attrs)?
+        .operation_id
+        .unwrap_or_else(|| name.clone());
+
+    let mut doc = String::new();
+    if include_schemas {
+        doc.push_str(&format!("<a id=\"{}\"></a>\n\n", operation_id));
+    }
+    doc.push_str(&format!("### {}\n\n", title));
 
     if let Some(desc) = &method.docs {
         doc.push_str(desc);
@@ -282,9 +484,14 @@ fn generate_method_doc(method: &MethodInfo, show_types: bool) -> String {
 
     doc.push_str("\n```\n\n");
 
-    if !method.params.is_empty() {
+    let inputs: Vec<&ParamInfo> = method
+        .params
+        .iter()
+        .filter(|p| is_input_param(p, &method.params))
+        .collect();
+    if !inputs.is_empty() {
         doc.push_str("**Parameters:**\n\n");
-        for param in &method.params {
+        for param in inputs {
             doc.push_str(&format!(
                 "- `{}`: {}{}\n",
                 param.name,
@@ -310,9 +517,192 @@ fn generate_method_doc(method: &MethodInfo, show_types: bool) -> String {
         doc.push_str("\n\n");
     }
 
+    for example in &method.examples {
+        doc.push_str("**Example:**\n\n");
+        if let Some(request) = &example.request {
+            doc.push_str(&format!("Request:\n\n```json\n{request}\n```\n\n"));
+        }
+        if let Some(response) = &example.response {
+            doc.push_str(&format!("Response:\n\n```json\n{response}\n```\n\n"));
+        }
+    }
+
+    if include_schemas {
+        doc.push_str(&format!("**Operation ID:** `{}`\n\n", operation_id));
+        doc.push_str(&generate_schema_tables(method, error_rows));
+    }
+
+    Ok(doc)
+}
+
+/// Whether a parameter is caller input, as opposed to one the framework supplies
+/// (`Context`, `Inject<T>`, `CancellationToken`).
+fn is_input_param(param: &ParamInfo, method_params: &[ParamInfo]) -> bool {
+    !should_inject_context(&param.ty, method_params) && !is_supplied_param(param)
+}
+
+/// Request and response schema tables, derived the way `#[openapi]` builds its schemas.
+fn generate_schema_tables(method: &MethodInfo, error_rows: &mut ErrorRows) -> String {
+    let mut doc = String::new();
+
+    let params: Vec<&ParamInfo> = method
+        .params
+        .iter()
+        .filter(|p| is_input_param(p, &method.params))
+        .collect();
+    if !params.is_empty() {
+        doc.push_str("**Request schema:**\n\n");
+        doc.push_str("| Field | Type | Required | Description |\n");
+        doc.push_str("|-------|------|----------|-------------|\n");
+        for param in params {
+            let name = param.wire_name.clone().unwrap_or_else(|| param.name_str());
+            let required = !param.is_optional && param.default_value.is_none();
+            doc.push_str(&format!(
+                "| `{}` | {} | {} | {} |\n",
+                name,
+                escape_cell(&schema_type(&param.ty)),
+                if required { "yes" } else { "no" },
+                escape_cell(&param_constraints(param))
+            ));
+        }
+        doc.push('\n');
+    }
+
+    let ret = &method.return_info;
+    doc.push_str("**Response schema:**\n\n");
+    doc.push_str("| Status | Description | Type |\n");
+    doc.push_str("|--------|-------------|------|\n");
+    match &ret.ty {
+        Some(ty) if !ret.is_unit => {
+            doc.push_str(&format!(
+                "| 200 | Successful response | {} |\n",
+                escape_cell(&schema_type(ty))
+            ));
+        }
+        _ => doc.push_str("| 204 | Successful response | — |\n"),
+    }
+    if ret.is_result {
+        doc.push_str(&error_rows.rows(ret.err_type.as_ref()));
+    }
+    doc.push('\n');
+
     doc
 }
 
+/// Error rows of the response schema tables.
+///
+/// The statuses of a `#[derive(ServerlessError)]` type are only known at run time,
+/// so its rows are left as placeholders that `markdown_docs()` fills in on first
+/// call. Error types naming a type or const parameter of the impl get the generic
+/// 400/500 rows: the filled-in docs are cached in a `static` shared by every
+/// instantiation.
+struct ErrorRows {
+    generic_params: Vec<String>,
+    /// Placeholder and error type of every deferred row set.
+    deferred: Vec<(String, syn::Type)>,
+}
+
+impl ErrorRows {
+    const GENERIC: &'static str = "| 400 | Bad request | — |\n| 500 | Internal server error | — |\n";
+
+    fn new(generics: &syn::Generics) -> Self {
+        let generic_params = generics
+            .type_params()
+            .map(|p| p.ident.to_string())
+            .chain(generics.const_params().map(|p| p.ident.to_string()))
+            .collect();
+        Self {
+            generic_params,
+            deferred: Vec::new(),
+        }
+    }
+
+    fn rows(&mut self, err_ty: Option<&syn::Type>) -> String {
+        let Some(err_ty) = err_ty else {
+            return Self::GENERIC.to_string();
+        };
+        let mentions_generic = quote::quote!(#err_ty).into_iter().any(|token| {
+            matches!(token, proc_macro2::TokenTree::Ident(ident)
+                if self.generic_params.contains(&ident.to_string()))
+        });
+        if mentions_generic {
+            return Self::GENERIC.to_string();
+        }
+        let placeholder = format!("\u{0}server-less-error-rows-{}\u{0}", self.deferred.len());
+        self.deferred.push((placeholder.clone(), err_ty.clone()));
+        placeholder
+    }
+
+    /// Body of `markdown_docs()` returning `markdown` with the placeholders filled in.
+    fn docs_body(&self, markdown: &str) -> TokenStream2 {
+        if self.deferred.is_empty() {
+            return quote! { #markdown };
+        }
+        let fills = self.deferred.iter().map(|(placeholder, err_ty)| {
+            quote! {
+                let rows = ::server_less::markdown_error_rows(
+                    &::server_less::ErrorVariantsHelper::<#err_ty>::new().error_variants(),
+                );
+                docs = docs.replacen(#placeholder, &rows, 1);
+            }
+        });
+        quote! {
+            static __SL_DOCS: ::std::sync::OnceLock<String> = ::std::sync::OnceLock::new();
+            __SL_DOCS.get_or_init(|| {
+                use ::server_less::ErrorVariantsFallback as _;
+                let mut docs = #markdown.to_string();
+                #(#fills)*
+                docs
+            })
+        }
+    }
+}
+
+/// Escape `|` so text stays inside its Markdown table cell.
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+/// JSON Schema type of a Rust type, with array items and named object types spelled out.
+fn schema_type(ty: &syn::Type) -> String {
+    if let Some(inner) = unwrap_result_ok_type(ty).or_else(|| unwrap_option_type(ty)) {
+        return schema_type(inner);
+    }
+    if let Some(inner) = unwrap_vec_type(ty) {
+        return format!("array of {}", schema_type(inner));
+    }
+    let json_type = server_less_rpc::infer_json_type(ty);
+    match ty {
+        syn::Type::Path(tp) if json_type == "object" => {
+            let last = tp
+                .path
+                .segments
+                .last()
+                .expect("BUG: type path has at least one segment");
+            format!("object (`{}`)", last.ident)
+        }
+        _ => json_type.to_string(),
+    }
+}
+
+/// Help text and `#[param(min, max, pattern)]` constraints of a parameter.
+fn param_constraints(param: &ParamInfo) -> String {
+    let mut parts = Vec::new();
+    if let Some(help) = &param.help_text {
+        parts.push(help.clone());
+    }
+    if let Some(min) = &param.min {
+        parts.push(format!("minimum {}", min));
+    }
+    if let Some(max) = &param.max {
+        parts.push(format!("maximum {}", max));
+    }
+    if let Some(pattern) = &param.pattern {
+        parts.push(format!("pattern `{}`", pattern));
+    }
+    parts.join("; ")
+}
+
 fn format_param(param: &ParamInfo, show_types: bool) -> String {
     let name = param.name_str();
     if show_types {
@@ -34,6 +34,14 @@
 //! - Custom structs → object (requires Serialize/Deserialize)
 //! - `Option<T>` → optional parameter (nullable)
 //!
+//! # Tool Annotations
+//!
+//! Each tool carries MCP `annotations` hints so clients can decide whether to
+//! confirm before calling it. They are inferred from the method name (`get_`,
+//! `list_`, ... → `readOnlyHint`; `delete_`, `remove_` → `destructiveHint`)
+//! unless `#[mcp_tool(...)]` sets them explicitly:
+//! `read_only`, `destructive`, `idempotent`, `open_world` (each also `= false`).
+//!
 //! # Return Types
 //!
 //! Return values are automatically converted to JSON:
@@ -54,11 +62,16 @@
 //!
 //! Also implements `McpNamespace` trait for composition.
 
-use crate::context::partition_context_params;
-use crate::server_attrs::{has_server_hidden, has_server_skip, validate_server_attrs};
+use crate::context::{
+    http_only_error, is_cancellation_token, is_supplied_param, never_cancelled,
+    partition_context_params,
+};
+use crate::server_attrs::{has_server_hidden, skips_operation, validate_server_attrs};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use server_less_parse::{MethodInfo, extract_methods, get_impl_name, partition_methods};
+use server_less_parse::{
+    MethodInfo, did_you_mean, extract_methods, get_impl_name, partition_methods,
+};
 use server_less_rpc::{self, AsyncHandling};
 use syn::{ItemImpl, Token, parse::Parse};
 
@@ -111,18 +124,18 @@ impl Parse for McpArgs {
     }
 }
 
-/// Strip `#[param(...)]` attributes from function parameters in the re-emitted impl block.
+/// Strip `#[param(...)]` and `#[mcp_tool(...)]` attributes in the re-emitted impl block.
 /// These are consumed by the `#[mcp]` macro during parsing; leaving them in the output
 /// would cause "cannot find attribute `param`" errors if `#[http]` is not also applied.
-fn strip_param_attrs(impl_block: &ItemImpl) -> ItemImpl {
+fn strip_mcp_attrs(impl_block: &ItemImpl) -> ItemImpl {
     let mut block = impl_block.clone();
+    crate::strip_webhook_attrs(&mut block);
+    crate::strip_param_attrs(&mut block);
     for item in &mut block.items {
         if let syn::ImplItem::Fn(method) = item {
-            for input in &mut method.sig.inputs {
-                if let syn::FnArg::Typed(pat_type) = input {
-                    pat_type.attrs.retain(|attr| !attr.path().is_ident("param"));
-                }
-            }
+            method
+                .attrs
+                .retain(|attr| !attr.path().is_ident("mcp_tool"));
         }
     }
     block
@@ -134,30 +147,24 @@ pub(crate) fn expand_mcp(args: McpArgs, mut impl_block: ItemImpl) -> syn::Result
     let app_name = args.name.or(app_meta.name);
     let _app_description = args.description.or(app_meta.description);
 
-    crate::reject_generic_impl(&impl_block)?;
     let _struct_name = get_impl_name(&impl_block)?;
     let (impl_generics, _ty_generics, where_clause) = impl_block.generics.split_for_impl();
     let self_ty = &impl_block.self_ty;
     let methods = extract_methods(&impl_block)?;
     let clean_impl = if crate::is_protocol_impl_emitter(&impl_block, "mcp") {
-        let stripped = strip_param_attrs(&impl_block);
+        let stripped = strip_mcp_attrs(&impl_block);
         quote! { #stripped }
     } else {
         quote! {}
     };
 
     // Use explicit namespace first; fall back to app_meta.name (from #[app]) as namespace.
-    let namespace = args.namespace.or(app_name).unwrap_or_default();
-    let namespace_prefix = if namespace.is_empty() {
-        String::new()
-    } else {
-        format!("{}_", namespace)
-    };
+    let namespace_prefix = crate::mcp_tool_prefix(args.namespace.or(app_name));
 
     for m in &methods {
         validate_server_attrs(m)?;
     }
-    let partitioned = partition_methods(&methods, has_server_skip);
+    let partitioned = partition_methods(&methods, skips_operation);
 
     // Separate hidden from visible leaf methods.
     // Hidden methods are still dispatchable but absent from tool listings.
@@ -214,7 +221,7 @@ pub(crate) fn expand_mcp(args: McpArgs, mut impl_block: ItemImpl) -> syn::Result
     let leaf_tool_names: Vec<_> = visible_leaf
         .iter()
         .map(|m| {
-            let name = format!("{}{}", namespace_prefix, m.name);
+            let name = crate::mcp_tool_name(&namespace_prefix, m);
             let cfg_attrs = &m.cfg_attrs;
             quote! {
                 #(#cfg_attrs)*
@@ -266,7 +273,7 @@ pub(crate) fn expand_mcp(args: McpArgs, mut impl_block: ItemImpl) -> syn::Result
     let tool_doc_entries: Vec<String> = visible_leaf
         .iter()
         .map(|m| {
-            let name = format!("{}{}", namespace_prefix, m.name);
+            let name = crate::mcp_tool_name(&namespace_prefix, m);
             match &m.docs {
                 Some(doc) => format!("- `{name}` — {doc}"),
                 None => format!("- `{name}`"),
@@ -292,6 +299,7 @@ pub(crate) fn expand_mcp(args: McpArgs, mut impl_block: ItemImpl) -> syn::Result
     Ok(quote! {
         #clean_impl
 
+        #[allow(deprecated)]
         impl #impl_generics ::server_less::McpNamespace for #self_ty #where_clause {
             fn mcp_namespace_tools() -> Vec<::server_less::serde_json::Value> {
                 Self::mcp_tools()
@@ -318,6 +326,7 @@ pub(crate) fn expand_mcp(args: McpArgs, mut impl_block: ItemImpl) -> syn::Result
             }
         }
 
+        #[allow(deprecated)]
         impl #impl_generics #self_ty #where_clause {
             #[doc = #mcp_tools_doc]
             pub fn mcp_tools() -> Vec<::server_less::serde_json::Value> {
@@ -368,6 +377,99 @@ pub(crate) fn expand_mcp(args: McpArgs, mut impl_block: ItemImpl) -> syn::Result
     })
 }
 
+/// Behaviour hints emitted as a tool's MCP `annotations`.
+#[derive(Default)]
+struct ToolHints {
+    read_only: Option<bool>,
+    destructive: Option<bool>,
+    idempotent: Option<bool>,
+    open_world: Option<bool>,
+}
+
+const TOOL_HINTS: &[&str] = &["read_only", "destructive", "idempotent", "open_world"];
+
+impl ToolHints {
+    /// Read `#[mcp_tool(...)]`, or infer the hints from the method's verb prefix.
+    fn for_method(method: &MethodInfo) -> syn::Result<Self> {
+        let mut hints = Self::default();
+        let mut explicit = false;
+        for attr in &method.method.attrs {
+            if !attr.path().is_ident("mcp_tool") {
+                continue;
+            }
+            explicit = true;
+            attr.parse_nested_meta(|meta| {
+                let slot = if meta.path.is_ident("read_only") {
+                    &mut hints.read_only
+                } else if meta.path.is_ident("destructive") {
+                    &mut hints.destructive
+                } else if meta.path.is_ident("idempotent") {
+                    &mut hints.idempotent
+                } else if meta.path.is_ident("open_world") {
+                    &mut hints.open_world
+                } else {
+                    let unknown = meta
+                        .path
+                        .get_ident()
+                        .map(|i| i.to_string())
+                        .unwrap_or_default();
+                    let suggestion = did_you_mean(&unknown, TOOL_HINTS)
+                        .map(|s| format!(" — did you mean `{s}`?"))
+                        .unwrap_or_default();
+                    return Err(meta.error(format!(
+                        "unknown `#[mcp_tool]` attribute `{unknown}`{suggestion}\n\
+                         \n\
+                         Valid attributes: read_only, destructive, idempotent, open_world\n\
+                         \n\
+                         Example: #[mcp_tool(read_only)]"
+                    )));
+                };
+                // Support both `read_only` and `read_only = false`
+                *slot = Some(if meta.input.peek(Token![=]) {
+                    meta.value()?.parse::<syn::LitBool>()?.value()
+                } else {
+                    true
+                });
+                Ok(())
+            })?;
+            if hints.read_only == Some(true) && hints.destructive == Some(true) {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "a `read_only` tool cannot also be `destructive`",
+                ));
+            }
+        }
+
+        if !explicit {
+            let name = method.name_str();
+            if crate::is_query_method(&name) {
+                hints.read_only = Some(true);
+            } else if name.starts_with("delete_") || name.starts_with("remove_") {
+                hints.destructive = Some(true);
+            }
+        }
+        Ok(hints)
+    }
+
+    /// The `, "annotations": {...}` field for the tool's `json!`, if any hint is set.
+    fn annotations_field(&self) -> TokenStream2 {
+        let entries: Vec<_> = [
+            ("readOnlyHint", self.read_only),
+            ("destructiveHint", self.destructive),
+            ("idempotentHint", self.idempotent),
+            ("openWorldHint", self.open_world),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| value.map(|value| quote! { #key: #value }))
+        .collect();
+        if entries.is_empty() {
+            quote! {}
+        } else {
+            quote! { , "annotations": { #(#entries),* } }
+        }
+    }
+}
+
 /// Generate MCP parameter schema entries, respecting `#[param(name = "...")]` wire-name overrides.
 ///
 /// Unlike `server_less_rpc::generate_param_schema_for`, this uses the wire name (from
@@ -409,12 +511,14 @@ fn generate_tool_definition(
         .clone()
         .unwrap_or(base_name.clone());
 
-    // Partition out Context parameters — they are injected, not user-visible inputs.
-    let (_ctx_param, user_params) =
+    // Partition out Context and supplied parameters — neither is a user-visible input.
+    let (_ctx_param, mut user_params) =
         partition_context_params(&method.params)?;
+    user_params.retain(|p| !is_supplied_param(p));
 
     // Generate parameter schema, honoring #[param(name = "...")] wire-name override.
     let (properties, required_params) = generate_mcp_param_schema(&user_params);
+    let annotations = ToolHints::for_method(method)?.annotations_field();
 
     Ok(quote! {
         {
@@ -437,6 +541,7 @@ fn generate_tool_definition(
                     "properties": properties,
                     "required": [#(#required_params),*]
                 }
+                #annotations
             })
         }
     })
@@ -447,7 +552,7 @@ fn generate_dispatch_arm_sync(
     namespace_prefix: &str,
     method: &MethodInfo,
 ) -> TokenStream2 {
-    let tool_name = format!("{}{}", namespace_prefix, method.name);
+    let tool_name = crate::mcp_tool_name(namespace_prefix, method);
     generate_dispatch_arm_with_context(method, Some(&tool_name), AsyncHandling::Error)
 }
 
@@ -456,7 +561,7 @@ fn generate_dispatch_arm_async(
     namespace_prefix: &str,
     method: &MethodInfo,
 ) -> TokenStream2 {
-    let tool_name = format!("{}{}", namespace_prefix, method.name);
+    let tool_name = crate::mcp_tool_name(namespace_prefix, method);
     generate_dispatch_arm_with_context(method, Some(&tool_name), AsyncHandling::Await)
 }
 
@@ -466,7 +571,19 @@ fn generate_dispatch_arm_with_context(
     tool_name: Option<&str>,
     async_handling: AsyncHandling,
 ) -> TokenStream2 {
-    // Find context parameter indices for injection (per-method detection).
+    if let Some(message) = http_only_error(method) {
+        let name = tool_name.map(String::from).unwrap_or_else(|| method.name.to_string());
+        return quote! {
+            #name => {
+                return Err(::server_less::ErrorResponse::new(
+                    ::server_less::ErrorCode::NotImplemented,
+                    #message,
+                ).into());
+            }
+        };
+    }
+
+    // Find context and cancellation parameter indices for injection (per-method detection).
     let injections: Vec<(usize, TokenStream2)> = method
         .params
         .iter()
@@ -474,6 +591,8 @@ fn generate_dispatch_arm_with_context(
         .filter_map(|(i, p)| {
             if crate::context::should_inject_context(&p.ty, &method.params) {
                 Some((i, quote! { ::server_less::Context::default() }))
+            } else if is_cancellation_token(&p.ty) {
+                Some((i, never_cancelled()))
             } else {
                 None
             }
@@ -653,7 +772,8 @@ fn generate_slug_mount_dispatch(
     let slug_extractions: Vec<_> = method
         .params
         .iter()
-        .map(server_less_rpc::generate_param_extraction)
+        .enumerate()
+        .map(|(i, p)| server_less_rpc::generate_param_extraction(p, i))
         .collect();
     let slug_names: Vec<_> = method.params.iter().map(|p| &p.name).collect();
 
@@ -61,13 +61,20 @@
 //! # Generated Methods
 //!
 //! - `openapi_spec() -> serde_json::Value` - OpenAPI 3.0 specification
+//!
+//! The spec is built on the first call and cached in a `OnceLock`; later calls
+//! return a clone. Impls with type parameters rebuild it on every call.
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use server_less_parse::{MethodInfo, extract_groups, extract_methods, get_impl_name, resolve_method_group};
+use server_less_parse::{
+    HttpMethod, MethodInfo, extract_groups, extract_methods, get_impl_name, resolve_method_group,
+};
 use syn::{ItemImpl, Token, parse::Parse};
 
-use crate::openapi_gen::{ResponseOverride, RouteOverride, generate_openapi_spec};
+use crate::openapi_gen::{
+    ResponseOverride, RouteOverride, generate_openapi_spec, infer_http_method, sibling_http_args,
+};
 use crate::server_attrs::{has_server_hidden, has_server_skip};
 
 /// Arguments for the #[openapi] attribute
@@ -88,7 +95,7 @@ impl Parse for OpenApiArgs {
             match ident.to_string().as_str() {
                 "prefix" => {
                     let lit: syn::LitStr = input.parse()?;
-                    args.prefix = Some(lit.value());
+                    args.prefix = Some(crate::openapi_gen::normalize_prefix(&lit)?);
                 }
                 other => {
                     const VALID_ARGS: &[&str] = &["prefix"];
@@ -156,7 +163,10 @@ impl DetectedProtocols {
         let mut merges = Vec::new();
 
         if self.http {
-            merges.push(quote! { .merge_paths(Self::http_openapi_paths()) });
+            merges.push(quote! {
+                .merge_paths(Self::http_openapi_paths())
+                .merge_webhooks(Self::http_openapi_webhooks())
+            });
         }
         if self.jsonrpc {
             merges.push(quote! { .merge_paths(Self::jsonrpc_openapi_paths()) });
@@ -173,7 +183,6 @@ impl DetectedProtocols {
 }
 
 pub(crate) fn expand_openapi(args: OpenApiArgs, mut impl_block: ItemImpl) -> syn::Result<TokenStream2> {
-    crate::reject_generic_impl(&impl_block)?;
     let app_meta = crate::app::extract_app_meta(&mut impl_block.attrs);
     let struct_name = get_impl_name(&impl_block)?;
     let generics_clone = impl_block.generics.clone();
@@ -220,46 +229,88 @@ pub(crate) fn expand_openapi(args: OpenApiArgs, mut impl_block: ItemImpl) -> syn
         // sibling protocol macros (#[http], #[jsonrpc], etc.) can process it afterward.
         // is_protocol_impl_emitter does NOT apply here; the sibling macros themselves
         // handle deduplication via their own is_protocol_impl_emitter checks.
+        let spec_body = crate::openapi_gen::memoize_spec(
+            &generics_clone,
+            quote! {
+                ::server_less::OpenApiBuilder::new()
+                    .title(#openapi_title)
+                    .version(#openapi_version)
+                    #merges
+                    .build()
+            },
+        );
+
         Ok(quote! {
             #impl_block
 
             impl #impl_generics #self_ty #where_clause {
                 #[doc = #openapi_doc]
                 pub fn openapi_spec() -> ::server_less::serde_json::Value {
-                    ::server_less::OpenApiBuilder::new()
-                        .title(#openapi_title)
-                        .version(#openapi_version)
-                        #merges
-                        .build()
+                    #spec_body
                 }
             }
         })
     } else {
         // Standalone mode: generate paths from method naming conventions
-        let methods = extract_methods(&impl_block)?;
+        let mut methods = extract_methods(&impl_block)?;
         let prefix = args.prefix.unwrap_or_default();
+        crate::openapi_gen::bind_prefix_params(&prefix, &mut methods);
+        let http_args = sibling_http_args(&impl_block)?;
 
         let group_registry = extract_groups(&impl_block)?;
         let mut openapi_methods: Vec<(MethodInfo, RouteOverride, ResponseOverride)> = Vec::new();
+        let mut webhook_methods: Vec<(MethodInfo, RouteOverride)> = Vec::new();
 
         for method in &methods {
             let mut overrides = RouteOverride::parse_from_attrs(&method.method.attrs)?;
-            let response_overrides = ResponseOverride::parse_from_attrs(&method.method.attrs)?;
+            let mut response_overrides = ResponseOverride::parse_from_attrs(&method.method.attrs)?;
 
             if overrides.skip || overrides.hidden || has_server_skip(method) || has_server_hidden(method) {
                 continue;
             }
 
+            if overrides.webhook {
+                webhook_methods.push((method.clone(), overrides));
+                continue;
+            }
+
             // Prepend group display name to OpenAPI tags
             if let Some(group_name) = resolve_method_group(method, &group_registry)? {
                 overrides.tags.insert(0, group_name);
             }
 
+            if http_args.created_location {
+                let http_method = overrides
+                    .method
+                    .as_deref()
+                    .and_then(HttpMethod::parse)
+                    .unwrap_or_else(|| infer_http_method(&method.name_str()));
+                crate::openapi_gen::apply_created_location(
+                    method,
+                    &http_method,
+                    &mut response_overrides,
+                );
+            }
+            crate::openapi_gen::apply_content_type(
+                &method.return_info,
+                http_args.content_type.as_deref(),
+                &mut response_overrides,
+            );
+
             openapi_methods.push((method.clone(), overrides, response_overrides));
         }
 
-        let openapi_fn =
-            generate_openapi_spec(&struct_name, &prefix, &openapi_methods)?;
+        let openapi_fn = crate::openapi_gen::memoize_spec(
+            &generics_clone,
+            generate_openapi_spec(
+                &struct_name,
+                &prefix,
+                http_args.content_type.as_deref().unwrap_or("application/json"),
+                http_args.envelope,
+                &openapi_methods,
+                &webhook_methods,
+            )?,
+        );
 
         let standalone_doc = format!(
             "Get OpenAPI 3.0 specification for this service ({} endpoint{}).",
@@ -279,7 +330,11 @@ pub(crate) fn expand_openapi(args: OpenApiArgs, mut impl_block: ItemImpl) -> syn
                 // Strip method-level HTTP attributes forwarded from parse stage.
                 method
                     .attrs
-                    .retain(|attr| !attr.path().is_ident("route") && !attr.path().is_ident("response"));
+                    .retain(|attr| {
+                        !attr.path().is_ident("route")
+                            && !attr.path().is_ident("response")
+                            && !attr.path().is_ident("webhook")
+                    });
                 // Strip #[param(...)] from function parameters.
                 for input in &mut method.sig.inputs {
                     if let syn::FnArg::Typed(pat_type) = input {
@@ -7,10 +7,13 @@
 
 use heck::ToKebabCase;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
-use server_less_parse::{HttpMethod, MethodInfo, ParamInfo, ParamLocation};
+use quote::{format_ident, quote};
+use server_less_parse::{
+    HttpMethod, MethodInfo, MethodVisibility, ParamInfo, ParamLocation, ReturnInfo,
+};
+use syn::{ItemImpl, Token};
 
-use crate::context::should_inject_context;
+use crate::context::{is_framework_param, is_raw_json};
 
 /// Per-method HTTP attribute overrides
 #[derive(Default, Clone)]
@@ -27,6 +30,40 @@ pub struct RouteOverride {
     pub tags: Vec<String>,
     /// Mark this operation as deprecated
     pub deprecated: bool,
+    /// Operation description, replacing the doc comment body
+    pub description: Option<String>,
+    /// OpenAPI `operationId`, replacing the method name
+    pub operation_id: Option<String>,
+    /// Serve `Range: bytes=...` requests from a byte-buffer return
+    pub ranges: bool,
+    /// Span of the `ranges` flag, for precise error reporting.
+    pub ranges_span: Option<proc_macro2::Span>,
+    /// Drive an SSE stream into a buffer of this many events, dropping the
+    /// oldest when the client lags
+    pub sse_buffer: Option<usize>,
+    /// Span of the `sse_buffer` value, for precise error reporting.
+    pub sse_buffer_span: Option<proc_macro2::Span>,
+    /// `#[route(stream = "ndjson")]`: send a stream as newline-delimited JSON
+    /// instead of SSE
+    pub ndjson: bool,
+    /// Span of the `stream` value, for precise error reporting.
+    pub stream_span: Option<proc_macro2::Span>,
+    /// Resource segment of the inferred path, replacing the pluralized method
+    /// name (`#[route(resource = "people")]`)
+    pub resource: Option<String>,
+    /// Span of the `resource` value, for precise error reporting.
+    pub resource_span: Option<proc_macro2::Span>,
+    /// Answer 504 when the handler takes longer than this many seconds
+    pub timeout: Option<u64>,
+    /// Span of the `timeout` value, for precise error reporting.
+    pub timeout_span: Option<proc_macro2::Span>,
+    /// Exempt this route from the duplicate-route check
+    pub allow_duplicate: bool,
+    /// `#[webhook]`: document the method as a callback the server sends
+    /// instead of routing it
+    pub webhook: bool,
+    /// `#[webhook(name = "...")]`, replacing the method name as the webhook key
+    pub webhook_name: Option<String>,
 }
 
 impl RouteOverride {
@@ -34,6 +71,34 @@ impl RouteOverride {
         let mut result = Self::default();
 
         for attr in attrs {
+            if attr.path().is_ident("webhook") {
+                result.webhook = true;
+                if matches!(attr.meta, syn::Meta::Path(_)) {
+                    continue;
+                }
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("name") {
+                        let value: syn::LitStr = meta.value()?.parse()?;
+                        if value.value().is_empty() {
+                            return Err(syn::Error::new(
+                                value.span(),
+                                "webhook `name` must not be empty",
+                            ));
+                        }
+                        result.webhook_name = Some(value.value());
+                        Ok(())
+                    } else {
+                        Err(meta.error(
+                            "unknown webhook attribute\n\
+                             \n\
+                             Valid attributes: name\n\
+                             \n\
+                             Example: #[webhook(name = \"orderShipped\")]",
+                        ))
+                    }
+                })?;
+                continue;
+            }
             if !attr.path().is_ident("route") {
                 continue;
             }
@@ -45,6 +110,72 @@ impl RouteOverride {
                 } else if meta.path.is_ident("hidden") {
                     result.hidden = true;
                     Ok(())
+                } else if meta.path.is_ident("ranges") {
+                    result.ranges = true;
+                    result.ranges_span = Some(syn::spanned::Spanned::span(&meta.path));
+                    Ok(())
+                } else if meta.path.is_ident("sse_buffer") {
+                    let value: syn::LitInt = meta.value()?.parse()?;
+                    let capacity: usize = value.base10_parse()?;
+                    if capacity == 0 {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            "`sse_buffer` must be at least 1",
+                        ));
+                    }
+                    result.sse_buffer = Some(capacity);
+                    result.sse_buffer_span = Some(value.span());
+                    Ok(())
+                } else if meta.path.is_ident("stream") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    result.ndjson = match value.value().as_str() {
+                        "sse" => false,
+                        "ndjson" => true,
+                        other => {
+                            return Err(syn::Error::new(
+                                value.span(),
+                                format!("unknown stream format `{other}` — expected \"sse\" or \"ndjson\""),
+                            ));
+                        }
+                    };
+                    result.stream_span = Some(value.span());
+                    Ok(())
+                } else if meta.path.is_ident("resource") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    let resource = value.value();
+                    if resource.is_empty()
+                        || !resource
+                            .chars()
+                            .all(|c| c.is_alphanumeric() || c == '-' || c == '_' || c == '.')
+                    {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            format!(
+                                "invalid resource name \"{resource}\"\n\
+                                 \n\
+                                 Hint: a resource is a single path segment, e.g. resource = \"people\"; \
+                                 use #[route(path = \"...\")] for anything more"
+                            ),
+                        ));
+                    }
+                    result.resource = Some(resource);
+                    result.resource_span = Some(value.span());
+                    Ok(())
+                } else if meta.path.is_ident("timeout") {
+                    let value: syn::LitInt = meta.value()?.parse()?;
+                    let secs: u64 = value.base10_parse()?;
+                    if secs == 0 {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            "`timeout` must be at least 1 second",
+                        ));
+                    }
+                    result.timeout = Some(secs);
+                    result.timeout_span = Some(value.span());
+                    Ok(())
+                } else if meta.path.is_ident("allow_duplicate") {
+                    result.allow_duplicate = true;
+                    Ok(())
                 } else if meta.path.is_ident("deprecated") {
                     // Support both `deprecated` and `deprecated = true`
                     if meta.input.peek(syn::Token![=]) {
@@ -64,6 +195,20 @@ impl RouteOverride {
                     result.path_span = Some(value.span());
                     result.path = Some(value.value());
                     Ok(())
+                } else if meta.path.is_ident("description") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    result.description = Some(value.value());
+                    Ok(())
+                } else if meta.path.is_ident("operation_id") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    if value.value().is_empty() {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            "`operation_id` must not be empty",
+                        ));
+                    }
+                    result.operation_id = Some(value.value());
+                    Ok(())
                 } else if meta.path.is_ident("tags") {
                     let value: syn::LitStr = meta.value()?.parse()?;
                     // Support comma-separated tags: tags = "users,admin"
@@ -75,8 +220,22 @@ impl RouteOverride {
                         .collect();
                     Ok(())
                 } else {
-                    const VALID: &[&str] =
-                        &["method", "path", "skip", "hidden", "tags", "deprecated"];
+                    const VALID: &[&str] = &[
+                        "method",
+                        "path",
+                        "skip",
+                        "hidden",
+                        "tags",
+                        "deprecated",
+                        "description",
+                        "operation_id",
+                        "ranges",
+                        "sse_buffer",
+                        "stream",
+                        "resource",
+                        "timeout",
+                        "allow_duplicate",
+                    ];
                     let unknown = meta
                         .path
                         .get_ident()
@@ -88,7 +247,7 @@ impl RouteOverride {
                     Err(meta.error(format!(
                         "unknown attribute `{unknown}`{suggestion}\n\
                          \n\
-                         Valid attributes: method, path, skip, hidden, tags, deprecated\n\
+                         Valid attributes: method, path, skip, hidden, tags, deprecated, description, operation_id, ranges, sse_buffer, stream, resource, timeout, allow_duplicate\n\
                          \n\
                          Examples:\n\
                          - #[route(method = \"POST\")]\n\
@@ -96,17 +255,71 @@ impl RouteOverride {
                          - #[route(skip)] or #[route(hidden)]\n\
                          - #[route(tags = \"users,admin\")]\n\
                          - #[route(deprecated)]\n\
+                         - #[route(description = \"Longer operation description\")]\n\
+                         - #[route(operation_id = \"createUserV2\")]\n\
+                         - #[route(ranges)]\n\
+                         - #[route(sse_buffer = 256)]\n\
+                         - #[route(stream = \"ndjson\")]\n\
+                         - #[route(resource = \"people\")]\n\
+                         - #[route(timeout = 30)]\n\
+                         - #[route(allow_duplicate)]\n\
                          \n\
-                         Note: Use doc comments for descriptions (first line = summary, full = description)"
+                         Note: doc comments supply the summary (first line) and description (the rest)"
                     )))
                 }
             })?;
         }
 
+        if let (Some(_), Some(span)) = (&result.path, result.resource_span) {
+            return Err(syn::Error::new(
+                span,
+                "#[route(resource = ...)] only changes an inferred path and cannot be \
+                 combined with #[route(path = ...)]",
+            ));
+        }
+
         Ok(result)
     }
 }
 
+/// Reject a `#[response]` header value that `HeaderValue::from_static` would
+/// panic on at runtime: anything but visible ASCII, spaces and tabs.
+fn check_static_header_value(value: &syn::LitStr) -> syn::Result<()> {
+    let valid = |b: u8| b == b'\t' || (b' '..=b'~').contains(&b);
+    if value.value().bytes().all(valid) {
+        return Ok(());
+    }
+    Err(syn::Error::new(
+        value.span(),
+        "invalid header value: only visible ASCII, spaces and tabs are allowed\n\
+         \n\
+         Hint: return `server_less::WithHeaders<T>` to compute header values at runtime",
+    ))
+}
+
+/// Reject a `#[response]` header name that is not an HTTP token.
+fn check_header_name(name: &syn::LitStr) -> syn::Result<()> {
+    let is_token = |b: u8| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b);
+    let value = name.value();
+    if !value.is_empty() && value.bytes().all(is_token) {
+        return Ok(());
+    }
+    Err(syn::Error::new(
+        name.span(),
+        format!(
+            "invalid header name \"{value}\"\n\
+             \n\
+             Hint: header names use letters, digits and !#$%&'*+-.^_`|~, e.g. \"X-Request-Id\""
+        ),
+    ))
+}
+
+/// Error for a `header` name with no `value` after it.
+const HEADER_WITHOUT_VALUE: &str = "incomplete `#[response]` attribute: `header` requires a \
+     following `value = \"...\"` argument\n\
+     \n\
+     Example: #[response(header = \"X-Foo\", value = \"bar\")]";
+
 /// Per-method response customization
 #[derive(Default, Clone)]
 pub struct ResponseOverride {
@@ -115,44 +328,113 @@ pub struct ResponseOverride {
     pub headers: Vec<(String, String)>,
     /// Custom description for the response
     pub description: Option<String>,
+    /// Further documented responses, from `#[response]` attributes naming a
+    /// status other than the first one declared
+    pub additional: Vec<AdditionalResponse>,
+    /// Answer `None` from an `Option` method with 200 and `null` instead of 404
+    pub none_as_null: bool,
+    /// Span of the `#[response(none = "...")]` literal, for precise error reporting.
+    pub none_span: Option<proc_macro2::Span>,
+    /// Answer with a `Location` header: the request path followed by the returned
+    /// `id`, set on `create_*` methods by `#[http(created_location = true)]`
+    pub created_location: bool,
+    /// Status answering request input that is missing or fails to parse, documented
+    /// when set by `#[http(deserialize_error_status = ...)]`
+    pub invalid_input_status: Option<u16>,
+}
+
+/// A documented response besides the one the handler produces itself.
+#[derive(Clone)]
+pub struct AdditionalResponse {
+    pub status: u16,
+    pub content_type: Option<String>,
+    pub headers: Vec<(String, String)>,
+    pub description: Option<String>,
 }
 
 impl ResponseOverride {
     pub fn parse_from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Self> {
         let mut result = Self::default();
-        let mut pending_header_name: Option<String> = None;
+        let mut preloads: Vec<syn::LitStr> = Vec::new();
 
         for attr in attrs {
             if !attr.path().is_ident("response") {
                 continue;
             }
 
+            let mut status: Option<u16> = None;
+            let mut content_type: Option<String> = None;
+            let mut headers: Vec<(String, String)> = Vec::new();
+            let mut description: Option<String> = None;
+            let mut pending_header_name: Option<syn::LitStr> = None;
+
             attr.parse_nested_meta(|meta| {
                 if meta.path.is_ident("status") {
                     let value: syn::LitInt = meta.value()?.parse()?;
-                    result.status = Some(value.base10_parse()?);
+                    status = Some(value.base10_parse()?);
                     Ok(())
                 } else if meta.path.is_ident("content_type") {
                     let value: syn::LitStr = meta.value()?.parse()?;
-                    result.content_type = Some(value.value());
+                    check_static_header_value(&value)?;
+                    content_type = Some(value.value());
                     Ok(())
                 } else if meta.path.is_ident("header") {
                     let name: syn::LitStr = meta.value()?.parse()?;
-                    pending_header_name = Some(name.value());
+                    check_header_name(&name)?;
+                    if let Some(previous) = pending_header_name.replace(name) {
+                        return Err(syn::Error::new(previous.span(), HEADER_WITHOUT_VALUE));
+                    }
                     Ok(())
                 } else if meta.path.is_ident("value") {
                     let value: syn::LitStr = meta.value()?.parse()?;
-                    if let Some(name) = pending_header_name.take() {
-                        result.headers.push((name, value.value()));
-                    }
+                    check_static_header_value(&value)?;
+                    let Some(name) = pending_header_name.take() else {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            "`value` must follow a `header` name\n\
+                             \n\
+                             Example: #[response(header = \"X-Custom\", value = \"foo\")]",
+                        ));
+                    };
+                    headers.push((name.value(), value.value()));
                     Ok(())
                 } else if meta.path.is_ident("description") {
                     let value: syn::LitStr = meta.value()?.parse()?;
-                    result.description = Some(value.value());
+                    description = Some(value.value());
+                    Ok(())
+                } else if meta.path.is_ident("none") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    result.none_as_null = match value.value().as_str() {
+                        "null" => true,
+                        "404" => false,
+                        other => {
+                            return Err(syn::Error::new(
+                                value.span(),
+                                format!(
+                                    "unknown `none` behavior `{other}`\n\
+                                     \n\
+                                     Expected \"404\" (the default) or \"null\" (200 with a null body)"
+                                ),
+                            ));
+                        }
+                    };
+                    result.none_span = Some(value.span());
+                    Ok(())
+                } else if meta.path.is_ident("preload") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    check_preload_target(&value)?;
+                    preloads.push(value);
                     Ok(())
                 } else {
-                    const VALID: &[&str] =
-                        &["status", "content_type", "header", "value", "description"];
+                    const VALID: &[&str] = &[
+                        "status",
+                        "content_type",
+                        "header",
+                        "value",
+                        "description",
+                        "none",
+                        "preload",
+                    ];
                     let unknown = meta
                         .path
                         .get_ident()
@@ -164,53 +446,520 @@ impl ResponseOverride {
                     Err(meta.error(format!(
                         "unknown attribute `{unknown}`{suggestion}\n\
                          \n\
-                         Valid attributes: status, content_type, header, value, description\n\
+                         Valid attributes: status, content_type, header, value, description, none, preload\n\
                          \n\
                          Examples:\n\
                          - #[response(status = 201)]\n\
                          - #[response(content_type = \"application/octet-stream\")]\n\
                          - #[response(header = \"X-Custom\", value = \"foo\")]\n\
-                         - #[response(description = \"User created successfully\")]"
+                         - #[response(description = \"User created successfully\")]\n\
+                         - #[response(none = \"null\")]\n\
+                         - #[response(preload = \"/static/app.js\")]"
                     )))
                 }
             })?;
+
+            if let Some(name) = pending_header_name {
+                return Err(syn::Error::new(name.span(), HEADER_WITHOUT_VALUE));
+            }
+
+            match (status, result.status) {
+                (Some(status), Some(primary)) if status != primary => {
+                    if result.additional.iter().any(|r| r.status == status) {
+                        return Err(syn::Error::new_spanned(
+                            attr,
+                            format!(
+                                "response status {status} is declared twice\n\
+                                 \n\
+                                 Hint: put its content_type, headers and description in one \
+                                 #[response(status = {status}, ...)] attribute"
+                            ),
+                        ));
+                    }
+                    result.additional.push(AdditionalResponse {
+                        status,
+                        content_type,
+                        headers,
+                        description,
+                    });
+                }
+                _ => {
+                    result.status = result.status.or(status);
+                    if content_type.is_some() {
+                        result.content_type = content_type;
+                    }
+                    result.headers.extend(headers);
+                    if description.is_some() {
+                        result.description = description;
+                    }
+                }
+            }
         }
 
-        if pending_header_name.is_some() {
-            return Err(syn::Error::new(
-                proc_macro2::Span::call_site(),
-                "incomplete `#[response]` attribute: `header` requires a following \
-                 `value = \"...\"` argument\n\
-                 \n\
-                 Example: #[response(header = \"X-Foo\", value = \"bar\")]",
-            ));
+        // All preloads share one `Link` header, comma-separated as RFC 8288 allows.
+        if let Some(first) = preloads.first() {
+            if result
+                .headers
+                .iter()
+                .any(|(name, _)| name.eq_ignore_ascii_case("link"))
+            {
+                return Err(syn::Error::new(
+                    first.span(),
+                    "`preload` sets the `Link` header, which a `header` argument already sets\n\
+                     \n\
+                     Hint: list the preload targets in that header's `value` instead",
+                ));
+            }
+            let link = preloads
+                .iter()
+                .map(|target| format!("<{}>; rel=preload", target.value()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            result.headers.push(("Link".to_string(), link));
         }
 
         Ok(result)
     }
 }
 
-/// Split doc comment into summary (first line) and description (full text).
+/// Reject a `#[response(preload = "...")]` target that cannot sit between the
+/// `<` and `>` of a `Link` header: empty, or containing whitespace, `<` or `>`.
+fn check_preload_target(target: &syn::LitStr) -> syn::Result<()> {
+    let value = target.value();
+    let valid = |b: u8| b.is_ascii_graphic() && b != b'<' && b != b'>';
+    if !value.is_empty() && value.bytes().all(valid) {
+        return Ok(());
+    }
+    Err(syn::Error::new(
+        target.span(),
+        "invalid preload target: expected a non-empty URL of visible ASCII without `<` or `>`\n\
+         \n\
+         Example: #[response(preload = \"/static/app.js\")]",
+    ))
+}
+
+/// OpenAPI schema for a response body of the given media type.
+fn content_schema(content_type: &str) -> TokenStream2 {
+    let essence = content_type.split(';').next().unwrap_or("").trim();
+    let is_json = essence == "application/json" || essence.ends_with("+json");
+    let is_text = essence.starts_with("text/");
+    if is_json {
+        quote! { {} }
+    } else if is_text {
+        quote! { { "type": "string" } }
+    } else {
+        quote! { { "type": "string", "format": "binary" } }
+    }
+}
+
+/// Tokens inserting one success response object into the map bound to `map`.
+///
+/// `content` is the media type and its schema, as `json!` tokens.
+fn response_object_insert(
+    map: &syn::Ident,
+    status: &str,
+    description: &str,
+    content: Option<(&str, TokenStream2)>,
+    headers: &[(String, String)],
+) -> TokenStream2 {
+    let content = content.map(|(ct, schema)| {
+        quote! {
+            __response.insert(
+                "content".to_string(),
+                ::server_less::serde_json::json!({
+                    #ct: { "schema": #schema }
+                }),
+            );
+        }
+    });
+    let header_inserts = headers.iter().map(|(name, _)| {
+        let header_description = format!("Custom header: {name}");
+        quote! {
+            __headers.insert(
+                #name.to_string(),
+                ::server_less::serde_json::json!({
+                    "description": #header_description,
+                    "schema": { "type": "string" }
+                }),
+            );
+        }
+    });
+    let headers = (!headers.is_empty()).then(|| {
+        quote! {
+            let mut __headers = ::server_less::serde_json::Map::new();
+            #(#header_inserts)*
+            __response.insert("headers".to_string(), ::server_less::serde_json::Value::Object(__headers));
+        }
+    });
+    quote! {
+        {
+            let mut __response = ::server_less::serde_json::Map::new();
+            __response.insert("description".to_string(), ::server_less::serde_json::json!(#description));
+            #content
+            #headers
+            #map.insert(#status.to_string(), ::server_less::serde_json::Value::Object(__response));
+        }
+    }
+}
+
+/// Tokens adding a method's success responses to the map bound to `map`: the
+/// one its handler produces, the `206` / `416` of `#[route(ranges)]`, the `504`
+/// of `#[route(timeout)]`, the invalid-input status of
+/// `#[http(deserialize_error_status)]`, then any additional `#[response(status = ...)]`.
+///
+/// With `envelope`, a JSON body is documented as the `{"data": ...}` wrapper
+/// `#[http(envelope = true)]` sends.
+fn success_response_inserts(
+    ret: &ReturnInfo,
+    overrides: &RouteOverride,
+    response_overrides: &ResponseOverride,
+    envelope: bool,
+    map: &syn::Ident,
+) -> TokenStream2 {
+    let inferred_code = if ret.is_unit { 204 } else { 200 };
+    let success_code = response_overrides
+        .status
+        .unwrap_or(inferred_code)
+        .to_string();
+    let success_description = response_overrides
+        .description
+        .as_deref()
+        .unwrap_or("Successful response");
+    // Byte buffers are sent raw, so they are documented as binary even without
+    // a `content_type`.
+    let content_type = response_overrides.content_type.as_deref().or(
+        server_less_rpc::returns_bytes(ret).then_some("application/octet-stream"),
+    );
+    let mut headers = response_overrides.headers.clone();
+    if response_overrides.created_location {
+        headers.push(("Location".to_string(), String::new()));
+    }
+    let primary_content = match content_type {
+        Some(ct) => Some((ct, content_schema(ct))),
+        None if envelope => enveloped_schema(ret, overrides).map(|schema| ("application/json", schema)),
+        None => None,
+    };
+    let primary = response_object_insert(
+        map,
+        &success_code,
+        success_description,
+        primary_content,
+        &headers,
+    );
+    let ranges = overrides.ranges.then(|| {
+        let content_range = vec![("Content-Range".to_string(), String::new())];
+        let partial = response_object_insert(
+            map,
+            "206",
+            "Partial content",
+            content_type.map(|ct| (ct, content_schema(ct))),
+            &content_range,
+        );
+        let unsatisfiable = response_object_insert(
+            map,
+            "416",
+            "Range not satisfiable",
+            None,
+            &content_range,
+        );
+        quote! {
+            #partial
+            #unsatisfiable
+        }
+    });
+    let timeout = overrides
+        .timeout
+        .map(|_| response_object_insert(map, "504", "Gateway timeout", None, &[]));
+    let invalid_input = response_overrides.invalid_input_status.map(|status| {
+        response_object_insert(map, &status.to_string(), "Invalid input", None, &[])
+    });
+    let additional = response_overrides.additional.iter().map(|r| {
+        response_object_insert(
+            map,
+            &r.status.to_string(),
+            r.description.as_deref().unwrap_or("Alternative response"),
+            r.content_type.as_deref().map(|ct| (ct, content_schema(ct))),
+            &r.headers,
+        )
+    });
+    quote! {
+        #primary
+        #ranges
+        #timeout
+        #invalid_input
+        #(#additional)*
+    }
+}
+
+/// Schema of the `{"data": ...}` body `#[http(envelope = true)]` wraps a JSON
+/// result in, or `None` for responses sent unwrapped (unit, streams, NDJSON and
+/// `(StatusCode, ...)` tuples).
+fn enveloped_schema(ret: &ReturnInfo, overrides: &RouteOverride) -> Option<TokenStream2> {
+    let value_ty = if ret.is_result {
+        ret.ok_type.as_ref()
+    } else if ret.is_option {
+        ret.some_type.as_ref()
+    } else {
+        ret.ty.as_ref()
+    };
+    let value_ty = value_ty.map(|ty| server_less_rpc::with_headers_inner(ty).unwrap_or(ty));
+    if ret.is_unit
+        || ret.is_stream
+        || (ret.is_iterator && overrides.ndjson)
+        || value_ty.is_some_and(server_less_rpc::is_status_tuple)
+    {
+        return None;
+    }
+    let data = match value_ty {
+        _ if ret.is_iterator => quote! { ::server_less::serde_json::json!({"type": "array"}) },
+        Some(ty) => server_less_rpc::json_schema_tokens(ty),
+        None => quote! { ::server_less::serde_json::json!({}) },
+    };
+    Some(quote! {
+        {
+            "type": "object",
+            "properties": { "data": #data },
+            "required": ["data"]
+        }
+    })
+}
+
+/// Split doc comment into summary (first line) and description (the rest).
 ///
 /// Returns (summary, description) where:
 /// - summary is the first non-empty line (or method name if no docs)
-/// - description is the full doc comment (or None if single line or no docs)
+/// - description is everything after the first line, with surrounding blank
+///   lines trimmed (or None if nothing follows the summary)
 fn split_doc_comment(docs: &Option<String>, fallback: &str) -> (String, Option<String>) {
     match docs {
-        Some(doc_text) if !doc_text.is_empty() => {
-            let first_line = doc_text.lines().next().unwrap_or(fallback).to_string();
-            // Only set description if there's more than just the first line
-            let description = if doc_text.contains('\n') {
-                Some(doc_text.clone())
-            } else {
-                None
-            };
-            (first_line, description)
+        Some(doc_text) if !doc_text.trim().is_empty() => {
+            let doc_text = doc_text.trim_start();
+            let (first_line, rest) = doc_text.split_once('\n').unwrap_or((doc_text, ""));
+            let rest = rest.trim();
+            let description = (!rest.is_empty()).then(|| rest.to_string());
+            (first_line.trim_end().to_string(), description)
         }
         _ => (fallback.to_string(), None),
     }
 }
 
+/// Schema of a parameter: the inferred type plus any `#[param(min, max, pattern)]`
+/// constraints and `#[param(default = ...)]` value.
+fn param_schema(param: &ParamInfo) -> TokenStream2 {
+    // `#[param(with)]` values travel as text, whatever type `parse` builds.
+    let schema = match param.with {
+        Some(_) => quote! { ::server_less::serde_json::json!({"type": "string"}) },
+        None => server_less_rpc::json_schema_tokens(&param.ty),
+    };
+    let mut keywords = Vec::new();
+    for (key, bound) in [("minimum", &param.min), ("maximum", &param.max)] {
+        if let Some(bound) = bound {
+            let value: TokenStream2 = bound.parse().expect("BUG: bound is a numeric literal");
+            keywords.push(quote! { (#key, ::server_less::serde_json::json!(#value)) });
+        }
+    }
+    if let Some(ref pattern) = param.pattern {
+        keywords.push(quote! { ("pattern", ::server_less::serde_json::json!(#pattern)) });
+    }
+    if let Some(ref raw) = param.default_value {
+        let default = server_less_rpc::default_value_tokens(raw);
+        keywords.push(quote! { ("default", #default) });
+    }
+    if keywords.is_empty() {
+        return schema;
+    }
+    quote! {
+        {
+            let mut __schema = #schema;
+            if let ::std::option::Option::Some(__obj) = __schema.as_object_mut() {
+                for (__key, __value) in [#(#keywords),*] {
+                    __obj.insert(__key.to_string(), __value);
+                }
+            }
+            __schema
+        }
+    }
+}
+
+/// Arguments of a sibling `#[http(...)]` that affect which routes exist.
+#[derive(Default)]
+pub(crate) struct SiblingHttpArgs {
+    pub prefix: Option<String>,
+    pub visibility: MethodVisibility,
+    pub rpc_style: bool,
+    pub envelope: bool,
+    pub created_location: bool,
+    pub content_type: Option<String>,
+    pub openapi_path: Option<syn::LitStr>,
+    pub docs_path: Option<syn::LitStr>,
+}
+
+/// Read the fields of [`SiblingHttpArgs`] from a sibling `#[http(...)]`
+/// attribute, if present.
+pub(crate) fn sibling_http_args(impl_block: &ItemImpl) -> syn::Result<SiblingHttpArgs> {
+    let mut args = SiblingHttpArgs::default();
+    for attr in &impl_block.attrs {
+        if !attr.path().is_ident("http") || !matches!(attr.meta, syn::Meta::List(_)) {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("prefix") {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                args.prefix = Some(normalize_prefix(&lit)?);
+            } else if meta.path.is_ident("visibility") {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                args.visibility = MethodVisibility::parse_lit(&lit)?;
+            } else if meta.path.is_ident("rpc_style") {
+                let lit: syn::LitBool = meta.value()?.parse()?;
+                args.rpc_style = lit.value();
+            } else if meta.path.is_ident("envelope") {
+                let lit: syn::LitBool = meta.value()?.parse()?;
+                args.envelope = lit.value();
+            } else if meta.path.is_ident("created_location") {
+                let lit: syn::LitBool = meta.value()?.parse()?;
+                args.created_location = lit.value();
+            } else if meta.path.is_ident("content_type") {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                args.content_type = Some(lit.value());
+            } else if meta.path.is_ident("openapi_path") {
+                args.openapi_path = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("docs_path") {
+                args.docs_path = Some(meta.value()?.parse()?);
+            } else if meta.input.peek(Token![=]) {
+                // Other #[http] arguments are validated by #[http] itself.
+                let _: syn::Lit = meta.value()?.parse()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(args)
+}
+
+/// Apply `#[http(created_location = true)]` to a method: a `create_*` POST returning a
+/// single resource answers 201 with a `Location`, unless it picks a status itself.
+pub(crate) fn apply_created_location(
+    method: &MethodInfo,
+    http_method: &HttpMethod,
+    response_overrides: &mut ResponseOverride,
+) {
+    let ret = &method.return_info;
+    if method.name_str().starts_with("create_")
+        && matches!(http_method, HttpMethod::Post)
+        && response_overrides.status.is_none()
+        && !(ret.is_unit || ret.is_stream || ret.is_iterator)
+        && !server_less_rpc::returns_bytes(ret)
+        && !server_less_rpc::returns_status_tuple(ret)
+    {
+        response_overrides.status = Some(201);
+        response_overrides.created_location = true;
+    }
+}
+
+/// Apply `#[http(content_type = "...")]` to a method: the impl-level media type
+/// labels its JSON success body, unless its own `#[response(content_type)]` wins.
+pub(crate) fn apply_content_type(
+    ret: &ReturnInfo,
+    content_type: Option<&str>,
+    response_overrides: &mut ResponseOverride,
+) {
+    if let Some(content_type) = content_type
+        && response_overrides.content_type.is_none()
+        && !(ret.is_unit || ret.is_stream)
+        && !server_less_rpc::returns_bytes(ret)
+    {
+        response_overrides.content_type = Some(content_type.to_string());
+    }
+}
+
+/// Validate and normalize a `prefix = "..."` argument so that joining it with a
+/// route path never yields `//`: a leading slash is added, trailing slashes are
+/// stripped, and `"/"` becomes the empty prefix. A `{name}` segment is a path
+/// parameter that every method must bind (see [`bind_prefix_params`]).
+pub(crate) fn normalize_prefix(lit: &syn::LitStr) -> syn::Result<String> {
+    let raw = lit.value();
+    let trimmed = raw.trim_end_matches('/');
+    if trimmed.is_empty() {
+        return Ok(String::new());
+    }
+    let prefix = if trimmed.starts_with('/') {
+        trimmed.to_string()
+    } else {
+        format!("/{trimmed}")
+    };
+
+    if prefix.contains("//") {
+        return Err(syn::Error::new(
+            lit.span(),
+            format!(
+                "prefix contains consecutive slashes. Prefix: '{raw}'\n\
+                 \n\
+                 Hint: Use single slashes to separate segments, e.g., /api/v1"
+            ),
+        ));
+    }
+    for segment in prefix.split('/') {
+        if !segment.contains('{') && !segment.contains('}') {
+            continue;
+        }
+        let well_formed = segment
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .is_some_and(|name| syn::parse_str::<syn::Ident>(name).is_ok());
+        if !well_formed {
+            return Err(syn::Error::new(
+                lit.span(),
+                format!(
+                    "prefix segment `{segment}` is not a path parameter. Prefix: '{raw}'\n\
+                     \n\
+                     Hint: a prefix parameter is a whole segment naming a method parameter, \
+                     e.g., /tenants/{{tenant_id}}"
+                ),
+            ));
+        }
+    }
+    let invalid_chars = ['<', '>', '"', '`', ' ', '\t', '\n', '?', '#'];
+    if let Some(ch) = invalid_chars.iter().find(|&&c| prefix.contains(c)) {
+        return Err(syn::Error::new(
+            lit.span(),
+            format!("prefix contains invalid character '{ch}'. Prefix: '{raw}'"),
+        ));
+    }
+    Ok(prefix)
+}
+
+/// Names of the `{name}` path parameters in a normalized prefix, in order.
+pub(crate) fn prefix_param_names(prefix: &str) -> Vec<&str> {
+    prefix
+        .split('/')
+        .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+        .collect()
+}
+
+/// Place each method parameter named like a `{name}` segment of `prefix` in
+/// the path, recording which segment it fills. Parameters with an explicit
+/// non-path `#[param(...)]` location are left alone; `#[http]` reports methods
+/// that leave a prefix parameter unbound.
+pub(crate) fn bind_prefix_params(prefix: &str, methods: &mut [MethodInfo]) {
+    let names = prefix_param_names(prefix);
+    if names.is_empty() {
+        return;
+    }
+    for method in methods {
+        for param in &mut method.params {
+            if param.is_extractor
+                || !matches!(param.location, None | Some(ParamLocation::Path))
+            {
+                continue;
+            }
+            let wire_name = param.wire_name.clone().unwrap_or_else(|| param.name_str());
+            if let Some(position) = names.iter().position(|name| *name == wire_name) {
+                param.location = Some(ParamLocation::Path);
+                param.prefix_position = Some(position);
+            }
+        }
+    }
+}
+
 /// Infer HTTP method from function name prefix.
 ///
 /// Recognized prefixes:
@@ -251,8 +1000,18 @@ pub fn infer_http_method(name: &str) -> HttpMethod {
 /// Infer a REST path from a method name, HTTP method, and parameter list.
 ///
 /// Uses parameter names to contextually infer `/{id}` paths (e.g. `get_user(id: u32)` → `GET /users/{id}`).
+/// With `rpc_style`, a name without a known verb prefix is an action rather than a resource:
+/// `do_thing` maps to `/do-thing` instead of `/do-things`.
+/// A `resource` (from `#[route(resource = "...")]`) is used verbatim as the resource
+/// segment instead of the kebab-cased, pluralized name.
 /// See also `server_less_core::infer_path` for the simpler runtime version used in generated code.
-pub fn infer_path(method_name: &str, http_method: &HttpMethod, params: &[ParamInfo]) -> String {
+pub fn infer_path(
+    method_name: &str,
+    http_method: &HttpMethod,
+    params: &[ParamInfo],
+    rpc_style: bool,
+    resource_override: Option<&str>,
+) -> String {
     let resource = method_name
         .strip_prefix("get_")
         .or_else(|| method_name.strip_prefix("fetch_"))
@@ -268,19 +1027,26 @@ pub fn infer_path(method_name: &str, http_method: &HttpMethod, params: &[ParamIn
         .or_else(|| method_name.strip_prefix("patch_"))
         .or_else(|| method_name.strip_prefix("modify_"))
         .or_else(|| method_name.strip_prefix("delete_"))
-        .or_else(|| method_name.strip_prefix("remove_"))
-        .unwrap_or(method_name);
-
-    let resource_kebab = resource.to_kebab_case();
-    let path_resource = if resource_kebab.ends_with('s') {
-        resource_kebab
-    } else {
-        format!("{}s", resource_kebab)
+        .or_else(|| method_name.strip_prefix("remove_"));
+    let path_resource = match (resource_override, resource) {
+        (Some(name), _) => name.to_string(),
+        (None, None) if rpc_style => return format!("/{}", method_name.to_kebab_case()),
+        (None, resource) => {
+            let resource_kebab = resource.unwrap_or(method_name).to_kebab_case();
+            if resource_kebab.ends_with('s') {
+                resource_kebab
+            } else {
+                format!("{}s", resource_kebab)
+            }
+        }
     };
 
-    // Find the first path-like parameter: one explicitly placed in Path, or an id-like param.
+    // Find the first path-like parameter: one explicitly placed in Path, or an id-like
+    // param. Parameters bound by the prefix already have their segment.
     let id_param = params.iter().find(|p| {
-        matches!(p.location.as_ref(), Some(ParamLocation::Path)) || p.is_id
+        !p.is_extractor
+            && p.prefix_position.is_none()
+            && (matches!(p.location.as_ref(), Some(ParamLocation::Path)) || p.is_id)
     });
 
     match http_method {
@@ -316,34 +1082,70 @@ pub fn infer_path(method_name: &str, http_method: &HttpMethod, params: &[ParamIn
     }
 }
 
+/// The verb and path (before any prefix) `#[http]` serves `method` on:
+/// `#[route(method, path)]` when given, inferred from the name and parameters
+/// otherwise.
+pub(crate) fn http_route(
+    method: &MethodInfo,
+    overrides: &RouteOverride,
+    rpc_style: bool,
+) -> (HttpMethod, String) {
+    let name = method.name_str();
+    let verb = overrides
+        .method
+        .as_deref()
+        .and_then(HttpMethod::parse)
+        .unwrap_or_else(|| infer_http_method(&name));
+    let path = overrides.path.clone().unwrap_or_else(|| {
+        infer_path(
+            &name,
+            &verb,
+            &method.params,
+            rpc_style,
+            overrides.resource.as_deref(),
+        )
+    });
+    (verb, path)
+}
+
+/// An axum route path as OpenAPI spells it: a `{*rest}` wildcard is a plain
+/// `{rest}` parameter there.
+fn openapi_path(path: &str) -> String {
+    path.replace("{*", "{")
+}
+
 /// Generate typed OpenAPI paths (Vec<OpenApiPath>)
 ///
-/// Used by protocols to return structured path data for composition.
+/// Used by protocols to return structured path data for composition. JSON
+/// request bodies are documented under `content_type`.
+#[cfg(feature = "http")]
 pub fn generate_openapi_paths(
+    struct_name: &syn::Ident,
     prefix: &str,
+    rpc_style: bool,
+    content_type: &str,
+    envelope: bool,
     methods_with_overrides: &[(MethodInfo, RouteOverride, ResponseOverride)],
 ) -> syn::Result<TokenStream2> {
     let mut path_constructors = Vec::new();
+    let service_tag = service_tag(&struct_name.to_string());
 
     for (method, overrides, response_overrides) in methods_with_overrides {
         let method_name = method.name_str();
 
-        let http_method = if let Some(ref m) = overrides.method {
-            HttpMethod::parse(m).unwrap_or_else(|| infer_http_method(&method_name))
-        } else {
-            infer_http_method(&method_name)
-        };
-
-        let path = if let Some(ref p) = overrides.path {
-            p.clone()
-        } else {
-            infer_path(&method_name, &http_method, &method.params)
-        };
-        let full_path = format!("{}{}", prefix, path);
+        let (http_method, path) = http_route(method, overrides, rpc_style);
+        let full_path = openapi_path(&format!("{}{}", prefix, path));
         let http_method_str = http_method.as_str().to_lowercase();
 
-        let (summary, description) = split_doc_comment(&method.docs, &method_name);
-        let operation_id = method_name.clone();
+        let (summary, doc_description) = split_doc_comment(&method.docs, &method_name);
+        let mut description = overrides.description.clone().or(doc_description);
+        if let Some(ref deprecation) = method.deprecation {
+            description = deprecation.annotate(description);
+        }
+        let operation_id = overrides
+            .operation_id
+            .clone()
+            .unwrap_or_else(|| method_name.clone());
 
         let default_has_body = matches!(
             http_method,
@@ -354,8 +1156,9 @@ pub fn generate_openapi_paths(
         let mut param_constructors = Vec::new();
 
         for param in &method.params {
-            // Skip Context parameters (per-method detection)
-            if should_inject_context(&param.ty, &method.params) {
+            // Skip Context, Inject and CancellationToken parameters (per-method detection),
+            // and a RawJson body
+            if is_framework_param(param, &method.params) || is_raw_json(&param.ty) {
                 continue;
             }
 
@@ -379,7 +1182,7 @@ pub fn generate_openapi_paths(
                 .wire_name
                 .clone()
                 .unwrap_or_else(|| param.name_str());
-            let json_type = server_less_rpc::infer_json_type(&param.ty);
+            let schema = param_schema(param);
             let required =
                 location == "path" || (!param.is_optional && param.default_value.is_none());
 
@@ -393,7 +1196,7 @@ pub fn generate_openapi_paths(
                     name: #name.to_string(),
                     location: #location.to_string(),
                     required: #required,
-                    schema: ::server_less::serde_json::json!({"type": #json_type}),
+                    schema: #schema,
                     description: #description_tokens,
                     extra: ::server_less::serde_json::Map::new(),
                 }
@@ -403,7 +1206,7 @@ pub fn generate_openapi_paths(
         // Build request body if needed
         let mut body_props = Vec::new();
         for param in &method.params {
-            if should_inject_context(&param.ty, &method.params) {
+            if is_framework_param(param, &method.params) {
                 continue;
             }
 
@@ -418,15 +1221,26 @@ pub fn generate_openapi_paths(
                     .wire_name
                     .clone()
                     .unwrap_or_else(|| param.name_str());
-                let json_type = server_less_rpc::infer_json_type(&param.ty);
-                body_props.push((name, json_type));
+                body_props.push((name, param_schema(param)));
             }
         }
 
-        let request_body = if !body_props.is_empty() {
-            let prop_insertions: Vec<_> = body_props.iter().map(|(name, ty)| {
+        let raw_body = method.params.iter().any(|p| is_raw_json(&p.ty));
+        let request_body = if raw_body {
+            quote! {
+                Some(::server_less::serde_json::json!({
+                    "required": true,
+                    "content": {
+                        #content_type: {
+                            "schema": { "type": "object" }
+                        }
+                    }
+                }))
+            }
+        } else if !body_props.is_empty() {
+            let prop_insertions: Vec<_> = body_props.iter().map(|(name, schema)| {
                 quote! {
-                    props.insert(#name.to_string(), ::server_less::serde_json::json!({"type": #ty}));
+                    props.insert(#name.to_string(), #schema);
                 }
             }).collect();
 
@@ -437,7 +1251,7 @@ pub fn generate_openapi_paths(
                     ::server_less::serde_json::json!({
                         "required": true,
                         "content": {
-                            "application/json": {
+                            #content_type: {
                                 "schema": {
                                     "type": "object",
                                     "properties": props
@@ -453,44 +1267,41 @@ pub fn generate_openapi_paths(
 
         // Build responses
         let ret = &method.return_info;
-        let inferred_code = if ret.is_unit { "204" } else { "200" };
-        let success_code = response_overrides
-            .status
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| inferred_code.to_string());
-        let has_error = ret.is_result;
-        let success_description = response_overrides
-            .description
-            .clone()
-            .unwrap_or_else(|| "Successful response".to_string());
-
-        let responses = if has_error {
-            quote! {
-                {
-                    let mut r = ::server_less::serde_json::Map::new();
-                    r.insert(#success_code.to_string(), ::server_less::serde_json::json!({"description": #success_description}));
-                    r.insert("400".to_string(), ::server_less::serde_json::json!({"description": "Bad request"}));
-                    r.insert("500".to_string(), ::server_less::serde_json::json!({"description": "Internal server error"}));
-                    r
-                }
-            }
+        let success_responses = success_response_inserts(
+            ret,
+            overrides,
+            response_overrides,
+            envelope,
+            &format_ident!("r"),
+        );
+        let error_responses = if ret.is_result {
+            error_response_inserts(ret, &format_ident!("r"))
         } else {
-            quote! {
-                {
-                    let mut r = ::server_less::serde_json::Map::new();
-                    r.insert(#success_code.to_string(), ::server_less::serde_json::json!({"description": #success_description}));
-                    r
-                }
+            quote! {}
+        };
+        let responses = quote! {
+            {
+                let mut r = ::server_less::serde_json::Map::new();
+                #success_responses
+                #error_responses
+                r
             }
         };
 
-        // Extract new fields from overrides
-        let tags = &overrides.tags;
-        let deprecated = overrides.deprecated;
+        // Extract new fields from overrides; untagged operations are grouped
+        // under their service, as in `generate_openapi_spec`.
+        let tags = if overrides.tags.is_empty() {
+            std::slice::from_ref(&service_tag)
+        } else {
+            overrides.tags.as_slice()
+        };
+        let deprecated = overrides.deprecated || method.deprecation.is_some();
         let has_description = description.is_some();
         let description_str = description.clone().unwrap_or_default();
 
+        let cfg_attrs = &method.cfg_attrs;
         path_constructors.push(quote! {
+            #(#cfg_attrs)*
             ::server_less::OpenApiPath {
                 path: #full_path.to_string(),
                 method: #http_method_str.to_string(),
@@ -514,33 +1325,181 @@ pub fn generate_openapi_paths(
     })
 }
 
+/// Tokens building a `#[webhook]` method's webhook key and OpenAPI 3.1 operation.
+///
+/// The operation is the `POST` the server sends to subscribers: its JSON body
+/// carries the method's parameters (minus `Context` / `Inject`), and any `2xx`
+/// from the receiver acknowledges delivery.
+pub fn webhook_operation(method: &MethodInfo, overrides: &RouteOverride) -> (String, TokenStream2) {
+    let method_name = method.name_str();
+    let name = overrides
+        .webhook_name
+        .clone()
+        .unwrap_or_else(|| method_name.clone());
+    let (summary, doc_description) = split_doc_comment(&method.docs, &method_name);
+    let mut description = overrides.description.clone().or(doc_description);
+    if let Some(ref deprecation) = method.deprecation {
+        description = deprecation.annotate(description);
+    }
+    let operation_id = overrides
+        .operation_id
+        .clone()
+        .unwrap_or_else(|| method_name.clone());
+
+    let mut prop_insertions = Vec::new();
+    let mut required = Vec::new();
+    for param in &method.params {
+        if is_framework_param(param, &method.params) {
+            continue;
+        }
+        let name = param.wire_name.clone().unwrap_or_else(|| param.name_str());
+        let schema = param_schema(param);
+        prop_insertions.push(quote! { props.insert(#name.to_string(), #schema); });
+        if !param.is_optional && param.default_value.is_none() {
+            required.push(name);
+        }
+    }
+
+    let description_insert = description.map(|d| {
+        quote! { op.insert("description".to_string(), ::server_less::serde_json::json!(#d)); }
+    });
+    let tags = &overrides.tags;
+    let tags_insert = (!tags.is_empty()).then(|| {
+        quote! { op.insert("tags".to_string(), ::server_less::serde_json::json!([#(#tags),*])); }
+    });
+    let deprecated_insert = (overrides.deprecated || method.deprecation.is_some()).then(|| {
+        quote! { op.insert("deprecated".to_string(), ::server_less::serde_json::Value::Bool(true)); }
+    });
+    let body_insert = (!prop_insertions.is_empty()).then(|| {
+        quote! {
+            let mut props = ::server_less::serde_json::Map::new();
+            #(#prop_insertions)*
+            op.insert(
+                "requestBody".to_string(),
+                ::server_less::serde_json::json!({
+                    "required": true,
+                    "content": {
+                        "application/json": {
+                            "schema": {
+                                "type": "object",
+                                "properties": props,
+                                "required": [#(#required),*]
+                            }
+                        }
+                    }
+                }),
+            );
+        }
+    });
+
+    let operation = quote! {
+        {
+            let mut op = ::server_less::serde_json::Map::new();
+            op.insert("summary".to_string(), ::server_less::serde_json::json!(#summary));
+            #description_insert
+            op.insert("operationId".to_string(), ::server_less::serde_json::json!(#operation_id));
+            #tags_insert
+            #deprecated_insert
+            #body_insert
+            op.insert(
+                "responses".to_string(),
+                ::server_less::serde_json::json!({
+                    "200": { "description": "Webhook received" }
+                }),
+            );
+            ::server_less::serde_json::Value::Object(op)
+        }
+    };
+    (name, operation)
+}
+
+/// Tokens adding a `Result` method's error responses to the map bound to `map`.
+///
+/// A `#[derive(ServerlessError)]` error type contributes one response per HTTP
+/// status its variants map to (several variants become an `anyOf`); any other
+/// error type gets generic 400/500 responses.
+fn error_response_inserts(ret: &ReturnInfo, map: &syn::Ident) -> TokenStream2 {
+    let generic = quote! {
+        #map.insert("400".to_string(), ::server_less::serde_json::json!({
+            "description": "Bad request"
+        }));
+        #map.insert("500".to_string(), ::server_less::serde_json::json!({
+            "description": "Internal server error"
+        }));
+    };
+    let Some(err_ty) = &ret.err_type else {
+        return generic;
+    };
+    quote! {
+        {
+            use ::server_less::ErrorVariantsFallback as _;
+            let __variants = ::server_less::ErrorVariantsHelper::<#err_ty>::new().error_variants();
+            if __variants.is_empty() {
+                #generic
+            } else {
+                #map.extend(::server_less::openapi_error_responses(&__variants));
+            }
+        }
+    }
+}
+
+/// Wrap the body of a generated spec method so the JSON is built once, on the
+/// first call, and cloned afterwards.
+///
+/// Impls with type or const generics rebuild on every call: a `static` inside
+/// a generic method is shared by all instantiations, and mounted children's
+/// paths may depend on the type arguments.
+pub fn memoize_spec(generics: &syn::Generics, body: TokenStream2) -> TokenStream2 {
+    if generics.type_params().next().is_some() || generics.const_params().next().is_some() {
+        return body;
+    }
+    quote! {
+        static __SL_SPEC: ::std::sync::OnceLock<::server_less::serde_json::Value> =
+            ::std::sync::OnceLock::new();
+        __SL_SPEC.get_or_init(|| { #body }).clone()
+    }
+}
+
+/// Default OpenAPI tag for a service: the struct name without a `Service` suffix
+/// (`UserService` -> `User`).
+pub(crate) fn service_tag(struct_name: &str) -> String {
+    match struct_name.strip_suffix("Service") {
+        Some(stem) if !stem.is_empty() => stem.to_string(),
+        _ => struct_name.to_string(),
+    }
+}
+
 /// Generate OpenAPI 3.0 specification
+///
+/// JSON request bodies are documented under `content_type`.
 pub fn generate_openapi_spec(
     struct_name: &syn::Ident,
     prefix: &str,
+    content_type: &str,
+    envelope: bool,
     methods_with_overrides: &[(MethodInfo, RouteOverride, ResponseOverride)],
+    webhooks: &[(MethodInfo, RouteOverride)],
 ) -> syn::Result<TokenStream2> {
     let mut operation_data = Vec::new();
+    let mut operation_cfgs = Vec::new();
+    let service_tag = service_tag(&struct_name.to_string());
 
     for (method, overrides, response_overrides) in methods_with_overrides {
         let method_name = method.name_str();
 
-        let http_method = if let Some(ref m) = overrides.method {
-            HttpMethod::parse(m).unwrap_or_else(|| infer_http_method(&method_name))
-        } else {
-            infer_http_method(&method_name)
-        };
-
-        let path = if let Some(ref p) = overrides.path {
-            p.clone()
-        } else {
-            infer_path(&method_name, &http_method, &method.params)
-        };
-        let full_path = format!("{}{}", prefix, path);
+        let (http_method, path) = http_route(method, overrides, false);
+        let full_path = openapi_path(&format!("{}{}", prefix, path));
         let http_method_str = http_method.as_str().to_lowercase();
 
-        let (summary, description) = split_doc_comment(&method.docs, &method_name);
-        let operation_id = method_name.clone();
+        let (summary, doc_description) = split_doc_comment(&method.docs, &method_name);
+        let mut description = overrides.description.clone().or(doc_description);
+        if let Some(ref deprecation) = method.deprecation {
+            description = deprecation.annotate(description);
+        }
+        let operation_id = overrides
+            .operation_id
+            .clone()
+            .unwrap_or_else(|| method_name.clone());
 
         let default_has_body = matches!(
             http_method,
@@ -555,8 +1514,9 @@ pub fn generate_openapi_spec(
         let mut header_params = Vec::new();
 
         for param in &method.params {
-            // Skip Context parameters - they're injected by the framework (per-method detection)
-            if should_inject_context(&param.ty, &method.params) {
+            // Skip Context, Inject and CancellationToken parameters - they're injected by the framework (per-method detection)
+            // A RawJson parameter is the whole body, documented below
+            if is_framework_param(param, &method.params) || is_raw_json(&param.ty) {
                 continue;
             }
 
@@ -582,12 +1542,12 @@ pub fn generate_openapi_spec(
             .iter()
             .map(|p| {
                 let name = p.wire_name.clone().unwrap_or_else(|| p.name_str());
-                let json_type = server_less_rpc::infer_json_type(&p.ty);
+                let schema = param_schema(p);
                 let description_tokens = match &p.help_text {
                     Some(text) => quote! { Some(#text) },
                     None => quote! { None::<&str> },
                 };
-                quote! { (#name, "path", #json_type, true, #description_tokens) }
+                quote! { (#name, "path", #schema, true, #description_tokens) }
             })
             .collect();
 
@@ -595,13 +1555,13 @@ pub fn generate_openapi_spec(
             .iter()
             .map(|p| {
                 let name = p.wire_name.clone().unwrap_or_else(|| p.name_str());
-                let json_type = server_less_rpc::infer_json_type(&p.ty);
+                let schema = param_schema(p);
                 let required = !p.is_optional && p.default_value.is_none();
                 let description_tokens = match &p.help_text {
                     Some(text) => quote! { Some(#text) },
                     None => quote! { None::<&str> },
                 };
-                quote! { (#name, "query", #json_type, #required, #description_tokens) }
+                quote! { (#name, "query", #schema, #required, #description_tokens) }
             })
             .collect();
 
@@ -609,13 +1569,13 @@ pub fn generate_openapi_spec(
             .iter()
             .map(|p| {
                 let name = p.wire_name.clone().unwrap_or_else(|| p.name_str());
-                let json_type = server_less_rpc::infer_json_type(&p.ty);
+                let schema = param_schema(p);
                 let required = !p.is_optional && p.default_value.is_none();
                 let description_tokens = match &p.help_text {
                     Some(text) => quote! { Some(#text) },
                     None => quote! { None::<&str> },
                 };
-                quote! { (#name, "header", #json_type, #required, #description_tokens) }
+                quote! { (#name, "header", #schema, #required, #description_tokens) }
             })
             .collect();
 
@@ -623,77 +1583,61 @@ pub fn generate_openapi_spec(
             .iter()
             .map(|p| {
                 let name = p.wire_name.clone().unwrap_or_else(|| p.name_str());
-                let json_type = server_less_rpc::infer_json_type(&p.ty);
+                let schema = param_schema(p);
                 let required = !p.is_optional && p.default_value.is_none();
-                quote! { (#name, #json_type, #required) }
+                quote! { (#name, #schema, #required) }
             })
             .collect();
         let has_body_props = !body_props.is_empty();
+        let raw_body = method.params.iter().any(|p| is_raw_json(&p.ty));
 
         let ret = &method.return_info;
 
-        // Determine success code - use override if provided, otherwise infer
-        let inferred_code = if ret.is_unit { "204" } else { "200" };
-        let success_code = response_overrides
-            .status
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| inferred_code.to_string());
-
+        let success_response_inserts = success_response_inserts(
+            ret,
+            overrides,
+            response_overrides,
+            envelope,
+            &format_ident!("responses"),
+        );
         let error_responses = ret.is_result;
+        let error_response_inserts = error_response_inserts(ret, &format_ident!("responses"));
 
-        // Build custom response metadata at macro expansion time
-        let has_content_type = response_overrides.content_type.is_some();
-        let content_type_value = response_overrides.content_type.as_deref().unwrap_or("");
-        let header_insertions: Vec<TokenStream2> = response_overrides
-            .headers
-            .iter()
-            .map(|(name, _)| {
-                quote! {
-                    headers_obj.insert(#name.to_string(), ::server_less::serde_json::json!({
-                        "description": format!("Custom header: {}", #name),
-                        "schema": {
-                            "type": "string"
-                        }
-                    }));
-                }
-            })
-            .collect();
-        let has_custom_headers = !response_overrides.headers.is_empty();
-
-        // Extract new OpenAPI fields from overrides
-        let tags = &overrides.tags;
-        let deprecated = overrides.deprecated;
+        // Extract new OpenAPI fields from overrides; untagged operations are
+        // grouped under their service so merged specs stay navigable.
+        let tags = if overrides.tags.is_empty() {
+            std::slice::from_ref(&service_tag)
+        } else {
+            overrides.tags.as_slice()
+        };
+        let deprecated = overrides.deprecated || method.deprecation.is_some();
         let has_description = description.is_some();
         let description_str = description.clone().unwrap_or_default();
-        let success_description = response_overrides
-            .description
-            .clone()
-            .unwrap_or_else(|| "Successful response".to_string());
 
+        let cfg_attrs = &method.cfg_attrs;
+        operation_cfgs.push(quote! { #(#cfg_attrs)* });
         operation_data.push(quote! {
             {
                 let path = #full_path;
                 let method = #http_method_str;
                 let summary = #summary;
                 let operation_id = #operation_id;
-                let success_code = #success_code;
                 let has_error_responses = #error_responses;
                 let has_body = #has_body_props;
                 let tags: Vec<&str> = vec![#(#tags),*];
                 let deprecated = #deprecated;
                 let has_description = #has_description;
                 let description_str = #description_str;
-                let success_description = #success_description;
 
                 let mut parameters: Vec<::server_less::serde_json::Value> = Vec::new();
                 #(
                     {
-                        let (name, location, schema_type, required, description): (&str, &str, &str, bool, Option<&str>) = #path_param_specs;
+                        let (name, location, schema, required, description): (&str, &str, ::server_less::serde_json::Value, bool, Option<&str>) = #path_param_specs;
                         let mut param = ::server_less::serde_json::json!({
                             "name": name,
                             "in": location,
                             "required": required,
-                            "schema": { "type": schema_type }
+                            "schema": schema
                         });
                         if let Some(desc) = description {
                             param.as_object_mut().unwrap_or_else(|| unreachable!("BUG: json!({{}}) must produce an Object"))
@@ -704,12 +1648,12 @@ pub fn generate_openapi_spec(
                 )*
                 #(
                     {
-                        let (name, location, schema_type, required, description): (&str, &str, &str, bool, Option<&str>) = #query_param_specs;
+                        let (name, location, schema, required, description): (&str, &str, ::server_less::serde_json::Value, bool, Option<&str>) = #query_param_specs;
                         let mut param = ::server_less::serde_json::json!({
                             "name": name,
                             "in": location,
                             "required": required,
-                            "schema": { "type": schema_type }
+                            "schema": schema
                         });
                         if let Some(desc) = description {
                             param.as_object_mut().unwrap_or_else(|| unreachable!("BUG: json!({{}}) must produce an Object"))
@@ -720,12 +1664,12 @@ pub fn generate_openapi_spec(
                 )*
                 #(
                     {
-                        let (name, location, schema_type, required, description): (&str, &str, &str, bool, Option<&str>) = #header_param_specs;
+                        let (name, location, schema, required, description): (&str, &str, ::server_less::serde_json::Value, bool, Option<&str>) = #header_param_specs;
                         let mut param = ::server_less::serde_json::json!({
                             "name": name,
                             "in": location,
                             "required": required,
-                            "schema": { "type": schema_type }
+                            "schema": schema
                         });
                         if let Some(desc) = description {
                             param.as_object_mut().unwrap_or_else(|| unreachable!("BUG: json!({{}}) must produce an Object"))
@@ -735,15 +1679,22 @@ pub fn generate_openapi_spec(
                     }
                 )*
 
-                let request_body: Option<::server_less::serde_json::Value> = if has_body {
+                let request_body: Option<::server_less::serde_json::Value> = if #raw_body {
+                    Some(::server_less::serde_json::json!({
+                        "required": true,
+                        "content": {
+                            #content_type: {
+                                "schema": { "type": "object" }
+                            }
+                        }
+                    }))
+                } else if has_body {
                     let mut properties = ::server_less::serde_json::Map::new();
                     let mut required_props: Vec<String> = Vec::new();
                     #(
                         {
-                            let (name, schema_type, required): (&str, &str, bool) = #body_props;
-                            properties.insert(name.to_string(), ::server_less::serde_json::json!({
-                                "type": schema_type
-                            }));
+                            let (name, schema, required): (&str, ::server_less::serde_json::Value, bool) = #body_props;
+                            properties.insert(name.to_string(), schema);
                             if required {
                                 required_props.push(name.to_string());
                             }
@@ -752,7 +1703,7 @@ pub fn generate_openapi_spec(
                     Some(::server_less::serde_json::json!({
                         "required": true,
                         "content": {
-                            "application/json": {
+                            #content_type: {
                                 "schema": {
                                     "type": "object",
                                     "properties": properties,
@@ -767,41 +1718,10 @@ pub fn generate_openapi_spec(
 
                 let mut responses = ::server_less::serde_json::Map::new();
 
-                // Build success response with optional content type and headers
-                let mut success_response = ::server_less::serde_json::json!({
-                    "description": success_description
-                });
-
-                // Add content type if specified
-                if #has_content_type {
-                    let content_obj = ::server_less::serde_json::json!({
-                        #content_type_value: {
-                            "schema": {
-                                "type": "string"
-                            }
-                        }
-                    });
-                    success_response.as_object_mut().unwrap_or_else(|| unreachable!("BUG: json!({{}}) must produce an Object"))
-                        .insert("content".to_string(), content_obj);
-                }
-
-                // Add custom headers if specified
-                if #has_custom_headers {
-                    let mut headers_obj = ::server_less::serde_json::Map::new();
-                    #(#header_insertions)*
-                    success_response.as_object_mut().unwrap_or_else(|| unreachable!("BUG: json!({{}}) must produce an Object"))
-                        .insert("headers".to_string(), ::server_less::serde_json::Value::Object(headers_obj));
-                }
-
-                responses.insert(success_code.to_string(), success_response);
+                #success_response_inserts
 
                 if has_error_responses {
-                    responses.insert("400".to_string(), ::server_less::serde_json::json!({
-                        "description": "Bad request"
-                    }));
-                    responses.insert("500".to_string(), ::server_less::serde_json::json!({
-                        "description": "Internal server error"
-                    }));
+                    #error_response_inserts
                 }
 
                 let mut operation = ::server_less::serde_json::json!({
@@ -846,11 +1766,41 @@ pub fn generate_openapi_spec(
         });
     }
 
+    // Webhooks are an OpenAPI 3.1 feature.
+    let (spec_version, webhooks_collect, webhooks_entry) = if webhooks.is_empty() {
+        ("3.0.0", quote! {}, quote! {})
+    } else {
+        let inserts = webhooks.iter().map(|(method, overrides)| {
+            let mut overrides = overrides.clone();
+            if overrides.tags.is_empty() {
+                overrides.tags.push(service_tag.clone());
+            }
+            let (name, operation) = webhook_operation(method, &overrides);
+            let cfg_attrs = &method.cfg_attrs;
+            quote! {
+                #(#cfg_attrs)*
+                {
+                    let operation = #operation;
+                    webhooks.insert(#name.to_string(), ::server_less::serde_json::json!({ "post": operation }));
+                }
+            }
+        });
+        (
+            "3.1.0",
+            quote! {
+                let mut webhooks = ::server_less::serde_json::Map::new();
+                #(#inserts)*
+            },
+            quote! { , "webhooks": webhooks },
+        )
+    };
+
     Ok(quote! {
         {
             let mut paths = ::server_less::serde_json::Map::new();
 
             #(
+                #operation_cfgs
                 {
                     let (path, method, operation): (String, String, ::server_less::serde_json::Value) = #operation_data;
                     let path_item = paths.entry(path)
@@ -861,13 +1811,16 @@ pub fn generate_openapi_spec(
                 }
             )*
 
+            #webhooks_collect
+
             ::server_less::serde_json::json!({
-                "openapi": "3.0.0",
+                "openapi": #spec_version,
                 "info": {
                     "title": stringify!(#struct_name),
                     "version": "0.1.0"
                 },
                 "paths": paths
+                #webhooks_entry
             })
         }
     })
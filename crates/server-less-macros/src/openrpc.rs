@@ -17,6 +17,9 @@
 //! - Parameter schemas (JSON Schema format)
 //! - Result type schemas
 //! - Optional/required parameter marking
+//! - `servers` block (from `servers = [...]`)
+//! - Per-method `errors` for `Result<T, E>` returns, with codes taken from
+//!   `IntoErrorCode` and definitions shared under `components.errors`
 //!
 //! # Generated Methods
 //!
@@ -29,7 +32,7 @@
 //!
 //! struct Calculator;
 //!
-//! #[openrpc(title = "Calculator API")]
+//! #[openrpc(title = "Calculator API", servers = ["http://localhost:3000/rpc"])]
 //! impl Calculator {
 //!     /// Add two numbers
 //!     fn add(&self, a: i32, b: i32) -> i32 {
@@ -59,6 +62,8 @@ pub(crate) struct OpenRpcArgs {
     title: Option<String>,
     /// Service version
     version: Option<String>,
+    /// Server URLs for the `servers` block
+    servers: Vec<String>,
 }
 
 impl Parse for OpenRpcArgs {
@@ -78,15 +83,24 @@ impl Parse for OpenRpcArgs {
                     let lit: syn::LitStr = input.parse()?;
                     args.version = Some(lit.value());
                 }
+                "servers" => {
+                    let content;
+                    syn::bracketed!(content in input);
+                    let urls =
+                        syn::punctuated::Punctuated::<syn::LitStr, Token![,]>::parse_terminated(
+                            &content,
+                        )?;
+                    args.servers = urls.iter().map(|lit| lit.value()).collect();
+                }
                 other => {
-                    const VALID: &[&str] = &["title", "version"];
+                    const VALID: &[&str] = &["title", "version", "servers"];
                     let suggestion = crate::did_you_mean(other, VALID)
                         .map(|s| format!(" — did you mean `{s}`?"))
                         .unwrap_or_default();
                     return Err(syn::Error::new(
                         ident.span(),
                         format!(
-                            "unknown argument `{other}`{suggestion}. Valid arguments: name, version"
+                            "unknown argument `{other}`{suggestion}. Valid arguments: title, version, servers"
                         ),
                     ));
                 }
@@ -102,7 +116,6 @@ impl Parse for OpenRpcArgs {
 }
 
 pub(crate) fn expand_openrpc(args: OpenRpcArgs, mut impl_block: ItemImpl) -> syn::Result<TokenStream2> {
-    crate::reject_generic_impl(&impl_block)?;
     let app_meta = extract_app_meta(&mut impl_block.attrs);
     let struct_name = get_impl_name(&impl_block)?;
     let (impl_generics, _ty_generics, where_clause) = impl_block.generics.split_for_impl();
@@ -129,10 +142,18 @@ pub(crate) fn expand_openrpc(args: OpenRpcArgs, mut impl_block: ItemImpl) -> syn
     let description = app_meta.description;
     let homepage = app_meta.homepage;
 
-    // Generate method specs
-    let method_specs: Vec<String> = methods.iter().map(generate_method_spec).collect();
-
-    let methods_json = method_specs.join(",\n");
+    // Method specs as statements, so #[cfg] guards individual methods
+    let method_pushes: Vec<TokenStream2> = methods
+        .iter()
+        .map(|m| {
+            let spec = generate_method_spec(m);
+            let cfg_attrs = &m.cfg_attrs;
+            quote! {
+                #(#cfg_attrs)*
+                __specs.push(#spec);
+            }
+        })
+        .collect();
 
     // M12: build optional info fields for description and homepage.
     let description_field = match &description {
@@ -152,6 +173,61 @@ pub(crate) fn expand_openrpc(args: OpenRpcArgs, mut impl_block: ItemImpl) -> syn
         None => quote! {},
     };
 
+    let servers_field = if args.servers.is_empty() {
+        quote! {}
+    } else {
+        let urls = &args.servers;
+        quote! {
+            __spec["servers"] = ::server_less::serde_json::json!([
+                #({"name": #urls, "url": #urls}),*
+            ]);
+        }
+    };
+
+    // Errors come from `IntoErrorCode::error_variants()` at runtime, keyed by
+    // variant name under `components.errors` and referenced from each method.
+    let error_collectors: Vec<TokenStream2> = methods
+        .iter()
+        .filter_map(|m| {
+            let err_ty = m.return_info.err_type.as_ref()?;
+            let name = m.name_str().to_lower_camel_case();
+            let cfg_attrs = &m.cfg_attrs;
+            Some(quote! {
+                #(#cfg_attrs)*
+                {
+                    use ::server_less::ErrorVariantsFallback as _;
+                    let __variants =
+                        ::server_less::ErrorVariantsHelper::<#err_ty>::new().error_variants();
+                    if !__variants.is_empty() {
+                        let mut __refs = Vec::new();
+                        for __v in __variants {
+                            __refs.push(::server_less::serde_json::json!({
+                                "$ref": format!("#/components/errors/{}", __v.name)
+                            }));
+                            __errors.entry(__v.name.to_string()).or_insert_with(|| {
+                                let mut __data = ::server_less::serde_json::json!({
+                                    "name": __v.name,
+                                    "code": __v.code.to_string()
+                                });
+                                if let Some(__app_code) = __v.app_code {
+                                    __data["app_code"] = __app_code.into();
+                                }
+                                ::server_less::serde_json::json!({
+                                    "code": __v.jsonrpc_code,
+                                    "message": __v.message,
+                                    "data": __data
+                                })
+                            });
+                        }
+                        if let Some(__m) = __methods.iter_mut().find(|__m| __m["name"] == #name) {
+                            __m["errors"] = ::server_less::serde_json::Value::Array(__refs);
+                        }
+                    }
+                }
+            })
+        })
+        .collect();
+
     // Only emit the impl block if no higher-priority protocol sibling is present.
     let maybe_impl = if crate::is_protocol_impl_emitter(&impl_block, "openrpc") {
         quote! { #impl_block }
@@ -171,17 +247,33 @@ pub(crate) fn expand_openrpc(args: OpenRpcArgs, mut impl_block: ItemImpl) -> syn
                 });
                 #description_field
                 #homepage_field
-                ::server_less::serde_json::json!({
+                let (__methods, __errors) = Self::openrpc_methods();
+                let mut __spec = ::server_less::serde_json::json!({
                     "openrpc": "1.0.0",
                     "info": __info,
-                    "methods": Self::openrpc_methods()
-                })
+                    "methods": __methods
+                });
+                #servers_field
+                if !__errors.is_empty() {
+                    __spec["components"] = ::server_less::serde_json::json!({ "errors": __errors });
+                }
+                __spec
             }
 
-            /// Get the OpenRPC methods array.
-            fn openrpc_methods() -> Vec<::server_less::serde_json::Value> {
-                let methods_str = concat!("[", #methods_json, "]");
-                ::server_less::serde_json::from_str(methods_str).unwrap_or_default()
+            /// Get the OpenRPC methods array and the shared error definitions.
+            #[allow(unused_mut)]
+            fn openrpc_methods() -> (
+                Vec<::server_less::serde_json::Value>,
+                ::server_less::serde_json::Map<String, ::server_less::serde_json::Value>,
+            ) {
+                let mut __specs: Vec<&str> = Vec::new();
+                #(#method_pushes)*
+                let methods_str = format!("[{}]", __specs.join(","));
+                let mut __methods: Vec<::server_less::serde_json::Value> =
+                    ::server_less::serde_json::from_str(&methods_str).unwrap_or_default();
+                let mut __errors = ::server_less::serde_json::Map::new();
+                #(#error_collectors)*
+                (__methods, __errors)
             }
 
             /// Get the OpenRPC spec as a JSON string.
@@ -201,7 +293,13 @@ pub(crate) fn expand_openrpc(args: OpenRpcArgs, mut impl_block: ItemImpl) -> syn
 /// Generate OpenRPC method specification
 fn generate_method_spec(method: &MethodInfo) -> String {
     let name = method.name_str().to_lower_camel_case();
-    let description = method.docs.clone().unwrap_or_default();
+    let mut description = method.docs.clone();
+    let mut deprecated = String::new();
+    if let Some(ref deprecation) = method.deprecation {
+        description = deprecation.annotate(description);
+        deprecated = "\n            \"deprecated\": true,".to_string();
+    }
+    let description = description.unwrap_or_default();
 
     let params: Vec<String> = method.params.iter().map(generate_param_spec).collect();
 
@@ -210,7 +308,7 @@ fn generate_method_spec(method: &MethodInfo) -> String {
     format!(
         r#"{{
             "name": "{}",
-            "description": "{}",
+            "description": "{}",{}
             "params": [{}],
             "result": {{
                 "name": "result",
@@ -218,12 +316,30 @@ fn generate_method_spec(method: &MethodInfo) -> String {
             }}
         }}"#,
         name,
-        description.replace('"', "\\\""),
+        json_escape(&description),
+        deprecated,
         params.join(", "),
         result_schema
     )
 }
 
+/// Escape a string for embedding inside a JSON string literal.
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
 /// Generate parameter specification
 fn generate_param_spec(param: &ParamInfo) -> String {
     let name = param.name_str().to_lower_camel_case();
@@ -269,9 +385,18 @@ fn get_json_schema_ty(ty: &syn::Type) -> String {
     }
     // Vec<T> → {"type": "array", "items": <inner_schema>}
     if let Some(inner) = unwrap_vec_type(ty) {
-        let inner_schema = get_json_schema_ty(inner);
+        // Nested 128-bit integers are not sent as strings, only top-level ones.
+        let inner_schema = if server_less_rpc::is_wide_integer(inner) {
+            r#"{"type": "integer"}"#.to_string()
+        } else {
+            get_json_schema_ty(inner)
+        };
         return format!(r#"{{"type": "array", "items": {}}}"#, inner_schema);
     }
+    // i128/u128/Decimal travel as decimal strings.
+    if server_less_rpc::is_decimal_type(ty) {
+        return r#"{"type": "string", "format": "decimal"}"#.to_string();
+    }
     let type_str = quote!(#ty).to_string();
     if type_str.contains("String") || type_str.contains("str") {
         r#"{"type": "string"}"#.to_string()
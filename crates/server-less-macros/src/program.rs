@@ -157,6 +157,7 @@ pub(crate) fn expand_program(args: ProgramArgs, mut impl_block: ItemImpl) -> syn
         manual: None,
         input_schema: None,
         output_schema: None,
+        output: None,
     };
     let cli_tokens = cli::expand_cli(cli_args, impl_block.clone())?;
 
@@ -166,6 +167,8 @@ pub(crate) fn expand_program(args: ProgramArgs, mut impl_block: ItemImpl) -> syn
             crate::markdown::MarkdownArgs {
                 title: None,
                 types: true,
+                include_schemas: false,
+                protocols: Vec::new(),
             },
             impl_block,
         )?)
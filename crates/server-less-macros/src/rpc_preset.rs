@@ -140,7 +140,19 @@ pub(crate) fn expand_rpc(args: RpcArgs, mut impl_block: ItemImpl) -> syn::Result
     let serve_tokens = {
         let serve_args = crate::http::ServeArgs {
             protocols: vec!["jsonrpc".into()],
+            mounts: Vec::new(),
             health_path: args.health,
+            readiness: None,
+            ready_path: None,
+            readiness_timeout: None,
+            metrics_path: None,
+            docs_path: None,
+            discovery_path: None,
+            json_errors: None,
+            redirect_slashes: false,
+            catch_panic: false,
+            maintenance: None,
+            cors: None,
             openapi: Some(false),
             name: name.clone(),
             description: description.clone(),
@@ -19,6 +19,14 @@ pub(crate) struct ServerArgs {
     pub openapi: Option<bool>,
     /// Health check path (forwarded to ServeArgs)
     pub health: Option<String>,
+    /// Readiness method called by the health route (forwarded to ServeArgs)
+    pub readiness: Option<syn::Ident>,
+    /// Separate readiness probe path (forwarded to ServeArgs)
+    pub ready: Option<syn::LitStr>,
+    /// Timeout of an async readiness method (forwarded to ServeArgs)
+    pub readiness_timeout: Option<syn::LitInt>,
+    /// Prometheus metrics path (forwarded to ServeArgs)
+    pub metrics: Option<syn::LitStr>,
     /// Application name (forwarded to HttpArgs/ServeArgs)
     pub name: Option<String>,
     /// Human-readable description (forwarded to HttpArgs/ServeArgs)
@@ -46,7 +54,7 @@ impl Parse for ServerArgs {
                 "prefix" => {
                     input.parse::<Token![=]>()?;
                     let lit: syn::LitStr = input.parse()?;
-                    args.prefix = Some(lit.value());
+                    args.prefix = Some(crate::openapi_gen::normalize_prefix(&lit)?);
                 }
                 "openapi" => {
                     if input.peek(Token![=]) {
@@ -63,6 +71,20 @@ impl Parse for ServerArgs {
                     let lit: syn::LitStr = input.parse()?;
                     args.health = Some(lit.value());
                 }
+                "readiness" => {
+                    args.readiness = Some(http::parse_readiness_method(&ident, input)?);
+                }
+                "ready" => {
+                    input.parse::<Token![=]>()?;
+                    args.ready = Some(input.parse()?);
+                }
+                "readiness_timeout" => {
+                    args.readiness_timeout = Some(http::parse_readiness_timeout(input)?);
+                }
+                "metrics" => {
+                    input.parse::<Token![=]>()?;
+                    args.metrics = Some(input.parse()?);
+                }
                 "name" => {
                     input.parse::<Token![=]>()?;
                     let lit: syn::LitStr = input.parse()?;
@@ -102,8 +124,9 @@ impl Parse for ServerArgs {
                 }
                 other => {
                     const VALID: &[&str] = &[
-                        "prefix", "openapi", "health", "name", "description", "version",
-                        "homepage", "config", "config_cmd",
+                        "prefix", "openapi", "health", "ready", "readiness", "readiness_timeout",
+                        "metrics", "name", "description", "version", "homepage", "config",
+                        "config_cmd",
                     ];
                     let suggestion = crate::did_you_mean(other, VALID)
                         .map(|s| format!(" — did you mean `{s}`?"))
@@ -112,7 +135,7 @@ impl Parse for ServerArgs {
                         ident.span(),
                         format!(
                             "unknown argument `{other}`{suggestion}\n\
-                             Valid arguments: prefix, openapi, health, name, description, version, homepage, config, config_cmd"
+                             Valid arguments: prefix, openapi, health, ready, readiness, readiness_timeout, metrics, name, description, version, homepage, config, config_cmd"
                         ),
                     ));
                 }
@@ -138,18 +161,41 @@ pub(crate) fn expand_server(args: ServerArgs, mut impl_block: ItemImpl) -> syn::
     let http_args = HttpArgs {
         prefix: args.prefix,
         openapi: args.openapi,
+        openapi_path: None,
+        docs_path: None,
         debug: false,
         trace: false,
         name: name.clone(),
         description: description.clone(),
         version: version.clone(),
         homepage: homepage.clone(),
+        visibility: Default::default(),
+        rpc_style: false,
+        envelope: false,
+        pretty: false,
+        skip_nulls: false,
+        created_location: false,
+        collection: None,
+        deserialize_error_status: None,
+        content_type: None,
     };
     let http_tokens = http::expand_http(http_args, impl_block.clone())?;
 
     let serve_args = ServeArgs {
         protocols: vec!["http".into()],
+        mounts: Vec::new(),
         health_path: args.health,
+        readiness: args.readiness,
+        ready_path: args.ready,
+        readiness_timeout: args.readiness_timeout,
+        metrics_path: args.metrics,
+        docs_path: None,
+        discovery_path: None,
+        json_errors: None,
+        redirect_slashes: false,
+        catch_panic: false,
+        maintenance: None,
+        cors: None,
         openapi: args.openapi,
         name: name.clone(),
         description,
@@ -18,6 +18,19 @@ pub(crate) fn has_server_skip(method: &MethodInfo) -> bool {
     has_server_flag(method, "skip")
 }
 
+/// Returns `true` if the method has `#[server(skip)]` or is a `#[webhook]`.
+///
+/// A webhook documents a callback the server sends, so protocols that expose
+/// callable operations (everything but `#[http]`'s spec) leave it out.
+pub(crate) fn skips_operation(method: &MethodInfo) -> bool {
+    has_server_skip(method)
+        || method
+            .method
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("webhook"))
+}
+
 /// Returns `true` if the method has `#[server(hidden)]`.
 pub(crate) fn has_server_hidden(method: &MethodInfo) -> bool {
     has_server_flag(method, "hidden")
@@ -0,0 +1,167 @@
+//! `ServerlessEnum` derive: variant introspection for C-like enums.
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize, ServerlessEnum)]
+//! #[serde(rename_all = "lowercase")]
+//! enum Status {
+//!     Active,
+//!     Inactive,
+//! }
+//!
+//! assert_eq!(Status::VARIANTS, &["active", "inactive"]);
+//! ```
+//!
+//! The variant names follow serde's `rename` / `rename_all` attributes so the
+//! list matches what actually goes over the wire. Variants marked
+//! `#[serde(skip)]` are left out.
+//!
+//! The derive also implements `FromStr` over the same names, which is how HTTP
+//! query/path parameters are parsed.
+
+use heck::{
+    ToKebabCase, ToLowerCamelCase, ToShoutyKebabCase, ToShoutySnakeCase, ToSnakeCase,
+    ToUpperCamelCase,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Token};
+
+/// serde attributes relevant to a variant's or field's wire name.
+#[derive(Default)]
+pub(crate) struct SerdeAttrs {
+    pub(crate) rename: Option<String>,
+    pub(crate) rename_all: Option<syn::LitStr>,
+    pub(crate) skip: bool,
+    /// `#[serde(default)]` or `#[serde(default = "path")]`.
+    pub(crate) default: bool,
+}
+
+impl SerdeAttrs {
+    pub(crate) fn from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut out = SerdeAttrs::default();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("serde")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") && meta.input.peek(Token![=]) {
+                    let lit: syn::LitStr = meta.value()?.parse()?;
+                    out.rename = Some(lit.value());
+                } else if meta.path.is_ident("rename_all") && meta.input.peek(Token![=]) {
+                    out.rename_all = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("skip") {
+                    out.skip = true;
+                } else if meta.path.is_ident("default") {
+                    out.default = true;
+                    if meta.input.peek(Token![=]) {
+                        meta.value()?.parse::<syn::LitStr>()?;
+                    }
+                } else if meta.input.peek(Token![=]) {
+                    // Other serde options are serde's business.
+                    meta.value()?.parse::<syn::Expr>()?;
+                } else if meta.input.peek(syn::token::Paren) {
+                    let _content;
+                    syn::parenthesized!(_content in meta.input);
+                }
+                Ok(())
+            })?;
+        }
+        Ok(out)
+    }
+}
+
+/// Apply a serde `rename_all` rule to a Rust variant or field name.
+pub(crate) fn apply_rename_all(rule: &syn::LitStr, name: &str) -> syn::Result<String> {
+    Ok(match rule.value().as_str() {
+        "lowercase" => name.to_lowercase(),
+        "UPPERCASE" => name.to_uppercase(),
+        "PascalCase" => name.to_upper_camel_case(),
+        "camelCase" => name.to_lower_camel_case(),
+        "snake_case" => name.to_snake_case(),
+        "SCREAMING_SNAKE_CASE" => name.to_shouty_snake_case(),
+        "kebab-case" => name.to_kebab_case(),
+        "SCREAMING-KEBAB-CASE" => name.to_shouty_kebab_case(),
+        other => {
+            return Err(syn::Error::new(
+                rule.span(),
+                format!(
+                    "unknown rename_all rule `{other}`\n\
+                     \n\
+                     Valid rules: lowercase, UPPERCASE, PascalCase, camelCase, snake_case, \
+                     SCREAMING_SNAKE_CASE, kebab-case, SCREAMING-KEBAB-CASE"
+                ),
+            ));
+        }
+    })
+}
+
+pub(crate) fn expand_serverless_enum(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input,
+            "ServerlessEnum can only be derived for enums",
+        ));
+    };
+
+    let enum_attrs = SerdeAttrs::from_attrs(&input.attrs)?;
+    let mut variants = Vec::new();
+    let mut parse_arms = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "ServerlessEnum only supports unit variants (no fields)\n\
+                 \n\
+                 Example:\n\
+                 #[derive(ServerlessEnum)]\n\
+                 enum Status {\n\
+                     Active,\n\
+                     Inactive,\n\
+                 }",
+            ));
+        }
+        let attrs = SerdeAttrs::from_attrs(&variant.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+        let ident = &variant.ident;
+        let ident_str = ident.to_string();
+        let wire_name = match (attrs.rename, &enum_attrs.rename_all) {
+            (Some(rename), _) => rename,
+            (None, Some(rule)) => apply_rename_all(rule, &ident_str)?,
+            (None, None) => ident_str,
+        };
+        parse_arms.push(quote! { #wire_name => ::std::result::Result::Ok(Self::#ident), });
+        variants.push(wire_name);
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::server_less::ServerlessEnum for #name #ty_generics #where_clause {
+            const VARIANTS: &'static [&'static str] = &[#(#variants),*];
+        }
+
+        impl #impl_generics ::server_less::ServerlessSchema for #name #ty_generics #where_clause {
+            fn json_schema() -> ::server_less::serde_json::Value {
+                ::server_less::serde_json::json!({
+                    "type": "string",
+                    "enum": <Self as ::server_less::ServerlessEnum>::VARIANTS
+                })
+            }
+        }
+
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::std::string::String;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    #(#parse_arms)*
+                    other => ::std::result::Result::Err(::std::format!(
+                        "unknown variant `{}`, expected one of: {}",
+                        other,
+                        <Self as ::server_less::ServerlessEnum>::VARIANTS.join(", ")
+                    )),
+                }
+            }
+        }
+    })
+}
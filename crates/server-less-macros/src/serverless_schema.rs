@@ -0,0 +1,144 @@
+//! `ServerlessSchema` derive: JSON schema for structs, using serde wire names.
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize, ServerlessSchema)]
+//! #[serde(rename_all = "camelCase")]
+//! struct CreateUser {
+//!     display_name: String,
+//!     #[serde(rename = "mail")]
+//!     email: Option<String>,
+//! }
+//!
+//! // {"type": "object",
+//! //  "properties": {"displayName": {"type": "string"}, "mail": {"type": "string"}},
+//! //  "required": ["displayName"]}
+//! let schema = <CreateUser as ServerlessSchema>::json_schema();
+//! ```
+//!
+//! Property names follow serde's `rename` / `rename_all` attributes, so specs
+//! match what actually goes over the wire. `#[serde(skip)]` fields are left out;
+//! `Option` and `#[serde(default)]` fields are not listed as required.
+//! A type reached again while its own schema is being built (directly or through
+//! other `ServerlessSchema` structs) is described as a plain object.
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{Data, DeriveInput, Fields};
+
+use crate::serverless_enum::{SerdeAttrs, apply_rename_all};
+
+/// Whether `ty` mentions the struct being derived, in which case its schema
+/// can't be inlined without recursing forever.
+fn mentions_self(ty: &syn::Type, name: &syn::Ident) -> bool {
+    fn walk(tokens: TokenStream, name: &syn::Ident) -> bool {
+        tokens.into_iter().any(|tt| match tt {
+            proc_macro2::TokenTree::Ident(ident) => ident == *name || ident == "Self",
+            proc_macro2::TokenTree::Group(group) => walk(group.stream(), name),
+            _ => false,
+        })
+    }
+    walk(ty.to_token_stream(), name)
+}
+
+pub(crate) fn expand_serverless_schema(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input,
+                    "ServerlessSchema requires a struct with named fields\n\
+                     \n\
+                     Example:\n\
+                     #[derive(ServerlessSchema)]\n\
+                     struct CreateUser {\n\
+                         name: String,\n\
+                         email: String,\n\
+                     }",
+                ));
+            }
+        },
+        Data::Enum(_) => {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "ServerlessSchema can only be derived for structs\n\
+                 \n\
+                 Hint: use #[derive(ServerlessEnum)] for C-like enums",
+            ));
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "ServerlessSchema can only be derived for structs",
+            ));
+        }
+    };
+
+    let struct_attrs = SerdeAttrs::from_attrs(&input.attrs)?;
+    let mut insertions = Vec::new();
+    for field in fields {
+        let attrs = SerdeAttrs::from_attrs(&field.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+        let ident = field.ident.as_ref().expect("named field");
+        // Raw identifiers (`r#type`) go over the wire without the prefix.
+        let ident_str = ident.to_string().trim_start_matches("r#").to_string();
+        let wire_name = match (attrs.rename, &struct_attrs.rename_all) {
+            (Some(rename), _) => rename,
+            (None, Some(rule)) => apply_rename_all(rule, &ident_str)?,
+            (None, None) => ident_str,
+        };
+
+        let ty = &field.ty;
+        let schema = if mentions_self(ty, name) {
+            let json_type = server_less_rpc::infer_json_type(ty);
+            quote! { ::server_less::serde_json::json!({"type": #json_type}) }
+        } else {
+            server_less_rpc::nested_schema_tokens(ty)
+        };
+        let description = server_less_parse::extract_docs(&field.attrs);
+        let describe = description.map(|doc| {
+            quote! {
+                if let ::std::option::Option::Some(__obj) = __schema.as_object_mut() {
+                    __obj.insert("description".to_string(), ::server_less::serde_json::json!(#doc));
+                }
+            }
+        });
+        let required = server_less_parse::unwrap_option_type(ty).is_none()
+            && !attrs.default
+            && !struct_attrs.default;
+        let push_required = required.then(|| quote! { __required.push(#wire_name); });
+
+        insertions.push(quote! {
+            {
+                #[allow(unused_mut)]
+                let mut __schema = #schema;
+                #describe
+                __properties.insert(#wire_name.to_string(), __schema);
+                #push_required
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::server_less::ServerlessSchema for #name #ty_generics #where_clause {
+            fn json_schema() -> ::server_less::serde_json::Value {
+                ::server_less::serverless_schema_guarded(::std::any::type_name::<Self>(), || {
+                    let mut __properties = ::server_less::serde_json::Map::new();
+                    #[allow(unused_mut)]
+                    let mut __required: ::std::vec::Vec<&str> = ::std::vec::Vec::new();
+                    #(#insertions)*
+                    ::server_less::serde_json::json!({
+                        "type": "object",
+                        "properties": __properties,
+                        "required": __required
+                    })
+                })
+            }
+        }
+    })
+}
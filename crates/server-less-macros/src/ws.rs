@@ -4,15 +4,32 @@
 //!
 //! # Protocol
 //!
-//! Uses JSON-RPC 2.0 message format over WebSocket:
+//! Uses a JSON-RPC style message format over WebSocket:
 //! - Request: `{"method": "echo", "params": {"message": "hello"}, "id": 1}`
 //! - Response: `{"result": "Echo: hello", "id": 1}`
-//! - Error: `{"error": {"message": "Unknown method"}, "id": 1}`
+//! - Error: `{"error": {"code": "NOT_FOUND", "message": "Unknown method: foo"}, "id": 1}`
+//!
+//! With `#[ws(jsonrpc = true)]` messages are strict JSON-RPC 2.0 instead: every
+//! response carries `"jsonrpc": "2.0"`, errors use numeric codes
+//! (`-32601` method not found, `-32602` invalid params, see
+//! `ErrorResponse::jsonrpc_error`), requests without an `id` are notifications
+//! that get no reply, and a batch array is answered with an array.
+//!
+//! With `#[ws(max_message = "64kb")]` the WebSocket transport refuses larger
+//! frames and messages, text or binary, before buffering them. The connection
+//! cannot continue past a refused frame, so it answers with an `INVALID_INPUT`
+//! error (`-32600` in JSON-RPC mode) and closes.
+//!
+//! `#[ws(subprotocols = "json-rpc,graphql-ws")]` negotiates `Sec-WebSocket-Protocol`:
+//! the upgrade response echoes the first protocol the client offers that is in
+//! the list, and the list is published in the OpenAPI `x-websocket-protocol`
+//! extension.
 //!
 //! # Message Handling
 //!
 //! - Methods are called by name via JSON messages
-//! - Parameters extracted from `params` object
+//! - Parameters extracted from `params`: an object binds by name, an array by
+//!   declaration order (`Context`/`WsSender` are not counted)
 //! - Both sync and async methods supported
 //! - Supports optional `id` field for request/response correlation
 //!
@@ -22,6 +39,7 @@
 //! - `ws_handle_message(&self, message: &str) -> Result<String, String>` - Sync handler
 //! - `ws_handle_message_async(&self, message: &str).await` - Async handler
 //! - `ws_router(self) -> axum::Router` - Complete WebSocket server
+//! - `ws_router_arc(self: Arc<Self>) -> axum::Router` - The same, sharing an existing `Arc`
 //!
 //! # Basic Example
 //!
@@ -154,7 +172,7 @@
 //! ```
 
 use crate::app::extract_app_meta;
-use crate::server_attrs::{has_server_hidden, has_server_skip, validate_server_attrs};
+use crate::server_attrs::{has_server_hidden, skips_operation, validate_server_attrs};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote};
 use server_less_parse::{MethodInfo, ParamInfo, extract_methods, get_impl_name, partition_methods};
@@ -162,7 +180,10 @@ use server_less_rpc::{self, AsyncHandling};
 use syn::{ItemImpl, Token, parse::Parse};
 
 // Import Context helpers
-use crate::context::{has_qualified_special_param, should_inject_special_param};
+use crate::context::{
+    has_qualified_special_param, http_only_error, is_cancellation_token, is_http_only_param,
+    never_cancelled, should_inject_special_param,
+};
 
 /// Check if a type is server_less::WsSender (fully qualified)
 fn is_qualified_ws_sender(ty: &syn::Type) -> bool {
@@ -207,7 +228,8 @@ fn has_qualified_ws_sender(methods: &[MethodInfo]) -> bool {
 /// Returns `(context_param, ws_sender_param, other_params)` where:
 /// - `context_param` is `Some(param)` if a Context parameter was found
 /// - `ws_sender_param` is `Some(param)` if a WsSender parameter was found
-/// - `other_params` contains all regular parameters
+/// - `other_params` contains all regular parameters (`CancellationToken`s are
+///   in none of the groups)
 ///
 /// Returns an error if multiple Context or WsSender parameters are found.
 fn partition_ws_params(
@@ -241,7 +263,7 @@ fn partition_ws_params(
                 ));
             }
             sender_param = Some(param);
-        } else {
+        } else if !is_cancellation_token(&param.ty) {
             other_params.push(param);
         }
     }
@@ -251,8 +273,9 @@ fn partition_ws_params(
 
 /// Build injection list for mount trait dispatch.
 ///
-/// Context params get `Context::new()`. Returns None if the method
-/// uses WsSender (can't be dispatched through mount).
+/// Context params get `Context::new()` and `CancellationToken`s one that never
+/// fires. Returns None if the method uses WsSender (can't be dispatched through
+/// mount) or an `#[http]`-only param.
 fn build_mount_injections(
     params: &[ParamInfo],
     has_qualified_sender: bool,
@@ -261,8 +284,12 @@ fn build_mount_injections(
     for (i, p) in params.iter().enumerate() {
         if crate::context::should_inject_context(&p.ty, params) {
             injections.push((i, quote! { ::server_less::Context::new() }));
+        } else if is_cancellation_token(&p.ty) {
+            injections.push((i, never_cancelled()));
         } else if should_inject_ws_sender(&p.ty, has_qualified_sender) {
             return None; // Can't dispatch methods requiring WsSender through mount
+        } else if is_http_only_param(p) {
+            return None;
         }
     }
     Some(injections)
@@ -273,6 +300,34 @@ fn build_mount_injections(
 pub(crate) struct WsArgs {
     /// WebSocket endpoint path (e.g., "/ws")
     pub path: Option<String>,
+    /// Speak strict JSON-RPC 2.0 (`jsonrpc` field, numeric error codes, batches)
+    pub jsonrpc: bool,
+    /// Largest accepted message in bytes (`max_message = "64kb"`)
+    pub max_message: Option<usize>,
+    /// Supported `Sec-WebSocket-Protocol` values, in order of preference
+    pub subprotocols: Vec<String>,
+}
+
+/// Parse a byte size such as `65536`, `"512b"`, `"64kb"` or `"1mb"` (units are
+/// powers of 1024, case-insensitive).
+fn parse_byte_size(value: &str) -> Option<usize> {
+    let value = value.trim().to_ascii_lowercase();
+    let digits = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(digits);
+    let multiplier: usize = match unit.trim() {
+        "" | "b" => 1,
+        "kb" => 1 << 10,
+        "mb" => 1 << 20,
+        "gb" => 1 << 30,
+        _ => return None,
+    };
+    number
+        .parse::<usize>()
+        .ok()
+        .and_then(|n| n.checked_mul(multiplier))
+        .filter(|&n| n > 0)
 }
 
 impl Parse for WsArgs {
@@ -281,21 +336,82 @@ impl Parse for WsArgs {
 
         while !input.is_empty() {
             let ident: syn::Ident = input.parse()?;
-            input.parse::<Token![=]>()?;
 
             match ident.to_string().as_str() {
                 "path" => {
+                    input.parse::<Token![=]>()?;
                     let lit: syn::LitStr = input.parse()?;
                     args.path = Some(lit.value());
                 }
+                "jsonrpc" => {
+                    if input.peek(Token![=]) {
+                        input.parse::<Token![=]>()?;
+                        let lit: syn::LitBool = input.parse()?;
+                        args.jsonrpc = lit.value();
+                    } else {
+                        args.jsonrpc = true;
+                    }
+                }
+                "max_message" => {
+                    input.parse::<Token![=]>()?;
+                    let lit: syn::Lit = input.parse()?;
+                    let text = match &lit {
+                        syn::Lit::Str(s) => s.value(),
+                        syn::Lit::Int(i) => i.base10_digits().to_string(),
+                        _ => String::new(),
+                    };
+                    let size = parse_byte_size(&text).ok_or_else(|| {
+                        syn::Error::new(
+                            lit.span(),
+                            "invalid max_message size\n\
+                             \n\
+                             Hint: use a positive byte count with an optional b, kb, mb or gb unit, \
+                             e.g. max_message = \"64kb\"",
+                        )
+                    })?;
+                    args.max_message = Some(size);
+                }
+                "subprotocols" => {
+                    input.parse::<Token![=]>()?;
+                    let lit: syn::LitStr = input.parse()?;
+                    let value = lit.value();
+                    let names: Vec<_> = value.split(',').map(str::trim).collect();
+                    // RFC 6455 subprotocols are HTTP tokens: visible ASCII minus separators.
+                    let is_token = |name: &str| {
+                        !name.is_empty()
+                            && name.bytes().all(|b| {
+                                b.is_ascii_graphic() && !b"()<>@,;:\\\"/[]?={}".contains(&b)
+                            })
+                    };
+                    if let Some(bad) = names.iter().find(|name| !is_token(name)) {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            format!(
+                                "invalid WebSocket subprotocol \"{bad}\"\n\
+                                 \n\
+                                 Hint: list protocol names separated by commas, e.g. \
+                                 subprotocols = \"json-rpc,graphql-ws\""
+                            ),
+                        ));
+                    }
+                    args.subprotocols = names.into_iter().map(String::from).collect();
+                }
                 other => {
-                    const VALID: &[&str] = &["path"];
+                    const VALID: &[&str] = &[
+                        "path",
+                        "jsonrpc",
+                        "max_message",
+                        "subprotocols",
+                    ];
                     let suggestion = crate::did_you_mean(other, VALID)
                         .map(|s| format!(" — did you mean `{s}`?"))
                         .unwrap_or_default();
                     return Err(syn::Error::new(
                         ident.span(),
-                        format!("unknown argument `{other}`{suggestion}. Valid arguments: path"),
+                        format!(
+                            "unknown argument `{other}`{suggestion}. Valid arguments: path, jsonrpc, \
+                             max_message, subprotocols"
+                        ),
                     ));
                 }
             }
@@ -310,7 +426,6 @@ impl Parse for WsArgs {
 }
 
 pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<TokenStream2> {
-    crate::reject_generic_impl(&impl_block)?;
     // L7: app_meta is extracted to consume the __app_meta attr (preventing it from leaking
     // to downstream macros), but ws doesn't produce named artifacts that use it.
     let _app_meta = extract_app_meta(&mut impl_block.attrs);
@@ -328,7 +443,7 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
     for m in &methods {
         validate_server_attrs(m)?;
     }
-    let partitioned = partition_methods(&methods, has_server_skip);
+    let partitioned = partition_methods(&methods, skips_operation);
 
     // Separate hidden from visible leaf methods.
     // Hidden methods are still dispatchable but absent from method listings.
@@ -366,10 +481,14 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
         })
         .collect::<syn::Result<Vec<_>>>()?;
 
-    // Method names for visible leaf methods only
+    // Method names for visible leaf methods only, each behind its method's #[cfg]
     let method_names: Vec<_> = visible_leaf
         .iter()
-        .map(|m| m.wire_name_or(|n| n))
+        .map(|m| {
+            let name = m.wire_name_or(|n| n);
+            let cfg_attrs = &m.cfg_attrs;
+            quote! { #(#cfg_attrs)* #name }
+        })
         .collect();
 
     // Build method documentation (visible methods only)
@@ -403,8 +522,8 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
         "Create an axum Router with WebSocket endpoint at `{}`.\n\n\
          Exposes {} method{}.",
         path,
-        method_names.len(),
-        if method_names.len() == 1 { "" } else { "s" }
+        visible_leaf.len(),
+        if visible_leaf.len() == 1 { "" } else { "s" }
     );
 
     // Generate mount dispatch arms and method names
@@ -457,7 +576,7 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
                         __sender: ::server_less::WsSender,
                         method: &str,
                         args: ::server_less::serde_json::Value,
-                    ) -> ::std::result::Result<::server_less::serde_json::Value, String>
+                    ) -> ::std::result::Result<::server_less::serde_json::Value, ::server_less::ErrorResponse>
                 },
                 quote! {
                     async fn ws_dispatch_async(
@@ -466,7 +585,7 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
                         __sender: ::server_less::WsSender,
                         method: &str,
                         args: ::server_less::serde_json::Value,
-                    ) -> ::std::result::Result<::server_less::serde_json::Value, String>
+                    ) -> ::std::result::Result<::server_less::serde_json::Value, ::server_less::ErrorResponse>
                 },
                 quote! { self.ws_dispatch(__ctx, __sender, method, params) },
                 quote! { self.ws_dispatch_async(__ctx, __sender, method, params).await },
@@ -478,14 +597,14 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
                         &self,
                         method: &str,
                         args: ::server_less::serde_json::Value,
-                    ) -> ::std::result::Result<::server_less::serde_json::Value, String>
+                    ) -> ::std::result::Result<::server_less::serde_json::Value, ::server_less::ErrorResponse>
                 },
                 quote! {
                     async fn ws_dispatch_async(
                         &self,
                         method: &str,
                         args: ::server_less::serde_json::Value,
-                    ) -> ::std::result::Result<::server_less::serde_json::Value, String>
+                    ) -> ::std::result::Result<::server_less::serde_json::Value, ::server_less::ErrorResponse>
                 },
                 quote! { self.ws_dispatch(method, params) },
                 quote! { self.ws_dispatch_async(method, params).await },
@@ -502,6 +621,9 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
     let struct_name_snake = struct_name.to_string().to_lowercase();
     let handler_name = format_ident!("__server_less_ws_handler_{}", struct_name_snake);
     let connection_fn_name = format_ident!("__server_less_ws_connection_{}", struct_name_snake);
+    let handler_turbofish = crate::generic_turbofish(&impl_block.generics);
+    let handler_generics = crate::handler_generics(&impl_block.generics, self_ty);
+    let (handler_impl_generics, _, handler_where_clause) = handler_generics.split_for_impl();
 
     // Generate method signatures based on injected params usage
     let (handle_sig_sync, handle_sig_async) = if uses_injected_params {
@@ -610,8 +732,145 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
         }
     };
 
+    let (handle_body_sync, handle_body_async, response_helpers) = if args.jsonrpc {
+        generate_jsonrpc_handling(
+            uses_injected_params,
+            &ctx_creation,
+            &dispatch_call_sync,
+            &dispatch_call_async,
+        )
+    } else {
+        generate_plain_handling(&ctx_creation, &dispatch_call_sync, &dispatch_call_async)
+    };
+
+    // Protocol-dependent pieces of dispatch, the connection loop and the OpenAPI extension
+    let (unknown_method, handler_error_reply, skip_empty_reply) = if args.jsonrpc {
+        (
+            quote! {
+                ::server_less::ErrorResponse::new(
+                    ::server_less::ErrorCode::NotFound,
+                    format!("Method not found: {}", method),
+                )
+                .with_jsonrpc_code(-32601)
+            },
+            quote! {
+                ::server_less::serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "error": {"code": -32603, "message": err},
+                    "id": null
+                }).to_string()
+            },
+            // Notifications produce no reply
+            quote! {
+                if reply.is_empty() {
+                    continue;
+                }
+            },
+        )
+    } else {
+        (
+            quote! {
+                ::server_less::ErrorResponse::new(
+                    ::server_less::ErrorCode::NotFound,
+                    format!("Unknown method: {}", method),
+                )
+            },
+            quote! {
+                ::server_less::ErrorResponse::new(
+                    ::server_less::ErrorCode::InvalidInput,
+                    err,
+                ).envelope().to_string()
+            },
+            quote! {},
+        )
+    };
+    // The transport refuses frames and messages over `max_message` before buffering
+    // them; the read then fails mid-frame, so the connection is answered and closed.
+    let (limit_upgrade, read_error) = match args.max_message {
+        Some(limit) => {
+            let oversized_reply = if args.jsonrpc {
+                quote! {
+                    ::server_less::serde_json::json!({
+                        "jsonrpc": "2.0",
+                        "error": {"code": -32600, "message": message},
+                        "id": null
+                    }).to_string()
+                }
+            } else {
+                quote! {
+                    ::server_less::ErrorResponse::new(
+                        ::server_less::ErrorCode::InvalidInput,
+                        message,
+                    ).envelope().to_string()
+                }
+            };
+            (
+                quote! { let ws = ws.max_message_size(#limit).max_frame_size(#limit); },
+                quote! {
+                    if ::server_less::ws_message_too_large(&err) {
+                        let message = format!("Message too large (limit {} bytes)", #limit);
+                        let _ = __sender.send(#oversized_reply).await;
+                        let _ = __sender.close().await;
+                    }
+                },
+            )
+        }
+        None => (quote! {}, quote! { let _ = err; }),
+    };
+    // The documented exchange is the first visible method's `#[example]`, wrapped in
+    // the wire envelope; without one, a generic echo call stands in.
+    let documented = visible_leaf
+        .iter()
+        .find_map(|m| m.examples.first().map(|e| (m.wire_name_or(|n| n), e)));
+    let (example_method, example_params, example_result) = match documented {
+        Some((name, example)) => {
+            let params = example
+                .request
+                .as_deref()
+                .map(server_less_rpc::example_value_tokens)
+                .unwrap_or_else(|| quote! { {} });
+            let result = example
+                .response
+                .as_deref()
+                .map(server_less_rpc::example_value_tokens)
+                .unwrap_or_else(|| quote! { null });
+            (name, quote! { (#params) }, quote! { (#result) })
+        }
+        None => (
+            "echo".to_string(),
+            quote! { {"message": "hello"} },
+            quote! { "Echo: hello" },
+        ),
+    };
+    // axum echoes the first subprotocol the client offers that is in this list.
+    let (select_subprotocol, subprotocols_extra) = if args.subprotocols.is_empty() {
+        (quote! {}, quote! {})
+    } else {
+        let subprotocols = &args.subprotocols;
+        (
+            quote! { let ws = ws.protocols([#(#subprotocols),*]); },
+            quote! { "subprotocols": [#(#subprotocols),*], },
+        )
+    };
+    let (protocol_format, request_example, response_example) = if args.jsonrpc {
+        (
+            "JSON-RPC 2.0",
+            quote! { {"jsonrpc": "2.0", "method": #example_method, "params": #example_params, "id": 1} },
+            quote! { {"jsonrpc": "2.0", "result": #example_result, "id": 1} },
+        )
+    } else {
+        (
+            "JSON-RPC style",
+            quote! { {"method": #example_method, "params": #example_params, "id": 1} },
+            quote! { {"result": #example_result, "id": 1} },
+        )
+    };
+
     let maybe_impl = if crate::is_protocol_impl_emitter(&impl_block, "ws") {
-        quote! { #impl_block }
+        let mut clean_impl = impl_block.clone();
+        crate::strip_webhook_attrs(&mut clean_impl);
+        crate::strip_param_attrs(&mut clean_impl);
+        quote! { #clean_impl }
     } else {
         quote! {}
     };
@@ -619,6 +878,7 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
     Ok(quote! {
         #maybe_impl
 
+        #[allow(deprecated)]
         impl #impl_generics ::server_less::WsMount for #self_ty #where_clause {
             fn ws_mount_methods() -> Vec<String> {
                 Self::ws_methods()
@@ -628,7 +888,7 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
                 &self,
                 method: &str,
                 params: ::server_less::serde_json::Value,
-            ) -> ::std::result::Result<::server_less::serde_json::Value, String> {
+            ) -> ::std::result::Result<::server_less::serde_json::Value, ::server_less::ErrorResponse> {
                 self.ws_mount_dispatch_inner(method, params)
             }
 
@@ -636,15 +896,17 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
                 &self,
                 method: &str,
                 params: ::server_less::serde_json::Value,
-            ) -> ::std::result::Result<::server_less::serde_json::Value, String> {
+            ) -> ::std::result::Result<::server_less::serde_json::Value, ::server_less::ErrorResponse> {
                 self.ws_mount_dispatch_async_inner(method, params).await
             }
         }
 
+        #[allow(deprecated)]
         impl #impl_generics #self_ty #where_clause {
             #[doc = #ws_methods_doc]
             pub fn ws_methods() -> Vec<String> {
-                let mut names: Vec<String> = vec![#(#method_names.to_string()),*];
+                let leaf: Vec<&str> = vec![#(#method_names),*];
+                let mut names: Vec<String> = leaf.into_iter().map(String::from).collect();
                 #(#mount_method_names)*
                 names
             }
@@ -653,96 +915,24 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
             ///
             /// Note: Async methods will return an error. Use `ws_handle_message_async` for async methods.
             #handle_sig_sync {
-                #ctx_creation
-                // Parse the incoming message as JSON-RPC
-                let parsed: ::server_less::serde_json::Value = ::server_less::serde_json::from_str(message)
-                    .map_err(|e| format!("Invalid JSON: {}", e))?;
-
-                let method = parsed.get("method")
-                    .and_then(|v| v.as_str())
-                    .ok_or_else(|| "Missing 'method' field".to_string())?;
-
-                let params = parsed.get("params")
-                    .cloned()
-                    .unwrap_or(::server_less::serde_json::json!({}));
-
-                let id = parsed.get("id").cloned();
-
-                // Dispatch to the appropriate method
-                let result = #dispatch_call_sync;
-
-                // Format response
-                Self::__format_ws_response(result, id)
+                #handle_body_sync
             }
 
             /// Handle an incoming WebSocket JSON-RPC message (async version)
             ///
             /// Supports both sync and async methods. Async methods are awaited properly.
             #handle_sig_async {
-                #ctx_creation
-                // Parse the incoming message as JSON-RPC
-                let parsed: ::server_less::serde_json::Value = ::server_less::serde_json::from_str(message)
-                    .map_err(|e| format!("Invalid JSON: {}", e))?;
-
-                let method = parsed.get("method")
-                    .and_then(|v| v.as_str())
-                    .ok_or_else(|| "Missing 'method' field".to_string())?;
-
-                let params = parsed.get("params")
-                    .cloned()
-                    .unwrap_or(::server_less::serde_json::json!({}));
-
-                let id = parsed.get("id").cloned();
-
-                // Dispatch to the appropriate method (async)
-                let result = #dispatch_call_async;
-
-                // Format response
-                Self::__format_ws_response(result, id)
+                #handle_body_async
             }
 
-            /// Format a WebSocket JSON-RPC response
-            fn __format_ws_response(
-                result: ::std::result::Result<::server_less::serde_json::Value, String>,
-                id: Option<::server_less::serde_json::Value>,
-            ) -> ::std::result::Result<String, String> {
-                let response = match result {
-                    Ok(value) => {
-                        let mut resp = ::server_less::serde_json::json!({
-                            "result": value
-                        });
-                        if let Some(id) = id {
-                            resp.as_object_mut()
-                                .expect("BUG: json!({}) must produce an Object")
-                                .insert("id".to_string(), id);
-                        }
-                        resp
-                    }
-                    Err(err) => {
-                        let mut resp = ::server_less::serde_json::json!({
-                            "error": {
-                                "message": err
-                            }
-                        });
-                        if let Some(id) = id {
-                            resp.as_object_mut()
-                                .expect("BUG: json!({}) must produce an Object")
-                                .insert("id".to_string(), id);
-                        }
-                        resp
-                    }
-                };
-
-                ::server_less::serde_json::to_string(&response)
-                    .map_err(|e| format!("Serialization error: {}", e))
-            }
+            #response_helpers
 
             /// Dispatch a method call (sync version)
             #dispatch_sig_sync {
                 match method {
                     #(#dispatch_arms_sync)*
                     #(#mount_dispatch_sync)*
-                    _ => Err(format!("Unknown method: {}", method)),
+                    _ => Err(#unknown_method),
                 }
             }
 
@@ -751,7 +941,7 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
                 match method {
                     #(#dispatch_arms_async)*
                     #(#mount_dispatch_async)*
-                    _ => Err(format!("Unknown method: {}", method)),
+                    _ => Err(#unknown_method),
                 }
             }
 
@@ -760,11 +950,14 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
                 &self,
                 method: &str,
                 args: ::server_less::serde_json::Value,
-            ) -> ::std::result::Result<::server_less::serde_json::Value, String> {
+            ) -> ::std::result::Result<::server_less::serde_json::Value, ::server_less::ErrorResponse> {
                 match method {
                     #(#mount_trait_dispatch_sync)*
                     #(#mount_dispatch_sync)*
-                    _ => Err(format!("Unknown method: {}", method)),
+                    _ => Err(::server_less::ErrorResponse::new(
+                        ::server_less::ErrorCode::NotFound,
+                        format!("Unknown method: {}", method),
+                    )),
                 }
             }
 
@@ -773,11 +966,14 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
                 &self,
                 method: &str,
                 args: ::server_less::serde_json::Value,
-            ) -> ::std::result::Result<::server_less::serde_json::Value, String> {
+            ) -> ::std::result::Result<::server_less::serde_json::Value, ::server_less::ErrorResponse> {
                 match method {
                     #(#mount_trait_dispatch_async)*
                     #(#mount_dispatch_async)*
-                    _ => Err(format!("Unknown method: {}", method)),
+                    _ => Err(::server_less::ErrorResponse::new(
+                        ::server_less::ErrorCode::NotFound,
+                        format!("Unknown method: {}", method),
+                    )),
                 }
             }
 
@@ -786,9 +982,17 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
             where
                 Self: Clone + Send + Sync + 'static,
             {
-                let state = ::std::sync::Arc::new(self);
+                Self::ws_router_arc(::std::sync::Arc::new(self))
+            }
+
+            /// Like `ws_router()`, but sharing an existing `Arc` of the service.
+            pub fn ws_router_arc(self: ::std::sync::Arc<Self>) -> ::server_less::axum::Router
+            where
+                Self: Send + Sync + 'static,
+            {
+                let state = self;
                 ::server_less::axum::Router::new()
-                    .route(#path, ::server_less::axum::routing::get(#handler_name))
+                    .route(#path, ::server_less::axum::routing::get(#handler_name #handler_turbofish))
                     .with_state(state)
             }
 
@@ -821,17 +1025,11 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
                             extra: {
                                 let mut e = ::server_less::serde_json::Map::new();
                                 e.insert("x-websocket-protocol".to_string(), ::server_less::serde_json::json!({
-                                    "format": "JSON-RPC style",
+                                    "format": #protocol_format,
+                                    #subprotocols_extra
                                     "methods": methods,
-                                    "request_example": {
-                                        "method": "echo",
-                                        "params": {"message": "hello"},
-                                        "id": 1
-                                    },
-                                    "response_example": {
-                                        "result": "Echo: hello",
-                                        "id": 1
-                                    }
+                                    "request_example": #request_example,
+                                    "response_example": #response_example
                                 }));
                                 e
                             },
@@ -842,27 +1040,29 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
         }
 
         // WebSocket upgrade handler
-        async fn #handler_name(
+        async fn #handler_name #handler_impl_generics (
             ws: ::server_less::axum::extract::WebSocketUpgrade,
             state_extractor: ::server_less::axum::extract::State<::std::sync::Arc<#self_ty>>,
             __context_headers: ::server_less::axum::http::HeaderMap,
-        ) -> impl ::server_less::axum::response::IntoResponse {
+        ) -> impl ::server_less::axum::response::IntoResponse #handler_where_clause {
             let state = state_extractor.0;
 
             // Extract Context from HTTP upgrade headers
             #ctx_init_code
 
+            #select_subprotocol
+            #limit_upgrade
             ws.on_upgrade(move |socket| async move {
-                #connection_fn_name(socket, state, __ctx).await
+                #connection_fn_name #handler_turbofish (socket, state, __ctx).await
             })
         }
 
         // Handle individual WebSocket connection
-        async fn #connection_fn_name(
+        async fn #connection_fn_name #handler_impl_generics (
             socket: ::server_less::axum::extract::ws::WebSocket,
             state: ::std::sync::Arc<#self_ty>,
             __ctx: ::server_less::Context,
-        ) {
+        ) #handler_where_clause {
             use ::futures::stream::StreamExt;
             use ::futures::sink::SinkExt;
 
@@ -878,10 +1078,9 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
                         let response = #message_handler_call;
                         let reply = match response {
                             Ok(json) => json,
-                            Err(err) => ::server_less::serde_json::json!({
-                                "error": {"message": err}
-                            }).to_string(),
+                            Err(err) => #handler_error_reply,
                         };
+                        #skip_empty_reply
                         // Send response using the sender through WsSender
                         if __sender.send(reply).await.is_err() {
                             break;
@@ -889,13 +1088,216 @@ pub(crate) fn expand_ws(args: WsArgs, mut impl_block: ItemImpl) -> syn::Result<T
                     }
                     Ok(::server_less::axum::extract::ws::Message::Close(_)) => break,
                     Ok(_) => {} // Ignore binary, ping, pong
-                    Err(_) => break,
+                    Err(err) => {
+                        #read_error
+                        break;
+                    }
                 }
             }
         }
     })
 }
 
+/// Generate the bodies of `ws_handle_message{,_async}` plus their private
+/// helpers for the default `{"method", "params", "id"}` message shape.
+fn generate_plain_handling(
+    ctx_creation: &TokenStream2,
+    dispatch_call_sync: &TokenStream2,
+    dispatch_call_async: &TokenStream2,
+) -> (TokenStream2, TokenStream2, TokenStream2) {
+    let body = |dispatch_call: &TokenStream2| {
+        quote! {
+            #ctx_creation
+            // Parse the incoming message as JSON-RPC
+            let parsed: ::server_less::serde_json::Value = ::server_less::serde_json::from_str(message)
+                .map_err(|e| format!("Invalid JSON: {}", e))?;
+
+            let method = parsed.get("method")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| "Missing 'method' field".to_string())?;
+
+            let params = parsed.get("params")
+                .cloned()
+                .unwrap_or(::server_less::serde_json::json!({}));
+
+            let id = parsed.get("id").cloned();
+
+            // Dispatch to the appropriate method
+            let result = #dispatch_call;
+
+            // Format response
+            Self::__format_ws_response(result, id)
+        }
+    };
+
+    let helpers = quote! {
+        /// Format a WebSocket JSON-RPC response
+        fn __format_ws_response(
+            result: ::std::result::Result<::server_less::serde_json::Value, ::server_less::ErrorResponse>,
+            id: Option<::server_less::serde_json::Value>,
+        ) -> ::std::result::Result<String, String> {
+            let mut response = match result {
+                Ok(value) => ::server_less::serde_json::json!({
+                    "result": value
+                }),
+                Err(err) => err.envelope(),
+            };
+            if let Some(id) = id {
+                response.as_object_mut()
+                    .expect("BUG: json!({}) must produce an Object")
+                    .insert("id".to_string(), id);
+            }
+
+            ::server_less::serde_json::to_string(&response)
+                .map_err(|e| format!("Serialization error: {}", e))
+        }
+    };
+
+    (body(dispatch_call_sync), body(dispatch_call_async), helpers)
+}
+
+/// Generate the bodies of `ws_handle_message{,_async}` plus their private
+/// helpers for `#[ws(jsonrpc = true)]`: strict JSON-RPC 2.0 envelopes,
+/// numeric error codes, notifications and batch arrays.
+///
+/// A notification (or a batch made only of notifications) yields an empty
+/// string, which the connection loop does not send.
+fn generate_jsonrpc_handling(
+    uses_injected_params: bool,
+    ctx_creation: &TokenStream2,
+    dispatch_call_sync: &TokenStream2,
+    dispatch_call_async: &TokenStream2,
+) -> (TokenStream2, TokenStream2, TokenStream2) {
+    let (injected_decls, injected_args) = if uses_injected_params {
+        (
+            quote! { __ctx: ::server_less::Context, __sender: ::server_less::WsSender, },
+            quote! { __ctx.clone(), __sender.clone(), },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    let body = |call: TokenStream2| {
+        quote! {
+            #ctx_creation
+            let parsed: ::server_less::serde_json::Value = match ::server_less::serde_json::from_str(message) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    return Self::__ws_jsonrpc_reply(Some(Self::__ws_jsonrpc_failure(
+                        -32700,
+                        &format!("Parse error: {}", e),
+                        None,
+                    )));
+                }
+            };
+
+            let reply = match &parsed {
+                ::server_less::serde_json::Value::Array(batch) if batch.is_empty() => Some(
+                    Self::__ws_jsonrpc_failure(-32600, "Invalid Request: empty batch", None),
+                ),
+                ::server_less::serde_json::Value::Array(batch) => {
+                    let mut responses = Vec::new();
+                    for request in batch {
+                        if let Some(response) = #call {
+                            responses.push(response);
+                        }
+                    }
+                    (!responses.is_empty()).then_some(::server_less::serde_json::Value::Array(responses))
+                }
+                request => #call,
+            };
+
+            Self::__ws_jsonrpc_reply(reply)
+        }
+    };
+    let body_sync = body(quote! { self.__ws_jsonrpc_call(#injected_args request) });
+    let body_async = body(quote! { self.__ws_jsonrpc_call_async(#injected_args request).await });
+
+    let call_body = |dispatch_call: &TokenStream2| {
+        quote! {
+            let id = request.get("id").cloned();
+
+            let version = request.get("jsonrpc").and_then(|v| v.as_str());
+            let method = match (version, request.get("method").and_then(|v| v.as_str())) {
+                (Some("2.0"), Some(method)) => method,
+                _ => return Some(Self::__ws_jsonrpc_failure(-32600, "Invalid Request", id)),
+            };
+
+            let params = request.get("params")
+                .cloned()
+                .unwrap_or(::server_less::serde_json::json!({}));
+
+            let result = #dispatch_call;
+
+            // Notifications (no `id`) never get a response
+            let id = id?;
+            Some(match result {
+                Ok(value) => ::server_less::serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "result": value,
+                    "id": id
+                }),
+                Err(err) => ::server_less::serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "error": err.jsonrpc_error(),
+                    "id": id
+                }),
+            })
+        }
+    };
+    let call_sync = call_body(dispatch_call_sync);
+    let call_async = call_body(dispatch_call_async);
+
+    let helpers = quote! {
+        /// Handle one JSON-RPC 2.0 request object; `None` for notifications.
+        fn __ws_jsonrpc_call(
+            &self,
+            #injected_decls
+            request: &::server_less::serde_json::Value,
+        ) -> Option<::server_less::serde_json::Value> {
+            #call_sync
+        }
+
+        /// Handle one JSON-RPC 2.0 request object (async); `None` for notifications.
+        async fn __ws_jsonrpc_call_async(
+            &self,
+            #injected_decls
+            request: &::server_less::serde_json::Value,
+        ) -> Option<::server_less::serde_json::Value> {
+            #call_async
+        }
+
+        /// Build a JSON-RPC 2.0 error response (`id` is null when unknown).
+        fn __ws_jsonrpc_failure(
+            code: i32,
+            message: &str,
+            id: Option<::server_less::serde_json::Value>,
+        ) -> ::server_less::serde_json::Value {
+            ::server_less::serde_json::json!({
+                "jsonrpc": "2.0",
+                "error": {
+                    "code": code,
+                    "message": message
+                },
+                "id": id
+            })
+        }
+
+        /// Serialize a reply; an empty string means nothing should be sent.
+        fn __ws_jsonrpc_reply(
+            reply: Option<::server_less::serde_json::Value>,
+        ) -> ::std::result::Result<String, String> {
+            match reply {
+                Some(reply) => ::server_less::serde_json::to_string(&reply)
+                    .map_err(|e| format!("Serialization error: {}", e)),
+                None => Ok(String::new()),
+            }
+        }
+    };
+
+    (body_sync, body_async, helpers)
+}
+
 /// Generate a dispatch match arm for a method (sync version)
 fn generate_dispatch_arm_sync(
     method: &MethodInfo,
@@ -928,12 +1330,23 @@ fn generate_dispatch_arm_with_injected_params(
 ) -> syn::Result<TokenStream2> {
     let method_name_str = method.wire_name_or(|n| n);
 
+    if let Some(message) = http_only_error(method) {
+        return Ok(quote! {
+            #method_name_str => {
+                return Err(::server_less::ErrorResponse::new(
+                    ::server_less::ErrorCode::NotImplemented,
+                    #message,
+                ));
+            }
+        });
+    }
+
     // Partition Context, WsSender, and regular parameters (Context detection is per-method)
-    let (context_param, sender_param, regular_params) =
+    let (_, _, regular_params) =
         partition_ws_params(&method.params, has_qualified_sender)?;
 
     // If no injected params, use default RPC dispatch
-    if context_param.is_none() && sender_param.is_none() {
+    if regular_params.len() == method.params.len() {
         return Ok(server_less_rpc::generate_dispatch_arm(
             method,
             None,
@@ -951,7 +1364,10 @@ fn generate_dispatch_arm_with_injected_params(
         return Ok(quote! {
             #method_name_str => {
                 #(#param_extractions)*
-                return Err("Async methods and streaming methods not supported in sync context".to_string());
+                return Err(::server_less::ErrorResponse::new(
+                    ::server_less::ErrorCode::NotImplemented,
+                    "Async methods and streaming methods not supported in sync context",
+                ));
             }
         });
     }
@@ -966,6 +1382,8 @@ fn generate_dispatch_arm_with_injected_params(
             arg_exprs.push(quote! { __ctx.clone() });
         } else if should_inject_ws_sender(&param.ty, has_qualified_sender) {
             arg_exprs.push(quote! { __sender.clone() });
+        } else if is_cancellation_token(&param.ty) {
+            arg_exprs.push(never_cancelled());
         } else {
             let name = &param.name;
             arg_exprs.push(quote! { #name });
@@ -1052,15 +1470,18 @@ fn generate_ws_slug_mount_dispatch(method: &MethodInfo, is_async: bool) -> syn::
     let slug_extractions: Vec<_> = method
         .params
         .iter()
-        .map(server_less_rpc::generate_param_extraction)
+        .enumerate()
+        .map(|(i, p)| server_less_rpc::generate_param_extraction(p, i))
         .collect();
     let slug_names: Vec<_> = method.params.iter().map(|p| &p.name).collect();
+    let slug_count = method.params.len();
 
     Ok(if is_async {
         quote! {
             __method if __method.starts_with(#mount_prefix) => {
                 let __stripped = &__method[#mount_prefix.len()..];
                 #(#slug_extractions)*
+                let args = ::server_less::rpc_skip_positional(args, #slug_count);
                 let __delegate = self.#method_name(#(#slug_names),*);
                 <#inner_ty as ::server_less::WsMount>::ws_mount_dispatch_async(__delegate, __stripped, args).await
             }
@@ -1070,9 +1491,26 @@ fn generate_ws_slug_mount_dispatch(method: &MethodInfo, is_async: bool) -> syn::
             __method if __method.starts_with(#mount_prefix) => {
                 let __stripped = &__method[#mount_prefix.len()..];
                 #(#slug_extractions)*
+                let args = ::server_less::rpc_skip_positional(args, #slug_count);
                 let __delegate = self.#method_name(#(#slug_names),*);
                 <#inner_ty as ::server_less::WsMount>::ws_mount_dispatch(__delegate, __stripped, args)
             }
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::parse_byte_size;
+
+    #[test]
+    fn test_parse_byte_size() {
+        assert_eq!(parse_byte_size("65536"), Some(65536));
+        assert_eq!(parse_byte_size("512b"), Some(512));
+        assert_eq!(parse_byte_size("64kb"), Some(64 * 1024));
+        assert_eq!(parse_byte_size("2 MB"), Some(2 * 1024 * 1024));
+        assert_eq!(parse_byte_size("0"), None);
+        assert_eq!(parse_byte_size("kb"), None);
+        assert_eq!(parse_byte_size("64kib"), None);
+    }
+}
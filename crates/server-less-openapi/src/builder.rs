@@ -24,12 +24,16 @@ use serde_json::{Map, Value};
 ///
 /// - **Paths**: Last write wins (later `merge()` calls override earlier ones for same path+method).
 /// - **Schemas**: Identical schemas are deduplicated; different schemas with same name cause an error.
+/// - **Webhooks**: Last write wins, like paths.
+///
+/// The spec declares OpenAPI 3.0.0, or 3.1.0 once it has webhooks (a 3.1 feature).
 #[derive(Debug, Clone)]
 pub struct OpenApiBuilder {
     title: Option<String>,
     version: Option<String>,
     description: Option<String>,
     paths: Map<String, Value>,
+    webhooks: Map<String, Value>,
     schemas: Map<String, Value>,
 }
 
@@ -47,6 +51,7 @@ impl OpenApiBuilder {
             version: None,
             description: None,
             paths: Map::new(),
+            webhooks: Map::new(),
             schemas: Map::new(),
         }
     }
@@ -71,26 +76,28 @@ impl OpenApiBuilder {
 
     /// Merge an OpenAPI spec (as JSON value).
     ///
-    /// This extracts paths and schemas from the spec and merges them.
+    /// This extracts paths, webhooks and schemas from the spec and merges them.
     ///
     /// # Conflict Resolution
     ///
-    /// - Paths: Last write wins
+    /// - Paths and webhooks: Last write wins
     /// - Schemas: Identical schemas dedupe, different schemas error
     pub fn merge(mut self, spec: Value) -> Result<Self> {
-        // Extract and merge paths
-        if let Some(paths) = spec.get("paths").and_then(|p| p.as_object()) {
-            for (path, methods) in paths {
+        // Extract and merge paths and webhooks (both map a key to operations by method)
+        for (section, target) in [("paths", &mut self.paths), ("webhooks", &mut self.webhooks)] {
+            let Some(items) = spec.get(section).and_then(|p| p.as_object()) else {
+                continue;
+            };
+            for (key, methods) in items {
                 if let Some(methods_obj) = methods.as_object() {
-                    let path_entry = self
-                        .paths
-                        .entry(path.clone())
+                    let entry = target
+                        .entry(key.clone())
                         .or_insert_with(|| Value::Object(Map::new()));
 
-                    if let Some(path_obj) = path_entry.as_object_mut() {
+                    if let Some(entry_obj) = entry.as_object_mut() {
                         for (method, operation) in methods_obj {
-                            // Last write wins for paths
-                            path_obj.insert(method.clone(), operation.clone());
+                            // Last write wins
+                            entry_obj.insert(method.clone(), operation.clone());
                         }
                     }
                 }
@@ -118,19 +125,13 @@ impl OpenApiBuilder {
 
     /// Merge typed paths.
     pub fn merge_paths(mut self, paths: Vec<OpenApiPath>) -> Self {
-        for path_def in paths {
-            let path_entry = self
-                .paths
-                .entry(path_def.path.clone())
-                .or_insert_with(|| Value::Object(Map::new()));
-
-            if let Some(path_obj) = path_entry.as_object_mut() {
-                // Convert operation to JSON
-                let operation = serde_json::to_value(&path_def.operation)
-                    .unwrap_or_else(|_| Value::Object(Map::new()));
-                path_obj.insert(path_def.method.to_lowercase(), operation);
-            }
-        }
+        insert_operations(&mut self.paths, paths);
+        self
+    }
+
+    /// Merge typed webhooks, each keyed by its webhook name (held in `path`).
+    pub fn merge_webhooks(mut self, webhooks: Vec<OpenApiPath>) -> Self {
+        insert_operations(&mut self.webhooks, webhooks);
         self
     }
 
@@ -160,8 +161,16 @@ impl OpenApiBuilder {
     pub fn build(self) -> Value {
         let mut spec = Map::new();
 
-        // OpenAPI version
-        spec.insert("openapi".to_string(), Value::String("3.0.0".to_string()));
+        // OpenAPI version: webhooks need 3.1
+        let openapi_version = if self.webhooks.is_empty() {
+            "3.0.0"
+        } else {
+            "3.1.0"
+        };
+        spec.insert(
+            "openapi".to_string(),
+            Value::String(openapi_version.to_string()),
+        );
 
         // Info object
         let mut info = Map::new();
@@ -183,6 +192,11 @@ impl OpenApiBuilder {
             spec.insert("paths".to_string(), Value::Object(self.paths));
         }
 
+        // Webhooks
+        if !self.webhooks.is_empty() {
+            spec.insert("webhooks".to_string(), Value::Object(self.webhooks));
+        }
+
         // Components/schemas
         if !self.schemas.is_empty() {
             let mut components = Map::new();
@@ -194,6 +208,22 @@ impl OpenApiBuilder {
     }
 }
 
+/// Insert typed operations into a paths-like map, keyed by `path` then method.
+fn insert_operations(target: &mut Map<String, Value>, paths: Vec<OpenApiPath>) {
+    for path_def in paths {
+        let path_entry = target
+            .entry(path_def.path.clone())
+            .or_insert_with(|| Value::Object(Map::new()));
+
+        if let Some(path_obj) = path_entry.as_object_mut() {
+            // Convert operation to JSON
+            let operation = serde_json::to_value(&path_def.operation)
+                .unwrap_or_else(|_| Value::Object(Map::new()));
+            path_obj.insert(path_def.method.to_lowercase(), operation);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -341,6 +371,27 @@ mod tests {
         assert_eq!(spec["paths"]["/users"]["post"]["summary"], "Create user");
     }
 
+    #[test]
+    fn test_webhooks_bump_openapi_version() {
+        use crate::types::{OpenApiOperation, OpenApiPath};
+
+        let webhooks = vec![
+            OpenApiPath::new("orderShipped", "post")
+                .with_operation(OpenApiOperation::new("Order shipped")),
+        ];
+        let spec = OpenApiBuilder::new().merge_webhooks(webhooks).build();
+
+        assert_eq!(spec["openapi"], "3.1.0");
+        assert_eq!(
+            spec["webhooks"]["orderShipped"]["post"]["summary"],
+            "Order shipped"
+        );
+
+        // Webhooks also survive a JSON merge
+        let merged = OpenApiBuilder::new().merge(spec).unwrap().build();
+        assert!(merged["webhooks"]["orderShipped"]["post"].is_object());
+    }
+
     #[test]
     fn test_merge_typed_schemas() {
         use crate::types::OpenApiSchema;
@@ -42,6 +42,50 @@ pub struct MethodInfo {
     pub wire_name: Option<String>,
     /// `#[cfg(...)]` attributes on this method, to be propagated to generated dispatch items.
     pub cfg_attrs: Vec<syn::Attribute>,
+    /// Standard `#[deprecated]` attribute on this method, if any.
+    pub deprecation: Option<Deprecation>,
+    /// Example exchanges from `#[example(...)]`, in declaration order.
+    pub examples: Vec<MethodExample>,
+}
+
+/// One `#[example(request = "...", response = "...")]` on a method.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MethodExample {
+    /// JSON text of an example request's parameters
+    pub request: Option<String>,
+    /// JSON text of the matching response
+    pub response: Option<String>,
+}
+
+/// A standard Rust `#[deprecated]` attribute on a method.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Deprecation {
+    /// `note = "..."` (or the string in `#[deprecated = "..."]`)
+    pub note: Option<String>,
+    /// `since = "..."`
+    pub since: Option<String>,
+}
+
+impl Deprecation {
+    /// Human-readable notice for spec descriptions, e.g.
+    /// `"Deprecated since 0.8.0: use new_thing"`. `None` when the attribute
+    /// carries neither a note nor a version.
+    pub fn notice(&self) -> Option<String> {
+        match (&self.since, &self.note) {
+            (Some(since), Some(note)) => Some(format!("Deprecated since {since}: {note}")),
+            (None, Some(note)) => Some(format!("Deprecated: {note}")),
+            (Some(since), None) => Some(format!("Deprecated since {since}.")),
+            (None, None) => None,
+        }
+    }
+
+    /// Append [`notice`](Self::notice) to an existing description as its own paragraph.
+    pub fn annotate(&self, description: Option<String>) -> Option<String> {
+        match (description, self.notice()) {
+            (Some(desc), Some(notice)) => Some(format!("{desc}\n\n{notice}")),
+            (desc, notice) => desc.or(notice),
+        }
+    }
 }
 
 /// Registry of declared method groups from `#[server(groups(...))]`.
@@ -84,6 +128,20 @@ pub struct ParamInfo {
     pub help_text: Option<String>,
     /// Whether this is a positional argument (from #[param(positional)] or is_id heuristic)
     pub is_positional: bool,
+    /// Inclusive lower bound as a numeric literal (from #[param(min = ...)])
+    pub min: Option<String>,
+    /// Inclusive upper bound as a numeric literal (from #[param(max = ...)])
+    pub max: Option<String>,
+    /// Regex the value must match (from #[param(pattern = "...")])
+    pub pattern: Option<String>,
+    /// Module whose `parse(&str) -> Result<T, E>` reads the raw value
+    /// (from #[param(with = "...")])
+    pub with: Option<syn::Path>,
+    /// Whether the type is used as an axum extractor as-is (from #[param(extractor)])
+    pub is_extractor: bool,
+    /// Index of the `{...}` segment of the impl's HTTP prefix this parameter binds
+    /// (set by the HTTP macros, never by parsing)
+    pub prefix_position: Option<usize>,
 }
 
 impl MethodInfo {
@@ -257,19 +315,51 @@ impl<'ast> syn::visit::Visit<'ast> for AwaitFinder {
 impl MethodInfo {
     /// Parse a method from an ImplItemFn
     ///
-    /// Returns None for associated functions without `&self` (constructors, etc.)
+    /// Returns None for associated functions without `&self` (constructors, etc.).
+    /// An associated function that carries a server-less method attribute is an
+    /// error instead, since the attribute shows it was meant to be exposed.
     pub fn parse(method: &ImplItemFn) -> syn::Result<Option<Self>> {
         let name = method.sig.ident.clone();
         let is_async = method.sig.asyncness.is_some();
 
         // Skip associated functions without self receiver (constructors, etc.)
-        let has_receiver = method
-            .sig
-            .inputs
-            .iter()
-            .any(|arg| matches!(arg, FnArg::Receiver(_)));
-        if !has_receiver {
+        let Some(receiver) = method.sig.receiver() else {
+            if let Some(attr) = exposure_attr(&method.attrs)
+                && !has_skip_marker(&method.attrs)
+            {
+                return Err(syn::Error::new_spanned(
+                    &method.sig.ident,
+                    format!(
+                        "`{name}` has no `self` receiver, so server-less cannot expose it, \
+                         but it is marked `#[{attr}]`\n\n\
+                         Associated functions are never projected: handlers are called on \
+                         the shared service instance, and functions such as `fn new() -> Self` \
+                         are skipped as constructors.\n\n\
+                         Hint: to expose it, take `&self` even if the body does not use it, \
+                         e.g. `fn {name}(&self, ...)`; otherwise remove the `#[{attr}]` attribute"
+                    ),
+                ));
+            }
             return Ok(None);
+        };
+
+        // Handlers share the service behind an `Arc`, so a `&mut self` method can
+        // never be projected. Skipped methods are plain Rust and may keep it.
+        if is_mut_ref_receiver(receiver) {
+            if has_skip_marker(&method.attrs) {
+                return Ok(None);
+            }
+            return Err(syn::Error::new_spanned(
+                receiver,
+                format!(
+                    "`{name}` takes `&mut self`, but server-less shares the service \
+                     across requests and can only call `&self` methods\n\n\
+                     Keep mutable state behind interior mutability instead, e.g. \
+                     `Arc<Mutex<T>>` or `Arc<RwLock<T>>` fields, and take `&self`.\n\n\
+                     Hint: if this is an internal helper, exclude it with `#[route(skip)]` \
+                     (or `#[server(skip)]` for every protocol)"
+                ),
+            ));
         }
 
         // Await-without-async: a sync method whose body really awaits cannot be
@@ -312,6 +402,9 @@ impl MethodInfo {
             .cloned()
             .collect();
 
+        let deprecation = extract_deprecation(&method.attrs)?;
+        let examples = parse_example_attrs(&method.attrs)?;
+
         Ok(Some(Self {
             method: method.clone(),
             name,
@@ -322,10 +415,79 @@ impl MethodInfo {
             group,
             wire_name,
             cfg_attrs,
+            deprecation,
+            examples,
         }))
     }
 }
 
+/// Method-level attributes that mark a function as meant to be exposed.
+const EXPOSURE_ATTRS: &[&str] = &["route", "response", "server", "cli"];
+
+/// Name of the first server-less method attribute on a function, if any.
+fn exposure_attr(attrs: &[syn::Attribute]) -> Option<&'static str> {
+    EXPOSURE_ATTRS
+        .iter()
+        .copied()
+        .find(|name| attrs.iter().any(|attr| attr.path().is_ident(name)))
+}
+
+/// Returns `true` for `&mut self` / `self: &mut Self` receivers.
+fn is_mut_ref_receiver(receiver: &syn::Receiver) -> bool {
+    matches!(receiver.ty.as_ref(), Type::Reference(TypeReference { mutability: Some(_), .. }))
+}
+
+/// Attributes whose `skip` keeps a method out of projection entirely.
+const SKIP_ATTRS: &[&str] = &["route", "server"];
+
+/// Returns `true` for `#[route(skip)]` or `#[server(skip)]`.
+///
+/// Other attributes may carry a `skip` of their own (`#[serde(skip)]`,
+/// `#[cli(skip)]`), which says nothing about the remaining protocols.
+fn has_skip_marker(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        if !SKIP_ATTRS.iter().any(|name| attr.path().is_ident(name)) {
+            return false;
+        }
+        let Meta::List(list) = &attr.meta else {
+            return false;
+        };
+        list.tokens.clone().into_iter().any(
+            |tt| matches!(tt, proc_macro2::TokenTree::Ident(ref ident) if ident == "skip"),
+        )
+    })
+}
+
+/// Parse a standard `#[deprecated]`, `#[deprecated = "note"]` or
+/// `#[deprecated(since = "...", note = "...")]` attribute.
+pub fn extract_deprecation(attrs: &[syn::Attribute]) -> syn::Result<Option<Deprecation>> {
+    let Some(attr) = attrs.iter().find(|a| a.path().is_ident("deprecated")) else {
+        return Ok(None);
+    };
+    let mut deprecation = Deprecation::default();
+    match &attr.meta {
+        Meta::Path(_) => {}
+        Meta::NameValue(nv) => {
+            if let syn::Expr::Lit(syn::ExprLit { lit: Lit::Str(s), .. }) = &nv.value {
+                deprecation.note = Some(s.value());
+            }
+        }
+        Meta::List(_) => {
+            attr.parse_nested_meta(|meta| {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                if meta.path.is_ident("note") {
+                    deprecation.note = Some(value.value());
+                } else if meta.path.is_ident("since") {
+                    deprecation.since = Some(value.value());
+                }
+                // Unknown keys are rustc's to reject.
+                Ok(())
+            })?;
+        }
+    }
+    Ok(Some(deprecation))
+}
+
 /// Extract doc comments from attributes
 pub fn extract_docs(attrs: &[syn::Attribute]) -> Option<String> {
     let docs: Vec<String> = attrs
@@ -541,6 +703,36 @@ pub struct ParsedParamAttrs {
     /// `#[serde(default)]` in the child type for defaults.  Only meaningful with
     /// `nested`.
     pub nested_serde: bool,
+    /// Inclusive lower bound (from `#[param(min = 1)]`), kept as the literal's text.
+    /// Emitted as OpenAPI `minimum` and checked by HTTP handlers.
+    pub min: Option<String>,
+    /// Inclusive upper bound (from `#[param(max = 100)]`), kept as the literal's text.
+    /// Emitted as OpenAPI `maximum` and checked by HTTP handlers.
+    pub max: Option<String>,
+    /// Regex the value must match (from `#[param(pattern = "^[a-z]+$")]`).
+    /// Emitted as OpenAPI `pattern` and checked by HTTP handlers.
+    pub pattern: Option<String>,
+    /// Module providing `fn parse(&str) -> Result<T, E>` (from
+    /// `#[param(with = "my_module")]`), used by HTTP handlers in place of
+    /// `FromStr` / serde to read the raw string value.
+    pub with: Option<syn::Path>,
+    /// Use the parameter's type as an axum extractor (from `#[param(extractor)]`)
+    /// instead of binding it from the path, query, body or headers.
+    pub extractor: bool,
+}
+
+/// Parse a possibly negative integer or float literal, returning its text.
+fn parse_numeric_bound(input: syn::parse::ParseStream) -> syn::Result<String> {
+    let negative = input.parse::<Option<syn::Token![-]>>()?.is_some();
+    let lookahead = input.lookahead1();
+    let digits = if lookahead.peek(syn::LitInt) {
+        input.parse::<syn::LitInt>()?.base10_digits().to_string()
+    } else if lookahead.peek(syn::LitFloat) {
+        input.parse::<syn::LitFloat>()?.base10_digits().to_string()
+    } else {
+        return Err(lookahead.error());
+    };
+    Ok(if negative { format!("-{digits}") } else { digits })
 }
 
 /// Compute Levenshtein edit distance between two strings.
@@ -594,6 +786,11 @@ pub fn parse_param_attrs(attrs: &[syn::Attribute]) -> syn::Result<ParsedParamAtt
     let mut nested = false;
     let mut env_prefix = None;
     let mut nested_serde = false;
+    let mut min = None;
+    let mut max = None;
+    let mut pattern = None;
+    let mut with = None;
+    let mut extractor = false;
 
     for attr in attrs {
         if !attr.path().is_ident("param") {
@@ -684,10 +881,36 @@ pub fn parse_param_attrs(attrs: &[syn::Attribute]) -> syn::Result<ParsedParamAtt
                 let value: syn::LitStr = meta.value()?.parse()?;
                 env_prefix = Some(value.value());
                 Ok(())
+            }
+            // #[param(min = 1)] / #[param(max = 100)]
+            else if meta.path.is_ident("min") {
+                min = Some(parse_numeric_bound(meta.value()?)?);
+                Ok(())
+            } else if meta.path.is_ident("max") {
+                max = Some(parse_numeric_bound(meta.value()?)?);
+                Ok(())
+            }
+            // #[param(pattern = "^[a-z]+$")]
+            else if meta.path.is_ident("pattern") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                pattern = Some(value.value());
+                Ok(())
+            }
+            // #[param(with = "my_module")]
+            else if meta.path.is_ident("with") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                with = Some(value.parse::<syn::Path>()?);
+                Ok(())
+            }
+            // #[param(extractor)]
+            else if meta.path.is_ident("extractor") {
+                extractor = true;
+                Ok(())
             } else {
                 const VALID: &[&str] = &[
                     "name", "default", "query", "path", "body", "header", "short", "help",
-                    "positional", "env", "file_key", "nested", "serde", "env_prefix",
+                    "positional", "env", "file_key", "nested", "serde", "env_prefix", "min",
+                    "max", "pattern", "with", "extractor",
                 ];
                 let unknown = meta
                     .path
@@ -700,7 +923,7 @@ pub fn parse_param_attrs(attrs: &[syn::Attribute]) -> syn::Result<ParsedParamAtt
                 Err(meta.error(format!(
                     "unknown attribute `{unknown}`{suggestion}\n\
                      \n\
-                     Valid attributes: name, default, query, path, body, header, short, help, positional, env, file_key, nested, serde, env_prefix\n\
+                     Valid attributes: name, default, query, path, body, header, short, help, positional, env, file_key, nested, serde, env_prefix, min, max, pattern, with, extractor\n\
                      \n\
                      Examples:\n\
                      - #[param(name = \"q\")]\n\
@@ -714,7 +937,11 @@ pub fn parse_param_attrs(attrs: &[syn::Attribute]) -> syn::Result<ParsedParamAtt
                      - #[param(file_key = \"database.host\")]\n\
                      - #[param(nested)]\n\
                      - #[param(nested, serde)]\n\
-                     - #[param(nested, env_prefix = \"SEARCH\")]"
+                     - #[param(nested, env_prefix = \"SEARCH\")]\n\
+                     - #[param(min = 1, max = 100)]\n\
+                     - #[param(pattern = \"^[a-z]+$\")]\n\
+                     - #[param(with = \"comma_list\")]\n\
+                     - #[param(extractor)]"
                 )))
             }
         })?;
@@ -726,6 +953,43 @@ pub fn parse_param_attrs(attrs: &[syn::Attribute]) -> syn::Result<ParsedParamAtt
         nested = true;
     }
 
+    if let (Some(lo), Some(hi)) = (&min, &max)
+        && let (Ok(lo_val), Ok(hi_val)) = (lo.parse::<f64>(), hi.parse::<f64>())
+        && lo_val > hi_val
+    {
+        let attr = attrs
+            .iter()
+            .find(|a| a.path().is_ident("param"))
+            .expect("BUG: bounds come from a #[param] attribute");
+        return Err(syn::Error::new_spanned(
+            attr,
+            format!("#[param] min ({lo}) is greater than max ({hi})"),
+        ));
+    }
+
+    if extractor
+        && (wire_name.is_some()
+            || location.is_some()
+            || default_value.is_some()
+            || min.is_some()
+            || max.is_some()
+            || pattern.is_some()
+            || with.is_some())
+    {
+        let attr = attrs
+            .iter()
+            .find(|a| a.path().is_ident("param"))
+            .expect("BUG: extractor comes from a #[param] attribute");
+        return Err(syn::Error::new_spanned(
+            attr,
+            "#[param(extractor)] cannot be combined with name, query, path, body, header, \
+             default, min, max, pattern or with\n\
+             \n\
+             Hint: the extractor type reads the request itself; put that logic in its \
+             FromRequestParts / FromRequest impl",
+        ));
+    }
+
     Ok(ParsedParamAttrs {
         wire_name,
         location,
@@ -738,9 +1002,73 @@ pub fn parse_param_attrs(attrs: &[syn::Attribute]) -> syn::Result<ParsedParamAtt
         nested,
         env_prefix,
         nested_serde,
+        min,
+        max,
+        pattern,
+        with,
+        extractor,
     })
 }
 
+/// The JSON text of an `#[example]` payload, checked to parse.
+fn example_payload(lit: &syn::LitStr, key: &str) -> syn::Result<String> {
+    let value = lit.value();
+    serde_json::from_str::<serde_json::Value>(&value).map_err(|err| {
+        syn::Error::new(lit.span(), format!("`{key}` is not valid JSON: {err}"))
+    })?;
+    Ok(value)
+}
+
+/// Parse `#[example(request = "...", response = "...")]` attributes on a method.
+///
+/// The attribute is repeatable; each one is an example exchange and needs at least
+/// one of `request` and `response`. Payloads are JSON text, kept as written; text
+/// that does not parse as JSON is a compile error.
+pub fn parse_example_attrs(attrs: &[syn::Attribute]) -> syn::Result<Vec<MethodExample>> {
+    let mut examples = Vec::new();
+    for attr in attrs {
+        if !attr.path().is_ident("example") {
+            continue;
+        }
+
+        let mut example = MethodExample::default();
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("request") {
+                example.request = Some(example_payload(&meta.value()?.parse()?, "request")?);
+                Ok(())
+            } else if meta.path.is_ident("response") {
+                example.response = Some(example_payload(&meta.value()?.parse()?, "response")?);
+                Ok(())
+            } else {
+                let unknown = meta
+                    .path
+                    .get_ident()
+                    .map(|i| i.to_string())
+                    .unwrap_or_default();
+                let suggestion = did_you_mean(&unknown, &["request", "response"])
+                    .map(|s| format!(" — did you mean `{s}`?"))
+                    .unwrap_or_default();
+                Err(meta.error(format!(
+                    "unknown attribute `{unknown}`{suggestion}\n\
+                     \n\
+                     Valid attributes: request, response\n\
+                     \n\
+                     Example: #[example(request = r#\"{{\"id\": 1}}\"#, response = r#\"{{\"name\": \"Ada\"}}\"#)]"
+                )))
+            }
+        })?;
+
+        if example.request.is_none() && example.response.is_none() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "#[example] needs a `request` or `response` payload",
+            ));
+        }
+        examples.push(example);
+    }
+    Ok(examples)
+}
+
 /// Parse function parameters (excluding self)
 pub fn parse_params(
     inputs: &syn::punctuated::Punctuated<FnArg, syn::Token![,]>,
@@ -792,6 +1120,12 @@ pub fn parse_params(
                     default_value: parsed.default_value,
                     short_flag: parsed.short_flag,
                     help_text: parsed.help_text,
+                    min: parsed.min,
+                    max: parsed.max,
+                    pattern: parsed.pattern,
+                    with: parsed.with,
+                    is_extractor: parsed.extractor,
+                    prefix_position: None,
                 });
             }
         }
@@ -956,15 +1290,25 @@ pub fn parse_return_type(output: &ReturnType) -> ReturnInfo {
     }
 }
 
-/// Check if a type is `bool`
+/// Check if a type is `bool`, spelled bare or as `std::primitive::bool` / `core::primitive::bool`
 pub fn is_bool_type(ty: &Type) -> bool {
-    if let Type::Path(type_path) = ty
-        && let Some(segment) = type_path.path.segments.last()
-        && type_path.path.segments.len() == 1
-    {
-        return segment.ident == "bool";
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    if type_path.qself.is_some() {
+        return false;
     }
-    false
+    let path = type_path
+        .path
+        .segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::");
+    matches!(
+        path.as_str(),
+        "bool" | "std::primitive::bool" | "core::primitive::bool"
+    )
 }
 
 /// Check if a type is `Vec<T>` and extract T
@@ -1129,12 +1473,54 @@ pub fn is_id_param(name: &Ident) -> bool {
     name_str == "id" || name_str.ends_with("_id")
 }
 
+/// Which methods of an impl block a protocol projects.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MethodVisibility {
+    /// Every method with a `self` receiver, regardless of visibility (default).
+    #[default]
+    All,
+    /// Only `pub` and restricted-`pub` (`pub(crate)`, `pub(super)`, ...) methods.
+    /// Private methods are ignored as if they were skipped.
+    Pub,
+}
+
+impl MethodVisibility {
+    /// Parse the value of a `visibility = "..."` macro argument (`"pub"` or `"all"`).
+    pub fn parse_lit(lit: &syn::LitStr) -> syn::Result<Self> {
+        match lit.value().as_str() {
+            "all" => Ok(Self::All),
+            "pub" => Ok(Self::Pub),
+            other => {
+                let suggestion = did_you_mean(other, &["all", "pub"])
+                    .map(|s| format!(" — did you mean `{s}`?"))
+                    .unwrap_or_default();
+                Err(syn::Error::new(
+                    lit.span(),
+                    format!(
+                        "unknown visibility `{other}`{suggestion}\n\
+                         Valid values: \"pub\" (only pub / pub(crate) methods), \"all\" (default)"
+                    ),
+                ))
+            }
+        }
+    }
+}
+
 /// Extract all methods from an impl block
 ///
 /// Skips:
 /// - Private methods (starting with `_`)
 /// - Associated functions without `&self` receiver (constructors, etc.)
 pub fn extract_methods(impl_block: &ItemImpl) -> syn::Result<Vec<MethodInfo>> {
+    extract_methods_with_visibility(impl_block, MethodVisibility::All)
+}
+
+/// Like [`extract_methods`], additionally skipping methods whose declared
+/// visibility is not covered by `visibility`.
+pub fn extract_methods_with_visibility(
+    impl_block: &ItemImpl,
+    visibility: MethodVisibility,
+) -> syn::Result<Vec<MethodInfo>> {
     let mut methods = Vec::new();
 
     for item in &impl_block.items {
@@ -1143,6 +1529,11 @@ pub fn extract_methods(impl_block: &ItemImpl) -> syn::Result<Vec<MethodInfo>> {
             if method.sig.ident.to_string().starts_with('_') {
                 continue;
             }
+            if visibility == MethodVisibility::Pub
+                && matches!(method.vis, syn::Visibility::Inherited)
+            {
+                continue;
+            }
             // Parse method - returns None for associated functions without self
             if let Some(info) = MethodInfo::parse(method)? {
                 methods.push(info);
@@ -1390,6 +1781,31 @@ mod tests {
         assert!(!is_unit_type(&ty));
     }
 
+    // ── is_bool_type ────────────────────────────────────────────────
+
+    #[test]
+    fn is_bool_type_accepts_primitive_paths() {
+        for ty in [
+            syn::parse_quote! { bool },
+            syn::parse_quote! { std::primitive::bool },
+            syn::parse_quote! { ::core::primitive::bool },
+        ] {
+            assert!(is_bool_type(&ty), "{}", quote!(#ty));
+        }
+    }
+
+    #[test]
+    fn is_bool_type_rejects_other_types() {
+        for ty in [
+            syn::parse_quote! { Option<bool> },
+            syn::parse_quote! { my::bool },
+            syn::parse_quote! { &bool },
+        ] {
+            let ty: Type = ty;
+            assert!(!is_bool_type(&ty), "{}", quote!(#ty));
+        }
+    }
+
     // ── is_id_param ─────────────────────────────────────────────────
 
     #[test]
@@ -1458,6 +1874,87 @@ mod tests {
         assert!(MethodInfo::parse(&method).unwrap().is_none());
     }
 
+    #[test]
+    fn method_info_parse_rejects_mut_self() {
+        let method: ImplItemFn = syn::parse_quote! {
+            fn bump(&mut self) -> u32 { 0 }
+        };
+        let err = MethodInfo::parse(&method).unwrap_err();
+        assert!(err.to_string().contains("`bump` takes `&mut self`"));
+    }
+
+    #[test]
+    fn method_info_parse_skips_mut_self_with_skip_marker() {
+        let method: ImplItemFn = syn::parse_quote! {
+            #[route(skip)]
+            fn bump(&mut self) -> u32 { 0 }
+        };
+        assert!(MethodInfo::parse(&method).unwrap().is_none());
+    }
+
+    #[test]
+    fn method_info_parse_ignores_foreign_skip_markers() {
+        for method in [
+            syn::parse_quote! {
+                #[serde(skip)]
+                fn bump(&mut self) -> u32 { 0 }
+            },
+            syn::parse_quote! {
+                #[cli(skip)]
+                fn bump(&mut self) -> u32 { 0 }
+            },
+        ] {
+            let method: ImplItemFn = method;
+            assert!(MethodInfo::parse(&method).is_err());
+        }
+    }
+
+    #[test]
+    fn method_info_parse_static_fns() {
+        let constructor: ImplItemFn = syn::parse_quote! {
+            fn new() -> Self { Self }
+        };
+        assert!(MethodInfo::parse(&constructor).unwrap().is_none());
+
+        let skipped: ImplItemFn = syn::parse_quote! {
+            #[server(skip)]
+            fn helper() -> u32 { 0 }
+        };
+        assert!(MethodInfo::parse(&skipped).unwrap().is_none());
+
+        let marked: ImplItemFn = syn::parse_quote! {
+            #[route(path = "/status")]
+            fn status() -> u32 { 0 }
+        };
+        let err = MethodInfo::parse(&marked).unwrap_err();
+        assert!(err.to_string().contains("`status` has no `self` receiver"));
+        assert!(err.to_string().contains("#[route]"));
+    }
+
+    #[test]
+    fn method_info_parse_deprecated_forms() {
+        let bare: ImplItemFn = syn::parse_quote! {
+            #[deprecated]
+            fn old(&self) {}
+        };
+        let info = MethodInfo::parse(&bare).unwrap().unwrap();
+        assert_eq!(info.deprecation, Some(Deprecation::default()));
+
+        let noted: ImplItemFn = syn::parse_quote! {
+            #[deprecated(since = "0.8.0", note = "use `new_thing`")]
+            fn old(&self) {}
+        };
+        let info = MethodInfo::parse(&noted).unwrap().unwrap();
+        let deprecation = info.deprecation.unwrap();
+        assert_eq!(deprecation.note.as_deref(), Some("use `new_thing`"));
+        assert_eq!(deprecation.since.as_deref(), Some("0.8.0"));
+
+        let plain: ImplItemFn = syn::parse_quote! {
+            fn current(&self) {}
+        };
+        assert!(MethodInfo::parse(&plain).unwrap().unwrap().deprecation.is_none());
+    }
+
     #[test]
     fn method_info_parse_optional_param() {
         let method: ImplItemFn = syn::parse_quote! {
@@ -1517,6 +2014,23 @@ mod tests {
         assert_eq!(methods[0].name.to_string(), "public");
     }
 
+    #[test]
+    fn extract_methods_pub_visibility_skips_private() {
+        let impl_block: ItemImpl = syn::parse_quote! {
+            impl MyApi {
+                pub fn public(&self) {}
+                pub(crate) fn crate_visible(&self) {}
+                fn helper(&self) {}
+            }
+        };
+        let all = extract_methods(&impl_block).unwrap();
+        assert_eq!(all.len(), 3);
+
+        let public = extract_methods_with_visibility(&impl_block, MethodVisibility::Pub).unwrap();
+        let names: Vec<_> = public.iter().map(|m| m.name.to_string()).collect();
+        assert_eq!(names, ["public", "crate_visible"]);
+    }
+
     #[test]
     fn extract_methods_skips_associated_functions() {
         let impl_block: ItemImpl = syn::parse_quote! {
@@ -1646,4 +2160,84 @@ mod tests {
         };
         assert!(MethodInfo::parse(&method).is_ok());
     }
+
+    // ── parse_param_attrs: validation bounds ────────────────────────
+
+    #[test]
+    fn param_bounds_and_pattern_are_parsed() {
+        let method: ImplItemFn = syn::parse_quote! {
+            fn f(
+                &self,
+                #[param(min = -5, max = 2.5)] x: f64,
+                #[param(pattern = "^[a-z]+$")] slug: String,
+            ) {}
+        };
+        let info = MethodInfo::parse(&method).unwrap().unwrap();
+        assert_eq!(info.params[0].min.as_deref(), Some("-5"));
+        assert_eq!(info.params[0].max.as_deref(), Some("2.5"));
+        assert_eq!(info.params[1].pattern.as_deref(), Some("^[a-z]+$"));
+    }
+
+    #[test]
+    fn param_with_is_parsed_as_path() {
+        let method: ImplItemFn = syn::parse_quote! {
+            fn f(&self, #[param(with = "crate::codecs::comma_list")] tags: Vec<String>) {}
+        };
+        let info = MethodInfo::parse(&method).unwrap().unwrap();
+        let with = info.params[0].with.as_ref().unwrap();
+        assert_eq!(quote::quote!(#with).to_string(), "crate :: codecs :: comma_list");
+
+        let method: ImplItemFn = syn::parse_quote! {
+            fn f(&self, #[param(with = "not a path")] tags: Vec<String>) {}
+        };
+        assert!(MethodInfo::parse(&method).is_err());
+    }
+
+    #[test]
+    fn param_min_above_max_is_err() {
+        let method: ImplItemFn = syn::parse_quote! {
+            fn f(&self, #[param(min = 10, max = 1)] x: u32) {}
+        };
+        assert!(MethodInfo::parse(&method).is_err());
+    }
+
+    // ── parse_example_attrs ─────────────────────────────────────────
+
+    #[test]
+    fn examples_are_parsed_in_order() {
+        let method: ImplItemFn = syn::parse_quote! {
+            #[example(request = r#"{"text": "hi"}"#, response = r#""ok""#)]
+            #[example(response = "null")]
+            fn send(&self, text: String) {}
+        };
+        let info = MethodInfo::parse(&method).unwrap().unwrap();
+        assert_eq!(
+            info.examples,
+            [
+                MethodExample {
+                    request: Some(r#"{"text": "hi"}"#.to_string()),
+                    response: Some(r#""ok""#.to_string()),
+                },
+                MethodExample {
+                    request: None,
+                    response: Some("null".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_or_unknown_example_is_err() {
+        let empty: ImplItemFn = syn::parse_quote! {
+            #[example()]
+            fn f(&self) {}
+        };
+        assert!(MethodInfo::parse(&empty).is_err());
+        let typo: ImplItemFn = syn::parse_quote! {
+            #[example(requst = "{}")]
+            fn f(&self) {}
+        };
+        let err = MethodInfo::parse(&typo).unwrap_err().to_string();
+        assert!(err.contains("did you mean `request`"), "{err}");
+    }
 }
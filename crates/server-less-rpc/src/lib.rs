@@ -1,7 +1,7 @@
 //! Shared utilities for RPC-style macros (MCP, WebSocket, JSON-RPC).
 //!
 //! These macros use JSON-RPC-like dispatch:
-//! - Receive `{"method": "name", "params": {...}}`
+//! - Receive `{"method": "name", "params": {...}}` (or positional `"params": [...]`)
 //! - Extract params from JSON
 //! - Call the method
 //! - Serialize result back to JSON
@@ -12,11 +12,15 @@
 //! depend on the `server-less` facade instead.
 
 use proc_macro2::TokenStream;
-use quote::quote;
-use server_less_parse::{MethodInfo, ParamInfo};
+use quote::{quote, quote_spanned};
+use server_less_parse::{MethodInfo, ParamInfo, ReturnInfo};
+use syn::spanned::Spanned;
 
-/// Generate code to extract a parameter from a `serde_json::Value` args object.
-pub fn generate_param_extraction(param: &ParamInfo) -> TokenStream {
+/// Generate code to extract a parameter from the `serde_json::Value` args.
+///
+/// Args may be an object (looked up by name) or a positional array (indexed by
+/// `position`, the parameter's index among those read from the request).
+pub fn generate_param_extraction(param: &ParamInfo, position: usize) -> TokenStream {
     let name = &param.name;
     let name_str = param.name_str();
     let ty = &param.ty;
@@ -45,30 +49,39 @@ pub fn generate_param_extraction(param: &ParamInfo) -> TokenStream {
             ty.clone()
         };
         let inner_ty_str = quote!(#inner_ty).to_string().replace(" ", "");
+        let from_value = decimal_from_value(&inner_ty, quote! { __v.clone() });
         // For Option<T>:
         //   - absent or null → None (correct)
         //   - present but wrong type → error (value was sent but dropped silently before)
         quote! {
-            let #name: #ty = match args.get(#name_str) {
+            let #name: #ty = match ::server_less::rpc_param(&args, #name_str, #position) {
                 None => None,
                 Some(__v) if __v.is_null() => None,
-                Some(__v) => match ::server_less::serde_json::from_value(__v.clone()) {
+                Some(__v) => match #from_value {
                     Ok(__val) => Some(__val),
-                    Err(__e) => return Err(format!(
-                        "Optional parameter '{}' has invalid type (expected {}): {}", #name_str, #inner_ty_str, __e
-                    )),
+                    Err(__e) => return Err(::server_less::ErrorResponse::new(
+                        ::server_less::ErrorCode::InvalidInput,
+                        format!("Optional parameter '{}' has invalid type (expected {}): {}", #name_str, #inner_ty_str, __e),
+                    ).into()),
                 },
             };
         }
     } else {
         let ty_str = quote!(#ty).to_string().replace(" ", "");
+        let from_value = decimal_from_value(ty, quote! { __val });
         // Required parameter - error if missing
         quote! {
-            let __val = args.get(#name_str)
-                .ok_or_else(|| format!("Missing required parameter: {} (expected {})", #name_str, #ty_str))?
+            let __val = ::server_less::rpc_param(&args, #name_str, #position)
+                .ok_or_else(|| ::server_less::ErrorResponse::new(
+                    ::server_less::ErrorCode::InvalidInput,
+                    format!("Missing required parameter: {} (expected {})", #name_str, #ty_str),
+                ))?
                 .clone();
-            let #name: #ty = ::server_less::serde_json::from_value::<#ty>(__val)
-                .map_err(|e| format!("Invalid parameter {} (expected {}): {}", #name_str, #ty_str, e))?;
+            let #name: #ty = #from_value
+                .map_err(|e| ::server_less::ErrorResponse::new(
+                    ::server_less::ErrorCode::InvalidInput,
+                    format!("Invalid parameter {} (expected {}): {}", #name_str, #ty_str, e),
+                ))?;
         }
     }
 }
@@ -78,7 +91,8 @@ pub fn generate_all_param_extractions(method: &MethodInfo) -> Vec<TokenStream> {
     method
         .params
         .iter()
-        .map(generate_param_extraction)
+        .enumerate()
+        .map(|(i, p)| generate_param_extraction(p, i))
         .collect()
 }
 
@@ -89,7 +103,8 @@ pub fn generate_all_param_extractions(method: &MethodInfo) -> Vec<TokenStream> {
 pub fn generate_param_extractions_for(params: &[&ParamInfo]) -> Vec<TokenStream> {
     params
         .iter()
-        .map(|p| generate_param_extraction(p))
+        .enumerate()
+        .map(|(i, p)| generate_param_extraction(p, i))
         .collect()
 }
 
@@ -97,15 +112,25 @@ pub fn generate_param_extractions_for(params: &[&ParamInfo]) -> Vec<TokenStream>
 /// that is not in the compile-time-known set of parameter names.
 ///
 /// The generated code is inserted at the top of a dispatch arm, before param extraction.
-/// It only runs when `args` is a JSON object (positional arrays are silently skipped
-/// because key-based unknown detection is not meaningful there).
+/// For positional arrays it warns when more values are sent than there are parameters.
 pub fn generate_unknown_param_warning(
     method_name_str: &str,
     params: &[&ParamInfo],
 ) -> TokenStream {
     let known: Vec<String> = params.iter().map(|p| p.name_str()).collect();
     let expected_display = known.join(", ");
-    if known.is_empty() {
+    let count = known.len();
+    let positional_warn = quote! {
+        if let Some(__arr) = args.as_array() {
+            if __arr.len() > #count {
+                eprintln!(
+                    "[server-less] warning: {} positional parameters in call to `{}`, which takes {} (expected: {})",
+                    __arr.len(), #method_name_str, #count, #expected_display,
+                );
+            }
+        }
+    };
+    let named_warn = if known.is_empty() {
         // No known params — any key is unknown
         quote! {
             if let Some(__obj) = args.as_object() {
@@ -133,6 +158,10 @@ pub fn generate_unknown_param_warning(
                 }
             }
         }
+    };
+    quote! {
+        #named_warn
+        #positional_warn
     }
 }
 
@@ -147,7 +176,10 @@ pub fn generate_method_call(method: &MethodInfo, handle_async: AsyncHandling) ->
     match (method.is_async, handle_async) {
         (true, AsyncHandling::Error) => {
             quote! {
-                return Err("Async methods not supported in sync context".to_string());
+                return Err(::server_less::ErrorResponse::new(
+                    ::server_less::ErrorCode::NotImplemented,
+                    "Async methods not supported in sync context",
+                ).into());
             }
         }
         (true, AsyncHandling::Await) => {
@@ -184,7 +216,10 @@ pub fn generate_method_call_with_args(
     match (method.is_async, handle_async) {
         (true, AsyncHandling::Error) => {
             quote! {
-                return Err("Async methods not supported in sync context".to_string());
+                return Err(::server_less::ErrorResponse::new(
+                    ::server_less::ErrorCode::NotImplemented,
+                    "Async methods not supported in sync context",
+                ).into());
             }
         }
         (true, AsyncHandling::Await) => {
@@ -222,11 +257,17 @@ pub enum AsyncHandling {
 ///
 /// Handles:
 /// - `()` → `{"success": true}`
-/// - `Result<T, E>` → `Ok(T)` or `Err(message)`
+/// - `Result<T, E>` → `Ok(T)` or `Err(ErrorResponse)` (code from `IntoErrorCode` when implemented)
 /// - `Option<T>` → `T` or `null`
 /// - `T` → serialized T
 pub fn generate_json_response(method: &MethodInfo) -> TokenStream {
     let ret = &method.return_info;
+    let serialization_error = quote! {
+        |e| ::server_less::ErrorResponse::new(
+            ::server_less::ErrorCode::Internal,
+            format!("Serialization error: {}", e),
+        )
+    };
 
     if ret.is_unit {
         quote! {
@@ -239,7 +280,7 @@ pub fn generate_json_response(method: &MethodInfo) -> TokenStream {
                 use ::server_less::futures::StreamExt;
                 let collected: Vec<_> = result.collect().await;
                 Ok(::server_less::serde_json::to_value(collected)
-                    .map_err(|e| format!("Serialization error: {}", e))?)
+                    .map_err(#serialization_error)?)
             }
         }
     } else if ret.is_iterator {
@@ -248,34 +289,57 @@ pub fn generate_json_response(method: &MethodInfo) -> TokenStream {
             {
                 let __collected: Vec<_> = result.collect();
                 Ok(::server_less::serde_json::to_value(&__collected)
-                    .map_err(|e| format!("Serialization error: {}", e))?)
+                    .map_err(#serialization_error)?)
             }
         }
     } else if ret.is_result {
+        let ok_value = decimal_wire_value(ret.ok_type.as_ref(), quote! { value });
+        let assert_error =
+            error_type_assertion(ret, quote! { ::server_less::assert_handler_error });
         quote! {
-            match result {
-                Ok(value) => Ok(::server_less::serde_json::to_value(value)
-                    .map_err(|e| format!("Serialization error: {}", e))?),
-                Err(err) => Err(format!("{:?}", err)),
+            {
+                use ::server_less::ErrorResponseFallback as _;
+                #assert_error
+                match result {
+                    Ok(value) => Ok(::server_less::serde_json::to_value(#ok_value)
+                        .map_err(#serialization_error)?),
+                    Err(err) => Err(::server_less::ErrorResponseHelper(&err).error_response().into()),
+                }
             }
         }
     } else if ret.is_option {
+        let some_value = decimal_wire_value(ret.some_type.as_ref(), quote! { value });
         quote! {
             match result {
-                Some(value) => Ok(::server_less::serde_json::to_value(value)
-                    .map_err(|e| format!("Serialization error: {}", e))?),
+                Some(value) => Ok(::server_less::serde_json::to_value(#some_value)
+                    .map_err(#serialization_error)?),
                 None => Ok(::server_less::serde_json::Value::Null),
             }
         }
     } else {
         // Plain T
+        let value = decimal_wire_value(ret.ty.as_ref(), quote! { result });
         quote! {
-            Ok(::server_less::serde_json::to_value(result)
-                .map_err(|e| format!("Serialization error: {}", e))?)
+            Ok(::server_less::serde_json::to_value(#value)
+                .map_err(#serialization_error)?)
         }
     }
 }
 
+/// A call to `assertion` (`::server_less::assert_handler_error` or
+/// `::server_less::assert_jsonrpc_error`) on the method's `Err` type, or nothing
+/// for methods that don't return a `Result`.
+///
+/// The call is spanned on the user's error type, so a missing trait impl is
+/// reported there and names the trait, instead of surfacing as an unsatisfied
+/// bound inside generated response handling.
+pub fn error_type_assertion(ret: &ReturnInfo, assertion: TokenStream) -> TokenStream {
+    match &ret.err_type {
+        Some(err_ty) => quote_spanned! {err_ty.span()=> #assertion::<#err_ty>(); },
+        None => quote! {},
+    }
+}
+
 /// Generate a complete dispatch match arm for an RPC method.
 ///
 /// Combines param extraction, method call, and response handling.
@@ -295,7 +359,10 @@ pub fn generate_dispatch_arm(
     if requires_async && matches!(async_handling, AsyncHandling::Error) {
         return quote! {
             #method_name_str => {
-                return Err("Async methods and streaming methods not supported in sync context".to_string());
+                return Err(::server_less::ErrorResponse::new(
+                    ::server_less::ErrorCode::NotImplemented,
+                    "Async methods and streaming methods not supported in sync context",
+                ).into());
             }
         };
     }
@@ -338,12 +405,17 @@ pub fn generate_dispatch_arm_with_injections(
     if requires_async && matches!(async_handling, AsyncHandling::Error) {
         return quote! {
             #method_name_str => {
-                return Err("Async methods and streaming methods not supported in sync context".to_string());
+                return Err(::server_less::ErrorResponse::new(
+                    ::server_less::ErrorCode::NotImplemented,
+                    "Async methods and streaming methods not supported in sync context",
+                ).into());
             }
         };
     }
 
-    // Generate param extractions, substituting injected params
+    // Generate param extractions, substituting injected params. Positions count
+    // only the params read from the request.
+    let mut position = 0;
     let param_extractions: Vec<TokenStream> = method
         .params
         .iter()
@@ -353,7 +425,8 @@ pub fn generate_dispatch_arm_with_injections(
                 let name = &p.name;
                 quote! { let #name = #injection; }
             } else {
-                generate_param_extraction(p)
+                position += 1;
+                generate_param_extraction(p, position - 1)
             }
         })
         .collect();
@@ -395,12 +468,14 @@ pub fn generate_dispatch_arm_with_injections(
 pub fn infer_json_type(ty: &syn::Type) -> &'static str {
     use syn::{GenericArgument, PathArguments, Type};
     match ty {
+        // Beyond what JSON numbers carry exactly; sent as decimal strings.
+        Type::Path(_) if is_decimal_type(ty) => "string",
         Type::Path(type_path) => {
             if let Some(segment) = type_path.path.segments.last() {
                 match segment.ident.to_string().as_str() {
                     "String" => "string",
-                    "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "isize"
-                    | "usize" => "integer",
+                    "i8" | "i16" | "i32" | "i64" | "i128" | "u8" | "u16" | "u32" | "u64"
+                    | "u128" | "isize" | "usize" => "integer",
                     "f32" | "f64" => "number",
                     "bool" => "boolean",
                     "Vec" => "array",
@@ -435,6 +510,265 @@ pub fn infer_json_type(ty: &syn::Type) -> &'static str {
     }
 }
 
+/// Whether `ty` (or the `T` of an `Option<T>`) is a number JSON can't carry
+/// exactly: `i128`, `u128`, or `Decimal` / `rust_decimal::Decimal`.
+///
+/// Generated code sends these parameters and return values as decimal strings
+/// through `server_less::DecimalString`, and documents them as
+/// `{"type": "string", "format": "decimal"}`. Wide numbers nested in a `Vec` or
+/// a struct keep their own serde form (see [`nested_schema_tokens`]).
+pub fn is_decimal_type(ty: &syn::Type) -> bool {
+    let ty = server_less_parse::unwrap_option_type(ty).unwrap_or(ty);
+    let syn::Type::Path(type_path) = ty else {
+        return false;
+    };
+    if type_path.qself.is_some()
+        || type_path.path.segments.iter().any(|s| !s.arguments.is_empty())
+    {
+        return false;
+    }
+    let segments: Vec<String> = type_path
+        .path
+        .segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect();
+    match segments.as_slice() {
+        [only] => matches!(only.as_str(), "i128" | "u128" | "Decimal"),
+        [krate, name] => krate == "rust_decimal" && name == "Decimal",
+        _ => false,
+    }
+}
+
+/// Whether `ty` (or the `T` of an `Option<T>`) is `i128` or `u128`.
+pub fn is_wide_integer(ty: &syn::Type) -> bool {
+    let ty = server_less_parse::unwrap_option_type(ty).unwrap_or(ty);
+    matches!(ty, syn::Type::Path(tp) if tp.path.is_ident("i128") || tp.path.is_ident("u128"))
+}
+
+/// Whether `ty` is a raw byte buffer: `Vec<u8>` or a `Bytes`.
+///
+/// `#[http]` answers these with the bytes themselves (`application/octet-stream`)
+/// rather than a JSON array of numbers.
+pub fn is_bytes_type(ty: &syn::Type) -> bool {
+    let syn::Type::Path(type_path) = ty else {
+        return false;
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return false;
+    };
+    if type_path.qself.is_some() {
+        return false;
+    }
+    match &segment.arguments {
+        syn::PathArguments::None => segment.ident == "Bytes",
+        syn::PathArguments::AngleBracketed(args) if segment.ident == "Vec" => {
+            matches!(
+                args.args.first(),
+                Some(syn::GenericArgument::Type(syn::Type::Path(inner)))
+                    if args.args.len() == 1 && inner.path.is_ident("u8")
+            )
+        }
+        _ => false,
+    }
+}
+
+/// A method's success value: the `T` of `T`, `Option<T>` or `Result<T, E>`.
+fn success_value_type(ret: &ReturnInfo) -> Option<&syn::Type> {
+    if ret.is_result {
+        ret.ok_type.as_ref()
+    } else if ret.is_option {
+        ret.some_type.as_ref()
+    } else {
+        ret.ty.as_ref()
+    }
+}
+
+/// Whether a method's success value (the `T` of `T`, `Option<T>` or
+/// `Result<T, E>`) is a byte buffer; see [`is_bytes_type`].
+pub fn returns_bytes(ret: &ReturnInfo) -> bool {
+    success_value_type(ret)
+        .map(|ty| with_headers_inner(ty).unwrap_or(ty))
+        .is_some_and(is_bytes_type)
+}
+
+/// Whether a method's success value is a `(StatusCode, ...)` response tuple;
+/// see [`is_status_tuple`].
+pub fn returns_status_tuple(ret: &ReturnInfo) -> bool {
+    success_value_type(ret).is_some_and(is_status_tuple)
+}
+
+/// The `T` of a `WithHeaders<T>` return value, whose headers `#[http]` adds to
+/// the response of `T`.
+pub fn with_headers_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "WithHeaders" {
+        return None;
+    }
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+            match args.args.first() {
+                Some(syn::GenericArgument::Type(inner)) => Some(inner),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Whether `ty` is an axum response tuple led by a status code, such as
+/// `(StatusCode, Json<T>)` or `(StatusCode, HeaderMap, String)`, which `#[http]`
+/// sends through its `IntoResponse` impl as is.
+pub fn is_status_tuple(ty: &syn::Type) -> bool {
+    let syn::Type::Tuple(tuple) = ty else {
+        return false;
+    };
+    tuple.elems.len() >= 2
+        && matches!(
+            tuple.elems.first(),
+            Some(syn::Type::Path(first))
+                if first.path.segments.last().is_some_and(|s| s.ident == "StatusCode")
+        )
+}
+
+/// Wrap `value`, of type `ty`, so a decimal type serializes as a decimal
+/// string (`Option`s are mapped); other values are returned unchanged.
+pub fn decimal_wire_value(ty: Option<&syn::Type>, value: TokenStream) -> TokenStream {
+    match ty {
+        Some(ty) if is_decimal_type(ty) => {
+            if server_less_parse::unwrap_option_type(ty).is_some() {
+                quote! { #value.map(::server_less::DecimalString) }
+            } else {
+                quote! { ::server_less::DecimalString(#value) }
+            }
+        }
+        _ => value,
+    }
+}
+
+/// `serde_json::from_value::<ty>(value)`, read through `DecimalString` when
+/// `ty` is a decimal type so string and number forms are both accepted.
+pub fn decimal_from_value(ty: &syn::Type, value: TokenStream) -> TokenStream {
+    if let Some(inner) = server_less_parse::unwrap_option_type(ty)
+        && is_decimal_type(inner)
+    {
+        quote! {
+            ::server_less::serde_json::from_value::<
+                ::std::option::Option<::server_less::DecimalString<#inner>>,
+            >(#value)
+            .map(|__d| __d.map(|__d| __d.0))
+        }
+    } else if is_decimal_type(ty) {
+        quote! {
+            ::server_less::serde_json::from_value::<::server_less::DecimalString<#ty>>(#value)
+                .map(|__d| __d.0)
+        }
+    } else {
+        quote! { ::server_less::serde_json::from_value::<#ty>(#value) }
+    }
+}
+
+/// Generate an expression building the JSON schema (`serde_json::Value`) of a type.
+///
+/// Mostly `{"type": infer_json_type(ty)}`. `Option` is peeled (optionality is
+/// expressed by `required`, not the schema), and `Vec<T>` / slices describe their
+/// elements under `items`, recursively. A plain named type that would otherwise
+/// be an `"object"` is routed through `SchemaHelper`, so types deriving
+/// `ServerlessEnum` get their variant list as an `enum` constraint and structs
+/// deriving `ServerlessSchema` get their properties under their serde names.
+pub fn json_schema_tokens(ty: &syn::Type) -> TokenStream {
+    if is_decimal_type(ty) {
+        return quote! { ::server_less::serde_json::json!({"type": "string", "format": "decimal"}) };
+    }
+    nested_schema_tokens(ty)
+}
+
+/// [`json_schema_tokens`] for a value inside a `Vec` or a struct field.
+///
+/// Only parameters and return values are wrapped in `DecimalString`; nested
+/// `i128` / `u128` values go through serde as-is, so they are documented as
+/// integers. `Decimal` serializes itself as a string either way.
+pub fn nested_schema_tokens(ty: &syn::Type) -> TokenStream {
+    if is_wide_integer(ty) {
+        return quote! { ::server_less::serde_json::json!({"type": "integer"}) };
+    }
+    if is_decimal_type(ty) {
+        return quote! { ::server_less::serde_json::json!({"type": "string", "format": "decimal"}) };
+    }
+    let json_type = infer_json_type(ty);
+    let inner = server_less_parse::unwrap_option_type(ty).unwrap_or(ty);
+    let item = match inner {
+        syn::Type::Slice(slice) => Some(&*slice.elem),
+        syn::Type::Reference(r) => match &*r.elem {
+            syn::Type::Slice(slice) => Some(&*slice.elem),
+            _ => None,
+        },
+        _ => server_less_parse::unwrap_vec_type(inner),
+    };
+    if let Some(item) = item {
+        let items = nested_schema_tokens(item);
+        return quote! { ::server_less::serde_json::json!({"type": "array", "items": (#items)}) };
+    }
+    let is_named_type = match inner {
+        syn::Type::Path(tp) => {
+            tp.qself.is_none()
+                && tp
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|s| s.arguments.is_empty())
+        }
+        _ => false,
+    };
+    if json_type == "object" && is_named_type {
+        quote! {
+            {
+                use ::server_less::SchemaFallback as _;
+                ::server_less::SchemaHelper::<#inner>::new().json_schema(#json_type)
+            }
+        }
+    } else {
+        quote! { ::server_less::serde_json::json!({"type": #json_type}) }
+    }
+}
+
+/// Content of a `#[param(default = ...)]` string literal.
+///
+/// The parser stores defaults as Rust literals (`"\"foo\""`, `"10"`, `"true"`);
+/// returns `None` for the numeric and bool ones.
+pub fn default_string_literal(raw: &str) -> Option<&str> {
+    (raw.len() >= 2 && raw.starts_with('"') && raw.ends_with('"'))
+        .then(|| &raw[1..raw.len() - 1])
+}
+
+/// Generate an expression building the JSON value of a `#[param(default = ...)]`.
+///
+/// String literals become JSON strings and numbers/bools pass through.
+pub fn default_value_tokens(raw: &str) -> TokenStream {
+    match default_string_literal(raw) {
+        Some(value) => quote! { ::server_less::serde_json::json!(#value) },
+        None => {
+            let value: TokenStream = raw
+                .parse()
+                .expect("BUG: default is a numeric or bool literal");
+            quote! { ::server_less::serde_json::json!(#value) }
+        }
+    }
+}
+
+/// Generate an expression building the JSON value of an `#[example]` payload.
+///
+/// Payloads are JSON text, already checked to parse when the attribute was read.
+pub fn example_value_tokens(raw: &str) -> TokenStream {
+    quote! {
+        ::server_less::serde_json::from_str::<::server_less::serde_json::Value>(#raw)
+            .expect("BUG: #[example] payloads are validated at compile time")
+    }
+}
+
 /// Generate JSON schema properties for method parameters.
 pub fn generate_param_schema(params: &[ParamInfo]) -> (Vec<TokenStream>, Vec<String>) {
     let properties: Vec<_> = params
@@ -545,6 +879,53 @@ mod tests {
         assert_eq!(infer_json_type(&ty_f64), "number");
     }
 
+    #[test]
+    fn infer_json_type_wide_numbers_are_strings() {
+        for ty in [
+            syn::parse_quote!(i128),
+            syn::parse_quote!(u128),
+            syn::parse_quote!(rust_decimal::Decimal),
+            syn::parse_quote!(Option<u128>),
+        ] {
+            assert_eq!(infer_json_type(&ty), "string");
+            assert!(is_decimal_type(&ty));
+        }
+        assert!(!is_decimal_type(&syn::parse_quote!(i64)));
+        assert!(!is_decimal_type(&syn::parse_quote!(money::Decimal)));
+        assert_eq!(infer_json_type(&syn::parse_quote!(std::primitive::u128)), "integer");
+    }
+
+    #[test]
+    fn is_bytes_type_matches_byte_buffers() {
+        assert!(is_bytes_type(&syn::parse_quote!(Vec<u8>)));
+        assert!(is_bytes_type(&syn::parse_quote!(bytes::Bytes)));
+        assert!(!is_bytes_type(&syn::parse_quote!(Vec<u16>)));
+        assert!(!is_bytes_type(&syn::parse_quote!(Option<Vec<u8>>)));
+    }
+
+    #[test]
+    fn with_headers_inner_unwraps_one_level() {
+        let ty: syn::Type = syn::parse_quote!(server_less::WithHeaders<Vec<u8>>);
+        let inner = with_headers_inner(&ty).unwrap();
+        assert!(is_bytes_type(inner));
+        assert!(with_headers_inner(&syn::parse_quote!(Vec<u8>)).is_none());
+    }
+
+    #[test]
+    fn is_status_tuple_needs_leading_status_code() {
+        assert!(is_status_tuple(&syn::parse_quote!((
+            StatusCode,
+            Json<User>
+        ))));
+        assert!(is_status_tuple(&syn::parse_quote!((
+            axum::http::StatusCode,
+            HeaderMap,
+            String
+        ))));
+        assert!(!is_status_tuple(&syn::parse_quote!((u16, String))));
+        assert!(!is_status_tuple(&syn::parse_quote!(StatusCode)));
+    }
+
     #[test]
     fn infer_json_type_bool() {
         let ty: syn::Type = syn::parse_quote!(bool);
@@ -631,7 +1012,7 @@ mod tests {
             fn search(&self, limit: Option<u32>) {}
         });
 
-        let tokens = generate_param_extraction(&method.params[0]);
+        let tokens = generate_param_extraction(&method.params[0], 0);
         let code = tokens.to_string();
 
         // New pattern: absent/null → None, present-wrong-type → Err
@@ -658,7 +1039,7 @@ mod tests {
             fn greet(&self, name: String) {}
         });
 
-        let tokens = generate_param_extraction(&method.params[0]);
+        let tokens = generate_param_extraction(&method.params[0], 0);
         let code = tokens.to_string();
 
         assert!(
@@ -679,7 +1060,7 @@ mod tests {
             fn greet(&self, user_name: String) {}
         });
 
-        let tokens = generate_param_extraction(&method.params[0]);
+        let tokens = generate_param_extraction(&method.params[0], 0);
         let code = tokens.to_string();
 
         assert!(
@@ -1032,6 +1413,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn param_extraction_passes_position() {
+        let method = parse_method(quote! {
+            fn greet(&self, greeting: String, user_name: String) {}
+        });
+
+        let code = generate_param_extraction(&method.params[1], 1).to_string();
+        assert!(
+            code.contains("rpc_param (& args , \"user_name\" , 1usize)"),
+            "extraction should look up by name or position, got: {}",
+            code
+        );
+    }
+
     // ---------------------------------------------------------------
     // generate_all_param_extractions
     // ---------------------------------------------------------------
@@ -1170,6 +1565,55 @@ mod tests {
         assert_eq!(infer_json_type(&ty), "object");
     }
 
+    #[test]
+    fn json_schema_tokens_checks_named_types_for_enum_variants() {
+        let ty: syn::Type = syn::parse_quote!(Option<Status>);
+        let code = json_schema_tokens(&ty).to_string();
+        assert!(code.contains("SchemaHelper :: < Status >"), "{code}");
+
+        // Primitives and generic containers never go through the helper.
+        for ty in [
+            syn::parse_quote!(u32),
+            syn::parse_quote!(HashMap<String, i32>),
+        ] {
+            let code = json_schema_tokens(&ty).to_string();
+            assert!(!code.contains("SchemaHelper"), "{code}");
+        }
+    }
+
+    #[test]
+    fn json_schema_tokens_describes_array_items() {
+        let ty: syn::Type = syn::parse_quote!(Option<Vec<String>>);
+        let code = json_schema_tokens(&ty).to_string();
+        assert!(code.contains(r#""type" : "array" , "items""#), "{code}");
+        assert!(code.contains(r#""type" : "string""#), "{code}");
+
+        // Element types keep their own handling, including enum lookup.
+        let ty: syn::Type = syn::parse_quote!(&[Vec<Status>]);
+        let code = json_schema_tokens(&ty).to_string();
+        assert_eq!(code.matches(r#""array""#).count(), 2, "{code}");
+        assert!(code.contains("SchemaHelper :: < Status >"), "{code}");
+    }
+
+    #[test]
+    fn json_schema_tokens_decimal_format() {
+        let ty: syn::Type = syn::parse_quote!(Option<i128>);
+        let code = json_schema_tokens(&ty).to_string();
+        assert!(code.contains(r#""format" : "decimal""#), "{code}");
+    }
+
+    #[test]
+    fn nested_wide_integers_are_integers() {
+        let ty: syn::Type = syn::parse_quote!(Vec<u128>);
+        let code = json_schema_tokens(&ty).to_string();
+        assert!(code.contains(r#""type" : "integer""#), "{code}");
+        assert!(!code.contains("decimal"), "{code}");
+
+        let ty: syn::Type = syn::parse_quote!(Vec<rust_decimal::Decimal>);
+        let code = json_schema_tokens(&ty).to_string();
+        assert!(code.contains(r#""format" : "decimal""#), "{code}");
+    }
+
     #[test]
     fn method_call_sync_ignores_async_handling_variant() {
         // A sync method should generate the same code regardless of AsyncHandling variant
@@ -0,0 +1,45 @@
+//! Example CLI with async commands.
+//!
+//! Async methods are awaited by the generated dispatch. Inside an existing
+//! runtime (`#[tokio::main]`), use `cli_run_async()`; `cli_run()` builds its own
+//! runtime and refuses to run inside one.
+//!
+//! ```bash
+//! cargo run --example cli_async -- fetch --url https://example.com
+//! cargo run --example cli_async -- wait --millis 250
+//! cargo run --example cli_async -- version
+//! ```
+
+use server_less::cli;
+use std::time::Duration;
+
+#[derive(Clone, Default)]
+pub struct FetchApp;
+
+#[cli(name = "fetch-app", version = "0.1.0", description = "Async commands")]
+impl FetchApp {
+    /// Pretend to fetch a URL
+    pub async fn fetch(&self, url: String) -> String {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        format!("fetched {url}")
+    }
+
+    /// Sleep for a while, then report how long
+    pub async fn wait(&self, millis: u64) -> Result<String, String> {
+        if millis > 10_000 {
+            return Err("refusing to wait more than 10s".to_string());
+        }
+        tokio::time::sleep(Duration::from_millis(millis)).await;
+        Ok(format!("waited {millis}ms"))
+    }
+
+    /// Sync commands work alongside async ones
+    pub fn version(&self) -> String {
+        "0.1.0".to_string()
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    FetchApp.cli_run_async().await
+}
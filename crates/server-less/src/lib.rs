@@ -57,6 +57,7 @@
 //! | `#[jsonrpc]` | JSON-RPC 2.0 | `jsonrpc_router()`, `jsonrpc_methods()`, `jsonrpc_handle_async()` |
 //! | `#[graphql]` | GraphQL | async-graphql integration |
 //! | `#[grpc]` | gRPC | `.proto` schema generation |
+//! | `#[client]` | HTTP client | `{Type}Client` with one async method per route |
 //!
 //! **Cross-cutting attributes:**
 //!
@@ -65,6 +66,8 @@
 //! | `#[app(...)]` | Attach protocol-neutral metadata (name, description, version, homepage) |
 //! | `#[derive(Config)]` | Generate config loading from env vars, TOML files, and defaults |
 //! | `#[derive(ServerlessError)]` | Derive `IntoErrorCode` + `Display` + `Error` for error enums |
+//! | `#[derive(ServerlessEnum)]` | List a C-like enum's variants so specs can constrain parameters |
+//! | `#[derive(ServerlessSchema)]` | Describe a struct's JSON shape in specs, using its serde field names |
 //! | `#[route(...)]` | Per-method HTTP overrides (method, path, skip, hidden) |
 //! | `#[response(...)]` | Per-method response customization |
 //! | `#[param(...)]` | Per-parameter metadata (name, default, location, env, help) |
@@ -241,12 +244,27 @@
 //! - `jsonschema` - JSON Schema generation (no runtime deps)
 //! - `markdown` - Markdown API docs generation (no runtime deps)
 //! - `config` - `#[derive(Config)]` for config loading (requires toml)
+//! - `client` - `#[client]` typed HTTP client for `#[http]` services (requires reqwest)
+//! - `metrics` - Prometheus request metrics for `#[serve(metrics = "...")]` (requires prometheus)
 //! - `full` - All features (default)
+//!
+//! The schema, spec and docs generators (`grpc` through `markdown` above) expand
+//! to code that only uses `std`, `serde` and `serde_json`. A codegen-only crate,
+//! such as an `xtask` or build-dependency that writes `.proto`/`.thrift` files,
+//! can enable just those features without pulling in axum, tokio or clap:
+//!
+//! ```toml
+//! [dependencies]
+//! server-less = { version = "0.4", default-features = false, features = ["grpc", "thrift"] }
+//! ```
 
 // Re-export macros (feature-gated)
 #[cfg(feature = "mcp")]
 pub use server_less_macros::mcp;
 
+#[cfg(feature = "mcp")]
+pub use server_less_macros::mcp_tool;
+
 #[cfg(feature = "http")]
 pub use server_less_macros::http;
 
@@ -259,15 +277,24 @@ pub use server_less_macros::route;
 #[cfg(feature = "http")]
 pub use server_less_macros::response;
 
+#[cfg(feature = "http")]
+pub use server_less_macros::webhook;
+
 #[cfg(any(feature = "http", feature = "cli", feature = "mcp"))]
 pub use server_less_macros::param;
 
+#[cfg(any(feature = "asyncapi", feature = "markdown", feature = "ws"))]
+pub use server_less_macros::example;
+
 #[cfg(feature = "http")]
 pub use server_less_macros::serve;
 
 #[cfg(feature = "cli")]
 pub use server_less_macros::cli;
 
+#[cfg(feature = "client")]
+pub use server_less_macros::client;
+
 #[cfg(feature = "cli")]
 pub use server_less_core::CliSubcommand;
 
@@ -348,6 +375,15 @@ pub use server_less_macros::program;
 // Error derive macro (always available - no deps, commonly needed)
 pub use server_less_macros::ServerlessError;
 
+// Enum variant introspection for spec generators (always available)
+pub use server_less_macros::ServerlessEnum;
+
+// Struct schemas for spec generators (always available)
+pub use server_less_macros::ServerlessSchema;
+
+// Runtime method metadata (always available)
+pub use server_less_macros::introspect;
+
 // Application metadata attribute (always available)
 pub use server_less_macros::app;
 #[doc(hidden)]
@@ -384,6 +420,11 @@ pub use tokio;
 #[doc(hidden)]
 pub use axum;
 
+// Re-export reqwest for generated #[client] code
+#[cfg(feature = "client")]
+#[doc(hidden)]
+pub use reqwest;
+
 // Re-export futures for generated WebSocket code
 #[cfg(feature = "ws")]
 #[doc(hidden)]
@@ -427,12 +468,16 @@ pub mod prelude {
     pub use super::WsMount;
     #[cfg(feature = "cli")]
     pub use super::cli;
+    #[cfg(feature = "client")]
+    pub use super::{ClientError, client};
     #[cfg(feature = "graphql")]
     pub use super::graphql;
     #[cfg(feature = "graphql")]
     pub use super::graphql_enum;
     #[cfg(feature = "graphql")]
     pub use super::graphql_input;
+    #[cfg(feature = "graphql")]
+    pub use super::{Connection, ConnectionArgs};
     #[cfg(feature = "http")]
     pub use super::http;
     #[cfg(feature = "jsonrpc")]
@@ -485,7 +530,10 @@ pub mod prelude {
     pub use super::tool;
 
     // Always available
-    pub use super::{Context, ErrorCode, ErrorResponse, IntoErrorCode, ServerlessError};
+    pub use super::{
+        Context, ErrorCode, ErrorResponse, IntoErrorCode, ServerlessEnum, ServerlessError,
+        ServerlessSchema,
+    };
 
     // OpenAPI composition (available when any protocol that generates OpenAPI is enabled)
     #[cfg(feature = "server-less-openapi")]
@@ -495,4 +543,11 @@ pub mod prelude {
     // WebSocket sender (when ws feature enabled)
     #[cfg(feature = "ws")]
     pub use super::WsSender;
+
+    // Injected HTTP dependencies (when http feature enabled)
+    #[cfg(feature = "http")]
+    pub use super::Inject;
+
+    // Raw JSON request bodies
+    pub use super::RawJson;
 }
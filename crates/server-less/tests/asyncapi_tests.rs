@@ -3,7 +3,7 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use server_less::asyncapi;
+use server_less::{asyncapi, example};
 
 #[derive(Clone)]
 struct ChatService;
@@ -137,3 +137,52 @@ fn test_asyncapi_with_ws() {
     let methods = CombinedService::ws_methods();
     assert!(methods.contains(&"echo".to_string()));
 }
+
+// Examples feed both the AsyncAPI messages and the WebSocket docs
+#[derive(Clone)]
+struct ExampleService;
+
+#[server_less::ws(path = "/ws")]
+#[asyncapi]
+impl ExampleService {
+    /// Post a message
+    #[example(request = r#"{"text": "hi"}"#, response = r#""Sent: hi""#)]
+    #[example(request = r#"{"text": ""}"#)]
+    pub fn post(&self, text: String) -> String {
+        format!("Sent: {text}")
+    }
+
+    pub fn ping(&self) -> String {
+        "pong".to_string()
+    }
+}
+
+#[test]
+fn test_asyncapi_message_examples() {
+    let spec = ExampleService::asyncapi_spec();
+    let messages = &spec["components"]["messages"];
+
+    assert_eq!(
+        messages["PostRequest"]["examples"],
+        serde_json::json!([{"payload": {"text": "hi"}}, {"payload": {"text": ""}}])
+    );
+    assert_eq!(
+        messages["PostResponse"]["examples"],
+        serde_json::json!([{"payload": "Sent: hi"}])
+    );
+    assert!(messages["PingRequest"].get("examples").is_none());
+}
+
+#[test]
+fn test_ws_docs_use_first_example() {
+    let paths = ExampleService::ws_openapi_paths();
+    let protocol = &paths[0].operation.extra["x-websocket-protocol"];
+    assert_eq!(
+        protocol["request_example"],
+        serde_json::json!({"method": "post", "params": {"text": "hi"}, "id": 1})
+    );
+    assert_eq!(
+        protocol["response_example"],
+        serde_json::json!({"result": "Sent: hi", "id": 1})
+    );
+}
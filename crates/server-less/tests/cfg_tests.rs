@@ -0,0 +1,115 @@
+//! Methods compiled out by `#[cfg]` are left out of every generated method
+//! list and spec, not just the routes and handlers.
+
+use serde_json::{Value, json};
+use server_less::{asyncapi, http, jsonrpc, jsonschema, openapi, openrpc, ws};
+
+#[derive(Clone)]
+struct GatedService;
+
+#[http]
+#[jsonrpc]
+#[ws(path = "/gated")]
+#[openapi]
+#[openrpc]
+#[asyncapi]
+#[jsonschema(per_method = true)]
+impl GatedService {
+    pub fn get_report(&self, id: u32) -> String {
+        format!("report {id}")
+    }
+
+    #[cfg(not(test))]
+    pub fn purge_reports(&self) -> u32 {
+        0
+    }
+}
+
+fn keys(value: &Value) -> Vec<String> {
+    value.as_object().unwrap().keys().cloned().collect()
+}
+
+#[test]
+fn test_cfg_disabled_method_left_out_of_every_list() {
+    let jsonrpc_paths = GatedService::jsonrpc_openapi_paths();
+    let jsonrpc_enum = &jsonrpc_paths[0].operation.request_body.as_ref().unwrap()["content"]
+        ["application/json"]["schema"]["properties"]["method"]["enum"];
+    let ws_summary = GatedService::ws_openapi_paths()[0]
+        .operation
+        .summary
+        .clone()
+        .unwrap();
+
+    // (source, names it lists, the gated method's name there)
+    let cases: Vec<(&str, Vec<String>, &str)> = vec![
+        (
+            "http_openapi_paths",
+            GatedService::http_openapi_paths()
+                .into_iter()
+                .map(|p| p.operation.operation_id.unwrap())
+                .collect(),
+            "purge_reports",
+        ),
+        (
+            "openapi_spec",
+            keys(&GatedService::openapi_spec()["paths"]),
+            "purge",
+        ),
+        (
+            "jsonrpc_methods",
+            GatedService::jsonrpc_methods(),
+            "purge_reports",
+        ),
+        (
+            "jsonrpc_openapi_paths",
+            serde_json::from_value(jsonrpc_enum.clone()).unwrap(),
+            "purge_reports",
+        ),
+        ("ws_methods", GatedService::ws_methods(), "purge_reports"),
+        (
+            "ws_openapi_paths",
+            ws_summary.split_whitespace().map(String::from).collect(),
+            "purge_reports",
+        ),
+        (
+            "openrpc_spec",
+            GatedService::openrpc_spec()["methods"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|m| m["name"].as_str().unwrap().to_string())
+                .collect(),
+            "purgeReports",
+        ),
+        (
+            "asyncapi_spec channels",
+            keys(&GatedService::asyncapi_spec()["channels"]),
+            "purge_reports",
+        ),
+        (
+            "json_schema definitions",
+            keys(&GatedService::json_schema()["definitions"]),
+            "Purge_reportsRequest",
+        ),
+        (
+            "json_schema_methods",
+            keys(&GatedService::json_schema_methods()),
+            "purgeReports",
+        ),
+    ];
+
+    for (source, names, gated) in cases {
+        assert!(!names.is_empty(), "{source} lists nothing");
+        assert!(
+            !names.iter().any(|n| n.contains(gated)),
+            "{source} still lists {gated}: {names:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_cfg_disabled_method_not_dispatched() {
+    let request = json!({"jsonrpc": "2.0", "method": "purge_reports", "id": 1});
+    let response = GatedService.jsonrpc_handle_async(request).await;
+    assert_eq!(response["error"]["code"], -32601);
+}
@@ -645,6 +645,32 @@ fn test_bool_arg_has_set_true_action() {
     assert!(!verbose_arg.is_required_set());
 }
 
+#[derive(Clone)]
+struct QualifiedBoolService;
+
+#[cli(name = "qualified-bool-app")]
+impl QualifiedBoolService {
+    /// Run with a fully qualified bool flag
+    pub fn run(&self, dry_run: std::primitive::bool) -> String {
+        format!("dry_run={dry_run}")
+    }
+}
+
+#[test]
+fn test_qualified_bool_is_a_switch() {
+    let svc = QualifiedBoolService;
+    assert!(svc.cli_run_with(["qualified-bool-app", "run", "--dry-run"]).is_ok());
+    assert!(svc.cli_run_with(["qualified-bool-app", "run"]).is_ok());
+
+    let cmd = QualifiedBoolService::cli_command();
+    let run_cmd = cmd.find_subcommand("run").unwrap();
+    let dry_run = run_cmd
+        .get_arguments()
+        .find(|a| a.get_id().as_str() == "dry-run")
+        .unwrap();
+    assert!(!dry_run.get_action().takes_values());
+}
+
 #[test]
 fn test_bool_dispatch_with_flag() {
     let svc = BoolService;
@@ -710,6 +736,51 @@ fn test_vec_dispatch_empty() {
     assert!(result.is_ok());
 }
 
+#[derive(Clone, Default)]
+struct VecCollectService {
+    seen: std::rc::Rc<std::cell::RefCell<Vec<String>>>,
+}
+
+#[cli(name = "vec-collect-app")]
+impl VecCollectService {
+    /// Sum numbers given as a repeated flag
+    pub fn sum(&self, values: Vec<u32>) -> u32 {
+        self.seen.borrow_mut().push(format!("{values:?}"));
+        values.iter().sum()
+    }
+
+    /// Add files given positionally
+    pub fn add(&self, #[param(positional)] files: Vec<String>) -> usize {
+        self.seen.borrow_mut().push(format!("{files:?}"));
+        files.len()
+    }
+}
+
+#[test]
+fn test_vec_collects_typed_values() {
+    let svc = VecCollectService::default();
+    let result = svc.cli_run_with(["vec-collect-app", "sum", "--values", "1,2", "--values", "3"]);
+    assert!(result.is_ok());
+    assert_eq!(*svc.seen.borrow(), ["[1, 2, 3]"]);
+}
+
+#[test]
+fn test_positional_vec_takes_remaining_values() {
+    let svc = VecCollectService::default();
+    let result = svc.cli_run_with(["vec-collect-app", "add", "a.txt", "b.txt", "c.txt"]);
+    assert!(result.is_ok());
+    assert_eq!(*svc.seen.borrow(), [r#"["a.txt", "b.txt", "c.txt"]"#]);
+
+    let cmd = VecCollectService::cli_command();
+    let files = cmd
+        .find_subcommand("add")
+        .unwrap()
+        .get_arguments()
+        .find(|a| a.get_id().as_str() == "files")
+        .unwrap();
+    assert!(files.is_positional());
+}
+
 // ── Global flag tests ─────────────────────────────────────────────────
 
 #[derive(Clone, Default)]
@@ -1203,6 +1274,14 @@ fn test_param_default_dispatch_without_arg() {
     assert!(svc.cli_run_with(["param-default-app", "connect", "--port", "9090"]).is_ok());
 }
 
+#[test]
+fn test_param_default_shown_in_help() {
+    let mut cmd = ParamDefaultService::cli_command();
+    let connect_cmd = cmd.find_subcommand_mut("connect").unwrap();
+    let help = connect_cmd.render_help().to_string();
+    assert!(help.contains("[default: 8080]"), "help was:\n{help}");
+}
+
 // ── CliGlobals delivery (the capability-wiring invariant) ─────────────
 // A service that declares `global = [...]` must impl `CliGlobals`; the macro
 // delivers each global flag's value to the sink before the method runs. The
@@ -1582,6 +1661,78 @@ mod enum_param_tests {
     }
 }
 
+// ============================================================================
+// Enum parameter: possible values from ServerlessEnum
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, server_less::ServerlessEnum)]
+#[serde(rename_all = "kebab-case")]
+enum Priority {
+    Low,
+    High,
+    RightNow,
+}
+
+#[derive(Clone, Default)]
+struct PriorityService {
+    seen: std::rc::Rc<std::cell::RefCell<Vec<String>>>,
+}
+
+#[cli(name = "priority-app")]
+impl PriorityService {
+    /// File a ticket
+    pub fn file(&self, priority: Priority, escalate: Option<Priority>) -> String {
+        let seen = format!("{priority:?}/{escalate:?}");
+        self.seen.borrow_mut().push(seen.clone());
+        seen
+    }
+}
+
+#[test]
+fn test_serverless_enum_arg_has_possible_values() {
+    let cmd = PriorityService::cli_command();
+    let file_cmd = cmd.find_subcommand("file").unwrap();
+    for id in ["priority", "escalate"] {
+        let arg = file_cmd
+            .get_arguments()
+            .find(|a| a.get_id().as_str() == id)
+            .unwrap();
+        let possible: Vec<String> = arg
+            .get_possible_values()
+            .iter()
+            .map(|pv| pv.get_name().to_string())
+            .collect();
+        assert_eq!(possible, ["low", "high", "right-now"], "{id}");
+    }
+}
+
+#[test]
+fn test_serverless_enum_arg_rejects_unknown_variant() {
+    let result = PriorityService::cli_command().try_get_matches_from([
+        "priority-app",
+        "file",
+        "--priority",
+        "urgent",
+    ]);
+    let err = result.unwrap_err();
+    assert_eq!(err.kind(), server_less::clap::error::ErrorKind::InvalidValue);
+}
+
+#[test]
+fn test_serverless_enum_arg_dispatches() {
+    let svc = PriorityService::default();
+    let result = svc.cli_run_with([
+        "priority-app",
+        "file",
+        "--priority",
+        "right-now",
+        "--escalate",
+        "high",
+    ]);
+    assert!(result.is_ok());
+    assert_eq!(*svc.seen.borrow(), ["RightNow/Some(High)"]);
+}
+
 // ============================================================================
 // Async dispatch tests
 // ============================================================================
@@ -2211,6 +2362,112 @@ fn test_manual_param_legal_when_surface_disabled() {
     );
 }
 
+// `--output json|text` selects the output format; `#[cli(output = false)]` drops it.
+#[derive(Clone)]
+struct OutputApp;
+
+#[cli(name = "output-app")]
+impl OutputApp {
+    /// A plain leaf
+    pub fn ping(&self) -> String {
+        "pong".to_string()
+    }
+
+    /// A list result
+    pub fn list_colors(&self) -> Vec<String> {
+        vec!["red".to_string(), "blue".to_string()]
+    }
+}
+
+/// Child half of `test_output_flag_renders_selected_format`: runs the CLI with the
+/// arguments in `OUTPUT_APP_ARGS` so the parent can read what it printed.
+#[test]
+fn output_app_child() {
+    let Ok(args) = std::env::var("OUTPUT_APP_ARGS") else {
+        return;
+    };
+    OutputApp
+        .cli_run_with(std::iter::once("output-app").chain(args.split(' ')))
+        .unwrap();
+}
+
+/// Stdout lines of `output-app` run with `args`.
+fn output_app_stdout(args: &str) -> Vec<String> {
+    let output = std::process::Command::new(std::env::current_exe().unwrap())
+        .args(["output_app_child", "--exact", "--nocapture", "--quiet"])
+        .env("OUTPUT_APP_ARGS", args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn test_output_flag_renders_selected_format() {
+    let json = output_app_stdout("list-colors --output json");
+    assert!(json.iter().any(|l| l == r#"["red","blue"]"#), "{json:?}");
+
+    let text = output_app_stdout("list-colors --output text");
+    assert!(text.iter().any(|l| l == "red"), "{text:?}");
+    assert!(text.iter().any(|l| l == "blue"), "{text:?}");
+    assert!(!text.iter().any(|l| l.contains('[')), "{text:?}");
+
+    // The harness adds its own lines (with timings) around the app's output.
+    let rendered = |lines: Vec<String>| lines.into_iter().find(|l| l.starts_with('['));
+    assert_eq!(
+        rendered(output_app_stdout("list-colors --output json")),
+        rendered(output_app_stdout("list-colors --json"))
+    );
+}
+
+#[test]
+fn test_output_flag_accepts_json_and_text() {
+    let cmd = OutputApp::cli_command();
+    let output = cmd
+        .get_arguments()
+        .find(|a| a.get_id().as_str() == "output")
+        .unwrap();
+    assert!(output.is_global_set());
+    let possible: Vec<String> = output
+        .get_possible_values()
+        .iter()
+        .map(|pv| pv.get_name().to_string())
+        .collect();
+    assert_eq!(possible, ["json", "text"]);
+
+    let app = OutputApp;
+    assert!(app.cli_run_with(["output-app", "ping", "--output", "json"]).is_ok());
+    assert!(app.cli_run_with(["output-app", "--output", "text", "ping"]).is_ok());
+    assert!(
+        OutputApp::cli_command()
+            .try_get_matches_from(["output-app", "ping", "--output", "yaml"])
+            .is_err()
+    );
+}
+
+#[derive(Clone)]
+struct OutputParamApp;
+
+#[cli(name = "output-param-app", output = false)]
+impl OutputParamApp {
+    pub fn convert(&self, output: String) -> String {
+        output
+    }
+}
+
+#[test]
+fn test_output_param_legal_when_flag_disabled() {
+    let app = OutputParamApp;
+    assert!(
+        app.cli_run_with(["output-param-app", "convert", "--output", "out.txt"])
+            .is_ok()
+    );
+}
+
 // Per-command `#[cli(manual = false)]` hides one leaf from the aggregate while
 // keeping the command runnable.
 #[derive(Clone)]
@@ -2240,3 +2497,95 @@ fn test_per_command_manual_false_excludes_from_aggregate() {
     // The command itself is still dispatchable.
     assert!(app.cli_run_with(["partial-manual-app", "internal"]).is_ok());
 }
+
+// `#[cli(confirm)]` prompts before running; `--yes` skips the prompt.
+#[derive(Clone, Default)]
+struct ConfirmApp {
+    deleted: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+#[cli(name = "confirm-app")]
+impl ConfirmApp {
+    /// Delete a user
+    #[cli(confirm)]
+    pub fn delete_user(&self, name: String) {
+        self.deleted.lock().unwrap().push(name);
+    }
+
+    /// Remove everything
+    #[cli(confirm = "Remove every user?")]
+    pub fn remove_all(&self) {
+        self.deleted.lock().unwrap().push("*".to_string());
+    }
+
+    /// List users
+    pub fn list_users(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[test]
+fn test_confirm_registers_yes_flag() {
+    let cmd = ConfirmApp::cli_command();
+    let delete = cmd.find_subcommand("delete-user").unwrap();
+    let yes = delete
+        .get_arguments()
+        .find(|a| a.get_id().as_str() == "yes")
+        .expect("--yes registered on confirming command");
+    assert_eq!(yes.get_short(), Some('y'));
+
+    let list = cmd.find_subcommand("list-users").unwrap();
+    assert!(list.get_arguments().all(|a| a.get_id().as_str() != "yes"));
+}
+
+#[test]
+fn test_confirm_skipped_with_yes() {
+    let app = ConfirmApp::default();
+    app.cli_run_with(["confirm-app", "delete-user", "--name", "alice", "--yes"])
+        .unwrap();
+    app.cli_run_with(["confirm-app", "remove-all", "-y"])
+        .unwrap();
+    app.cli_run_with([
+        "confirm-app",
+        "delete-user",
+        "--yes",
+        "--params-json",
+        r#"{"name": "bob"}"#,
+    ])
+    .unwrap();
+    assert_eq!(*app.deleted.lock().unwrap(), ["alice", "*", "bob"]);
+}
+
+/// Child half of `test_confirm_declined_does_not_run`: answers the prompt from the
+/// stdin the parent supplies.
+#[test]
+fn confirm_app_decline_child() {
+    if std::env::var_os("CONFIRM_APP_DECLINE").is_none() {
+        return;
+    }
+    let app = ConfirmApp::default();
+    let err = app
+        .cli_run_with(["confirm-app", "delete-user", "--name", "alice"])
+        .unwrap_err();
+    assert!(err.to_string().contains("aborted"), "{err}");
+    assert!(app.deleted.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_confirm_declined_does_not_run() {
+    use std::io::Write;
+
+    let mut child = std::process::Command::new(std::env::current_exe().unwrap())
+        .args(["confirm_app_decline_child", "--exact", "--nocapture", "--quiet"])
+        .env("CONFIRM_APP_DECLINE", "1")
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"n\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Are you sure? [y/N]"), "{stderr}");
+}
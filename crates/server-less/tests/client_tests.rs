@@ -0,0 +1,383 @@
+//! Round-trip tests for the `#[client]` macro.
+//!
+//! Each test serves an `#[http]` router on an ephemeral local port and calls it
+//! through the generated `{Type}Client`, so client and server routing are
+//! exercised against each other.
+
+#![allow(dead_code)]
+// `route`/`param` are consumed by #[http]; the imports look unused after stripping.
+#![allow(unused_imports)]
+
+use serde::{Deserialize, Serialize};
+use server_less::{ClientError, client, http, param, route};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct Note {
+    id: u32,
+    title: String,
+    tags: Vec<String>,
+}
+
+#[derive(Debug, server_less::ServerlessError)]
+enum NoteError {
+    #[error(code = InvalidInput, message = "Title must not be empty")]
+    EmptyTitle,
+}
+
+#[derive(Clone)]
+struct NoteService {
+    notes: std::sync::Arc<std::sync::Mutex<Vec<Note>>>,
+}
+
+impl NoteService {
+    fn new() -> Self {
+        Self {
+            notes: std::sync::Arc::new(std::sync::Mutex::new(vec![Note {
+                id: 1,
+                title: "First".to_string(),
+                tags: vec!["intro".to_string()],
+            }])),
+        }
+    }
+}
+
+#[client]
+#[http(prefix = "/api")]
+impl NoteService {
+    /// Create a note
+    pub fn create_note(&self, title: String, tags: Option<Vec<String>>) -> Result<Note, NoteError> {
+        if title.is_empty() {
+            return Err(NoteError::EmptyTitle);
+        }
+        let mut notes = self.notes.lock().unwrap();
+        let note = Note {
+            id: notes.len() as u32 + 1,
+            title,
+            tags: tags.unwrap_or_default(),
+        };
+        notes.push(note.clone());
+        Ok(note)
+    }
+
+    /// Get a note by ID
+    pub fn get_note(&self, id: u32) -> Option<Note> {
+        self.notes.lock().unwrap().iter().find(|n| n.id == id).cloned()
+    }
+
+    /// List notes, optionally filtered by tag
+    pub fn list_notes(&self, tag: Option<String>, limit: u32) -> Vec<Note> {
+        self.notes
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|n| tag.as_ref().is_none_or(|t| n.tags.contains(t)))
+            .take(limit as usize)
+            .cloned()
+            .collect()
+    }
+
+    /// Delete a note
+    pub fn delete_note(&self, id: u32) {
+        self.notes.lock().unwrap().retain(|n| n.id != id);
+    }
+
+    /// Rename a note via an explicit route with a header parameter
+    #[route(method = "POST", path = "/notes/{id}/rename")]
+    pub fn rename_note(
+        &self,
+        #[param(path)] id: u32,
+        #[param(header, name = "x-new-title")] title: String,
+    ) -> Option<Note> {
+        let mut notes = self.notes.lock().unwrap();
+        let note = notes.iter_mut().find(|n| n.id == id)?;
+        note.title = title;
+        Some(note.clone())
+    }
+
+    /// Fetch a note along with its revision header
+    pub fn get_note_revision(&self, id: u32) -> Option<server_less::WithHeaders<Note>> {
+        let note = self.get_note(id)?;
+        let revision = note.tags.len().to_string();
+        Some(server_less::WithHeaders::new(note).header("x-revision", revision))
+    }
+}
+
+/// Serve `NoteService` on an ephemeral port and return a client pointed at it.
+async fn spawn_client() -> NoteServiceClient {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let router = NoteService::new().http_router();
+    tokio::spawn(async move {
+        axum::serve(listener, router).await.unwrap();
+    });
+    NoteServiceClient::new(format!("http://{addr}"), server_less::reqwest::Client::new())
+}
+
+#[tokio::test]
+async fn test_client_post_body() {
+    let client = spawn_client().await;
+    let note = client
+        .create_note("Second".to_string(), Some(vec!["todo".to_string()]))
+        .await
+        .unwrap();
+    assert_eq!(
+        note,
+        Note {
+            id: 2,
+            title: "Second".to_string(),
+            tags: vec!["todo".to_string()],
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_client_omits_absent_optional_body_field() {
+    let client = spawn_client().await;
+    let note = client.create_note("Bare".to_string(), None).await.unwrap();
+    assert!(note.tags.is_empty());
+}
+
+#[tokio::test]
+async fn test_client_error_status() {
+    let client = spawn_client().await;
+    let err = client.create_note(String::new(), None).await.unwrap_err();
+    assert_eq!(err.status(), Some(400));
+    match err {
+        ClientError::Status { body, .. } => assert!(body.contains("Title must not be empty")),
+        other => panic!("expected Status error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_client_path_param_and_option() {
+    let client = spawn_client().await;
+    let found = client.get_note(1).await.unwrap();
+    assert_eq!(found.map(|n| n.title), Some("First".to_string()));
+
+    let missing = client.get_note(99).await.unwrap();
+    assert_eq!(missing, None);
+}
+
+#[tokio::test]
+async fn test_client_query_params() {
+    let client = spawn_client().await;
+    client
+        .create_note("Tagged".to_string(), Some(vec!["todo".to_string()]))
+        .await
+        .unwrap();
+
+    let all = client.list_notes(None, 10).await.unwrap();
+    assert_eq!(all.len(), 2);
+
+    let todo = client.list_notes(Some("todo".to_string()), 10).await.unwrap();
+    assert_eq!(todo.len(), 1);
+    assert_eq!(todo[0].title, "Tagged");
+
+    let limited = client.list_notes(None, 1).await.unwrap();
+    assert_eq!(limited.len(), 1);
+}
+
+#[tokio::test]
+async fn test_client_unit_return() {
+    let client = spawn_client().await;
+    client.delete_note(1).await.unwrap();
+    assert_eq!(client.get_note(1).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_client_route_override_and_header() {
+    let client = spawn_client().await;
+    let renamed = client.rename_note(1, "Renamed".to_string()).await.unwrap();
+    assert_eq!(renamed.map(|n| n.title), Some("Renamed".to_string()));
+}
+
+#[tokio::test]
+async fn test_client_reads_response_headers() {
+    let client = spawn_client().await;
+    let found = client.get_note_revision(1).await.unwrap().unwrap();
+    assert_eq!(found.value.title, "First");
+    assert_eq!(found.headers["x-revision"], "1");
+
+    assert!(client.get_note_revision(99).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_client_base_url_with_path() {
+    let client = spawn_client().await;
+    let nested = NoteServiceClient::new(
+        format!("{}/", client.base_url()),
+        server_less::reqwest::Client::new(),
+    );
+    assert!(nested.get_note(1).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn test_client_invalid_base_url() {
+    let client = NoteServiceClient::new("not a url", server_less::reqwest::Client::new());
+    let err = client.get_note(1).await.unwrap_err();
+    assert!(matches!(err, ClientError::InvalidUrl(_)));
+}
+
+#[derive(Clone)]
+struct ActionService;
+
+#[client]
+#[http(rpc_style = true)]
+impl ActionService {
+    /// Reindex everything
+    pub fn rebuild_index(&self, full: bool) -> String {
+        if full {
+            "full".to_string()
+        } else {
+            "partial".to_string()
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_client_follows_sibling_rpc_style() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, ActionService.http_router())
+            .await
+            .unwrap();
+    });
+    let client = ActionServiceClient::new(
+        format!("http://{addr}"),
+        server_less::reqwest::Client::new(),
+    );
+    assert_eq!(client.rebuild_index(true).await.unwrap(), "full");
+}
+
+#[derive(Clone)]
+struct WrappedService;
+
+#[client]
+#[http(envelope = true)]
+impl WrappedService {
+    pub fn get_label(&self, id: u32) -> Option<String> {
+        (id == 1).then(|| "one".to_string())
+    }
+
+    pub fn list_labels(&self) -> Vec<String> {
+        vec!["one".to_string()]
+    }
+
+    #[response(none = "null")]
+    pub fn get_selection(&self) -> Option<String> {
+        None
+    }
+
+    pub fn get_blob(&self, id: u32) -> Option<Vec<u8>> {
+        (id == 1).then(|| vec![0, 1, 2])
+    }
+}
+
+#[tokio::test]
+async fn test_client_unwraps_sibling_envelope() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, WrappedService.http_router())
+            .await
+            .unwrap();
+    });
+    let client = WrappedServiceClient::new(
+        format!("http://{addr}"),
+        server_less::reqwest::Client::new(),
+    );
+    assert_eq!(client.get_label(1).await.unwrap().as_deref(), Some("one"));
+    assert_eq!(client.get_label(2).await.unwrap(), None);
+    assert_eq!(client.list_labels().await.unwrap(), ["one"]);
+    assert_eq!(client.get_selection().await.unwrap(), None);
+    // Bytes bypass the envelope
+    assert_eq!(client.get_blob(1).await.unwrap(), Some(vec![0, 1, 2]));
+    assert_eq!(client.get_blob(2).await.unwrap(), None);
+}
+
+#[derive(Clone)]
+struct ShelfService;
+
+#[client]
+#[http(prefix = "/libraries/{library_id}")]
+impl ShelfService {
+    pub fn get_shelf(&self, id: u32, library_id: String) -> String {
+        format!("{library_id}/{id}")
+    }
+}
+
+#[tokio::test]
+async fn test_client_fills_prefix_params() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, ShelfService.http_router())
+            .await
+            .unwrap();
+    });
+    let client = ShelfServiceClient::new(
+        format!("http://{addr}"),
+        server_less::reqwest::Client::new(),
+    );
+    assert_eq!(
+        client.get_shelf(4, "north".to_string()).await.unwrap(),
+        "north/4"
+    );
+}
+
+mod hex {
+    pub fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+        u32::from_str_radix(s, 16)
+    }
+}
+
+#[derive(Clone)]
+struct PaletteService;
+
+#[client]
+#[http]
+impl PaletteService {
+    pub fn get_shade(
+        &self,
+        #[param(with = "hex")] shade_id: u32,
+        #[param(with = "hex")] mix: Option<u32>,
+        #[param(header, name = "x-tint", with = "hex")] tint: u32,
+    ) -> u32 {
+        shade_id + mix.unwrap_or(0) + tint
+    }
+
+    pub fn create_shade(&self, #[param(with = "hex")] rgb: u32) -> u32 {
+        rgb
+    }
+}
+
+#[tokio::test]
+async fn test_client_sends_param_with_as_text() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, PaletteService.http_router())
+            .await
+            .unwrap();
+    });
+    let client = PaletteServiceClient::new(
+        format!("http://{addr}"),
+        server_less::reqwest::Client::new(),
+    );
+    let shade = client
+        .get_shade("10".to_string(), Some("1".to_string()), "100".to_string())
+        .await
+        .unwrap();
+    assert_eq!(shade, 0x111);
+    assert_eq!(client.create_shade("ff".to_string()).await.unwrap(), 0xff);
+
+    // The parser's error reaches the 400 body.
+    match client.create_shade("zz".to_string()).await.unwrap_err() {
+        ClientError::Status { status, body } => {
+            assert_eq!(status, 400);
+            assert!(body.contains("invalid digit found in string"), "{body}");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
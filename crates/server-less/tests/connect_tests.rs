@@ -228,3 +228,55 @@ fn test_connect_return_types() {
     assert!(schema.contains("double result"), "Should map f64 to double");
     assert!(schema.contains("bool result"), "Should map bool to bool");
 }
+
+#[test]
+fn test_connect_query_methods_have_no_side_effects() {
+    let schema = UserService::connect_schema();
+
+    assert!(
+        schema.contains(
+            "rpc GetUser(GetUserRequest) returns (GetUserResponse) {\n    option idempotency_level = NO_SIDE_EFFECTS;\n  }"
+        ),
+        "get_* should be marked side-effect free:\n{schema}"
+    );
+    assert!(
+        schema.contains("rpc CreateUser(CreateUserRequest) returns (CreateUserResponse);"),
+        "create_* should stay a plain rpc:\n{schema}"
+    );
+    assert_eq!(schema.matches("NO_SIDE_EFFECTS").count(), 2, "{schema}");
+}
+
+#[test]
+fn test_connect_get_paths() {
+    let paths = UserService::connect_get_paths();
+    assert_eq!(
+        paths,
+        vec![
+            "/users.v1.UserService/GetUser",
+            "/users.v1.UserService/ListUsers"
+        ]
+    );
+}
+
+#[derive(Clone)]
+struct CacheService;
+
+#[connect(package = "cache.v1")]
+impl CacheService {
+    /// Drop every cached entry
+    pub fn clear_cache(&self) {}
+}
+
+#[test]
+fn test_connect_unit_return_uses_empty() {
+    let schema = CacheService::connect_schema();
+    assert!(
+        schema.contains("rpc ClearCache(ClearCacheRequest) returns (google.protobuf.Empty);"),
+        "Unit returns should use google.protobuf.Empty:\n{schema}"
+    );
+    assert!(
+        schema.contains("import \"google/protobuf/empty.proto\";"),
+        "{schema}"
+    );
+    assert!(!schema.contains("ClearCacheResponse"), "{schema}");
+}
@@ -125,6 +125,37 @@ fn test_struct_variants() {
     assert_eq!(err.message(), "Validation failed");
 }
 
+// Test message templates interpolating variant fields
+#[derive(Debug, ServerlessError)]
+enum TemplateError {
+    #[error(code = NotFound, message = "user {0} not found")]
+    UserNotFound(u64),
+    #[error(code = InvalidInput, message = "field `{field}` is invalid: {reason:?}")]
+    Invalid { field: String, reason: String },
+    #[error(message = "literal {{braces}}")]
+    Braces,
+}
+
+#[test]
+fn test_message_templates() {
+    let err = TemplateError::UserNotFound(42);
+    assert_eq!(err.message(), "user 42 not found");
+    assert_eq!(err.to_string(), "user 42 not found");
+
+    let err = TemplateError::Invalid {
+        field: "email".to_string(),
+        reason: "no @".to_string(),
+    };
+    assert_eq!(err.message(), r#"field `email` is invalid: "no @""#);
+    assert_eq!(err.to_string(), err.message());
+
+    assert_eq!(TemplateError::Braces.message(), "literal {braces}");
+    // Variant metadata keeps the placeholders, with escaped braces unescaped
+    let variants = TemplateError::error_variants();
+    assert_eq!(variants[0].message, "user {0} not found");
+    assert_eq!(variants[2].message, "literal {braces}");
+}
+
 // Test that std::error::Error is implemented
 #[test]
 fn test_std_error_impl() {
@@ -175,6 +206,35 @@ fn test_explicit_jsonrpc_code_override() {
     );
 }
 
+// Test application-specific codes
+#[derive(Debug, ServerlessError)]
+enum AppCodeError {
+    #[error(code = NotFound, message = "User not found", app_code = "USER_NOT_FOUND")]
+    UserNotFound,
+    #[error(code = NotFound)]
+    Missing,
+}
+
+#[test]
+fn test_app_code() {
+    assert_eq!(AppCodeError::UserNotFound.app_code(), Some("USER_NOT_FOUND"));
+    assert_eq!(AppCodeError::Missing.app_code(), None);
+
+    let response = server_less::ErrorResponse::from(&AppCodeError::UserNotFound);
+    assert_eq!(
+        response.envelope(),
+        serde_json::json!({
+            "error": {"code": "NOT_FOUND", "message": "User not found", "app_code": "USER_NOT_FOUND"}
+        })
+    );
+    let response = server_less::ErrorResponse::from(&AppCodeError::Missing);
+    assert!(response.envelope()["error"].get("app_code").is_none());
+
+    let variants = AppCodeError::error_variants();
+    assert_eq!(variants[0].app_code, Some("USER_NOT_FOUND"));
+    assert_eq!(variants[1].app_code, None);
+}
+
 // Test HTTP status code mapping
 #[derive(Debug, ServerlessError)]
 enum HttpStatusError {
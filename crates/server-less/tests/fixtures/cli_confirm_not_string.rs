@@ -0,0 +1,13 @@
+use server_less::cli;
+
+#[derive(Clone)]
+struct MyApp;
+
+// A malformed `confirm` must fail to compile rather than drop the prompt.
+#[cli(name = "my-app")]
+impl MyApp {
+    #[cli(confirm = 1)]
+    fn wipe(&self) {}
+}
+
+fn main() {}
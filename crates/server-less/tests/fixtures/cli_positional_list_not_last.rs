@@ -0,0 +1,13 @@
+use server_less::cli;
+
+#[derive(Clone)]
+struct MyApp;
+
+#[cli(name = "my-app")]
+impl MyApp {
+    fn copy(&self, #[param(positional)] sources: Vec<String>, #[param(positional)] dest: String) {
+        let _ = (sources, dest);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use server_less::cli;
+
+struct MyApp;
+
+// A parameter named `output` collides with the injected `--output` global flag.
+// The collision guard turns clap's runtime panic into a spanned compile error.
+#[cli]
+impl MyApp {
+    fn render(&self, output: String) {
+        println!("{output}");
+    }
+}
+
+fn main() {}
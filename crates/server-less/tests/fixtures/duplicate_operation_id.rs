@@ -0,0 +1,18 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http]
+impl MyService {
+    pub fn get_user(&self, id: u32) -> String {
+        format!("user {}", id)
+    }
+
+    #[route(operation_id = "get_user")]
+    pub fn list_users(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+fn main() {}
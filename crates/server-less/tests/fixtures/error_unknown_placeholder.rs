@@ -0,0 +1,9 @@
+use server_less::ServerlessError;
+
+#[derive(Debug, ServerlessError)]
+enum UserError {
+    #[error(code = NotFound, message = "user {name} not found")]
+    NotFound { id: u64 },
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+// `example` is consumed by #[markdown]; the import looks unused after expansion.
+#[allow(unused_imports)]
+use server_less::{example, markdown};
+
+#[derive(Clone)]
+struct MyService;
+
+#[markdown]
+impl MyService {
+    #[example(request = r#"{"name": "Ada""#)]
+    pub fn greet(&self, name: String) -> String {
+        name
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use server_less::{grpc, http};
+
+#[derive(Clone)]
+struct MyService;
+
+#[http(prefix = "/v1")]
+#[grpc(package = "my.v1", http_annotations = true)]
+impl MyService {
+    pub fn get_note(&self, id: u32) -> u32 {
+        id
+    }
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use server_less::grpc;
+
+#[derive(Clone)]
+struct MyService;
+
+#[grpc(go_package = "example.com/gen\";\noption x = \"y")]
+impl MyService {
+    pub fn list_items(&self) -> Vec<u32> {
+        vec![1, 2, 3]
+    }
+}
+
+fn main() {}
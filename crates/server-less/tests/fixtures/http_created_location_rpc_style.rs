@@ -0,0 +1,13 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http(rpc_style = true, created_location = true)]
+impl MyService {
+    pub fn create_note(&self, text: String) -> String {
+        text
+    }
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http(deserialize_error_status = 500)]
+impl MyService {
+    pub fn get_note(&self, id: u32) -> u32 {
+        id
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use server_less::{http, serve};
+
+#[derive(Clone)]
+struct MyService;
+
+#[http(openapi_path = "/spec.json", docs_path = "/health")]
+#[serve(http)]
+impl MyService {
+    pub fn hello(&self) -> String {
+        "hello".into()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http(docs_path = "/docs")]
+impl MyService {
+    pub fn hello(&self) -> String {
+        "hello".into()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use server_less::http;
+
+struct LookupFailed;
+
+#[derive(Clone)]
+struct LookupService;
+
+#[http]
+impl LookupService {
+    pub fn get_item(&self, _id: u32) -> Result<String, LookupFailed> {
+        Err(LookupFailed)
+    }
+}
+
+fn main() {}
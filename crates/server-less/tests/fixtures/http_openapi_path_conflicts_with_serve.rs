@@ -0,0 +1,14 @@
+use server_less::{http, serve};
+
+#[derive(Clone)]
+struct MyService;
+
+#[http(openapi_path = "/openapi.json")]
+#[serve(http)]
+impl MyService {
+    pub fn hello(&self) -> String {
+        "hello".into()
+    }
+}
+
+fn main() {}
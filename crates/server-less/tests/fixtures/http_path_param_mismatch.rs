@@ -0,0 +1,14 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http]
+impl MyService {
+    #[route(path = "/items/{item_id}")]
+    pub fn get_item(&self, id: u32) -> String {
+        id.to_string()
+    }
+}
+
+fn main() {}
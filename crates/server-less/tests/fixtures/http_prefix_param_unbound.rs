@@ -0,0 +1,17 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http(prefix = "/tenants/{tenant_id}/")]
+impl MyService {
+    pub fn get_item(&self, tenant_id: String, id: u32) -> String {
+        format!("{tenant_id}/{id}")
+    }
+
+    pub fn list_items(&self) -> Vec<u32> {
+        vec![1, 2, 3]
+    }
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http(prefix = "/files/{*path}")]
+impl MyService {
+    pub fn list_items(&self, path: String) -> Vec<u32> {
+        vec![1, 2, 3]
+    }
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct FileService;
+
+#[http]
+impl FileService {
+    pub fn get_file(&self, file_id: String) -> String {
+        file_id
+    }
+
+    #[route(method = "POST", path = "/files/upload")]
+    pub fn upload_file(&self, contents: String) -> usize {
+        contents.len()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct AssetService;
+
+#[http]
+impl AssetService {
+    #[route(path = "/assets/{*file}")]
+    pub fn get_asset(&self, #[param(path)] file: String) -> String {
+        file
+    }
+
+    #[route(method = "POST", path = "/assets/upload")]
+    pub fn upload_asset(&self, contents: String) -> usize {
+        contents.len()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http]
+impl MyService {
+    pub fn create_note(&self, title: &str) -> String {
+        title.to_string()
+    }
+}
+
+fn main() {}
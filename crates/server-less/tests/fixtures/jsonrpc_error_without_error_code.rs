@@ -0,0 +1,16 @@
+use server_less::jsonrpc;
+
+#[derive(Debug)]
+struct LookupFailed;
+
+#[derive(Clone)]
+struct LookupService;
+
+#[jsonrpc]
+impl LookupService {
+    pub fn get_item(&self, _id: u32) -> Result<String, LookupFailed> {
+        Err(LookupFailed)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use server_less::markdown;
+
+#[derive(Clone)]
+struct NoteService;
+
+#[markdown(protocols = "http, websocket")]
+impl NoteService {
+    pub fn get_note(&self, id: u32) -> Option<String> {
+        None
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use server_less::mcp;
+
+#[derive(Clone)]
+struct MyService;
+
+#[mcp]
+impl MyService {
+    #[mcp_tool(read_only, destructive)]
+    pub fn purge_cache(&self) -> bool {
+        true
+    }
+}
+
+fn main() {}
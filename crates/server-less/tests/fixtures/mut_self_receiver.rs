@@ -0,0 +1,16 @@
+use server_less::http;
+
+struct Counter {
+    count: u32,
+}
+
+#[http]
+impl Counter {
+    // Handlers share the service behind an Arc; `&mut self` cannot be projected.
+    fn increment(&mut self) -> u32 {
+        self.count += 1;
+        self.count
+    }
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http]
+impl MyService {
+    pub fn list_items(&self, #[param(min = 1)] name: String) -> String {
+        name
+    }
+}
+
+fn main() {}
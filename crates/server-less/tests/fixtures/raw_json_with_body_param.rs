@@ -0,0 +1,13 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http]
+impl MyService {
+    pub fn create_event(&self, topic: String, payload: server_less::RawJson) -> String {
+        format!("{topic}: {}", payload.get())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http]
+impl MyService {
+    #[response(header = "X-Greeting", value = "hello\nworld")]
+    pub fn get_greeting(&self) -> String {
+        "hi".to_string()
+    }
+}
+
+fn main() {}
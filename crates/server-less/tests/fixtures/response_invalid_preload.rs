@@ -0,0 +1,14 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http]
+impl MyService {
+    #[response(preload = "/static/app.js>; rel=stylesheet")]
+    pub fn get_page(&self) -> String {
+        "page".to_string()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http]
+impl MyService {
+    #[response(none = "null")]
+    pub fn get_user(&self, id: u32) -> String {
+        format!("user {}", id)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http]
+impl MyService {
+    #[response(status = 201)]
+    pub fn create_item(&self, name: String) -> (axum::http::StatusCode, String) {
+        (axum::http::StatusCode::CREATED, name)
+    }
+}
+
+fn main() {}
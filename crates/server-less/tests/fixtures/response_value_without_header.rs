@@ -0,0 +1,14 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http]
+impl MyService {
+    #[response(value = "1.0", header = "X-Version")]
+    pub fn get_version(&self) -> String {
+        "v1".to_string()
+    }
+}
+
+fn main() {}
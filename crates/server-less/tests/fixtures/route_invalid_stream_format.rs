@@ -0,0 +1,14 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http]
+impl MyService {
+    #[route(stream = "jsonl")]
+    pub fn list_items(&self) -> impl futures::Stream<Item = u32> + use<> {
+        futures::stream::iter(0..3)
+    }
+}
+
+fn main() {}
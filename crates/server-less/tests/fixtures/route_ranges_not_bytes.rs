@@ -0,0 +1,14 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http]
+impl MyService {
+    #[route(ranges)]
+    pub fn get_report(&self, id: u32) -> String {
+        format!("report {}", id)
+    }
+}
+
+fn main() {}
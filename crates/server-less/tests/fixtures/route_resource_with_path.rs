@@ -0,0 +1,14 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http]
+impl MyService {
+    #[route(path = "/folks/{id}", resource = "people")]
+    pub fn get_person(&self, id: u32) -> u32 {
+        id
+    }
+}
+
+fn main() {}
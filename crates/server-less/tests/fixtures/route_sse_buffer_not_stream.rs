@@ -0,0 +1,14 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http]
+impl MyService {
+    #[route(sse_buffer = 16)]
+    pub fn list_items(&self) -> Vec<u32> {
+        vec![1, 2, 3]
+    }
+}
+
+fn main() {}
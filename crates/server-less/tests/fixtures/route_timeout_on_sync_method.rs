@@ -0,0 +1,14 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http]
+impl MyService {
+    #[route(timeout = 5)]
+    pub fn get_report(&self, id: u32) -> u32 {
+        id
+    }
+}
+
+fn main() {}
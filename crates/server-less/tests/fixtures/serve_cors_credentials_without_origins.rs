@@ -0,0 +1,13 @@
+use server_less::serve;
+
+#[derive(Clone)]
+struct MyService;
+
+#[serve(cors(credentials, max_age = 600))]
+impl MyService {
+    pub fn hello(&self) -> String {
+        "hello".into()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use server_less::{http, serve};
+
+#[derive(Clone)]
+struct MyService;
+
+#[serve(http, docs = "/docs")]
+#[http(openapi_path = "/spec.json", docs_path = "/docs")]
+impl MyService {
+    pub fn hello(&self) -> String {
+        "hello".into()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use server_less::serve;
+
+#[derive(Clone)]
+struct MyService;
+
+#[serve(docs = "/docs", openapi = false)]
+impl MyService {
+    pub fn hello(&self) -> String {
+        "hello".into()
+    }
+}
+
+fn main() {}
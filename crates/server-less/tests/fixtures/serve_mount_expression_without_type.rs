@@ -0,0 +1,13 @@
+use server_less::serve;
+
+#[derive(Clone)]
+struct MyService;
+
+#[serve(mount("/v1/users", MyService::new()))]
+impl MyService {
+    pub fn hello(&self) -> String {
+        "hello".into()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use server_less::serve;
+
+#[derive(Clone)]
+struct ProbeService;
+
+#[serve(http, readiness, readiness_timeout = 2)]
+impl ProbeService {
+    pub fn health_check(&self) -> bool {
+        true
+    }
+}
+
+fn main() {}
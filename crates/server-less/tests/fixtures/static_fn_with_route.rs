@@ -0,0 +1,22 @@
+use server_less::http;
+
+#[derive(Clone)]
+struct MyService;
+
+#[http]
+impl MyService {
+    pub fn new() -> Self {
+        MyService
+    }
+
+    #[route(path = "/status")]
+    pub fn status() -> String {
+        "ok".into()
+    }
+
+    pub fn get_item(&self, id: u32) -> u32 {
+        id
+    }
+}
+
+fn main() {}
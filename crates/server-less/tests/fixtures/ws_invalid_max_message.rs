@@ -0,0 +1,13 @@
+use server_less::ws;
+
+#[derive(Clone)]
+struct MyService;
+
+#[ws(path = "/ws", max_message = "64 kilobytes")]
+impl MyService {
+    pub fn echo(&self, message: String) -> String {
+        message
+    }
+}
+
+fn main() {}
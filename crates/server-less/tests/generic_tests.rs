@@ -0,0 +1,225 @@
+//! Integration tests for protocol macros on generic impl blocks.
+//!
+//! Services are commonly parameterized over a storage backend; the generated
+//! impls, handlers and trait implementations must carry the impl's generics
+//! and where-clause through.
+
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+use server_less::{
+    asyncapi, cli, graphql, grpc, http, jsonrpc, jsonschema, markdown, mcp, openapi, openrpc, serve,
+    thrift, ws,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, schemars::JsonSchema)]
+struct User {
+    id: u32,
+    name: String,
+}
+
+impl std::fmt::Display for User {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.name, self.id)
+    }
+}
+
+trait Store: Clone + Send + Sync + 'static {
+    fn get(&self, id: u32) -> Option<User>;
+    fn all(&self) -> Vec<User>;
+}
+
+#[derive(Clone)]
+struct MemoryStore {
+    users: Vec<User>,
+}
+
+impl Store for MemoryStore {
+    fn get(&self, id: u32) -> Option<User> {
+        self.users.iter().find(|u| u.id == id).cloned()
+    }
+
+    fn all(&self) -> Vec<User> {
+        self.users.clone()
+    }
+}
+
+fn memory_store() -> MemoryStore {
+    MemoryStore {
+        users: vec![
+            User { id: 1, name: "Ada".to_string() },
+            User { id: 2, name: "Grace".to_string() },
+        ],
+    }
+}
+
+#[derive(Clone)]
+struct UserService<S> {
+    store: S,
+}
+
+#[cli(name = "users")]
+#[http]
+#[mcp]
+#[jsonrpc]
+#[ws]
+#[serve(http, jsonrpc, ws)]
+impl<S: Store> UserService<S> {
+    /// Get a user by ID
+    pub fn get_user(&self, id: u32) -> Option<User> {
+        self.store.get(id)
+    }
+
+    /// List all users
+    pub fn list_users(&self) -> Vec<User> {
+        self.store.all()
+    }
+
+    /// Count users
+    pub async fn count_users(&self) -> u32 {
+        self.store.all().len() as u32
+    }
+}
+
+/// Same shape, with bounds expressed in a where-clause.
+#[derive(Clone)]
+struct Directory<S> {
+    store: S,
+}
+
+#[graphql]
+#[openapi]
+#[openrpc]
+#[grpc(package = "directory.v1")]
+impl<S> Directory<S>
+where
+    S: Store,
+{
+    /// Get a user by ID
+    pub fn get_user(&self, id: u32) -> Option<User> {
+        self.store.get(id)
+    }
+
+    /// List all users
+    pub fn list_users(&self) -> Vec<User> {
+        self.store.all()
+    }
+}
+
+/// Schema-only generators on a generic impl.
+#[derive(Clone)]
+struct Catalog<S> {
+    store: S,
+}
+
+#[thrift(namespace = "catalog")]
+#[asyncapi]
+#[jsonschema]
+#[markdown]
+impl<S: Store> Catalog<S> {
+    /// Get a user by ID
+    pub fn get_user(&self, id: u32) -> Option<User> {
+        self.store.get(id)
+    }
+}
+
+async fn get(router: axum::Router, path: &str) -> (axum::http::StatusCode, serde_json::Value) {
+    use tower::ServiceExt;
+    let request = axum::http::Request::builder()
+        .uri(path)
+        .body(axum::body::Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null))
+}
+
+#[tokio::test]
+async fn test_generic_http_router() {
+    let service = UserService { store: memory_store() };
+    let (status, body) = get(service.http_router(), "/users/2").await;
+    assert_eq!(status, axum::http::StatusCode::OK);
+    assert_eq!(body["name"], "Grace");
+
+    let service = UserService { store: memory_store() };
+    let (status, body) = get(service.http_router(), "/users").await;
+    assert_eq!(status, axum::http::StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_generic_serve_router() {
+    let service = UserService { store: memory_store() };
+    let (status, _) = get(service.router(), "/health").await;
+    assert_eq!(status, axum::http::StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_generic_jsonrpc() {
+    let service = UserService { store: memory_store() };
+    let response = service
+        .jsonrpc_handle_async(serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "count_users",
+            "params": {},
+            "id": 1
+        }))
+        .await;
+    assert_eq!(response["result"], 2);
+}
+
+#[test]
+fn test_generic_ws() {
+    let service = UserService { store: memory_store() };
+    let response = service
+        .ws_handle_message(r#"{"method": "get_user", "params": {"id": 1}}"#)
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&response).unwrap();
+    assert_eq!(json["result"]["name"], "Ada");
+}
+
+#[test]
+fn test_generic_mcp() {
+    let names = UserService::<MemoryStore>::mcp_method_names();
+    assert!(names.contains(&"get_user".to_string()));
+
+    let service = UserService { store: memory_store() };
+    let result = service
+        .mcp_call("list_users", serde_json::json!({}))
+        .unwrap();
+    assert_eq!(result.as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_generic_cli() {
+    let service = UserService { store: memory_store() };
+    assert!(service.cli_run_with(["users", "get-user", "1"]).is_ok());
+}
+
+#[test]
+fn test_generic_specs() {
+    let openapi = Directory::<MemoryStore>::openapi_spec();
+    assert!(openapi["paths"]["/users/{id}"]["get"].is_object());
+
+    let openrpc = Directory::<MemoryStore>::openrpc_spec();
+    assert_eq!(openrpc["methods"].as_array().unwrap().len(), 2);
+
+    let proto = Directory::<MemoryStore>::grpc_schema();
+    assert!(proto.contains("package directory.v1;"));
+
+    assert!(Catalog::<MemoryStore>::thrift_schema().contains("get_user"));
+    assert!(Catalog::<MemoryStore>::asyncapi_spec()["channels"].is_object());
+    assert!(Catalog::<MemoryStore>::json_schema().is_object());
+    assert!(Catalog::<MemoryStore>::markdown_docs().contains("get_user"));
+}
+
+#[tokio::test]
+async fn test_generic_graphql() {
+    let service = Directory { store: memory_store() };
+    let result = service
+        .graphql_schema()
+        .execute("{ getUser(id: 1) }")
+        .await;
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+}
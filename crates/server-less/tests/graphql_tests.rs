@@ -1340,3 +1340,414 @@ async fn test_serve_graphql_openapi_spec() {
         serde_json::to_string_pretty(paths).unwrap()
     );
 }
+
+// ============================================================================
+// 64-bit integers
+// ============================================================================
+
+#[derive(Clone)]
+struct LedgerService;
+
+#[graphql]
+impl LedgerService {
+    /// Largest id issued so far
+    pub fn get_max_id(&self) -> u64 {
+        9_007_199_254_740_993
+    }
+
+    /// Echo a timestamp
+    pub fn get_timestamp(&self, at: i64) -> i64 {
+        at
+    }
+}
+
+#[tokio::test]
+async fn test_graphql_preserves_64_bit_integers() {
+    let schema = LedgerService.graphql_schema();
+
+    let result = schema
+        .execute("{ getMaxId getTimestamp(at: -4102444800000) }")
+        .await;
+    assert!(result.errors.is_empty(), "Query should succeed: {:?}", result.errors);
+    let data = result.data.into_json().unwrap();
+    assert_eq!(data["getMaxId"].as_u64(), Some(9_007_199_254_740_993));
+    assert_eq!(data["getTimestamp"].as_i64(), Some(-4_102_444_800_000));
+}
+
+// ============================================================================
+// Deprecation and descriptions
+// ============================================================================
+
+#[derive(Clone)]
+struct ShelfService;
+
+#[graphql]
+impl ShelfService {
+    /// List every product in the catalog, including ones
+    /// that are out of stock.
+    ///
+    /// Results are sorted by name.
+    pub fn list_products(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Old listing
+    #[deprecated(note = "use listProducts")]
+    pub fn list_items(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    #[deprecated]
+    pub fn get_legacy(&self) -> String {
+        String::new()
+    }
+}
+
+#[test]
+fn test_graphql_deprecated_fields() {
+    let sdl = ShelfService.graphql_sdl();
+    assert!(
+        sdl.contains(r#"listItems: [String!] @deprecated(reason: "use listProducts")"#),
+        "SDL should mark listItems deprecated with its note:\n{sdl}"
+    );
+    assert!(
+        sdl.contains("getLegacy: String! @deprecated"),
+        "SDL should mark getLegacy deprecated:\n{sdl}"
+    );
+    assert!(
+        !sdl.contains("listProducts: [String!] @deprecated"),
+        "{sdl}"
+    );
+}
+
+#[test]
+fn test_graphql_description_keeps_doc_line_breaks() {
+    let sdl = ShelfService.graphql_sdl();
+    let lines: Vec<&str> = sdl.lines().map(str::trim).collect();
+    assert!(
+        lines.windows(4).any(|w| w
+            == [
+                "List every product in the catalog, including ones",
+                "that are out of stock.",
+                "",
+                "Results are sorted by name.",
+            ]),
+        "Doc comment line breaks should be kept in the description:\n{sdl}"
+    );
+}
+
+// ============================================================================
+// Relay connections
+// ============================================================================
+
+#[derive(Clone, Debug, Serialize)]
+struct Book {
+    title: String,
+}
+
+#[derive(Clone)]
+struct LibraryService;
+
+#[graphql]
+impl LibraryService {
+    pub fn list_books(&self, page: server_less::ConnectionArgs) -> server_less::Connection<Book> {
+        let books = ["Dune", "Emma", "Ulysses", "Walden"]
+            .into_iter()
+            .map(|title| Book {
+                title: title.to_string(),
+            })
+            .collect();
+        server_less::Connection::paginate(books, &page)
+    }
+
+    pub fn list_tags(&self) -> server_less::Connection<String> {
+        server_less::Connection::paginate(vec!["new".to_string()], &Default::default())
+    }
+}
+
+#[test]
+fn test_graphql_connection_sdl() {
+    let sdl = LibraryService.graphql_sdl();
+    assert!(
+        sdl.contains("listBooks(first: Int, after: String, last: Int, before: String): BookConnection!"),
+        "SDL should expose pagination arguments:\n{sdl}"
+    );
+    assert!(sdl.contains("type BookConnection"), "{sdl}");
+    assert!(sdl.contains("edges: [BookEdge!]!"), "{sdl}");
+    assert!(sdl.contains("pageInfo: PageInfo!"), "{sdl}");
+    assert!(sdl.contains("type PageInfo"), "{sdl}");
+    assert!(sdl.contains("hasNextPage: Boolean!"), "{sdl}");
+    assert!(sdl.contains("listTags: StringConnection!"), "{sdl}");
+}
+
+#[tokio::test]
+async fn test_graphql_connection_pages() {
+    let schema = LibraryService.graphql_schema();
+    let result = schema
+        .execute(
+            r#"{ listBooks(first: 2, after: "0") {
+                edges { cursor node }
+                pageInfo { hasNextPage hasPreviousPage startCursor endCursor }
+            } }"#,
+        )
+        .await;
+    assert!(result.errors.is_empty(), "Query should succeed: {:?}", result.errors);
+    let data = result.data.into_json().unwrap();
+    let connection = &data["listBooks"];
+    assert_eq!(connection["edges"][0]["cursor"], "1");
+    assert_eq!(connection["edges"][0]["node"]["title"], "Emma");
+    assert_eq!(connection["edges"][1]["node"]["title"], "Ulysses");
+    assert_eq!(
+        connection["pageInfo"],
+        serde_json::json!({
+            "hasNextPage": true,
+            "hasPreviousPage": true,
+            "startCursor": "1",
+            "endCursor": "2",
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_graphql_connection_rejects_negative_page_size() {
+    let schema = LibraryService.graphql_schema();
+    for query in [
+        "{ listBooks(first: -1) { edges { cursor } } }",
+        "{ listBooks(last: -2) { edges { cursor } } }",
+    ] {
+        let result = schema.execute(query).await;
+        assert!(
+            result.errors.iter().any(|e| e.message.contains("must not be negative")),
+            "{query} should be rejected: {:?}",
+            result.errors
+        );
+    }
+}
+
+// ============================================================================
+// User-defined scalars
+// ============================================================================
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+struct Money(i64);
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+struct Email(String);
+
+#[derive(Clone)]
+struct BillingService;
+
+#[graphql(scalars(Money, Email))]
+impl BillingService {
+    pub fn get_balance(&self, owner: Email) -> Money {
+        Money(owner.0.len() as i64 * 100)
+    }
+
+    pub fn list_charges(&self) -> Vec<Money> {
+        vec![Money(250), Money(-75)]
+    }
+
+    pub fn find_owner(&self, balance: Option<Money>) -> Option<Email> {
+        balance.map(|_| Email("ada@example.com".to_string()))
+    }
+}
+
+#[test]
+fn test_graphql_user_scalars_in_sdl() {
+    let sdl = BillingService.graphql_sdl();
+    assert!(sdl.contains("scalar Money"), "{sdl}");
+    assert!(sdl.contains("scalar Email"), "{sdl}");
+    assert!(sdl.contains("getBalance(owner: Email!): Money!"), "{sdl}");
+    assert!(sdl.contains("listCharges: [Money!]"), "{sdl}");
+    assert!(sdl.contains("findOwner(balance: Money): Email"), "{sdl}");
+}
+
+#[tokio::test]
+async fn test_graphql_user_scalars_round_trip_through_serde() {
+    let schema = BillingService.graphql_schema();
+    let result = schema
+        .execute(r#"{ getBalance(owner: "ada@example.com") listCharges findOwner(balance: 5) }"#)
+        .await;
+    assert!(result.errors.is_empty(), "Query should succeed: {:?}", result.errors);
+    let data = result.data.into_json().unwrap();
+    assert_eq!(data["getBalance"], 1500);
+    assert_eq!(data["listCharges"], serde_json::json!([250, -75]));
+    assert_eq!(data["findOwner"], "ada@example.com");
+}
+
+// ============================================================================
+// Subscriptions
+// ============================================================================
+
+#[derive(Clone)]
+struct TickerService;
+
+#[graphql]
+impl TickerService {
+    pub fn get_status(&self) -> String {
+        "ticking".to_string()
+    }
+
+    /// Count up from one
+    pub fn ticks(&self, count: i32) -> impl futures::Stream<Item = i32> + use<> {
+        futures::stream::iter(1..=count)
+    }
+
+    pub fn checked_ticks(&self) -> impl futures::Stream<Item = Result<i32, String>> + use<> {
+        futures::stream::iter(vec![Ok(1), Err("sensor offline".to_string())])
+    }
+}
+
+#[test]
+fn test_graphql_subscriptions_in_sdl() {
+    let sdl = TickerService.graphql_sdl();
+    assert!(sdl.contains("type TickerServiceSubscription"), "{sdl}");
+    assert!(sdl.contains("ticks(count: Int!): Int!"), "{sdl}");
+    assert!(
+        sdl.contains("subscription: TickerServiceSubscription"),
+        "{sdl}"
+    );
+    assert!(!sdl.contains("TickerServiceMutation"), "{sdl}");
+}
+
+#[tokio::test]
+async fn test_graphql_subscription_streams_items() {
+    use futures::StreamExt;
+
+    let schema = TickerService.graphql_schema();
+    let responses: Vec<_> = schema
+        .execute_stream("subscription { ticks(count: 3) }")
+        .collect()
+        .await;
+    let values: Vec<_> = responses
+        .into_iter()
+        .map(|r| r.data.into_json().unwrap()["ticks"].clone())
+        .collect();
+    assert_eq!(values, vec![1, 2, 3]);
+
+    let responses: Vec<_> = schema
+        .execute_stream("subscription { checkedTicks }")
+        .collect()
+        .await;
+    assert_eq!(
+        responses[0].data.clone().into_json().unwrap()["checkedTicks"],
+        1
+    );
+    assert_eq!(responses[1].errors[0].message, "sensor offline");
+}
+
+#[tokio::test]
+async fn test_graphql_transport_ws_subscription() {
+    use futures::{SinkExt, StreamExt};
+    use tokio_tungstenite::tungstenite::Message;
+    use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, TickerService.graphql_router())
+            .await
+            .unwrap();
+    });
+    let mut request = format!("ws://{addr}/graphql/ws")
+        .into_client_request()
+        .unwrap();
+    request.headers_mut().insert(
+        "sec-websocket-protocol",
+        "graphql-transport-ws".parse().unwrap(),
+    );
+    let (mut socket, response) = tokio_tungstenite::connect_async(request).await.unwrap();
+    assert_eq!(
+        response.headers()["sec-websocket-protocol"],
+        "graphql-transport-ws"
+    );
+
+    let next_json = async |socket: &mut tokio_tungstenite::WebSocketStream<_>| {
+        let message: Message = socket.next().await.unwrap().unwrap();
+        serde_json::from_str::<serde_json::Value>(message.to_text().unwrap()).unwrap()
+    };
+    let send = |value: serde_json::Value| Message::text(value.to_string());
+
+    socket
+        .send(send(serde_json::json!({"type": "connection_init"})))
+        .await
+        .unwrap();
+    assert_eq!(next_json(&mut socket).await["type"], "connection_ack");
+
+    socket
+        .send(send(serde_json::json!({
+            "id": "1",
+            "type": "subscribe",
+            "payload": {"query": "subscription { ticks(count: 2) }"}
+        })))
+        .await
+        .unwrap();
+    for expected in [1, 2] {
+        let message = next_json(&mut socket).await;
+        assert_eq!(message["type"], "next");
+        assert_eq!(message["id"], "1");
+        assert_eq!(message["payload"]["data"]["ticks"], expected);
+    }
+    let message = next_json(&mut socket).await;
+    assert_eq!(message["type"], "complete");
+    assert_eq!(message["id"], "1");
+}
+
+#[test]
+fn test_graphql_subscription_endpoint_in_openapi() {
+    let paths = TickerService::graphql_openapi_paths();
+    assert!(
+        paths
+            .iter()
+            .any(|p| p.path == "/graphql/ws" && p.method == "get")
+    );
+    assert!(
+        !BillingService::graphql_openapi_paths()
+            .iter()
+            .any(|p| p.path == "/graphql/ws")
+    );
+}
+
+// ============================================================================
+// Query limits
+// ============================================================================
+
+#[derive(Clone)]
+struct LimitedService;
+
+#[graphql(max_depth = 3, max_complexity = 5)]
+impl LimitedService {
+    pub fn get_status(&self) -> String {
+        "ok".to_string()
+    }
+}
+
+#[tokio::test]
+async fn test_graphql_depth_limit() {
+    let schema = LimitedService.graphql_schema();
+    let result = schema.execute("{ __schema { queryType { name } } }").await;
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+    let result = schema
+        .execute("{ __schema { queryType { fields { name } } } }")
+        .await;
+    assert_eq!(result.errors[0].message, "Query is nested too deep.");
+}
+
+#[tokio::test]
+async fn test_graphql_complexity_limit() {
+    let schema = LimitedService.graphql_schema();
+    let result = schema
+        .execute("{ a: getStatus b: getStatus c: getStatus d: getStatus e: getStatus }")
+        .await;
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+    let result = schema
+        .execute(
+            "{ a: getStatus b: getStatus c: getStatus d: getStatus e: getStatus f: getStatus }",
+        )
+        .await;
+    assert_eq!(result.errors[0].message, "Query is too complex.");
+}
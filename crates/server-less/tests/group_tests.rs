@@ -251,16 +251,13 @@ fn test_openapi_ungrouped_no_group_tag() {
     let spec = OpenApiGroupedService::openapi_spec();
     let paths = &spec["paths"];
 
-    let health = &paths["/healths"]["get"];
-    // Ungrouped method — should have no tags or empty tags
-    let tags = health.get("tags");
-    if let Some(tags) = tags {
-        let arr = tags.as_array().unwrap();
-        assert!(
-            arr.is_empty(),
-            "ungrouped method should have no tags: {arr:?}"
-        );
-    }
+    let health = &paths["/healths"]["post"];
+    // Ungrouped method — falls back to the service tag only
+    assert_eq!(
+        health["tags"],
+        serde_json::json!(["OpenApiGrouped"]),
+        "ungrouped method should only have the service tag"
+    );
 }
 
 #[test]
@@ -321,3 +321,288 @@ fn test_streaming_response_message() {
         proto
     );
 }
+
+// ============================================================================
+// File Options Tests
+// ============================================================================
+
+#[derive(Clone)]
+struct OptionsService;
+
+#[grpc(
+    package = "users.v1",
+    go_package = "example.com/gen/users/v1;usersv1",
+    java_package = "com.example.users.v1"
+)]
+impl OptionsService {
+    pub fn get_user(&self, id: String) -> String {
+        id
+    }
+}
+
+#[test]
+fn test_proto_file_options() {
+    let proto = OptionsService::grpc_schema();
+    assert!(proto.starts_with("syntax = \"proto3\";\n\npackage users.v1;\n\n"), "{proto}");
+    assert!(
+        proto.contains("option go_package = \"example.com/gen/users/v1;usersv1\";\n"),
+        "{proto}"
+    );
+    assert!(
+        proto.contains("option java_package = \"com.example.users.v1\";\n"),
+        "{proto}"
+    );
+}
+
+#[test]
+fn test_proto_without_options_has_no_option_lines() {
+    let proto = UserService::grpc_schema();
+    assert!(!proto.contains("option "), "{proto}");
+}
+
+#[derive(Clone)]
+struct LegacyService;
+
+#[grpc(package = "legacy", syntax = "proto2")]
+impl LegacyService {
+    pub fn find_user(&self, name: String, limit: Option<u32>, tags: Vec<String>) -> Option<String> {
+        None
+    }
+}
+
+#[test]
+fn test_proto2_syntax_labels_fields() {
+    let proto = LegacyService::grpc_schema();
+    assert!(proto.starts_with("syntax = \"proto2\";"), "{proto}");
+    assert!(proto.contains("required string name = 1;"), "{proto}");
+    assert!(proto.contains("optional uint32 limit = 2;"), "{proto}");
+    assert!(proto.contains("  repeated string tags = 3;"), "{proto}");
+    assert!(proto.contains("optional string result = 1;"), "{proto}");
+}
+
+// ============================================================================
+// Well-Known Types Tests
+// ============================================================================
+
+use std::time::{Duration, SystemTime};
+
+#[derive(Clone)]
+struct SessionService;
+
+#[grpc(package = "sessions.v1")]
+impl SessionService {
+    pub fn get_expiry(&self, id: String, grace: Option<Duration>) -> SystemTime {
+        SystemTime::UNIX_EPOCH
+    }
+
+    pub fn find_started(&self, id: String) -> Option<SystemTime> {
+        None
+    }
+
+    pub fn delete_session(&self, id: String) {}
+}
+
+#[test]
+fn test_proto_well_known_types() {
+    let proto = SessionService::grpc_schema();
+    assert!(proto.contains("optional google.protobuf.Duration grace = 2;"), "{proto}");
+    assert!(proto.contains("  google.protobuf.Timestamp result = 1;"), "{proto}");
+    assert!(proto.contains("optional google.protobuf.Timestamp result = 1;"), "{proto}");
+}
+
+#[test]
+fn test_proto_unit_return_is_empty() {
+    let proto = SessionService::grpc_schema();
+    assert!(
+        proto.contains("rpc DeleteSession(DeleteSessionRequest) returns (google.protobuf.Empty);"),
+        "{proto}"
+    );
+    assert!(!proto.contains("message DeleteSessionResponse"), "{proto}");
+}
+
+#[test]
+fn test_proto_well_known_imports() {
+    let proto = SessionService::grpc_schema();
+    assert!(
+        proto.contains(
+            "package sessions.v1;\n\n\
+             import \"google/protobuf/duration.proto\";\n\
+             import \"google/protobuf/empty.proto\";\n\
+             import \"google/protobuf/timestamp.proto\";\n\n\
+             service SessionService"
+        ),
+        "{proto}"
+    );
+    assert!(!UserService::grpc_schema().contains("import "));
+}
+
+// ============================================================================
+// Split Files Tests
+// ============================================================================
+
+#[derive(Clone)]
+struct AccountService;
+
+#[grpc(package = "shop.v1", split = true)]
+impl AccountService {
+    pub fn get_account(&self, id: String) -> String {
+        id
+    }
+
+    pub fn close_account(&self, id: String) {}
+}
+
+#[derive(Clone)]
+struct OrderService;
+
+#[grpc(package = "shop.v1", split = true)]
+impl OrderService {
+    pub fn get_order(&self, id: String, placed_after: Option<SystemTime>) -> String {
+        id
+    }
+}
+
+#[test]
+fn test_proto_split_service_schema() {
+    let service = AccountService::grpc_service_schema();
+    assert!(
+        service.contains(
+            "import \"common.proto\";\nimport \"google/protobuf/empty.proto\";\n\nservice AccountService {"
+        ),
+        "{service}"
+    );
+    assert!(!service.contains("message "), "{service}");
+
+    let common = AccountService::grpc_common_schema();
+    assert!(common.contains("package shop.v1;"), "{common}");
+    assert!(common.contains("message GetAccountRequest"), "{common}");
+    assert!(!common.contains("service "), "{common}");
+    assert!(!common.contains("import "), "{common}");
+    assert_eq!(server_less::merge_common_proto("", common).unwrap(), common);
+}
+
+#[test]
+fn test_proto_write_dir_shares_common_file() {
+    let dir = tempfile::tempdir().unwrap();
+    AccountService::write_grpc_dir(dir.path()).unwrap();
+    OrderService::write_grpc_dir(dir.path()).unwrap();
+    // Rewriting a service replaces its messages instead of duplicating them.
+    AccountService::write_grpc_dir(dir.path()).unwrap();
+
+    let account = std::fs::read_to_string(dir.path().join("account_service.proto")).unwrap();
+    assert_eq!(account, AccountService::grpc_service_schema());
+    assert!(dir.path().join("order_service.proto").exists());
+
+    let common = std::fs::read_to_string(dir.path().join("common.proto")).unwrap();
+    assert_eq!(common.matches("message GetAccountRequest").count(), 1, "{common}");
+    assert!(common.contains("message GetOrderRequest"), "{common}");
+    assert!(
+        common.contains("import \"google/protobuf/timestamp.proto\";"),
+        "{common}"
+    );
+}
+
+#[derive(Clone)]
+struct BillingService;
+
+#[grpc(package = "billing.v1", split = true)]
+impl BillingService {
+    pub fn get_invoice(&self, id: String) -> String {
+        id
+    }
+}
+
+#[test]
+fn test_proto_write_dir_rejects_other_package() {
+    let dir = tempfile::tempdir().unwrap();
+    AccountService::write_grpc_dir(dir.path()).unwrap();
+    let err = BillingService::write_grpc_dir(dir.path()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+// ============================================================================
+// HTTP Annotation Tests
+// ============================================================================
+
+// `route` is consumed by #[http]; the import looks unused after stripping.
+#[allow(unused_imports)]
+use server_less::{http, route};
+
+#[derive(Clone)]
+struct CatalogService;
+
+#[grpc(package = "catalog.v1", http_annotations = true)]
+#[http(prefix = "/v1", visibility = "pub")]
+impl CatalogService {
+    pub fn get_product(&self, id: u32) -> String {
+        id.to_string()
+    }
+
+    pub fn create_product(&self, name: String) -> String {
+        name
+    }
+
+    #[route(path = "/products/{id}/archive")]
+    pub fn archive_product(&self, id: u32) {}
+
+    #[route(skip)]
+    pub fn rebuild_index(&self) {}
+
+    // Not `pub`, so `#[http(visibility = "pub")]` serves no route for it.
+    #[allow(dead_code)]
+    fn compact_index(&self) {}
+}
+
+#[test]
+fn test_proto_http_annotations_follow_http_routes() {
+    let schema = CatalogService::grpc_schema();
+    assert!(
+        schema.contains("import \"google/api/annotations.proto\";"),
+        "{schema}"
+    );
+    assert!(
+        schema.contains(
+            "  rpc GetProduct(GetProductRequest) returns (GetProductResponse) {\n    \
+             option (google.api.http) = {\n      get: \"/v1/products/{id}\"\n    };\n  }"
+        ),
+        "{schema}"
+    );
+    assert!(
+        schema.contains("      post: \"/v1/products\"\n      body: \"*\"\n"),
+        "{schema}"
+    );
+    assert!(
+        schema.contains("      post: \"/v1/products/{id}/archive\"\n      body: \"*\"\n"),
+        "{schema}"
+    );
+    assert!(
+        schema.contains("  rpc RebuildIndex(RebuildIndexRequest) returns (google.protobuf.Empty);"),
+        "{schema}"
+    );
+    assert!(
+        schema.contains("  rpc CompactIndex(CompactIndexRequest) returns (google.protobuf.Empty);"),
+        "{schema}"
+    );
+}
+
+#[test]
+fn test_http_below_grpc_still_serves_routes() {
+    let paths = CatalogService::http_openapi_paths();
+    assert!(
+        paths
+            .iter()
+            .any(|p| p.method == "get" && p.path == "/v1/products/{id}")
+    );
+    assert!(
+        paths
+            .iter()
+            .any(|p| p.method == "post" && p.path == "/v1/products/{id}/archive")
+    );
+}
+
+#[test]
+fn test_proto_without_http_annotations_has_plain_rpcs() {
+    let schema = UserService::grpc_schema();
+    assert!(!schema.contains("google.api.http"), "{schema}");
+    assert!(!schema.contains("annotations.proto"), "{schema}");
+}
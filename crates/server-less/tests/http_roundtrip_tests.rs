@@ -222,8 +222,8 @@ async fn test_post_create_validation_error() {
     assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 
     let body: serde_json::Value = body_json(response).await;
-    assert!(body.get("error").is_some());
-    assert!(body.get("message").is_some());
+    assert_eq!(body["error"]["code"], "INVALID_INPUT");
+    assert!(body["error"]["message"].is_string());
 }
 
 // ============================================================================
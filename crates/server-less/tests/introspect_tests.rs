@@ -0,0 +1,118 @@
+//! Integration tests for the `service_methods()` runtime metadata table.
+
+#![allow(dead_code)]
+#![allow(unused_variables)]
+
+use server_less::{cli, http, introspect, jsonschema, mcp};
+
+#[derive(Clone)]
+struct UserService;
+
+// Stacked with protocol macros: the table covers the same methods they parse.
+#[introspect]
+#[cli(name = "users")]
+#[http]
+#[mcp]
+impl UserService {
+    /// Get user by ID
+    pub fn get_user(&self, user_id: String) -> Option<String> {
+        Some(user_id)
+    }
+
+    /// List users, optionally filtered
+    pub async fn list_users(&self, tag: String, limit: Option<u32>) -> Vec<String> {
+        vec![]
+    }
+
+    /// Delete a user
+    pub fn delete_user(&self, user_id: String) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_service_methods_lists_exposed_methods() {
+    let names: Vec<&str> = UserService::service_methods()
+        .iter()
+        .map(|m| m.name.as_str())
+        .collect();
+    assert_eq!(names, ["get_user", "list_users", "delete_user"]);
+}
+
+#[test]
+fn test_service_methods_describe_signature() {
+    let methods = UserService::service_methods();
+
+    let get = &methods[0];
+    assert_eq!(get.docs.as_deref(), Some("Get user by ID"));
+    assert_eq!(get.return_type, "Option<String>");
+    assert!(get.is_optional);
+    assert!(!get.is_async);
+    assert_eq!(get.params.len(), 1);
+    assert_eq!(get.params[0].name, "user_id");
+    assert_eq!(get.params[0].ty, "String");
+    assert!(get.params[0].is_id);
+
+    let list = &methods[1];
+    assert!(list.is_async);
+    assert_eq!(list.return_type, "Vec<String>");
+    assert!(!list.params[0].is_optional);
+    assert_eq!(list.params[1].ty, "Option<u32>");
+    assert!(list.params[1].is_optional);
+
+    let delete = &methods[2];
+    assert!(delete.is_result);
+    assert_eq!(delete.return_type, "Result<(), String>");
+}
+
+#[test]
+fn test_service_methods_is_cached() {
+    assert!(std::ptr::eq(
+        UserService::service_methods(),
+        UserService::service_methods()
+    ));
+}
+
+#[derive(Clone)]
+struct SchemaOnly;
+
+#[jsonschema]
+#[introspect]
+impl SchemaOnly {
+    pub fn ping(&self) -> String {
+        "pong".to_string()
+    }
+}
+
+#[test]
+fn test_service_methods_below_protocol_macro() {
+    let methods = SchemaOnly::service_methods();
+    assert_eq!(methods.len(), 1);
+    assert_eq!(methods[0].name, "ping");
+    assert_eq!(methods[0].docs, None);
+    assert!(methods[0].params.is_empty());
+}
+
+// Without `#[introspect]` nothing is generated, so a second annotated impl block
+// and a user method named `service_methods` both compile.
+#[derive(Clone)]
+struct PlainService;
+
+#[http]
+impl PlainService {
+    pub fn service_methods(&self) -> Vec<String> {
+        vec!["ping".to_string()]
+    }
+}
+
+#[mcp]
+impl PlainService {
+    pub fn ping(&self) -> String {
+        "pong".to_string()
+    }
+}
+
+#[test]
+fn test_service_methods_is_opt_in() {
+    assert_eq!(PlainService.service_methods(), ["ping"]);
+}
@@ -72,6 +72,30 @@ async fn test_jsonrpc_handle_subtract() {
     assert_eq!(response["result"], 6);
 }
 
+#[tokio::test]
+async fn test_jsonrpc_positional_params() {
+    let calc = Calculator;
+    let response = calc
+        .jsonrpc_handle_async(json!({
+            "jsonrpc": "2.0",
+            "method": "subtract",
+            "params": [10, 4],
+            "id": 3
+        }))
+        .await;
+    assert_eq!(response["result"], 6);
+
+    let response = calc
+        .jsonrpc_handle_async(json!({
+            "jsonrpc": "2.0",
+            "method": "subtract",
+            "params": [10],
+            "id": 4
+        }))
+        .await;
+    assert_eq!(response["error"]["code"], -32602);
+}
+
 #[tokio::test]
 async fn test_jsonrpc_handle_string_params() {
     let calc = Calculator;
@@ -407,6 +431,21 @@ async fn test_jsonrpc_slug_mount_dispatch() {
     assert_eq!(response["result"], 7);
 }
 
+#[tokio::test]
+async fn test_jsonrpc_slug_mount_positional_params() {
+    let app = JsonRpcSlugApp { math: MathTools };
+
+    let response = app
+        .jsonrpc_handle_async(json!({
+            "jsonrpc": "2.0",
+            "method": "calc.add",
+            "params": ["calc-1", 3, 4],
+            "id": 1
+        }))
+        .await;
+    assert_eq!(response["result"], 7);
+}
+
 /// JsonRpcMount trait test
 #[test]
 fn test_jsonrpc_mount_trait_implemented() {
@@ -849,3 +888,25 @@ fn test_jsonrpc_hidden_method_absent_from_openapi_paths() {
     assert!(body_str.contains("public_method"), "public_method must be in OpenRPC body");
     assert!(!body_str.contains("hidden_method"), "hidden_method must not be in OpenRPC body");
 }
+
+#[derive(Clone)]
+struct WideCalculator;
+
+#[jsonrpc]
+impl WideCalculator {
+    pub fn double(&self, value: u128) -> u128 {
+        value * 2
+    }
+}
+
+#[tokio::test]
+async fn test_jsonrpc_wide_integers_are_decimal_strings() {
+    let request = json!({
+        "jsonrpc": "2.0",
+        "method": "double",
+        "params": {"value": "100000000000000000000000"},
+        "id": 1
+    });
+    let response = WideCalculator.jsonrpc_handle_async(request).await;
+    assert_eq!(response["result"], "200000000000000000000000");
+}
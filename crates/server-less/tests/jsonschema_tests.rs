@@ -255,3 +255,61 @@ fn test_jsonschema_empty_response() {
     let props = resp["properties"].as_object().unwrap();
     assert!(props.is_empty(), "Unit return should have empty properties");
 }
+
+// ============================================================================
+// Per-method request/response schemas
+// ============================================================================
+
+#[derive(Clone)]
+struct ContractService;
+
+#[jsonschema(per_method = true)]
+impl ContractService {
+    /// Create a user
+    pub fn create_user(&self, name: String, age: Option<i32>) -> String {
+        name
+    }
+
+    /// Ping the service
+    pub fn ping(&self) {}
+}
+
+#[test]
+fn test_jsonschema_per_method_keys() {
+    let methods = ContractService::json_schema_methods();
+    let keys: Vec<_> = methods.as_object().unwrap().keys().cloned().collect();
+    assert_eq!(keys.len(), 2);
+    assert!(methods["createUser"]["request"].is_object());
+    assert!(methods["createUser"]["response"].is_object());
+    assert!(methods["ping"]["request"].is_object());
+}
+
+#[test]
+fn test_jsonschema_per_method_request() {
+    let methods = ContractService::json_schema_methods();
+    let request = &methods["createUser"]["request"];
+    assert_eq!(request["properties"]["name"]["type"], "string");
+    assert!(request["properties"]["age"]["anyOf"].is_array());
+    assert_eq!(request["required"], serde_json::json!(["name"]));
+}
+
+#[test]
+fn test_jsonschema_per_method_response() {
+    let methods = ContractService::json_schema_methods();
+    let response = &methods["createUser"]["response"];
+    assert_eq!(response["properties"]["result"]["type"], "string");
+    assert_eq!(response["required"], serde_json::json!(["result"]));
+
+    let unit = &methods["ping"]["response"];
+    assert!(unit["properties"].as_object().unwrap().is_empty());
+}
+
+#[test]
+fn test_jsonschema_per_method_matches_definitions() {
+    let schema = ContractService::json_schema();
+    let methods = ContractService::json_schema_methods();
+    assert_eq!(
+        schema["definitions"]["Create_userRequest"],
+        methods["createUser"]["request"]
+    );
+}
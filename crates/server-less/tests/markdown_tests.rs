@@ -3,7 +3,7 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use server_less::markdown;
+use server_less::{example, http, jsonrpc, markdown, mcp, server, ws};
 
 #[derive(Clone)]
 struct UserService;
@@ -170,3 +170,221 @@ fn test_markdown_return_descriptions() {
     assert!(docs.contains("Boolean"));
     assert!(docs.contains("Integer"));
 }
+
+// ============================================================================
+// Schema tables
+// ============================================================================
+
+#[derive(Clone)]
+struct CatalogService;
+
+#[markdown(include_schemas = true)]
+impl CatalogService {
+    /// Add a product
+    pub fn create_product(
+        &self,
+        name: String,
+        tags: Vec<String>,
+        price: Option<f64>,
+    ) -> Result<u64, String> {
+        Ok(1)
+    }
+
+    /// Remove a product
+    pub fn delete_product(&self, id: u64) {}
+}
+
+#[test]
+fn test_markdown_operation_anchor() {
+    let docs = CatalogService::markdown_docs();
+    assert!(docs.contains("<a id=\"create_product\"></a>"));
+    assert!(docs.contains("**Operation ID:** `create_product`"));
+}
+
+#[test]
+fn test_markdown_request_schema_table() {
+    let docs = CatalogService::markdown_docs();
+    assert!(docs.contains("| Field | Type | Required | Description |"));
+    assert!(docs.contains("| `name` | string | yes |  |"));
+    assert!(docs.contains("| `tags` | array of string | yes |  |"));
+    assert!(docs.contains("| `price` | number | no |  |"));
+}
+
+#[test]
+fn test_markdown_response_schema_table() {
+    let docs = CatalogService::markdown_docs();
+    assert!(docs.contains("| 200 | Successful response | integer |"));
+    assert!(docs.contains("| 400 | Bad request | — |"));
+    assert!(docs.contains("| 204 | Successful response | — |"));
+}
+
+#[derive(Debug, server_less::ServerlessError)]
+enum StockError {
+    /// No product with that id
+    ProductNotFound,
+    /// Already reserved
+    #[error(code = Conflict)]
+    AlreadyReserved,
+}
+
+#[derive(Clone)]
+struct StockService;
+
+#[markdown(include_schemas = true)]
+#[http]
+impl StockService {
+    /// Reserve stock for checkout
+    #[route(operation_id = "reserveStock")]
+    pub fn reserve(
+        &self,
+        #[param(help = "SKU | barcode")] sku: String,
+    ) -> Result<u32, StockError> {
+        Ok(1)
+    }
+}
+
+#[test]
+fn test_markdown_anchor_uses_route_operation_id() {
+    let docs = StockService::markdown_docs();
+    assert!(docs.contains("<a id=\"reserveStock\"></a>"), "{docs}");
+    assert!(docs.contains("**Operation ID:** `reserveStock`"), "{docs}");
+}
+
+#[test]
+fn test_markdown_error_rows_follow_error_variants() {
+    let docs = StockService::markdown_docs();
+    assert!(docs.contains("| 404 | ProductNotFound | — |"), "{docs}");
+    assert!(docs.contains("| 409 | AlreadyReserved | — |"), "{docs}");
+    assert!(!docs.contains("| 400 |"), "{docs}");
+    assert!(!docs.contains("| 500 |"), "{docs}");
+}
+
+#[test]
+fn test_markdown_escapes_pipes_in_cells() {
+    let docs = StockService::markdown_docs();
+    assert!(docs.contains("| `sku` | string | yes | SKU \\| barcode |"), "{docs}");
+}
+
+#[test]
+fn test_markdown_schemas_off_by_default() {
+    let docs = UserService::markdown_docs();
+    assert!(!docs.contains("**Request schema:**"));
+    assert!(!docs.contains("<a id="));
+}
+
+// ============================================================================
+// Examples
+// ============================================================================
+
+#[derive(Clone)]
+struct GreetService;
+
+#[markdown]
+impl GreetService {
+    /// Greet someone
+    #[example(request = r#"{"name": "Ada"}"#, response = r#""Hello, Ada""#)]
+    pub fn greet(&self, name: String) -> String {
+        format!("Hello, {name}")
+    }
+}
+
+#[test]
+fn test_markdown_examples() {
+    let docs = GreetService::markdown_docs();
+    assert!(docs.contains("**Example:**"));
+    assert!(docs.contains("Request:\n\n```json\n{\"name\": \"Ada\"}\n```"));
+    assert!(docs.contains("Response:\n\n```json\n\"Hello, Ada\"\n```"));
+}
+
+// ============================================================================
+// Protocol Section Tests
+// ============================================================================
+
+#[derive(Clone)]
+struct PolyglotService;
+
+#[markdown(protocols = "http, ws, jsonrpc, mcp")]
+#[http(prefix = "/api")]
+#[mcp(namespace = "notes")]
+#[jsonrpc]
+#[ws(path = "/socket")]
+impl PolyglotService {
+    /// Fetch one note
+    pub fn get_note(&self, id: u32) -> Option<String> {
+        None
+    }
+
+    /// Create a note
+    pub fn create_note(&self, title: String) -> String {
+        title
+    }
+}
+
+#[test]
+fn test_markdown_http_routes_section() {
+    let docs = PolyglotService::markdown_docs();
+    assert!(docs.contains("## HTTP Routes\n\n| Method | Path | Handler |"));
+    assert!(docs.contains("| GET | `/api/notes/{id}` | `get_note` |"));
+    assert!(docs.contains("| POST | `/api/notes` | `create_note` |"));
+}
+
+#[test]
+fn test_markdown_rpc_sections() {
+    let docs = PolyglotService::markdown_docs();
+    assert!(docs.contains("## WebSocket Methods\n\nConnect to `/socket`"));
+    assert!(docs.contains("## JSON-RPC Methods\n\nPOST JSON-RPC 2.0 requests to `/rpc`."));
+    assert!(docs.contains("| `get_note` | Fetch one note |"));
+    assert!(docs.contains("## MCP Tools\n\n| Tool | Description |"));
+    assert!(docs.contains("| `notes_create_note` | Create a note |"));
+}
+
+#[derive(Clone)]
+struct DeskService;
+
+#[markdown(protocols = "http, jsonrpc, mcp")]
+#[http(visibility = "pub")]
+#[mcp(namespace = "desk")]
+#[jsonrpc]
+impl DeskService {
+    /// Look up a ticket
+    #[server(name = "lookup")]
+    pub fn get_ticket(&self, id: u32) -> u32 {
+        id
+    }
+
+    /// Close every ticket
+    fn close_tickets(&self) {}
+}
+
+#[test]
+fn test_markdown_protocol_sections_use_protocol_names() {
+    let docs = DeskService::markdown_docs();
+    // `#[http(visibility = "pub")]` serves no route for the private method.
+    assert!(docs.contains("| GET | `/tickets/{id}` | `get_ticket` |"), "{docs}");
+    assert!(!docs.contains("`close_tickets` |\n"), "{docs}");
+    // JSON-RPC dispatches on the `#[server(name)]`, MCP on the method name.
+    assert!(docs.contains("| `lookup` | Look up a ticket |"), "{docs}");
+    assert!(docs.contains("| `desk_get_ticket` | Look up a ticket |"), "{docs}");
+    assert!(docs.contains("| `desk_close_tickets` | Close every ticket |"), "{docs}");
+    assert_eq!(
+        DeskService.mcp_call("desk_get_ticket", serde_json::json!({"id": 3})),
+        Ok(serde_json::json!(3))
+    );
+}
+
+#[test]
+fn test_markdown_protocol_sections_follow_listed_order() {
+    let docs = PolyglotService::markdown_docs();
+    let http = docs.find("## HTTP Routes").unwrap();
+    let ws = docs.find("## WebSocket Methods").unwrap();
+    let mcp = docs.find("## MCP Tools").unwrap();
+    assert!(docs.find("## Methods").unwrap() < http);
+    assert!(http < ws && ws < mcp);
+}
+
+#[test]
+fn test_markdown_no_protocol_sections_by_default() {
+    let docs = UserService::markdown_docs();
+    assert!(!docs.contains("## HTTP Routes"));
+    assert!(!docs.contains("## MCP Tools"));
+}
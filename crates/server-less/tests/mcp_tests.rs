@@ -960,3 +960,71 @@ async fn test_mcp_unknown_param_async_does_not_break_dispatch() {
     );
     assert_eq!(result.unwrap(), serde_json::json!("Hello, Bob!"));
 }
+
+// ============================================================================
+// Tool annotations
+// ============================================================================
+
+#[derive(Clone)]
+struct FileTools;
+
+#[mcp]
+impl FileTools {
+    pub fn list_files(&self) -> Vec<String> {
+        vec!["a.txt".to_string()]
+    }
+
+    pub fn delete_file(&self, path: String) -> bool {
+        true
+    }
+
+    pub fn write_file(&self, path: String, contents: String) {}
+
+    #[mcp_tool(destructive, idempotent)]
+    pub fn truncate_file(&self, path: String) -> String {
+        format!("truncated {path}")
+    }
+
+    /// An explicit attribute replaces the hints inferred from `get_`.
+    #[mcp_tool(open_world = false)]
+    pub fn get_cached_page(&self, url: String) -> String {
+        url
+    }
+}
+
+fn tool_annotations(name: &str) -> Option<serde_json::Value> {
+    FileTools::mcp_tools()
+        .into_iter()
+        .find(|t| t["name"] == name)
+        .expect("tool should exist")
+        .get("annotations")
+        .cloned()
+}
+
+#[test]
+fn test_mcp_annotations_inferred_from_name() {
+    assert_eq!(
+        tool_annotations("list_files"),
+        Some(serde_json::json!({"readOnlyHint": true}))
+    );
+    assert_eq!(
+        tool_annotations("delete_file"),
+        Some(serde_json::json!({"destructiveHint": true}))
+    );
+    assert_eq!(tool_annotations("write_file"), None);
+}
+
+#[test]
+fn test_mcp_tool_attribute_sets_annotations() {
+    assert_eq!(
+        tool_annotations("truncate_file"),
+        Some(serde_json::json!({"destructiveHint": true, "idempotentHint": true}))
+    );
+    assert_eq!(
+        tool_annotations("get_cached_page"),
+        Some(serde_json::json!({"openWorldHint": false}))
+    );
+
+    let result = FileTools.mcp_call("truncate_file", serde_json::json!({"path": "a.txt"}));
+    assert_eq!(result.unwrap(), serde_json::json!("truncated a.txt"));
+}
@@ -37,6 +37,15 @@ fn test_openapi_standalone_generates_spec() {
     assert_eq!(spec["info"]["title"], "StandaloneService");
 }
 
+#[test]
+fn test_openapi_spec_is_stable_across_calls() {
+    // The first call builds and caches the spec; later calls return the same value.
+    assert_eq!(
+        StandaloneService::openapi_spec(),
+        StandaloneService::openapi_spec()
+    );
+}
+
 #[test]
 fn test_openapi_standalone_has_paths() {
     let spec = StandaloneService::openapi_spec();
@@ -262,6 +271,14 @@ impl EnhancedAttrsService {
         name
     }
 
+    /// Delete a user
+    ///
+    /// This text is replaced by the route description.
+    #[route(description = "Removes the user and all of their sessions.")]
+    pub fn delete_user(&self, id: String) -> String {
+        id
+    }
+
     /// Hidden endpoint
     #[route(hidden)]
     pub fn internal_method(&self) -> String {
@@ -292,6 +309,23 @@ fn test_openapi_tags_attribute() {
     );
 }
 
+#[test]
+fn test_openapi_default_service_tag() {
+    let spec = EnhancedAttrsService::openapi_spec();
+    let paths = &spec["paths"];
+
+    // Untagged operations are tagged with the struct name minus `Service`
+    assert_eq!(
+        paths["/api/users/{id}"]["delete"]["tags"],
+        serde_json::json!(["EnhancedAttrs"])
+    );
+    // Explicit tags replace the default
+    assert_eq!(
+        paths["/api/users/{id}"]["get"]["tags"],
+        serde_json::json!(["users", "public"])
+    );
+}
+
 #[test]
 fn test_openapi_deprecated_attribute() {
     let spec = EnhancedAttrsService::openapi_spec();
@@ -321,21 +355,35 @@ fn test_openapi_description_from_doc_comment() {
         "Summary should be first line of doc comment"
     );
 
-    // Description should be the full doc comment
+    // Description should be the rest of the doc comment, without the summary
     let description = get_user["description"].as_str();
     assert!(
         description.is_some(),
         "Should have description from doc comment"
     );
-    assert!(
-        description
-            .unwrap()
-            .contains("Fetch a user by their unique ID"),
-        "Description should contain full doc text. Got: {:?}",
-        description
+    assert_eq!(
+        description.unwrap(),
+        "Fetch a user by their unique ID from the database.\n\
+         Returns the user's data as a JSON string.",
     );
 }
 
+#[test]
+fn test_openapi_single_line_doc_has_no_description() {
+    let spec = EnhancedAttrsService::openapi_spec();
+    let create_user = &spec["paths"]["/api/users"]["post"];
+    assert_eq!(create_user["summary"], "Create user (deprecated)");
+    assert!(create_user.get("description").is_none());
+}
+
+#[test]
+fn test_openapi_route_description_overrides_doc_comment() {
+    let spec = EnhancedAttrsService::openapi_spec();
+    let delete_user = &spec["paths"]["/api/users/{id}"]["delete"];
+    assert_eq!(delete_user["summary"], "Delete a user");
+    assert_eq!(delete_user["description"], "Removes the user and all of their sessions.");
+}
+
 #[test]
 fn test_openapi_response_description_attribute() {
     let spec = EnhancedAttrsService::openapi_spec();
@@ -452,3 +500,373 @@ fn test_param_help_in_standalone_openapi_optional_query_param() {
         "#[param(help = \"...\")] should populate the description for optional query params in standalone mode"
     );
 }
+
+// ============================================================================
+// ServerlessEnum parameters
+// ============================================================================
+
+#[derive(Clone, Copy, serde::Serialize, serde::Deserialize, server_less::ServerlessEnum)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    Active,
+    Inactive,
+    #[serde(rename = "on-hold")]
+    OnHold,
+    #[serde(skip)]
+    Internal,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Filter {
+    text: String,
+}
+
+#[derive(Clone)]
+struct EnumParamService;
+
+#[openapi(prefix = "/api")]
+impl EnumParamService {
+    /// List users by status
+    pub fn list_users(&self, status: Option<Status>, filter: Filter) -> Vec<String> {
+        let _ = (status, filter);
+        Vec::new()
+    }
+
+    /// Create a user
+    pub fn create_user(&self, name: String, status: Status) -> String {
+        let _ = status;
+        name
+    }
+}
+
+#[test]
+fn test_serverless_enum_variants_follow_serde() {
+    use server_less::ServerlessEnum;
+    assert_eq!(Status::VARIANTS, &["active", "inactive", "on-hold"]);
+}
+
+#[test]
+fn test_enum_query_param_lists_variants() {
+    let spec = EnumParamService::openapi_spec();
+    let params = spec["paths"]["/api/users"]["get"]["parameters"]
+        .as_array()
+        .expect("Should have parameters array");
+    let status = params.iter().find(|p| p["name"] == "status").unwrap();
+    assert_eq!(
+        status["schema"],
+        serde_json::json!({"type": "string", "enum": ["active", "inactive", "on-hold"]})
+    );
+    assert_eq!(status["required"], false);
+
+    // Structs without the derive keep the plain object schema.
+    let filter = params.iter().find(|p| p["name"] == "filter").unwrap();
+    assert_eq!(filter["schema"], serde_json::json!({"type": "object"}));
+}
+
+#[test]
+fn test_enum_body_property_lists_variants() {
+    let spec = EnumParamService::openapi_spec();
+    let schema =
+        &spec["paths"]["/api/users"]["post"]["requestBody"]["content"]["application/json"]["schema"];
+    assert_eq!(
+        schema["properties"]["status"]["enum"],
+        serde_json::json!(["active", "inactive", "on-hold"])
+    );
+}
+
+#[derive(Clone)]
+struct BoundedService;
+
+#[openapi]
+impl BoundedService {
+    /// Search items
+    pub fn search_items(
+        &self,
+        #[param(pattern = "^[a-z]+$")] q: String,
+        #[param(max = 50)] limit: Option<u32>,
+    ) -> Vec<String> {
+        let _ = (q, limit);
+        Vec::new()
+    }
+}
+
+#[test]
+fn test_param_constraints_in_standalone_openapi() {
+    let spec = BoundedService::openapi_spec();
+    let params = spec["paths"]["/items"]["get"]["parameters"].as_array().unwrap();
+    assert_eq!(params[0]["schema"], serde_json::json!({"type": "string", "pattern": "^[a-z]+$"}));
+    assert_eq!(params[1]["schema"], serde_json::json!({"type": "integer", "maximum": 50}));
+}
+
+#[derive(Clone)]
+struct DefaultedService;
+
+#[openapi]
+impl DefaultedService {
+    /// List items
+    pub fn list_items(
+        &self,
+        #[param(default = 10)] limit: u32,
+        #[param(default = "name")] sort: String,
+        #[param(default = false)] archived: bool,
+    ) -> Vec<String> {
+        let _ = (limit, sort, archived);
+        Vec::new()
+    }
+}
+
+#[test]
+fn test_param_default_in_openapi_schema() {
+    let spec = DefaultedService::openapi_spec();
+    let params = spec["paths"]["/items"]["get"]["parameters"].as_array().unwrap();
+    assert_eq!(params[0]["schema"], serde_json::json!({"type": "integer", "default": 10}));
+    assert_eq!(params[0]["required"], false);
+    assert_eq!(params[1]["schema"]["default"], "name");
+    assert_eq!(params[2]["schema"]["default"], false);
+}
+
+#[derive(Clone)]
+struct TaggedService;
+
+#[openapi]
+impl TaggedService {
+    /// Create a post
+    pub fn create_post(&self, title: String, tags: Option<Vec<String>>) -> String {
+        let _ = tags;
+        title
+    }
+}
+
+#[test]
+fn test_optional_vec_body_property() {
+    let spec = TaggedService::openapi_spec();
+    let schema =
+        &spec["paths"]["/posts"]["post"]["requestBody"]["content"]["application/json"]["schema"];
+    assert_eq!(
+        schema["properties"]["tags"],
+        serde_json::json!({"type": "array", "items": {"type": "string"}})
+    );
+    assert_eq!(schema["required"], serde_json::json!(["title"]));
+}
+
+// ============================================================================
+// ServerlessSchema structs
+// ============================================================================
+
+#[derive(serde::Serialize, serde::Deserialize, server_less::ServerlessSchema)]
+#[serde(rename_all = "camelCase")]
+struct Profile {
+    /// Name shown to other users
+    display_name: String,
+    #[serde(rename = "mail")]
+    email_address: Option<String>,
+    #[serde(default)]
+    status_list: Vec<Status>,
+    #[serde(skip)]
+    cached: bool,
+    manager: Option<Box<Profile>>,
+}
+
+#[derive(Clone)]
+struct ProfileService;
+
+#[openapi]
+impl ProfileService {
+    /// Update a profile
+    pub fn update_profile(&self, profile: Profile) -> String {
+        profile.display_name
+    }
+}
+
+#[test]
+fn test_serverless_schema_uses_serde_names() {
+    use server_less::ServerlessSchema;
+    assert_eq!(
+        Profile::json_schema(),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "displayName": {"type": "string", "description": "Name shown to other users"},
+                "mail": {"type": "string"},
+                "statusList": {
+                    "type": "array",
+                    "items": {"type": "string", "enum": ["active", "inactive", "on-hold"]}
+                },
+                "manager": {"type": "object"}
+            },
+            "required": ["displayName"]
+        })
+    );
+}
+
+#[derive(serde::Serialize, serde::Deserialize, server_less::ServerlessSchema)]
+struct Ledger {
+    total: u128,
+    entries: Vec<i128>,
+}
+
+#[test]
+fn test_serverless_schema_wide_fields_match_serde() {
+    use server_less::ServerlessSchema;
+    // Struct fields are serialized by serde, not as decimal strings.
+    let schema = Ledger::json_schema();
+    assert_eq!(schema["properties"]["total"], serde_json::json!({"type": "integer"}));
+    assert_eq!(
+        schema["properties"]["entries"]["items"],
+        serde_json::json!({"type": "integer"})
+    );
+    let value = serde_json::to_value(Ledger { total: 1, entries: vec![-2] }).unwrap();
+    assert!(value["total"].is_number() && value["entries"][0].is_number());
+}
+
+#[derive(serde::Serialize, serde::Deserialize, server_less::ServerlessSchema)]
+struct Team {
+    name: String,
+    members: Vec<Member>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, server_less::ServerlessSchema)]
+struct Member {
+    name: String,
+    teams: Vec<Team>,
+}
+
+#[test]
+fn test_serverless_schema_mutual_recursion_terminates() {
+    use server_less::ServerlessSchema;
+    let schema = Team::json_schema();
+    let member = &schema["properties"]["members"]["items"];
+    assert_eq!(member["properties"]["name"]["type"], "string");
+    assert_eq!(
+        member["properties"]["teams"]["items"],
+        serde_json::json!({"type": "object"})
+    );
+    let schema = Member::json_schema();
+    let team = &schema["properties"]["teams"]["items"];
+    assert_eq!(team["properties"]["name"]["type"], "string");
+    assert_eq!(
+        team["properties"]["members"]["items"],
+        serde_json::json!({"type": "object"})
+    );
+}
+
+#[test]
+fn test_serverless_schema_body_property() {
+    let spec = ProfileService::openapi_spec();
+    let schema =
+        &spec["paths"]["/profiles"]["put"]["requestBody"]["content"]["application/json"]["schema"];
+    let profile = &schema["properties"]["profile"];
+    assert_eq!(profile["properties"]["displayName"]["type"], "string");
+    assert!(profile["properties"].get("display_name").is_none());
+    assert!(profile["properties"].get("cached").is_none());
+}
+
+#[derive(Debug, server_less::ServerlessError)]
+enum LookupError {
+    /// No user with that id
+    UserNotFound,
+    /// No team with that id
+    #[error(code = NotFound)]
+    TeamMissing,
+    /// Malformed id
+    InvalidInput,
+}
+
+#[derive(Clone)]
+struct LookupService;
+
+#[openapi]
+impl LookupService {
+    /// Get a user
+    pub fn get_user(&self, user_id: String) -> Result<String, LookupError> {
+        Ok(user_id)
+    }
+}
+
+#[test]
+fn test_error_enum_responses_by_status() {
+    let spec = LookupService::openapi_spec();
+    let responses = &spec["paths"]["/users/{user_id}"]["get"]["responses"];
+    let not_found = &responses["404"]["content"]["application/json"]["schema"];
+    // Both variants send `code: NOT_FOUND`, so a body matches either branch: `anyOf`.
+    assert!(not_found.get("oneOf").is_none());
+    let variants = not_found["anyOf"].as_array().unwrap();
+    assert_eq!(variants.len(), 2);
+    assert_eq!(variants[0]["title"], "UserNotFound");
+    assert_eq!(
+        variants[0]["properties"]["error"]["properties"]["code"]["enum"],
+        serde_json::json!(["NOT_FOUND"])
+    );
+    let invalid = &responses["400"]["content"]["application/json"]["schema"];
+    assert_eq!(invalid["title"], "InvalidInput");
+    assert!(responses.get("500").is_none());
+}
+
+#[derive(Clone)]
+struct RenamedOpService;
+
+#[openapi]
+impl RenamedOpService {
+    #[route(operation_id = "fetchWidget")]
+    pub fn get_widget(&self, id: u32) -> u32 {
+        id
+    }
+}
+
+#[test]
+fn test_route_operation_id_override() {
+    let spec = RenamedOpService::openapi_spec();
+    assert_eq!(spec["paths"]["/widgets/{id}"]["get"]["operationId"], "fetchWidget");
+}
+
+#[derive(Clone)]
+struct ExportService;
+
+#[openapi]
+impl ExportService {
+    #[response(status = 200, content_type = "application/json")]
+    #[response(status = 206, content_type = "application/octet-stream")]
+    pub fn get_export(&self, id: u32) -> u32 {
+        id
+    }
+}
+
+#[test]
+fn test_response_additional_statuses() {
+    let spec = ExportService::openapi_spec();
+    let responses = &spec["paths"]["/exports/{id}"]["get"]["responses"];
+    assert!(responses["200"]["content"]["application/json"].is_object());
+    assert_eq!(
+        responses["206"]["content"]["application/octet-stream"]["schema"]["format"],
+        "binary"
+    );
+}
+
+#[allow(unused_imports)]
+use server_less::webhook;
+
+#[derive(Clone)]
+struct EventService;
+
+#[openapi]
+impl EventService {
+    pub fn list_events(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// A new event was recorded
+    #[webhook(name = "eventRecorded")]
+    pub fn event_recorded(&self, id: u32) {
+        let _ = id;
+    }
+}
+
+#[test]
+fn test_openapi_webhooks() {
+    let spec = EventService::openapi_spec();
+    assert_eq!(spec["openapi"], "3.1.0");
+    let recorded = &spec["webhooks"]["eventRecorded"]["post"];
+    assert_eq!(recorded["summary"], "A new event was recorded");
+    assert_eq!(recorded["tags"], serde_json::json!(["Event"]));
+    assert!(spec["paths"].get("/event-recordeds").is_none());
+}
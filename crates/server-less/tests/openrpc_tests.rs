@@ -165,3 +165,123 @@ fn test_openrpc_with_jsonrpc() {
     let methods = CombinedService::jsonrpc_methods();
     assert!(methods.contains(&"greet".to_string()));
 }
+
+// Servers block and error definitions
+#[derive(Debug, server_less::ServerlessError)]
+enum AccountError {
+    #[error(code = NotFound, message = "Account not found", app_code = "ACCOUNT_NOT_FOUND")]
+    AccountNotFound,
+    #[error(code = InvalidInput, jsonrpc_code = -32602)]
+    InvalidAmount,
+}
+
+#[derive(Clone)]
+struct Bank;
+
+#[openrpc(
+    title = "Bank API",
+    version = "3.1.0",
+    servers = ["http://localhost:3000/rpc", "https://bank.example.com/rpc"]
+)]
+impl Bank {
+    pub fn balance(&self, account_id: String) -> Result<i64, AccountError> {
+        Err(AccountError::AccountNotFound)
+    }
+
+    pub fn withdraw(&self, account_id: String, amount: i64) -> Result<i64, AccountError> {
+        Err(AccountError::InvalidAmount)
+    }
+
+    pub fn ping(&self) -> Result<String, String> {
+        Ok("pong".to_string())
+    }
+}
+
+#[test]
+fn test_openrpc_servers_block() {
+    let spec = Bank::openrpc_spec();
+    let servers = spec["servers"].as_array().unwrap();
+    assert_eq!(servers.len(), 2);
+    assert_eq!(servers[0]["url"], "http://localhost:3000/rpc");
+    assert_eq!(servers[1]["url"], "https://bank.example.com/rpc");
+    assert_eq!(spec["info"]["title"], "Bank API");
+    assert_eq!(spec["info"]["version"], "3.1.0");
+}
+
+#[test]
+fn test_openrpc_no_servers_by_default() {
+    let spec = Calculator::openrpc_spec();
+    assert!(spec.get("servers").is_none());
+    assert!(spec.get("components").is_none());
+}
+
+#[test]
+fn test_openrpc_method_errors() {
+    let spec = Bank::openrpc_spec();
+    let methods = spec["methods"].as_array().unwrap();
+
+    let balance = methods.iter().find(|m| m["name"] == "balance").unwrap();
+    let errors = balance["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0]["$ref"], "#/components/errors/AccountNotFound");
+    assert_eq!(errors[1]["$ref"], "#/components/errors/InvalidAmount");
+
+    // Error types without variant metadata produce no `errors` list
+    let ping = methods.iter().find(|m| m["name"] == "ping").unwrap();
+    assert!(ping.get("errors").is_none());
+}
+
+#[test]
+fn test_openrpc_error_components() {
+    let spec = Bank::openrpc_spec();
+    let errors = &spec["components"]["errors"];
+
+    assert_eq!(errors["AccountNotFound"]["code"], -32002);
+    assert_eq!(errors["AccountNotFound"]["message"], "Account not found");
+    assert_eq!(errors["AccountNotFound"]["data"]["code"], "NOT_FOUND");
+    assert_eq!(errors["AccountNotFound"]["data"]["app_code"], "ACCOUNT_NOT_FOUND");
+    assert!(errors["InvalidAmount"]["data"].get("app_code").is_none());
+    assert_eq!(errors["InvalidAmount"]["code"], -32602);
+    assert_eq!(errors["InvalidAmount"]["message"], "Invalid amount");
+}
+
+// ============================================================================
+// #[deprecated] and multi-line docs
+// ============================================================================
+
+#[derive(Clone)]
+struct LegacyApi;
+
+#[openrpc]
+impl LegacyApi {
+    /// Look up a record
+    ///
+    /// Returns "null" when the record is missing.
+    #[deprecated(since = "0.8.0", note = "use `find_record`")]
+    pub fn lookup(&self, key: String) -> Option<String> {
+        None
+    }
+
+    /// Look up a record by key
+    pub fn find_record(&self, key: String) -> Option<String> {
+        None
+    }
+}
+
+#[test]
+fn test_openrpc_deprecated_attribute() {
+    let spec = LegacyApi::openrpc_spec();
+    let methods = spec["methods"].as_array().unwrap();
+    assert_eq!(methods.len(), 2);
+
+    let lookup = methods.iter().find(|m| m["name"] == "lookup").unwrap();
+    assert_eq!(lookup["deprecated"], true);
+    assert_eq!(
+        lookup["description"],
+        "Look up a record\n\nReturns \"null\" when the record is missing.\n\n\
+         Deprecated since 0.8.0: use `find_record`"
+    );
+
+    let find = methods.iter().find(|m| m["name"] == "findRecord").unwrap();
+    assert!(find.get("deprecated").is_none());
+}
@@ -0,0 +1,62 @@
+//! Schema generators built without any runtime protocol.
+//!
+//! CI also runs this file with only the schema features enabled
+//! (`--no-default-features --features grpc,capnp,...`), so the generated code
+//! must compile without axum, tokio or the other runtime dependencies.
+
+#![allow(dead_code)]
+#![allow(unused_variables)]
+
+use serde::{Deserialize, Serialize};
+use server_less::{asyncapi, capnp, connect, grpc, jsonschema, markdown, openapi, openrpc, smithy, thrift};
+
+#[derive(Serialize, Deserialize)]
+struct Note {
+    id: u32,
+    text: String,
+}
+
+#[derive(Clone)]
+struct NoteService;
+
+// Stacked in priority order; the first macro emits the impl block.
+#[openapi]
+#[openrpc(title = "Notes")]
+#[grpc(package = "notes.v1")]
+#[capnp(id = "0x85150b117366d14c")]
+#[thrift(namespace = "notes")]
+#[smithy(namespace = "com.example.notes")]
+#[connect(package = "notes.v1")]
+#[asyncapi(title = "Notes")]
+#[jsonschema(title = "Notes")]
+#[markdown(title = "Notes")]
+impl NoteService {
+    /// Get a note
+    fn get_note(&self, id: u32) -> Option<Note> {
+        None
+    }
+
+    /// Create a note
+    fn create_note(&self, text: String) -> Note {
+        Note { id: 1, text }
+    }
+}
+
+/// Every generator names the operation differently (`GetNote`, `getNote`, `get_note`).
+fn mentions_get_note(output: impl ToString) -> bool {
+    output.to_string().to_lowercase().replace('_', "").contains("getnote")
+}
+
+#[test]
+fn test_schema_generators_without_runtime() {
+    assert!(NoteService::openapi_spec().to_string().contains("/notes"));
+    assert!(mentions_get_note(NoteService::openrpc_spec()));
+    assert!(mentions_get_note(NoteService::grpc_schema()));
+    assert!(mentions_get_note(NoteService::capnp_schema()));
+    assert!(mentions_get_note(NoteService::thrift_schema()));
+    assert!(mentions_get_note(NoteService::smithy_schema()));
+    assert!(mentions_get_note(NoteService::connect_schema()));
+    assert!(mentions_get_note(NoteService::asyncapi_spec()));
+    assert!(mentions_get_note(NoteService::json_schema()));
+    assert!(mentions_get_note(NoteService::markdown_docs()));
+}
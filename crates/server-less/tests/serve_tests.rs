@@ -3,7 +3,7 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use server_less::{http, jsonrpc, serve, ws};
+use server_less::{http, jsonrpc, serve, server, ws};
 
 #[derive(Clone)]
 struct MultiService {
@@ -162,6 +162,58 @@ fn test_serve_jsonrpc_only() {
     let _router = service.router();
 }
 
+// Every protocol shares one instance instead of cloning the service
+struct CloneCountingService {
+    clones: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+}
+
+impl Clone for CloneCountingService {
+    fn clone(&self) -> Self {
+        self.clones
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        Self {
+            clones: self.clones.clone(),
+        }
+    }
+}
+
+#[http]
+#[jsonrpc(path = "/rpc")]
+#[ws(path = "/ws")]
+#[serve(http, jsonrpc, ws, readiness)]
+impl CloneCountingService {
+    pub fn get_clones(&self) -> usize {
+        self.clones.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    #[server(skip)]
+    pub fn health_check(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn test_serve_router_shares_one_service_instance() {
+    let clones = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let _router = CloneCountingService {
+        clones: clones.clone(),
+    }
+    .router();
+    assert_eq!(clones.load(std::sync::atomic::Ordering::SeqCst), 0);
+}
+
+#[test]
+fn test_router_arc_variants_share_the_arc() {
+    let service = std::sync::Arc::new(CloneCountingService {
+        clones: Default::default(),
+    });
+    let _http = service.clone().http_router_arc();
+    let _rpc = service.clone().jsonrpc_router_arc();
+    let _ws = service.clone().ws_router_arc();
+    assert_eq!(service.get_clones(), 0);
+    assert_eq!(std::sync::Arc::strong_count(&service), 4);
+}
+
 // ============================================================================
 // OpenAPI Integration Tests
 // ============================================================================
@@ -233,3 +285,687 @@ fn test_serve_openapi_disabled() {
     // Note: combined openapi_spec() should NOT exist on this type
     // (verified by the fact that it compiles without the method)
 }
+
+// Readiness-backed health route
+#[derive(Clone)]
+struct ReadyService {
+    ready: std::sync::Arc<std::sync::atomic::AtomicBool>,
+}
+
+#[http]
+#[serve(http, readiness)]
+impl ReadyService {
+    pub fn list_items(&self) -> Vec<String> {
+        vec![]
+    }
+
+    #[server(skip)]
+    pub async fn health_check(&self) -> bool {
+        self.ready.load(std::sync::atomic::Ordering::SeqCst)
+    }
+}
+
+async fn get_status(router: axum::Router, path: &str) -> axum::http::StatusCode {
+    use tower::ServiceExt;
+    let request = axum::http::Request::builder()
+        .uri(path)
+        .body(axum::body::Body::empty())
+        .unwrap();
+    router.oneshot(request).await.unwrap().status()
+}
+
+#[tokio::test]
+async fn test_serve_readiness_reflects_health_check() {
+    let ready = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(true));
+    let service = ReadyService { ready: ready.clone() };
+    let router = service.router();
+
+    assert_eq!(get_status(router.clone(), "/health").await, axum::http::StatusCode::OK);
+
+    ready.store(false, std::sync::atomic::Ordering::SeqCst);
+    assert_eq!(
+        get_status(router, "/health").await,
+        axum::http::StatusCode::SERVICE_UNAVAILABLE
+    );
+}
+
+#[tokio::test]
+async fn test_serve_readiness_method_not_routed() {
+    let ready = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(true));
+    let router = ReadyService { ready }.router();
+    assert_eq!(
+        get_status(router, "/health-checks").await,
+        axum::http::StatusCode::NOT_FOUND
+    );
+}
+
+// Named, sync readiness method returning Result
+#[derive(Clone)]
+struct NamedReadyService {
+    db_up: bool,
+}
+
+#[http]
+#[serve(http, health = "/healthz", readiness = "check_db")]
+impl NamedReadyService {
+    pub fn list_items(&self) -> Vec<String> {
+        vec![]
+    }
+
+    #[server(skip)]
+    pub fn check_db(&self) -> Result<(), String> {
+        if self.db_up { Ok(()) } else { Err("database unreachable".to_string()) }
+    }
+}
+
+#[tokio::test]
+async fn test_serve_readiness_named_result_method() {
+    let up = NamedReadyService { db_up: true }.router();
+    assert_eq!(get_status(up, "/healthz").await, axum::http::StatusCode::OK);
+
+    let down = NamedReadyService { db_up: false }.router();
+    assert_eq!(
+        get_status(down, "/healthz").await,
+        axum::http::StatusCode::SERVICE_UNAVAILABLE
+    );
+}
+
+#[tokio::test]
+async fn test_serve_health_without_readiness_is_constant() {
+    let router = CustomHealthService.router();
+    assert_eq!(get_status(router, "/healthz").await, axum::http::StatusCode::OK);
+}
+
+// Separate liveness and readiness probes
+#[derive(Clone)]
+struct ProbeService {
+    ready: bool,
+}
+
+#[http]
+#[serve(http, health = "/healthz", ready = "/readyz")]
+impl ProbeService {
+    pub fn list_items(&self) -> Vec<String> {
+        vec![]
+    }
+
+    #[server(skip)]
+    pub async fn health_check(&self) -> Result<(), String> {
+        if self.ready { Ok(()) } else { Err("warming up".to_string()) }
+    }
+}
+
+#[tokio::test]
+async fn test_serve_separate_liveness_and_readiness() {
+    let ready = ProbeService { ready: true }.router();
+    assert_eq!(get_status(ready.clone(), "/healthz").await, axum::http::StatusCode::OK);
+    assert_eq!(get_status(ready, "/readyz").await, axum::http::StatusCode::OK);
+
+    // Liveness stays ok while readiness reports unavailable.
+    let not_ready = ProbeService { ready: false }.router();
+    assert_eq!(get_status(not_ready.clone(), "/healthz").await, axum::http::StatusCode::OK);
+    assert_eq!(
+        get_status(not_ready, "/readyz").await,
+        axum::http::StatusCode::SERVICE_UNAVAILABLE
+    );
+}
+
+// Async readiness method bounded by a timeout
+#[derive(Clone)]
+struct HangingProbeService {
+    check_ms: u64,
+}
+
+#[http]
+#[serve(http, readiness, readiness_timeout = 1)]
+impl HangingProbeService {
+    pub fn list_items(&self) -> Vec<String> {
+        vec![]
+    }
+
+    #[server(skip)]
+    pub async fn health_check(&self) -> bool {
+        tokio::time::sleep(std::time::Duration::from_millis(self.check_ms)).await;
+        true
+    }
+}
+
+#[tokio::test]
+async fn test_serve_readiness_timeout_answers_503() {
+    let quick = HangingProbeService { check_ms: 5 }.router();
+    assert_eq!(get_status(quick, "/health").await, axum::http::StatusCode::OK);
+
+    let hung = HangingProbeService { check_ms: 3000 }.router();
+    assert_eq!(
+        get_status(hung, "/health").await,
+        axum::http::StatusCode::SERVICE_UNAVAILABLE
+    );
+}
+
+// ============================================================================
+// Prometheus metrics
+// ============================================================================
+
+#[derive(Clone)]
+struct MeteredService;
+
+#[http]
+#[serve(http, metrics = "/metrics")]
+impl MeteredService {
+    pub fn get_item(&self, id: u32) -> Option<String> {
+        (id == 1).then(|| "one".to_string())
+    }
+}
+
+#[tokio::test]
+async fn test_serve_metrics_records_route_templates() {
+    use tower::ServiceExt;
+
+    let router = MeteredService.router();
+    assert_eq!(get_status(router.clone(), "/items/1").await, axum::http::StatusCode::OK);
+    assert_eq!(get_status(router.clone(), "/items/1").await, axum::http::StatusCode::OK);
+    assert_eq!(
+        get_status(router.clone(), "/items/2").await,
+        axum::http::StatusCode::NOT_FOUND
+    );
+
+    let request = axum::http::Request::builder()
+        .uri("/metrics")
+        .body(axum::body::Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), axum::http::StatusCode::OK);
+    assert!(
+        response.headers()[axum::http::header::CONTENT_TYPE]
+            .to_str()
+            .unwrap()
+            .starts_with("text/plain")
+    );
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let text = String::from_utf8(body.to_vec()).unwrap();
+
+    // Path parameters are collapsed into the route template.
+    assert!(text.contains(
+        r#"http_requests_total{method="GET",route="/items/{id}",status="200"} 2"#
+    ));
+    assert!(text.contains(
+        r#"http_requests_total{method="GET",route="/items/{id}",status="404"} 1"#
+    ));
+    assert!(text.contains("http_request_duration_seconds_bucket"));
+}
+
+// ============================================================================
+// JSON 404 fallback
+// ============================================================================
+
+#[derive(Clone)]
+struct PlainErrorsService;
+
+#[http]
+#[serve(http, json_errors = false)]
+impl PlainErrorsService {
+    pub fn get_item(&self, id: u32) -> Option<String> {
+        (id == 1).then(|| "one".to_string())
+    }
+}
+
+async fn get_body(router: axum::Router, path: &str) -> (axum::http::StatusCode, Vec<u8>) {
+    use tower::ServiceExt;
+
+    let request = axum::http::Request::builder()
+        .uri(path)
+        .body(axum::body::Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, body.to_vec())
+}
+
+#[tokio::test]
+async fn test_serve_unmatched_route_returns_json_404() {
+    let (status, body) = get_body(MeteredService.router(), "/no/such/route").await;
+    assert_eq!(status, axum::http::StatusCode::NOT_FOUND);
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["error"]["code"], "NOT_FOUND");
+    assert_eq!(json["error"]["details"]["path"], "/no/such/route");
+}
+
+#[tokio::test]
+async fn test_serve_json_errors_disabled() {
+    let (status, body) = get_body(PlainErrorsService.router(), "/no/such/route").await;
+    assert_eq!(status, axum::http::StatusCode::NOT_FOUND);
+    assert!(body.is_empty());
+}
+
+#[tokio::test]
+async fn test_serve_wrong_method_returns_json_405_with_allow() {
+    use tower::ServiceExt;
+
+    let request = axum::http::Request::builder()
+        .method("DELETE")
+        .uri("/items/1")
+        .body(axum::body::Body::empty())
+        .unwrap();
+    let response = MeteredService.router().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), axum::http::StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(response.headers()[axum::http::header::ALLOW], "GET,HEAD");
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["error"]["code"], "METHOD_NOT_ALLOWED");
+    assert_eq!(json["error"]["details"]["method"], "DELETE");
+    assert_eq!(json["error"]["details"]["path"], "/items/1");
+
+    let request = axum::http::Request::builder()
+        .method("DELETE")
+        .uri("/items/1")
+        .body(axum::body::Body::empty())
+        .unwrap();
+    let response = PlainErrorsService.router().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), axum::http::StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(response.headers()[axum::http::header::ALLOW], "GET,HEAD");
+}
+
+// Handler panics answered with a JSON 500
+#[derive(Clone)]
+struct PanickyService;
+
+#[http]
+#[serve(http, catch_panic)]
+impl PanickyService {
+    pub fn get_item(&self, id: u32) -> u32 {
+        if id == 0 {
+            panic!("item 0 is cursed");
+        }
+        id
+    }
+}
+
+#[tokio::test]
+async fn test_serve_catch_panic_returns_json_500() {
+    let (status, body) = get_body(PanickyService.router(), "/items/0").await;
+    assert_eq!(status, axum::http::StatusCode::INTERNAL_SERVER_ERROR);
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["error"]["code"], "INTERNAL");
+    assert!(!String::from_utf8_lossy(&body).contains("cursed"));
+
+    let (status, _) = get_body(PanickyService.router(), "/items/7").await;
+    assert_eq!(status, axum::http::StatusCode::OK);
+}
+
+#[derive(Clone)]
+struct MaintainedService;
+
+#[http]
+#[serve(http, maintenance(retry_after = 120))]
+impl MaintainedService {
+    pub fn list_items(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[tokio::test]
+async fn test_serve_maintenance_returns_503_except_health() {
+    let switch = server_less::MaintenanceSwitch::new();
+    let router = MaintainedService.router_with_maintenance(switch.clone());
+    assert!(!switch.is_on());
+
+    let (status, _) = get_body(router.clone(), "/items").await;
+    assert_eq!(status, axum::http::StatusCode::OK);
+
+    switch.set(true);
+    let response = get_response(router.clone(), "/items").await;
+    assert_eq!(
+        response.status(),
+        axum::http::StatusCode::SERVICE_UNAVAILABLE
+    );
+    assert_eq!(response.headers()["retry-after"], "120");
+    let (status, _) = get_body(router.clone(), "/health").await;
+    assert_eq!(status, axum::http::StatusCode::OK);
+    // Each router has its own switch.
+    let (status, _) = get_body(MaintainedService.router(), "/items").await;
+    assert_eq!(status, axum::http::StatusCode::OK);
+
+    switch.set(false);
+    let (status, _) = get_body(router, "/items").await;
+    assert_eq!(status, axum::http::StatusCode::OK);
+}
+
+#[derive(Clone)]
+struct DiscoverableService;
+
+#[http]
+#[serve(http, discovery)]
+impl DiscoverableService {
+    pub fn list_items(&self) -> Vec<String> {
+        vec![]
+    }
+
+    pub fn delete_item(&self, id: u32) {}
+}
+
+#[tokio::test]
+async fn test_serve_discovery_lists_routes() {
+    let (status, body) = get_body(DiscoverableService.router(), "/_routes").await;
+    assert_eq!(status, axum::http::StatusCode::OK);
+    let routes: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(
+        routes,
+        serde_json::json!([
+            {"method": "GET", "path": "/items", "operationId": "list_items"},
+            {"method": "DELETE", "path": "/items/{id}", "operationId": "delete_item"},
+        ])
+    );
+}
+
+// ============================================================================
+// Unix domain socket
+// ============================================================================
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_serve_unix_answers_over_socket() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let path = std::env::temp_dir().join(format!("server-less-{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    tokio::spawn(HttpOnlyService.serve_unix(path.clone()));
+
+    let mut stream = loop {
+        match tokio::net::UnixStream::connect(&path).await {
+            Ok(stream) => break stream,
+            Err(_) => tokio::time::sleep(std::time::Duration::from_millis(10)).await,
+        }
+    };
+    stream
+        .write_all(b"GET /health HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+        .await
+        .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(response.starts_with("HTTP/1.1 200 OK"), "{response}");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_serve_unix_with_maintenance_answers_503() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let path = std::env::temp_dir().join(format!(
+        "server-less-maintenance-{}.sock",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    let switch = server_less::MaintenanceSwitch::new();
+    switch.set(true);
+    tokio::spawn(MaintainedService.serve_unix_with_maintenance(path.clone(), switch));
+
+    let mut stream = loop {
+        match tokio::net::UnixStream::connect(&path).await {
+            Ok(stream) => break stream,
+            Err(_) => tokio::time::sleep(std::time::Duration::from_millis(10)).await,
+        }
+    };
+    stream
+        .write_all(b"GET /items HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+        .await
+        .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(
+        response.starts_with("HTTP/1.1 503 Service Unavailable"),
+        "{response}"
+    );
+}
+
+// ============================================================================
+// Trailing-slash redirect
+// ============================================================================
+
+#[derive(Clone)]
+struct SlashService;
+
+#[http]
+#[serve(http, redirect_slashes)]
+impl SlashService {
+    pub fn list_items(&self, tag: Option<String>) -> Vec<String> {
+        tag.into_iter().collect()
+    }
+}
+
+async fn get_response(router: axum::Router, path: &str) -> axum::response::Response {
+    use tower::ServiceExt;
+
+    let request = axum::http::Request::builder()
+        .uri(path)
+        .body(axum::body::Body::empty())
+        .unwrap();
+    router.oneshot(request).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_serve_redirects_trailing_slash() {
+    let response = get_response(SlashService.router(), "/items/?tag=new").await;
+    assert_eq!(response.status(), axum::http::StatusCode::PERMANENT_REDIRECT);
+    assert_eq!(response.headers()["location"], "/items?tag=new");
+
+    let (status, _) = get_body(SlashService.router(), "/items").await;
+    assert_eq!(status, axum::http::StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_serve_redirect_slashes_leaves_root_and_protocol_relative_paths() {
+    let (status, _) = get_body(SlashService.router(), "/").await;
+    assert_eq!(status, axum::http::StatusCode::NOT_FOUND);
+    let (status, _) = get_body(SlashService.router(), "//evil.example/").await;
+    assert_eq!(status, axum::http::StatusCode::NOT_FOUND);
+    let (status, _) = get_body(SlashService.router(), "/\\evil.example/").await;
+    assert_eq!(status, axum::http::StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_serve_trailing_slash_not_redirected_by_default() {
+    let (status, _) = get_body(MeteredService.router(), "/items/1/").await;
+    assert_eq!(status, axum::http::StatusCode::NOT_FOUND);
+}
+
+// ============================================================================
+// Mounted services
+// ============================================================================
+
+#[derive(Clone)]
+struct AccountService;
+
+#[http]
+impl AccountService {
+    pub fn get_account(&self, id: u32) -> String {
+        format!("account-{id}")
+    }
+}
+
+#[derive(Clone)]
+struct BillingService {
+    currency: String,
+}
+
+impl BillingService {
+    fn new() -> Self {
+        Self {
+            currency: "EUR".to_string(),
+        }
+    }
+}
+
+#[http]
+impl BillingService {
+    pub fn list_invoices(&self) -> Vec<String> {
+        vec![format!("invoice-{}", self.currency)]
+    }
+}
+
+#[derive(Clone)]
+struct GatewayService;
+
+#[http]
+#[serve(
+    http,
+    mount("/v1/accounts", AccountService),
+    mount("/v1/billing", BillingService = BillingService::new())
+)]
+impl GatewayService {
+    pub fn get_status(&self) -> String {
+        "up".to_string()
+    }
+}
+
+#[tokio::test]
+async fn test_serve_mounts_nest_service_routers() {
+    let (status, body) = get_body(GatewayService.router(), "/v1/accounts/accounts/7").await;
+    assert_eq!(status, axum::http::StatusCode::OK);
+    assert_eq!(serde_json::from_slice::<String>(&body).unwrap(), "account-7");
+
+    let (status, body) = get_body(GatewayService.router(), "/v1/billing/invoices").await;
+    assert_eq!(status, axum::http::StatusCode::OK);
+    assert_eq!(
+        serde_json::from_slice::<Vec<String>>(&body).unwrap(),
+        ["invoice-EUR"]
+    );
+
+    let (status, _) = get_body(GatewayService.router(), "/status").await;
+    assert_eq!(status, axum::http::StatusCode::OK);
+}
+
+#[test]
+fn test_serve_mounts_prefix_openapi_paths() {
+    let spec = GatewayService::openapi_spec();
+    let paths = spec["paths"].as_object().unwrap();
+    assert!(paths.contains_key("/status"));
+    assert!(paths.contains_key("/v1/accounts/accounts/{id}"));
+    assert!(paths.contains_key("/v1/billing/invoices"));
+}
+
+// ============================================================================
+// Docs page
+// ============================================================================
+
+#[derive(Clone)]
+struct DocsService;
+
+#[http]
+#[serve(http, docs = "/docs", name = "Docs API")]
+impl DocsService {
+    pub fn get_item(&self, id: u32) -> u32 {
+        id
+    }
+}
+
+#[tokio::test]
+async fn test_serve_docs_page_points_at_spec() {
+    let (status, body) = get_body(DocsService.router(), "/docs").await;
+    assert_eq!(status, axum::http::StatusCode::OK);
+    let html = String::from_utf8(body).unwrap();
+    assert!(html.contains("swagger-ui"));
+    assert!(html.contains(r#"url: "/openapi.json""#));
+    assert!(html.contains("<title>Docs API</title>"));
+
+    let (status, _) = get_body(DocsService.router(), "/openapi.json").await;
+    assert_eq!(status, axum::http::StatusCode::OK);
+}
+
+// ============================================================================
+// CORS
+// ============================================================================
+
+#[derive(Clone)]
+struct CorsService;
+
+#[http]
+#[serve(
+    http,
+    cors(credentials, max_age = 3600, origins = "https://a.com,https://b.com")
+)]
+impl CorsService {
+    pub fn list_notes(&self) -> Vec<String> {
+        vec![]
+    }
+
+    pub fn delete_note(
+        &self,
+        id: u32,
+        #[param(header, name = "X-Api-Key")] api_key: Option<String>,
+    ) -> bool {
+        true
+    }
+}
+
+async fn cors_request(
+    method: &str,
+    path: &str,
+    headers: &[(&str, &str)],
+) -> axum::response::Response {
+    use tower::ServiceExt;
+
+    let mut request = axum::http::Request::builder().method(method).uri(path);
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    let request = request.body(axum::body::Body::empty()).unwrap();
+    CorsService.router().oneshot(request).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_serve_cors_preflight_uses_route_table() {
+    let response = cors_request(
+        "OPTIONS",
+        "/notes/1",
+        &[
+            ("origin", "https://b.com"),
+            ("access-control-request-method", "DELETE"),
+        ],
+    )
+    .await;
+    assert_eq!(response.status(), axum::http::StatusCode::NO_CONTENT);
+    let headers = response.headers();
+    assert_eq!(headers["access-control-allow-origin"], "https://b.com");
+    assert_eq!(headers["access-control-allow-credentials"], "true");
+    assert_eq!(headers["access-control-allow-methods"], "GET, DELETE");
+    assert_eq!(
+        headers["access-control-allow-headers"],
+        "content-type, x-api-key"
+    );
+    assert_eq!(headers["access-control-max-age"], "3600");
+}
+
+#[tokio::test]
+async fn test_serve_cors_marks_allowed_origins_only() {
+    let response = cors_request("GET", "/notes", &[("origin", "https://a.com")]).await;
+    assert_eq!(response.status(), axum::http::StatusCode::OK);
+    assert_eq!(
+        response.headers()["access-control-allow-origin"],
+        "https://a.com"
+    );
+    assert_eq!(response.headers()["vary"], "origin");
+
+    let response = cors_request("GET", "/notes", &[("origin", "https://evil.com")]).await;
+    assert_eq!(response.status(), axum::http::StatusCode::OK);
+    assert!(
+        !response
+            .headers()
+            .contains_key("access-control-allow-origin")
+    );
+
+    let response = cors_request(
+        "OPTIONS",
+        "/notes",
+        &[
+            ("origin", "https://evil.com"),
+            ("access-control-request-method", "GET"),
+        ],
+    )
+    .await;
+    assert!(!response.headers().contains_key("access-control-allow-methods"));
+}
@@ -109,6 +109,37 @@ fn test_server_custom_health() {
     let _router = service.router();
 }
 
+// Server whose health route reports readiness
+#[derive(Clone)]
+struct ReadinessServer {
+    ready: bool,
+}
+
+#[server(readiness)]
+impl ReadinessServer {
+    pub fn list_items(&self) -> Vec<String> {
+        vec![]
+    }
+
+    #[server(skip)]
+    pub async fn health_check(&self) -> bool {
+        self.ready
+    }
+}
+
+#[tokio::test]
+async fn test_server_readiness() {
+    use tower::ServiceExt;
+    for (ready, expected) in [(true, 200), (false, 503)] {
+        let request = axum::http::Request::builder()
+            .uri("/health")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = ReadinessServer { ready }.router().oneshot(request).await.unwrap();
+        assert_eq!(response.status().as_u16(), expected);
+    }
+}
+
 // Server with all options
 #[derive(Clone)]
 struct FullServer;
@@ -114,6 +114,7 @@ fn test_ws_handle_unknown_method() {
     assert!(response.is_ok());
 
     let json: serde_json::Value = serde_json::from_str(&response.unwrap()).unwrap();
+    assert_eq!(json["error"]["code"], "NOT_FOUND");
     assert!(
         json["error"]["message"]
             .as_str()
@@ -122,6 +123,30 @@ fn test_ws_handle_unknown_method() {
     );
 }
 
+#[test]
+fn test_ws_handle_positional_params() {
+    let service = TestService::new();
+    let response = service
+        .ws_handle_message(r#"{"method": "search", "params": ["item", 1], "id": 1}"#)
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&response).unwrap();
+    assert_eq!(json["result"], serde_json::json!([{"id": 0, "name": "item 0"}]));
+
+    // Trailing optional params may be omitted
+    let response = service
+        .ws_handle_message(r#"{"method": "search", "params": ["item"]}"#)
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&response).unwrap();
+    assert_eq!(json["result"].as_array().unwrap().len(), 10);
+
+    // Named params keep working
+    let response = service
+        .ws_handle_message(r#"{"method": "add", "params": {"b": 3, "a": 2}}"#)
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&response).unwrap();
+    assert_eq!(json["result"], 5);
+}
+
 #[test]
 fn test_ws_handle_missing_param() {
     let service = TestService::new();
@@ -129,6 +154,7 @@ fn test_ws_handle_missing_param() {
     assert!(response.is_ok());
 
     let json: serde_json::Value = serde_json::from_str(&response.unwrap()).unwrap();
+    assert_eq!(json["error"]["code"], "INVALID_INPUT");
     assert!(
         json["error"]["message"]
             .as_str()
@@ -600,3 +626,301 @@ fn test_ws_hidden_method_not_in_openapi_summary() {
     assert!(extra_str.contains("public_ws"), "public_ws must be in WS openapi extra");
     assert!(!extra_str.contains("hidden_ws"), "hidden_ws must not be in WS openapi extra");
 }
+
+// ============================================================================
+// Error envelope
+// ============================================================================
+
+#[derive(Debug, server_less::ServerlessError)]
+enum LookupError {
+    #[error(code = NotFound, message = "Item not found")]
+    Missing,
+}
+
+#[derive(Clone)]
+struct LookupWs;
+
+#[ws(path = "/lookup")]
+impl LookupWs {
+    pub fn find(&self, id: u32) -> Result<u32, LookupError> {
+        if id == 0 { Err(LookupError::Missing) } else { Ok(id) }
+    }
+}
+
+#[test]
+fn test_ws_method_error_uses_error_code() {
+    let response = LookupWs
+        .ws_handle_message(r#"{"method": "find", "params": {"id": 0}, "id": 3}"#)
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&response).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "error": {"code": "NOT_FOUND", "message": "Item not found"},
+            "id": 3
+        })
+    );
+}
+
+// ============================================================================
+// Strict JSON-RPC 2.0 mode
+// ============================================================================
+
+#[derive(Clone)]
+struct RpcWs;
+
+#[ws(path = "/rpc", jsonrpc = true)]
+impl RpcWs {
+    pub fn add(&self, a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    pub fn find(&self, id: u32) -> Result<u32, LookupError> {
+        if id == 0 { Err(LookupError::Missing) } else { Ok(id) }
+    }
+
+    pub async fn double(&self, n: i32) -> i32 {
+        n * 2
+    }
+}
+
+fn rpc_call(message: &str) -> serde_json::Value {
+    serde_json::from_str(&RpcWs.ws_handle_message(message).unwrap()).unwrap()
+}
+
+#[test]
+fn test_ws_jsonrpc_result_envelope() {
+    assert_eq!(
+        rpc_call(r#"{"jsonrpc": "2.0", "method": "add", "params": {"a": 2, "b": 3}, "id": 1}"#),
+        serde_json::json!({"jsonrpc": "2.0", "result": 5, "id": 1})
+    );
+}
+
+#[test]
+fn test_ws_jsonrpc_standard_error_codes() {
+    let json = rpc_call(r#"{"jsonrpc": "2.0", "method": "nope", "id": 1}"#);
+    assert_eq!(json["jsonrpc"], "2.0");
+    assert_eq!(json["error"]["code"], -32601);
+    assert_eq!(json["id"], 1);
+
+    let json = rpc_call(r#"{"jsonrpc": "2.0", "method": "add", "params": {"a": 2}, "id": 2}"#);
+    assert_eq!(json["error"]["code"], -32602);
+
+    let json = rpc_call(r#"{"jsonrpc": "2.0", "method": "find", "params": {"id": 0}, "id": 3}"#);
+    assert_eq!(
+        json["error"],
+        serde_json::json!({"code": -32002, "message": "Item not found"})
+    );
+
+    let json = rpc_call(r#"{"method": "add", "params": {"a": 1, "b": 1}, "id": 4}"#);
+    assert_eq!(json["error"]["code"], -32600);
+    assert_eq!(json["id"], 4);
+
+    let json = rpc_call("not json");
+    assert_eq!(json["error"]["code"], -32700);
+    assert_eq!(json["id"], serde_json::Value::Null);
+}
+
+#[test]
+fn test_ws_jsonrpc_notification_has_no_reply() {
+    let reply = RpcWs
+        .ws_handle_message(r#"{"jsonrpc": "2.0", "method": "add", "params": {"a": 1, "b": 1}}"#)
+        .unwrap();
+    assert!(reply.is_empty());
+}
+
+#[tokio::test]
+async fn test_ws_jsonrpc_batch() {
+    let reply = RpcWs
+        .ws_handle_message_async(
+            r#"[
+                {"jsonrpc": "2.0", "method": "double", "params": {"n": 4}, "id": 1},
+                {"jsonrpc": "2.0", "method": "add", "params": {"a": 1, "b": 1}},
+                {"jsonrpc": "2.0", "method": "missing", "id": 2}
+            ]"#,
+        )
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&reply).unwrap();
+    let responses = json.as_array().unwrap();
+    assert_eq!(responses.len(), 2);
+    assert_eq!(responses[0], serde_json::json!({"jsonrpc": "2.0", "result": 8, "id": 1}));
+    assert_eq!(responses[1]["error"]["code"], -32601);
+
+    let json = rpc_call("[]");
+    assert_eq!(json["error"]["code"], -32600);
+}
+
+// ============================================================================
+// Message size limit
+// ============================================================================
+
+#[derive(Clone)]
+struct LimitedWs;
+
+#[ws(path = "/ws", max_message = "1kb")]
+impl LimitedWs {
+    pub fn echo(&self, message: String) -> String {
+        message
+    }
+}
+
+#[derive(Clone)]
+struct StrictLimitWs;
+
+#[ws(path = "/ws", jsonrpc = true, max_message = 64)]
+impl StrictLimitWs {
+    pub fn echo(&self, message: String) -> String {
+        message
+    }
+}
+
+/// Serve `router` on an ephemeral port and open a WebSocket to `/ws`.
+async fn connect(
+    router: axum::Router,
+) -> tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>> {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, router).await.unwrap();
+    });
+    let (socket, _) = tokio_tungstenite::connect_async(format!("ws://{addr}/ws"))
+        .await
+        .unwrap();
+    socket
+}
+
+async fn exchange(
+    socket: &mut tokio_tungstenite::WebSocketStream<
+        tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+    >,
+    text: String,
+) -> Option<tokio_tungstenite::tungstenite::Message> {
+    use futures::{SinkExt, StreamExt};
+
+    socket
+        .send(tokio_tungstenite::tungstenite::Message::text(text))
+        .await
+        .unwrap();
+    socket.next().await.map(Result::unwrap)
+}
+
+fn echo_request(message: &str) -> String {
+    serde_json::json!({"method": "echo", "params": {"message": message}, "id": 1}).to_string()
+}
+
+/// Whether the server's next message, after any error reply, closes the connection.
+async fn closes_after(
+    socket: &mut tokio_tungstenite::WebSocketStream<
+        tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+    >,
+) -> bool {
+    use futures::StreamExt;
+
+    matches!(
+        socket.next().await,
+        Some(Ok(tokio_tungstenite::tungstenite::Message::Close(_))) | Some(Err(_)) | None
+    )
+}
+
+#[tokio::test]
+async fn test_ws_rejects_oversized_message_and_closes() {
+    let mut socket = connect(LimitedWs.ws_router()).await;
+
+    let reply = exchange(&mut socket, echo_request("hi")).await.unwrap();
+    let json: serde_json::Value = serde_json::from_str(reply.to_text().unwrap()).unwrap();
+    assert_eq!(json["result"], "hi");
+
+    let reply = exchange(&mut socket, echo_request(&"x".repeat(2048)))
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(reply.to_text().unwrap()).unwrap();
+    assert_eq!(json["error"]["code"], "INVALID_INPUT");
+    assert!(
+        json["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("limit 1024 bytes")
+    );
+    assert!(closes_after(&mut socket).await);
+}
+
+#[tokio::test]
+async fn test_ws_rejects_oversized_binary_frame() {
+    use futures::{SinkExt, StreamExt};
+
+    let mut socket = connect(StrictLimitWs.ws_router()).await;
+
+    socket
+        .send(tokio_tungstenite::tungstenite::Message::binary(vec![0u8; 100]))
+        .await
+        .unwrap();
+    let reply = socket.next().await.unwrap().unwrap();
+    let json: serde_json::Value = serde_json::from_str(reply.to_text().unwrap()).unwrap();
+    assert_eq!(json["jsonrpc"], "2.0");
+    assert_eq!(json["error"]["code"], -32600);
+    assert!(closes_after(&mut socket).await);
+}
+
+// ============================================================================
+// Subprotocol negotiation
+// ============================================================================
+
+#[derive(Clone)]
+struct ProtocolWs;
+
+#[ws(path = "/ws", subprotocols = "json-rpc, graphql-ws")]
+impl ProtocolWs {
+    pub fn echo(&self, message: String) -> String {
+        message
+    }
+}
+
+/// Upgrade to `/ws` offering `offered` subprotocols; returns the one the server echoed.
+async fn negotiate(router: axum::Router, offered: &str) -> Option<String> {
+    use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, router).await.unwrap();
+    });
+    let mut request = format!("ws://{addr}/ws").into_client_request().unwrap();
+    request
+        .headers_mut()
+        .insert("sec-websocket-protocol", offered.parse().unwrap());
+    // tungstenite rejects a handshake whose answer lacks an offered protocol, so
+    // a failed connect means nothing was selected.
+    let (_, response) = tokio_tungstenite::connect_async(request).await.ok()?;
+    response
+        .headers()
+        .get("sec-websocket-protocol")
+        .map(|v| v.to_str().unwrap().to_string())
+}
+
+#[tokio::test]
+async fn test_ws_negotiates_subprotocol() {
+    assert_eq!(
+        negotiate(ProtocolWs.ws_router(), "graphql-ws")
+            .await
+            .as_deref(),
+        Some("graphql-ws")
+    );
+    assert_eq!(
+        negotiate(ProtocolWs.ws_router(), "mqtt, json-rpc")
+            .await
+            .as_deref(),
+        Some("json-rpc")
+    );
+    assert_eq!(negotiate(ProtocolWs.ws_router(), "mqtt").await, None);
+}
+
+#[test]
+fn test_ws_subprotocols_in_openapi() {
+    let paths = ProtocolWs::ws_openapi_paths();
+    let extra = &paths[0].operation.extra["x-websocket-protocol"];
+    assert_eq!(
+        extra["subprotocols"],
+        serde_json::json!(["json-rpc", "graphql-ws"])
+    );
+}